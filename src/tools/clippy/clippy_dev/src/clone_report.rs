@@ -0,0 +1,316 @@
+//! Runs only the `redundant_clone` lint on a workspace and prints its findings, grouped by file
+//! and sorted so that the files where removing the redundant clones saves the most allocated
+//! bytes come first.
+
+use serde_json::Value;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::fs;
+use std::ops::AddAssign;
+use std::process::{Command, Stdio};
+
+const LINT_CODE: &str = "clippy::redundant_clone";
+
+/// The size of the allocations avoided by removing redundant clones, as estimated by the notes
+/// `redundant_clone` adds to its warnings, like `removing the clone avoids allocating 8 bytes`.
+///
+/// The per-element size grows with the length of the collection, so it is compared first.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Savings {
+    /// The bytes allocated for each element of a cloned `Vec` or `VecDeque`
+    pub per_element: u64,
+    /// The bytes allocated for the contents of a cloned `Box`
+    pub bytes: u64,
+}
+
+impl Savings {
+    /// Parses the note of a `redundant_clone` warning, like
+    /// `removing the clone avoids allocating 12 bytes per element`.
+    fn from_note(note: &str) -> Option<Self> {
+        let size = note
+            .strip_prefix("removing the clone avoids allocating ")
+            .or_else(|| note.strip_prefix("removing each clone avoids allocating "))?;
+        let (size, per_element) = match size.strip_suffix(" per element") {
+            Some(size) => (size, true),
+            None => (size, false),
+        };
+        let size = size
+            .strip_suffix(" bytes")
+            .or_else(|| size.strip_suffix(" byte"))?
+            .parse()
+            .ok()?;
+        let (per_element, bytes) = if per_element { (size, 0) } else { (0, size) };
+        Some(Self { per_element, bytes })
+    }
+}
+
+impl AddAssign for Savings {
+    fn add_assign(&mut self, other: Self) {
+        self.per_element += other.per_element;
+        self.bytes += other.bytes;
+    }
+}
+
+impl fmt::Display for Savings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let plural = |bytes| if bytes == 1 { "" } else { "s" };
+        match (self.bytes, self.per_element) {
+            (bytes, 0) => write!(f, "{} byte{}", bytes, plural(bytes)),
+            (0, per_element) => write!(f, "{} byte{} per element", per_element, plural(per_element)),
+            (bytes, per_element) => write!(
+                f,
+                "{} byte{} + {} byte{} per element",
+                bytes,
+                plural(bytes),
+                per_element,
+                plural(per_element)
+            ),
+        }
+    }
+}
+
+/// A single redundant clone.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Finding {
+    pub file: String,
+    pub line: u64,
+    pub column: u64,
+    /// The estimated savings of removing the clone, `None` if its size is not known statically,
+    /// like for a `String`
+    pub savings: Option<Savings>,
+}
+
+/// The findings of a workspace, grouped by file.
+#[derive(Debug, PartialEq)]
+pub struct Report {
+    /// The files with the most estimated savings come first, then the ones with the most
+    /// findings, then the rest is sorted by name. The findings of a file are sorted by estimated
+    /// savings, then by position.
+    pub files: Vec<(String, Vec<Finding>)>,
+}
+
+impl Report {
+    /// Builds the report from the output of `cargo clippy --message-format=json`. Lines which
+    /// are not JSON, messages of other lints and duplicated messages (e.g. from the library and
+    /// the test target of a crate) are ignored.
+    #[must_use]
+    pub fn from_messages(messages: &str) -> Self {
+        let findings: BTreeSet<Finding> = messages
+            .lines()
+            .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+            .filter(|msg| msg["reason"] == "compiler-message" && msg["message"]["code"]["code"] == LINT_CODE)
+            .filter_map(|msg| {
+                let span = msg["message"]["spans"]
+                    .as_array()?
+                    .iter()
+                    .find(|span| span["is_primary"] == true)?;
+                let savings = msg["message"]["children"]
+                    .as_array()?
+                    .iter()
+                    .filter_map(|child| Savings::from_note(child["message"].as_str()?))
+                    .next();
+                Some(Finding {
+                    file: span["file_name"].as_str()?.to_string(),
+                    line: span["line_start"].as_u64()?,
+                    column: span["column_start"].as_u64()?,
+                    savings,
+                })
+            })
+            .collect();
+
+        let mut by_file = BTreeMap::<_, Vec<_>>::new();
+        for finding in findings {
+            by_file.entry(finding.file.clone()).or_default().push(finding);
+        }
+        let mut files: Vec<_> = by_file.into_iter().collect();
+        // The sorts are stable, so findings and files which compare equal stay sorted by position
+        // and by name
+        for (_, findings) in &mut files {
+            findings.sort_by_key(|finding| Reverse(finding.savings));
+        }
+        files.sort_by_key(|(_, findings)| Reverse((total_savings(findings), findings.len())));
+
+        Self { files }
+    }
+
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.files.iter().map(|(_, findings)| findings.len()).sum()
+    }
+
+    /// The estimated savings of removing all the redundant clones, `None` if the size of none of
+    /// them is known.
+    #[must_use]
+    pub fn savings(&self) -> Option<Savings> {
+        total_savings(self.files.iter().flat_map(|(_, findings)| findings))
+    }
+
+    /// The number of redundant clones whose size is not known.
+    #[must_use]
+    pub fn unknown(&self) -> usize {
+        self.files
+            .iter()
+            .flat_map(|(_, findings)| findings)
+            .filter(|finding| finding.savings.is_none())
+            .count()
+    }
+}
+
+fn total_savings<'a>(findings: impl IntoIterator<Item = &'a Finding>) -> Option<Savings> {
+    findings
+        .into_iter()
+        .filter_map(|finding| finding.savings)
+        .fold(None, |total, savings| {
+            let mut total = total.unwrap_or_default();
+            total += savings;
+            Some(total)
+        })
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (file, findings) in &self.files {
+            write!(f, "{:>5}  {}", findings.len(), file)?;
+            if let Some(savings) = total_savings(findings) {
+                write!(f, ", saves {}", savings)?;
+            }
+            writeln!(f)?;
+            for finding in findings {
+                write!(f, "         {}:{}:{}", finding.file, finding.line, finding.column)?;
+                if let Some(savings) = finding.savings {
+                    write!(f, ", saves {}", savings)?;
+                }
+                writeln!(f)?;
+            }
+        }
+        write!(
+            f,
+            "{} redundant clone(s) in {} file(s), estimated allocation savings: {}",
+            self.total(),
+            self.files.len(),
+            self.savings().unwrap_or_default()
+        )?;
+        match self.unknown() {
+            0 => writeln!(f),
+            unknown => writeln!(f, ", plus {} clone(s) of unknown size", unknown),
+        }
+    }
+}
+
+/// Runs `cargo clippy` with only `redundant_clone` enabled in `path`, or reads the JSON messages
+/// from `input` if given, and prints the report.
+pub fn run(path: Option<&str>, input: Option<&str>) {
+    let messages = match input {
+        Some(input) => fs::read_to_string(input).unwrap_or_else(|e| panic!("failed to read `{}`: {}", input, e)),
+        None => run_clippy(path.unwrap_or(".")),
+    };
+    print!("{}", Report::from_messages(&messages));
+}
+
+fn run_clippy(path: &str) -> String {
+    let output = Command::new("cargo")
+        .current_dir(path)
+        .args(&[
+            "clippy",
+            "--workspace",
+            "--all-targets",
+            "--message-format=json",
+            "--",
+            "-A",
+            "clippy::all",
+            "-W",
+            "clippy::redundant_clone",
+        ])
+        .stderr(Stdio::inherit())
+        .output()
+        .expect("failed to run `cargo clippy`");
+    String::from_utf8(output.stdout).expect("`cargo clippy` printed invalid UTF-8")
+}
+
+#[test]
+fn test_savings_from_note() {
+    let savings = |note| Savings::from_note(note);
+    let bytes = |bytes| Some(Savings { per_element: 0, bytes });
+    let per_element = |per_element| Some(Savings { per_element, bytes: 0 });
+    assert_eq!(savings("removing the clone avoids allocating 12 bytes"), bytes(12));
+    assert_eq!(
+        savings("removing the clone avoids allocating 1 byte per element"),
+        per_element(1)
+    );
+    assert_eq!(
+        savings("removing each clone avoids allocating 8 bytes per element"),
+        per_element(8)
+    );
+    assert_eq!(savings("this value is dropped without further use"), None);
+}
+
+#[test]
+fn test_report_from_messages() {
+    let report = Report::from_messages(include_str!("../tests/clone_report/messages.json"));
+    let bytes = |bytes| Some(Savings { per_element: 0, bytes });
+    let per_element = |per_element| Some(Savings { per_element, bytes: 0 });
+    let finding = |file: &str, line, column, savings| Finding {
+        file: file.to_string(),
+        line,
+        column,
+        savings,
+    };
+    let expected = Report {
+        files: vec![
+            (
+                "core/src/lib.rs".to_string(),
+                vec![
+                    finding("core/src/lib.rs", 4, 14, per_element(8)),
+                    finding("core/src/lib.rs", 11, 23, bytes(16)),
+                    finding("core/src/lib.rs", 2, 13, None),
+                ],
+            ),
+            (
+                "core/src/parse.rs".to_string(),
+                vec![finding("core/src/parse.rs", 20, 9, bytes(24))],
+            ),
+            (
+                "app/src/main.rs".to_string(),
+                vec![finding("app/src/main.rs", 6, 18, None)],
+            ),
+        ],
+    };
+    assert_eq!(report, expected);
+    assert_eq!(report.total(), 5);
+    assert_eq!(
+        report.savings(),
+        Some(Savings {
+            per_element: 8,
+            bytes: 40
+        })
+    );
+    assert_eq!(report.unknown(), 2);
+}
+
+#[test]
+fn test_report_display() {
+    let report = Report::from_messages(include_str!("../tests/clone_report/messages.json"));
+    let expected = "    3  core/src/lib.rs, saves 16 bytes + 8 bytes per element
+         core/src/lib.rs:4:14, saves 8 bytes per element
+         core/src/lib.rs:11:23, saves 16 bytes
+         core/src/lib.rs:2:13
+    1  core/src/parse.rs, saves 24 bytes
+         core/src/parse.rs:20:9, saves 24 bytes
+    1  app/src/main.rs
+         app/src/main.rs:6:18
+5 redundant clone(s) in 3 file(s), estimated allocation savings: 40 bytes + 8 bytes per element, plus 2 clone(s) of unknown size
+";
+    assert_eq!(report.to_string(), expected);
+}
+
+#[test]
+fn test_empty_report() {
+    let report = Report::from_messages("");
+    assert_eq!(report.total(), 0);
+    assert_eq!(report.savings(), None);
+    assert_eq!(
+        report.to_string(),
+        "0 redundant clone(s) in 0 file(s), estimated allocation savings: 0 bytes\n"
+    );
+}
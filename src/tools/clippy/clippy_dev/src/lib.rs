@@ -9,6 +9,7 @@ use std::fs;
 use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
+pub mod clone_report;
 pub mod fmt;
 pub mod new_lint;
 pub mod ra_setup;
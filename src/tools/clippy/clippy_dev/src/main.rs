@@ -1,7 +1,7 @@
 #![cfg_attr(feature = "deny-warnings", deny(warnings))]
 
 use clap::{App, Arg, SubCommand};
-use clippy_dev::{fmt, new_lint, ra_setup, stderr_length_check, update_lints};
+use clippy_dev::{clone_report, fmt, new_lint, ra_setup, stderr_length_check, update_lints};
 
 fn main() {
     let matches = App::new("Clippy developer tooling")
@@ -87,6 +87,29 @@ fn main() {
             SubCommand::with_name("limit_stderr_length")
                 .about("Ensures that stderr files do not grow longer than a certain amount of lines."),
         )
+        .subcommand(
+            SubCommand::with_name("clone_report")
+                .about(
+                    "Runs only `redundant_clone` on a workspace and prints its findings, largest estimated allocation savings first",
+                )
+                .arg(
+                    Arg::with_name("path")
+                        .long("path")
+                        .short("p")
+                        .help("The directory of the workspace to check, defaults to the current directory")
+                        .takes_value(true)
+                        .value_name("path"),
+                )
+                .arg(
+                    Arg::with_name("input")
+                        .long("input")
+                        .short("i")
+                        .help("Read the output of `cargo clippy --message-format=json` from this file instead")
+                        .takes_value(true)
+                        .value_name("file")
+                        .conflicts_with("path"),
+                ),
+        )
         .subcommand(
             SubCommand::with_name("ra-setup")
                 .about("Alter dependencies so rust-analyzer can find rustc internals")
@@ -128,6 +151,7 @@ fn main() {
         ("limit_stderr_length", _) => {
             stderr_length_check::check();
         },
+        ("clone_report", Some(matches)) => clone_report::run(matches.value_of("path"), matches.value_of("input")),
         ("ra-setup", Some(matches)) => ra_setup::run(matches.value_of("rustc-repo-path")),
         _ => {},
     }
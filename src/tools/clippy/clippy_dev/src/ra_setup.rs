@@ -56,7 +56,7 @@ fn inject_deps_into_manifest(
         // we have something like "extern crate foo;", we only care about the "foo"
         //              ↓          ↓
         // extern crate rustc_middle;
-        .map(|s| &s[13..(s.len() - 1)]);
+        .filter_map(|s| s[13..].strip_suffix(';'));
 
     let new_deps = extern_crates.map(|dep| {
         // format the dependencies that are going to be put inside the Cargo.toml
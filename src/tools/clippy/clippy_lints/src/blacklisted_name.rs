@@ -1,4 +1,4 @@
-use crate::utils::span_lint;
+use crate::utils::{glob, span_lint};
 use rustc_data_structures::fx::FxHashSet;
 use rustc_hir::{Pat, PatKind};
 use rustc_lint::{LateContext, LateLintPass};
@@ -11,6 +11,8 @@ declare_clippy_lint! {
     /// **Why is this bad?** These names are usually placeholder names and should be
     /// avoided.
     ///
+    /// Names matching the `allowed-names` configuration are not linted.
+    ///
     /// **Known problems:** None.
     ///
     /// **Example:**
@@ -25,11 +27,12 @@ declare_clippy_lint! {
 #[derive(Clone, Debug)]
 pub struct BlacklistedName {
     blacklist: FxHashSet<String>,
+    allowed: Vec<String>,
 }
 
 impl BlacklistedName {
-    pub fn new(blacklist: FxHashSet<String>) -> Self {
-        Self { blacklist }
+    pub fn new(blacklist: FxHashSet<String>, allowed: Vec<String>) -> Self {
+        Self { blacklist, allowed }
     }
 }
 
@@ -38,7 +41,8 @@ impl_lint_pass!(BlacklistedName => [BLACKLISTED_NAME]);
 impl<'tcx> LateLintPass<'tcx> for BlacklistedName {
     fn check_pat(&mut self, cx: &LateContext<'tcx>, pat: &'tcx Pat<'_>) {
         if let PatKind::Binding(.., ident, _) = pat.kind {
-            if self.blacklist.contains(&ident.name.to_string()) {
+            let name = ident.name.to_string();
+            if self.blacklist.contains(&name) && !glob::matches_any(&self.allowed, &name) {
                 span_lint(
                     cx,
                     BLACKLISTED_NAME,
@@ -0,0 +1,95 @@
+use crate::utils::usage::is_local_used_in_stmt;
+use crate::utils::{in_macro, is_copy, match_function_call, match_trait_method, paths, qpath_res, span_lint_and_then};
+use if_chain::if_chain;
+use rustc_hir::def::Res;
+use rustc_hir::{BindingAnnotation, Block, Expr, ExprKind, HirId, PatKind, Stmt, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for a value that is cloned into a new binding, where the
+    /// clone is explicitly `drop`ped before it is ever used.
+    ///
+    /// **Why is this bad?** The clone is never read, so both the `clone()` and the `drop()`
+    /// are wasted work.
+    ///
+    /// **Known problems:** None. Dropping the *original* value after cloning it
+    /// (`let c = x.clone(); drop(x);`) is a legitimate pattern and is not linted.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// let x = String::from("foo");
+    /// let c = x.clone();
+    /// drop(c);
+    /// println!("{}", x);
+    /// ```
+    /// Could be written as:
+    /// ```rust
+    /// let x = String::from("foo");
+    /// println!("{}", x);
+    /// ```
+    pub CLONE_BEFORE_DROP_EXPLICIT,
+    perf,
+    "cloning a value into a binding which is explicitly dropped without being used"
+}
+
+declare_lint_pass!(CloneBeforeDropExplicit => [CLONE_BEFORE_DROP_EXPLICIT]);
+
+impl<'tcx> LateLintPass<'tcx> for CloneBeforeDropExplicit {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'_>) {
+        for (idx, stmt) in block.stmts.iter().enumerate() {
+            if_chain! {
+                if !in_macro(stmt.span);
+                if let StmtKind::Local(ref local) = stmt.kind;
+                if let PatKind::Binding(BindingAnnotation::Unannotated | BindingAnnotation::Mutable, binding_id, ..) =
+                    local.pat.kind;
+                if let Some(ref init) = local.init;
+                if let ExprKind::MethodCall(ref path, _, _, _) = init.kind;
+                if path.ident.name == sym!(clone);
+                if match_trait_method(cx, init, &paths::CLONE_TRAIT);
+                if !is_copy(cx, cx.typeck_results().expr_ty(init));
+                // Only the first statement mentioning the clone matters: if it is `drop(clone)`,
+                // the clone was never read.
+                if let Some(first_use) = block.stmts[idx + 1..]
+                    .iter()
+                    .find(|s| is_local_used_in_stmt(cx, s, binding_id));
+                if is_drop_of_local(cx, first_use, binding_id);
+                then {
+                    span_lint_and_then(
+                        cx,
+                        CLONE_BEFORE_DROP_EXPLICIT,
+                        stmt.span,
+                        "this value is cloned only to be dropped",
+                        |diag| {
+                            diag.help("remove both the `clone()` and the `drop()`");
+                            diag.span_note(first_use.span, "the clone is dropped here without being used");
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Returns `true` if `stmt` is `drop(local);`.
+fn is_drop_of_local(cx: &LateContext<'_>, stmt: &Stmt<'_>, local_id: HirId) -> bool {
+    if_chain! {
+        if let StmtKind::Semi(ref expr) | StmtKind::Expr(ref expr) = stmt.kind;
+        if let Some([arg]) = match_function_call(cx, expr, &paths::DROP);
+        then {
+            is_local(cx, arg, local_id)
+        } else {
+            false
+        }
+    }
+}
+
+fn is_local(cx: &LateContext<'_>, expr: &Expr<'_>, local_id: HirId) -> bool {
+    if let ExprKind::Path(ref qpath) = expr.kind {
+        if let Res::Local(id) = qpath_res(cx, qpath, expr.hir_id) {
+            return id == local_id;
+        }
+    }
+    false
+}
@@ -0,0 +1,83 @@
+use crate::utils::{
+    get_parent_expr, in_macro, is_copy, match_trait_method, paths, snippet_with_applicability, span_lint_and_sugg,
+};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{BorrowKind, Expr, ExprKind, Mutability};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for function and method arguments of the form `&x.clone()`,
+    /// like `serde_json::to_string(&x.clone())`.
+    ///
+    /// **Why is this bad?** The clone is only borrowed and dropped at the end of the
+    /// statement, it is never moved. Serialization functions and other APIs taking a reference
+    /// can borrow the original value instead.
+    ///
+    /// **Known problems:** The callee can observe the difference for types with interior
+    /// mutability or whose clones share state, like `Rc` and its reference count.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # fn to_string<T: std::fmt::Debug>(value: &T) -> String { format!("{:?}", value) }
+    /// let config = vec![1, 2, 3];
+    /// let json = to_string(&config.clone());
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # fn to_string<T: std::fmt::Debug>(value: &T) -> String { format!("{:?}", value) }
+    /// let config = vec![1, 2, 3];
+    /// let json = to_string(&config);
+    /// ```
+    pub CLONE_BEFORE_SERIALIZE,
+    pedantic,
+    "passing a reference to a fresh clone, like `to_string(&x.clone())`, instead of borrowing the original"
+}
+
+declare_lint_pass!(CloneBeforeSerialize => [CLONE_BEFORE_SERIALIZE]);
+
+impl<'tcx> LateLintPass<'tcx> for CloneBeforeSerialize {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if !in_macro(expr.span);
+            if let ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, ref clone) = expr.kind;
+            if let ExprKind::MethodCall(ref path, _, [recv], _) = clone.kind;
+            if path.ident.name == sym!(clone);
+            if match_trait_method(cx, clone, &paths::CLONE_TRAIT);
+            if is_argument(cx, expr);
+            let clone_ty = cx.typeck_results().expr_ty(clone);
+            if !is_copy(cx, clone_ty);
+            then {
+                let recv_ty = cx.typeck_results().expr_ty(recv);
+                let borrow = if recv_ty == clone_ty {
+                    "&"
+                } else if matches!(recv_ty.kind(), ty::Ref(_, inner, _) if *inner == clone_ty) {
+                    ""
+                } else {
+                    return;
+                };
+                let mut app = Applicability::MachineApplicable;
+                span_lint_and_sugg(
+                    cx,
+                    CLONE_BEFORE_SERIALIZE,
+                    expr.span,
+                    "this clone is only borrowed for the call",
+                    "borrow the original value instead",
+                    format!("{}{}", borrow, snippet_with_applicability(cx, recv.span, "..", &mut app)),
+                    app,
+                );
+            }
+        }
+    }
+}
+
+/// Returns `true` if `expr` is an argument of a function or method call, but not the receiver.
+fn is_argument(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    match get_parent_expr(cx, expr).map(|parent| &parent.kind) {
+        Some(ExprKind::Call(_, args)) => args.iter().any(|arg| arg.hir_id == expr.hir_id),
+        Some(ExprKind::MethodCall(_, _, args, _)) => args[1..].iter().any(|arg| arg.hir_id == expr.hir_id),
+        _ => false,
+    }
+}
@@ -0,0 +1,85 @@
+use crate::utils::{get_parent_expr, is_expn_of, match_trait_method, paths, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, BorrowKind, Expr, ExprKind, Mutability, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `clone()` calls on the arguments of `debug_assert_eq!` and
+    /// `debug_assert_ne!`, and on the operands of a `==` or `!=` comparison in `debug_assert!`.
+    ///
+    /// **Why is this bad?** These macros only borrow their arguments for the comparison, so
+    /// the clone is never needed. It slows down debug builds for nothing.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let names = vec!["a".to_string()];
+    /// # let expected = names.clone();
+    /// debug_assert_eq!(names.clone(), expected);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let names = vec!["a".to_string()];
+    /// # let expected = names.clone();
+    /// debug_assert_eq!(names, expected);
+    /// ```
+    pub CLONE_IN_DEBUG_ASSERT,
+    pedantic,
+    "cloning an argument of `debug_assert_eq!`, `debug_assert_ne!` or of a comparison in `debug_assert!`"
+}
+
+declare_lint_pass!(CloneInDebugAssert => [CLONE_IN_DEBUG_ASSERT]);
+
+impl<'tcx> LateLintPass<'tcx> for CloneInDebugAssert {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if !expr.span.from_expansion();
+            if let ExprKind::MethodCall(ref path, _, [recv], _) = expr.kind;
+            if path.ident.name == sym!(clone);
+            if match_trait_method(cx, expr, &paths::CLONE_TRAIT);
+            if cx.typeck_results().expr_ty(recv) == cx.typeck_results().expr_ty(expr);
+            if let Some(parent) = get_parent_expr(cx, expr);
+            if let Some(name) = debug_assert_name(cx, parent);
+            then {
+                span_lint_and_sugg(
+                    cx,
+                    CLONE_IN_DEBUG_ASSERT,
+                    expr.span.with_lo(recv.span.hi()),
+                    &format!("this clone is not needed, `{}!` only borrows the cloned value", name),
+                    "remove this",
+                    String::new(),
+                    Applicability::MachineApplicable,
+                );
+            }
+        }
+    }
+}
+
+/// Returns the name of the macro if `parent`, the parent of the clone, is the borrow of an
+/// argument of `debug_assert_eq!` or `debug_assert_ne!`, or a comparison in `debug_assert!`.
+fn debug_assert_name(cx: &LateContext<'_>, parent: &Expr<'_>) -> Option<&'static str> {
+    match parent.kind {
+        // `debug_assert_eq!(a, b)` expands to `match (&a, &b) { .. }`
+        ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, _) => ["debug_assert_eq", "debug_assert_ne"]
+            .iter()
+            .copied()
+            .find(|name| is_expn_of(parent.span, name).is_some()),
+        // `debug_assert!(a == b)` expands to `if !(a == b) { .. }`
+        ExprKind::Binary(op, ..)
+            if !parent.span.from_expansion() && matches!(op.node, BinOpKind::Eq | BinOpKind::Ne) =>
+        {
+            match get_parent_expr(cx, parent) {
+                Some(Expr {
+                    kind: ExprKind::Unary(UnOp::UnNot, _),
+                    span,
+                    ..
+                }) if is_expn_of(*span, "debug_assert").is_some() => Some("debug_assert"),
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
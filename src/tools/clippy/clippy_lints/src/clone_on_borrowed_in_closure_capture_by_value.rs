@@ -0,0 +1,219 @@
+use crate::utils::usage::{is_local_used, is_local_used_in_stmt};
+use crate::utils::{contains_ty, get_parent_expr, in_macro, match_trait_method, paths, snippet, span_lint_and_then};
+use if_chain::if_chain;
+use rustc_hir::{BindingAnnotation, Block, CaptureBy, Expr, ExprKind, HirId, Local, Node, PatKind};
+use rustc_infer::infer::TyCtxtInferExt;
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Ty};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_typeck::expr_use_visitor::{ConsumeMode, Delegate, ExprUseVisitor, PlaceBase, PlaceWithHirId};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for values which are cloned right before being captured by a
+    /// `move` closure, when the closure only reads them and does not outlive the expression
+    /// it is passed to.
+    ///
+    /// **Why is this bad?** The clone is only needed to satisfy the `move`, capturing the
+    /// original value by reference works just as well and saves an allocation.
+    ///
+    /// **Known problems:** Only closures passed to `Iterator` methods, whose result does not
+    /// contain the closure, are checked. Removing the `move` also changes how the other
+    /// captured values are captured.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let v = vec![1, 2, 3];
+    /// # let prefix = String::from("x");
+    /// let p = prefix.clone();
+    /// let n = v.iter().filter(move |i| p.len() < **i).count();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let v = vec![1, 2, 3];
+    /// # let prefix = String::from("x");
+    /// let n = v.iter().filter(|i| prefix.len() < **i).count();
+    /// ```
+    pub CLONE_ON_BORROWED_IN_CLOSURE_CAPTURE_BY_VALUE,
+    nursery,
+    "cloning a value only to capture it by value in a closure which only reads it"
+}
+
+declare_lint_pass!(CloneOnBorrowedInClosureCaptureByValue => [CLONE_ON_BORROWED_IN_CLOSURE_CAPTURE_BY_VALUE]);
+
+impl<'tcx> LateLintPass<'tcx> for CloneOnBorrowedInClosureCaptureByValue {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if let ExprKind::MethodCall(_, _, ref args, _) = expr.kind;
+            if !in_macro(expr.span);
+            if match_trait_method(cx, expr, &paths::ITERATOR);
+            then {
+                for closure in &args[1..] {
+                    if let ExprKind::Closure(CaptureBy::Value, ..) = closure.kind {
+                        check_closure(cx, expr, closure);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn check_closure<'tcx>(cx: &LateContext<'tcx>, call: &'tcx Expr<'_>, closure: &'tcx Expr<'_>) {
+    let closure_ty = cx.typeck_results().expr_ty(closure);
+    if escapes(cx, call, closure_ty) {
+        return;
+    }
+    let closure_def_id = cx.tcx.hir().local_def_id(closure.hir_id);
+    let upvars = match cx.tcx.upvars_mentioned(closure_def_id) {
+        Some(upvars) => upvars,
+        None => return,
+    };
+    for &var_id in upvars.keys() {
+        if_chain! {
+            if let Some(local) = cloned_binding(cx, var_id);
+            if let Some(ref init) = local.init;
+            if let ExprKind::MethodCall(_, _, ref clone_args, _) = init.kind;
+            if let Some((block, index)) = enclosing_block(cx, local);
+            if is_last_use(cx, block, index, closure, var_id);
+            if is_only_read(cx, closure, var_id);
+            then {
+                let source = snippet(cx, clone_args[0].span, "..");
+                span_lint_and_then(
+                    cx,
+                    CLONE_ON_BORROWED_IN_CLOSURE_CAPTURE_BY_VALUE,
+                    init.span,
+                    "this value is cloned only to be moved into a closure which only reads it",
+                    |diag| {
+                        diag.help(&format!(
+                            "remove the `move` and use `{}` in the closure to capture it by reference",
+                            source
+                        ));
+                        diag.span_note(closure.span, "the clone is captured by this closure");
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Checks whether the closure may outlive the method chain it is passed to, i.e. whether the
+/// type of the chain still contains the closure type.
+fn escapes<'tcx>(cx: &LateContext<'tcx>, call: &'tcx Expr<'_>, closure_ty: Ty<'tcx>) -> bool {
+    let mut expr = call;
+    while contains_ty(cx.typeck_results().expr_ty(expr), closure_ty) {
+        let parent = match get_parent_expr(cx, expr) {
+            Some(parent) => parent,
+            None => return true,
+        };
+        match parent.kind {
+            ExprKind::MethodCall(_, _, ref args, _) if args[0].hir_id == expr.hir_id => expr = parent,
+            _ => return true,
+        }
+    }
+    false
+}
+
+/// Returns the `let` statement of the immutable binding `var_id`, if it is initialized with a
+/// call of `Clone::clone` on a place.
+fn cloned_binding<'tcx>(cx: &LateContext<'tcx>, var_id: HirId) -> Option<&'tcx Local<'tcx>> {
+    if_chain! {
+        if let Some(Node::Local(local)) = cx.tcx.hir().find(cx.tcx.hir().get_parent_node(var_id));
+        if local.pat.hir_id == var_id;
+        if let PatKind::Binding(BindingAnnotation::Unannotated, ..) = local.pat.kind;
+        if let Some(ref init) = local.init;
+        if !in_macro(init.span);
+        if let ExprKind::MethodCall(ref path, _, ref args, _) = init.kind;
+        if path.ident.name == sym!(clone) && args.len() == 1;
+        if match_trait_method(cx, init, &paths::CLONE_TRAIT);
+        if matches!(args[0].kind, ExprKind::Path(_) | ExprKind::Field(..));
+        then {
+            Some(local)
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the block containing the `let` statement `local`, and the index of the statement.
+fn enclosing_block<'tcx>(cx: &LateContext<'tcx>, local: &Local<'_>) -> Option<(&'tcx Block<'tcx>, usize)> {
+    let map = cx.tcx.hir();
+    let stmt_id = map.get_parent_node(local.hir_id);
+    if let Some(Node::Block(block)) = map.find(map.get_parent_node(stmt_id)) {
+        let index = block.stmts.iter().position(|stmt| stmt.hir_id == stmt_id)?;
+        Some((block, index))
+    } else {
+        None
+    }
+}
+
+/// Checks whether the statement right after the `let` at `index` contains the closure, and
+/// whether the binding is not used after that statement.
+fn is_last_use<'tcx>(
+    cx: &LateContext<'tcx>,
+    block: &'tcx Block<'_>,
+    index: usize,
+    closure: &Expr<'_>,
+    var_id: HirId,
+) -> bool {
+    let rest = &block.stmts[index + 1..];
+    match rest.split_first() {
+        Some((next, rest)) => {
+            next.span.contains(closure.span)
+                && !rest.iter().any(|stmt| is_local_used_in_stmt(cx, stmt, var_id))
+                && block.expr.map_or(true, |e| !is_local_used(cx, e, var_id))
+        },
+        None => block.expr.map_or(false, |e| e.span.contains(closure.span)),
+    }
+}
+
+/// Checks whether the body of the closure neither moves nor mutates the upvar `var_id`.
+fn is_only_read<'tcx>(cx: &LateContext<'tcx>, closure: &'tcx Expr<'_>, var_id: HirId) -> bool {
+    let body_id = if let ExprKind::Closure(_, _, body_id, _, _) = closure.kind {
+        body_id
+    } else {
+        return false;
+    };
+    let mut delegate = UpvarUseDelegate {
+        var_id,
+        used_by_value: false,
+    };
+    // the closure is the body owner, so that captured variables are categorized as upvars
+    let def_id = cx.tcx.hir().local_def_id(closure.hir_id);
+    cx.tcx.infer_ctxt().enter(|infcx| {
+        ExprUseVisitor::new(&mut delegate, &infcx, def_id, cx.param_env, cx.typeck_results())
+            .walk_expr(&cx.tcx.hir().body(body_id).value);
+    });
+    !delegate.used_by_value
+}
+
+struct UpvarUseDelegate {
+    var_id: HirId,
+    used_by_value: bool,
+}
+
+impl UpvarUseDelegate {
+    fn update(&mut self, place: &PlaceWithHirId<'_>) {
+        if let PlaceBase::Upvar(upvar_id) = place.place.base {
+            if upvar_id.var_path.hir_id == self.var_id {
+                self.used_by_value = true;
+            }
+        }
+    }
+}
+
+impl<'tcx> Delegate<'tcx> for UpvarUseDelegate {
+    fn consume(&mut self, place: &PlaceWithHirId<'tcx>, mode: ConsumeMode) {
+        if mode == ConsumeMode::Move {
+            self.update(place);
+        }
+    }
+
+    fn borrow(&mut self, place: &PlaceWithHirId<'tcx>, bk: ty::BorrowKind) {
+        if bk != ty::BorrowKind::ImmBorrow {
+            self.update(place);
+        }
+    }
+
+    fn mutate(&mut self, place: &PlaceWithHirId<'tcx>) {
+        self.update(place);
+    }
+}
@@ -0,0 +1,170 @@
+use crate::utils::{
+    get_parent_expr, in_macro, match_def_path, paths, qpath_res, snippet_with_applicability, span_lint_and_sugg,
+};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{BindingAnnotation, Block, BorrowKind, Expr, ExprKind, HirId, Mutability, PatKind, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_middle::ty::adjustment::{Adjust, AutoBorrow, AutoBorrowMutability};
+use rustc_middle::ty::{self, Ty};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for bindings initialized with `to_string()` or `to_owned()` of
+    /// a `&'static str` or a `&'static` slice, like a string literal or a constant, which are
+    /// only borrowed afterwards.
+    ///
+    /// **Why is this bad?** The owned copy allocates, while the `&'static` reference it is
+    /// made from can be borrowed for as long as needed.
+    ///
+    /// **Known problems:** The binding changes its type, e.g. from `String` to `&'static str`.
+    /// Uses which need a `&String` or call a method only `String` has do not compile after
+    /// the change.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # fn greet(_: &str) {}
+    /// let name = "world".to_string();
+    /// greet(&name);
+    /// println!("{}", name);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # fn greet(_: &str) {}
+    /// let name = "world";
+    /// greet(&name);
+    /// println!("{}", name);
+    /// ```
+    pub CLONE_ON_STATIC_REF,
+    pedantic,
+    "calling `to_string()` or `to_owned()` on `&'static` data which is only borrowed afterwards"
+}
+
+declare_lint_pass!(CloneOnStaticRef => [CLONE_ON_STATIC_REF]);
+
+impl<'tcx> LateLintPass<'tcx> for CloneOnStaticRef {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'_>) {
+        for (idx, stmt) in block.stmts.iter().enumerate() {
+            if_chain! {
+                if !in_macro(stmt.span);
+                if let StmtKind::Local(ref local) = stmt.kind;
+                if local.ty.is_none();
+                if let PatKind::Binding(BindingAnnotation::Unannotated, binding_id, ..) = local.pat.kind;
+                if let Some(ref init) = local.init;
+                if let ExprKind::MethodCall(ref path, _, ref args, _) = init.kind;
+                if let Some(method_id) = cx.typeck_results().type_dependent_def_id(init.hir_id);
+                if match_def_path(cx, method_id, &paths::TO_STRING_METHOD)
+                    || match_def_path(cx, method_id, &paths::TO_OWNED_METHOD);
+                if let Some(pointee) = static_ref_pointee(cx, &args[0]);
+                then {
+                    let mut visitor = BorrowedOnlyVisitor {
+                        cx,
+                        binding_id,
+                        used: false,
+                        borrowed_only: true,
+                    };
+                    for stmt in &block.stmts[idx + 1..] {
+                        visitor.visit_stmt(stmt);
+                    }
+                    if let Some(expr) = block.expr {
+                        visitor.visit_expr(expr);
+                    }
+                    if !visitor.used || !visitor.borrowed_only {
+                        continue;
+                    }
+
+                    let what = match pointee.kind() {
+                        ty::Str => "a `&'static str`",
+                        _ => "a `&'static` slice",
+                    };
+                    let mut app = Applicability::MaybeIncorrect;
+                    span_lint_and_sugg(
+                        cx,
+                        CLONE_ON_STATIC_REF,
+                        init.span,
+                        &format!("calling `{}()` on {} whose result is only borrowed", path.ident, what),
+                        "use the reference directly",
+                        snippet_with_applicability(cx, args[0].span, "..", &mut app).into_owned(),
+                        app,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Returns the pointee of `expr` if it is a string literal, or a constant or static whose type
+/// is a `&'static str` or a `&'static` slice.
+fn static_ref_pointee<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>) -> Option<Ty<'tcx>> {
+    let ty = match expr.kind {
+        ExprKind::Lit(_) => cx.typeck_results().expr_ty(expr),
+        ExprKind::Path(ref qpath) => match qpath_res(cx, qpath, expr.hir_id) {
+            Res::Def(DefKind::Const | DefKind::Static, def_id) => cx.tcx.type_of(def_id),
+            _ => return None,
+        },
+        _ => return None,
+    };
+    match ty.kind() {
+        ty::Ref(region, pointee, Mutability::Not)
+            if matches!(expr.kind, ExprKind::Lit(_)) || matches!(region, ty::ReStatic) =>
+        {
+            match pointee.kind() {
+                ty::Str | ty::Slice(_) => Some(*pointee),
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
+
+/// Checks whether all uses of a local only borrow it immutably: with `&local`, as the receiver
+/// of a method taking `&self`, or as an operand of a comparison.
+struct BorrowedOnlyVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    binding_id: HirId,
+    used: bool,
+    borrowed_only: bool,
+}
+
+impl<'a, 'tcx> BorrowedOnlyVisitor<'a, 'tcx> {
+    fn is_borrowed(&self, expr: &Expr<'_>) -> bool {
+        match get_parent_expr(self.cx, expr).map(|parent| &parent.kind) {
+            Some(ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, _)) => true,
+            Some(ExprKind::Binary(op, _, _)) => op.node.is_comparison(),
+            Some(ExprKind::MethodCall(_, _, args, _)) if args[0].hir_id == expr.hir_id => matches!(
+                self.cx
+                    .typeck_results()
+                    .expr_adjustments(expr)
+                    .last()
+                    .map(|adjustment| &adjustment.kind),
+                Some(Adjust::Borrow(AutoBorrow::Ref(_, AutoBorrowMutability::Not)))
+            ),
+            _ => false,
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for BorrowedOnlyVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
+        if !self.borrowed_only {
+            return;
+        }
+        if let ExprKind::Path(ref qpath) = expr.kind {
+            if qpath_res(self.cx, qpath, expr.hir_id) == Res::Local(self.binding_id) {
+                self.used = true;
+                self.borrowed_only = self.is_borrowed(expr);
+                return;
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
@@ -0,0 +1,173 @@
+use crate::utils::{in_macro, is_copy, match_def_path, match_trait_method, paths, snippet, span_lint_and_help};
+use if_chain::if_chain;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def::Res;
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{BodyId, Expr, ExprKind, HirId, QPath};
+use rustc_infer::infer::TyCtxtInferExt;
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_typeck::expr_use_visitor::{ConsumeMode, Delegate, ExprUseVisitor, PlaceWithHirId};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `clone()` calls on values captured by reference in closures
+    /// passed to `Iterator` methods, when the clone is only borrowed.
+    ///
+    /// **Why is this bad?** The closure is called once per item, so the captured value is cloned
+    /// again on every call, although the clone is never consumed. Borrowing the captured value,
+    /// or cloning it once before the closure, does the work only once.
+    ///
+    /// **Known problems:** Clones which are bound to a local in the closure, or which are
+    /// compared with `==`, are not checked.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let names = vec!["foo", "bar"];
+    /// let prefix = String::from("f");
+    /// let n = names.iter().filter(|n| n.starts_with(&prefix.clone())).count();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let names = vec!["foo", "bar"];
+    /// let prefix = String::from("f");
+    /// let n = names.iter().filter(|n| n.starts_with(&prefix)).count();
+    /// ```
+    pub CLONE_TO_SATISFY_FNMUT_REUSE,
+    nursery,
+    "cloning a captured value on every call of a closure passed to an iterator method"
+}
+
+declare_lint_pass!(CloneToSatisfyFnmutReuse => [CLONE_TO_SATISFY_FNMUT_REUSE]);
+
+impl<'tcx> LateLintPass<'tcx> for CloneToSatisfyFnmutReuse {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if let ExprKind::MethodCall(_, _, ref args, _) = expr.kind;
+            if !in_macro(expr.span);
+            if match_trait_method(cx, expr, &paths::ITERATOR);
+            then {
+                for closure in &args[1..] {
+                    if let ExprKind::Closure(_, _, body_id, _, _) = closure.kind {
+                        check_closure(cx, closure, body_id);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn check_closure<'tcx>(cx: &LateContext<'tcx>, closure: &'tcx Expr<'_>, body_id: BodyId) {
+    let closure_def_id = cx.tcx.hir().local_def_id(closure.hir_id);
+    let body = cx.tcx.hir().body(body_id);
+    let mut visitor = UpvarCloneVisitor {
+        cx,
+        closure_def_id,
+        clones: Vec::new(),
+    };
+    visitor.visit_expr(&body.value);
+    if visitor.clones.is_empty() {
+        return;
+    }
+
+    let mut delegate = ConsumedDelegate {
+        consumed: FxHashSet::default(),
+    };
+    // the closure is the body owner, so that captured variables are categorized as upvars
+    cx.tcx.infer_ctxt().enter(|infcx| {
+        ExprUseVisitor::new(&mut delegate, &infcx, closure_def_id, cx.param_env, cx.typeck_results())
+            .walk_expr(&body.value);
+    });
+
+    for (clone, recv) in visitor.clones {
+        if !delegate.consumed.contains(&clone.hir_id) {
+            let recv = snippet(cx, recv.span, "..");
+            span_lint_and_help(
+                cx,
+                CLONE_TO_SATISFY_FNMUT_REUSE,
+                clone.span,
+                &format!("`{}` is cloned on every call of this closure", recv),
+                None,
+                &format!(
+                    "borrow `{}` instead, or clone it once before the closure if an owned value is needed",
+                    recv
+                ),
+            );
+        }
+    }
+}
+
+/// Collects the `clone()` calls on upvars captured by reference in the body of a closure, with
+/// their receivers. Nested closures are not visited.
+struct UpvarCloneVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    closure_def_id: LocalDefId,
+    clones: Vec<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)>,
+}
+
+impl<'a, 'tcx> UpvarCloneVisitor<'a, 'tcx> {
+    fn is_upvar_captured_by_ref(&self, var_id: HirId) -> bool {
+        let upvar_id = ty::UpvarId {
+            var_path: ty::UpvarPath { hir_id: var_id },
+            closure_expr_id: self.closure_def_id,
+        };
+        self.cx
+            .tcx
+            .upvars_mentioned(self.closure_def_id)
+            .map_or(false, |upvars| upvars.contains_key(&var_id))
+            && matches!(
+                self.cx.typeck_results().upvar_capture(upvar_id),
+                ty::UpvarCapture::ByRef(_)
+            )
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for UpvarCloneVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if_chain! {
+            if let ExprKind::MethodCall(_, _, ref args, _) = expr.kind;
+            if !in_macro(expr.span);
+            if let Some(def_id) = self.cx.typeck_results().type_dependent_def_id(expr.hir_id);
+            if match_def_path(self.cx, def_id, &paths::CLONE_TRAIT_METHOD);
+            if let ExprKind::Path(QPath::Resolved(None, ref path)) = args[0].kind;
+            if let Res::Local(var_id) = path.res;
+            if self.is_upvar_captured_by_ref(var_id);
+            if !is_copy(self.cx, self.cx.typeck_results().expr_ty(expr));
+            then {
+                self.clones.push((expr, &args[0]));
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
+
+/// Collects the places which are moved, mutated or mutably borrowed.
+struct ConsumedDelegate {
+    consumed: FxHashSet<HirId>,
+}
+
+impl<'tcx> Delegate<'tcx> for ConsumedDelegate {
+    fn consume(&mut self, place: &PlaceWithHirId<'tcx>, mode: ConsumeMode) {
+        if mode == ConsumeMode::Move {
+            self.consumed.insert(place.hir_id);
+        }
+    }
+
+    fn borrow(&mut self, place: &PlaceWithHirId<'tcx>, bk: ty::BorrowKind) {
+        if bk != ty::BorrowKind::ImmBorrow {
+            self.consumed.insert(place.hir_id);
+        }
+    }
+
+    fn mutate(&mut self, place: &PlaceWithHirId<'tcx>) {
+        self.consumed.insert(place.hir_id);
+    }
+}
@@ -1,19 +1,26 @@
 use crate::utils::paths;
 use crate::utils::{
-    get_trait_def_id, is_allowed, is_automatically_derived, is_copy, match_path, span_lint_and_help,
-    span_lint_and_note, span_lint_and_then,
+    contains_name, get_trait_def_id, implements_trait, is_allowed, is_automatically_derived, is_copy,
+    is_type_diagnostic_item, match_def_path, match_path, match_qpath, qpath_res, span_lint_and_help,
+    span_lint_and_note, span_lint_and_sugg, span_lint_and_then, span_lint_hir_and_then, walk_ptrs_ty,
 };
 use if_chain::if_chain;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
 use rustc_hir::def_id::DefId;
 use rustc_hir::intravisit::{walk_expr, walk_fn, walk_item, FnKind, NestedVisitorMap, Visitor};
 use rustc_hir::{
-    BlockCheckMode, BodyId, Expr, ExprKind, FnDecl, HirId, Item, ItemKind, TraitRef, UnsafeSource, Unsafety,
+    BlockCheckMode, Body, BodyId, Crate, Expr, ExprKind, FnDecl, HirId, ImplItem, ImplItemKind, ImplItemRef, Item,
+    ItemKind, MatchSource, Pat, PatKind, TraitRef, UnOp, UnsafeSource, Unsafety,
 };
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::hir::map::Map;
-use rustc_middle::ty::{self, Ty};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_middle::ty::subst::SubstsRef;
+use rustc_middle::ty::{self, AdtDef, Ty, TypeckResults, VariantDef};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::source_map::Span;
+use rustc_span::symbol::Symbol;
 
 declare_clippy_lint! {
     /// **What it does:** Checks for deriving `Hash` but implementing `PartialEq`
@@ -28,7 +35,11 @@ declare_clippy_lint! {
     /// k1 == k2 ⇒ hash(k1) == hash(k2)
     /// ```
     ///
-    /// **Known problems:** None.
+    /// Explicit `PartialEq<Other>` implementations are checked as well if the type can be
+    /// borrowed as `Other`, as e.g. `HashMap` lookups by `&Other` rely on both agreeing.
+    ///
+    /// **Known problems:** The derived `Hash` implementation of a type borrowed as `Other` may
+    /// well agree with the one of `Other`, e.g. for a newtype around `Other`.
     ///
     /// **Example:**
     /// ```ignore
@@ -58,6 +69,9 @@ declare_clippy_lint! {
     /// k1.cmp(&k2) == k1.partial_cmp(&k2).unwrap()
     /// ```
     ///
+    /// Explicit implementations which delegate to the derived one, i.e. `partial_cmp` returning
+    /// `Some(self.cmp(other))` or `cmp` returning `self.partial_cmp(other).unwrap()`, are allowed.
+    ///
     /// **Known problems:** None.
     ///
     /// **Example:**
@@ -155,17 +169,314 @@ declare_clippy_lint! {
     "deriving `serde::Deserialize` on a type that has methods using `unsafe`"
 }
 
-declare_lint_pass!(Derive => [
+declare_clippy_lint! {
+    /// **What it does:** Checks for manual `PartialOrd` implementations on types which
+    /// also implement `Ord`, where `partial_cmp` does anything else than
+    /// `Some(self.cmp(other))`.
+    ///
+    /// **Why is this bad?** `partial_cmp` and `cmp` must agree. Two separate
+    /// implementations of the same ordering can easily drift apart, while delegating to
+    /// `cmp` is consistent by construction.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::cmp::Ordering;
+    /// #[derive(PartialEq, Eq)]
+    /// struct Version(u32, u32);
+    ///
+    /// impl Ord for Version {
+    ///     fn cmp(&self, other: &Self) -> Ordering {
+    ///         self.0.cmp(&other.0).then(self.1.cmp(&other.1))
+    ///     }
+    /// }
+    ///
+    /// impl PartialOrd for Version {
+    ///     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    ///         self.0.partial_cmp(&other.0)
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::cmp::Ordering;
+    /// # #[derive(PartialEq, Eq)]
+    /// # struct Version(u32, u32);
+    /// # impl Ord for Version {
+    /// #     fn cmp(&self, other: &Self) -> Ordering {
+    /// #         self.0.cmp(&other.0).then(self.1.cmp(&other.1))
+    /// #     }
+    /// # }
+    /// impl PartialOrd for Version {
+    ///     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    ///         Some(self.cmp(other))
+    ///     }
+    /// }
+    /// ```
+    pub NON_CANONICAL_PARTIAL_ORD,
+    style,
+    "manual `PartialOrd` implementations on `Ord` types not delegating to `Ord::cmp`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for manual `PartialEq` implementations on structs with a
+    /// derived `Hash` implementation, where `eq` does not read all the fields of the
+    /// struct.
+    ///
+    /// **Why is this bad?** The derived `Hash` implementation hashes all fields, so two
+    /// values which only differ in a field ignored by `eq` are equal but may have different
+    /// hashes. This breaks `HashMap` and `HashSet`, which rely on
+    /// `k1 == k2 ⇒ hash(k1) == hash(k2)`.
+    ///
+    /// **Known problems:** Only fields read directly from `self` and `other` (e.g.
+    /// `self.id == other.id`) are recognized. If `self` or `other` is used in any other
+    /// way, e.g. passed to a function, the implementation is not checked.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// #[derive(Hash)]
+    /// struct User {
+    ///     id: u32,
+    ///     last_seen: u64,
+    /// }
+    ///
+    /// impl PartialEq for User {
+    ///     fn eq(&self, other: &Self) -> bool {
+    ///         self.id == other.id
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::hash::{Hash, Hasher};
+    /// # struct User {
+    /// #     id: u32,
+    /// #     last_seen: u64,
+    /// # }
+    /// # impl PartialEq for User {
+    /// #     fn eq(&self, other: &Self) -> bool {
+    /// #         self.id == other.id
+    /// #     }
+    /// # }
+    /// impl Hash for User {
+    ///     fn hash<H: Hasher>(&self, state: &mut H) {
+    ///         self.id.hash(state);
+    ///     }
+    /// }
+    /// ```
+    pub PARTIAL_EQ_IGNORES_HASHED_FIELDS,
+    correctness,
+    "manual `PartialEq` implementations not comparing all fields hashed by a derived `Hash`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for structs deriving `Clone` but not `Copy`, whose fields are
+    /// all `Copy` and whose size is at most `clone-without-copy-size-limit` bytes.
+    ///
+    /// **Why is this bad?** Such a type can derive `Copy` as well. Small `Copy` types are
+    /// passed around without any `.clone()` calls, and copying them is as cheap as it gets.
+    ///
+    /// **Known problems:** Deriving `Copy` on a public type is a commitment, removing it
+    /// later is a breaking change. Some types are deliberately not `Copy`, e.g. iterators
+    /// or handles whose copies should be explicit.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// #[derive(Clone)]
+    /// struct Point {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// #[derive(Clone, Copy)]
+    /// struct Point {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    /// ```
+    pub CLONE_WITHOUT_COPY,
+    pedantic,
+    "small structs deriving `Clone` but not `Copy`, although all their fields are `Copy`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for explicit `Clone` implementations which do exactly what
+    /// `#[derive(Clone)]` does: construct the same struct or enum variant with each field
+    /// cloned, with a `Clone` bound on each type parameter.
+    ///
+    /// **Why is this bad?** The implementation is boilerplate which has to be kept in sync
+    /// with the fields of the type, while the derived implementation is always up to date.
+    ///
+    /// **Known problems:** Only fields cloned with `.clone()` directly are recognized.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct Tag {
+    ///     name: String,
+    ///     color: u32,
+    /// }
+    ///
+    /// impl Clone for Tag {
+    ///     fn clone(&self) -> Self {
+    ///         Self {
+    ///             name: self.name.clone(),
+    ///             color: self.color.clone(),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// #[derive(Clone)]
+    /// struct Tag {
+    ///     name: String,
+    ///     color: u32,
+    /// }
+    /// ```
+    pub DERIVABLE_CLONE_IMPL,
+    pedantic,
+    "explicit `Clone` implementations equivalent to `#[derive(Clone)]`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `Hash` implementations hashing the bit pattern of a float
+    /// field with `to_bits()`, while the `PartialEq` implementation of the type does not
+    /// compare the bit patterns of that field.
+    ///
+    /// **Why is this bad?** Floats are not `Hash`, so hashing `to_bits()` is the usual way
+    /// around it. But `0.0 == -0.0` although their bit patterns differ, so two values which
+    /// are equal may have different hashes, which breaks `HashMap` and `HashSet`. The
+    /// other way around, `NaN != NaN` although the bits are the same.
+    ///
+    /// **Known problems:** Only `to_bits()` calls directly on a field of `self` or `other`,
+    /// like `self.x.to_bits()`, are recognized.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use std::hash::{Hash, Hasher};
+    ///
+    /// struct Point {
+    ///     x: f64,
+    /// }
+    ///
+    /// impl PartialEq for Point {
+    ///     fn eq(&self, other: &Self) -> bool {
+    ///         self.x == other.x
+    ///     }
+    /// }
+    ///
+    /// impl Hash for Point {
+    ///     fn hash<H: Hasher>(&self, state: &mut H) {
+    ///         self.x.to_bits().hash(state);
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # struct Point {
+    /// #     x: f64,
+    /// # }
+    /// impl PartialEq for Point {
+    ///     fn eq(&self, other: &Self) -> bool {
+    ///         self.x.to_bits() == other.x.to_bits()
+    ///     }
+    /// }
+    /// ```
+    pub FLOAT_BITS_HASH_XOR_EQ,
+    pedantic,
+    "`Hash` implementations hashing the bit pattern of a float field which `PartialEq` compares with `==`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for types deriving `PartialEq` which contain a float field,
+    /// directly or in a nested struct or tuple, and are inserted as keys into a `HashMap` or
+    /// `HashSet` elsewhere in the crate.
+    ///
+    /// **Why is this bad?** The derived `PartialEq` compares floats with `==`, for which
+    /// `NaN != NaN`: a key containing `NaN` is never found again once inserted. Such a
+    /// type also needs a manual `Eq` and `Hash` implementation, which usually hashes the bit
+    /// pattern of the float and so does not agree with `==` for `0.0` and `-0.0` either.
+    ///
+    /// **Known problems:** Only `insert` calls are recognized as key usages.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use std::collections::HashSet;
+    /// use std::hash::{Hash, Hasher};
+    ///
+    /// #[derive(PartialEq)]
+    /// struct Weight(f64);
+    ///
+    /// impl Eq for Weight {}
+    ///
+    /// impl Hash for Weight {
+    ///     fn hash<H: Hasher>(&self, state: &mut H) {
+    ///         self.0.to_bits().hash(state);
+    ///     }
+    /// }
+    ///
+    /// let mut seen = HashSet::new();
+    /// seen.insert(Weight(0.5));
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # struct Weight(f64);
+    /// impl PartialEq for Weight {
+    ///     fn eq(&self, other: &Self) -> bool {
+    ///         self.0.to_bits() == other.0.to_bits()
+    ///     }
+    /// }
+    /// ```
+    pub FLOAT_PARTIAL_EQ_HASH_KEY,
+    pedantic,
+    "types deriving `PartialEq` with float fields used as `HashMap` or `HashSet` keys"
+}
+
+pub struct Derive {
+    clone_without_copy_size_limit: u64,
+    /// Local types deriving `PartialEq` with a float field: the derived impl and the path to
+    /// the field
+    derived_float_eqs: Vec<(DefId, Span, String)>,
+    /// Local types inserted as keys into a `HashMap` or `HashSet`: the first `insert` call and
+    /// the collection
+    hash_keys: FxHashMap<DefId, (Span, &'static str)>,
+}
+
+impl Derive {
+    pub fn new(clone_without_copy_size_limit: u64) -> Self {
+        Self {
+            clone_without_copy_size_limit,
+            derived_float_eqs: Vec::new(),
+            hash_keys: FxHashMap::default(),
+        }
+    }
+}
+
+impl_lint_pass!(Derive => [
     EXPL_IMPL_CLONE_ON_COPY,
     DERIVE_HASH_XOR_EQ,
     DERIVE_ORD_XOR_PARTIAL_ORD,
-    UNSAFE_DERIVE_DESERIALIZE
+    UNSAFE_DERIVE_DESERIALIZE,
+    NON_CANONICAL_PARTIAL_ORD,
+    PARTIAL_EQ_IGNORES_HASHED_FIELDS,
+    CLONE_WITHOUT_COPY,
+    DERIVABLE_CLONE_IMPL,
+    FLOAT_BITS_HASH_XOR_EQ,
+    FLOAT_PARTIAL_EQ_HASH_KEY
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Derive {
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        if let ItemKind::Struct(..) = item.kind {
+            check_clone_without_copy(cx, item, self.clone_without_copy_size_limit);
+        }
+
         if let ItemKind::Impl {
             of_trait: Some(ref trait_ref),
+            items,
             ..
         } = item.kind
         {
@@ -173,12 +484,91 @@ impl<'tcx> LateLintPass<'tcx> for Derive {
             let is_automatically_derived = is_automatically_derived(&*item.attrs);
 
             check_hash_peq(cx, item.span, trait_ref, ty, is_automatically_derived);
-            check_ord_partial_ord(cx, item.span, trait_ref, ty, is_automatically_derived);
+            check_ord_partial_ord(cx, item, trait_ref, ty, is_automatically_derived);
 
             if is_automatically_derived {
                 check_unsafe_derive_deserialize(cx, item, trait_ref, ty);
+                self.check_derived_float_eq(cx, item, trait_ref, ty);
             } else {
                 check_copy_clone(cx, item, trait_ref, ty);
+
+                if !item.span.from_expansion() && is_impl_for_self(cx, item, ty) {
+                    check_partial_ord_canonical(cx, items, trait_ref, ty);
+                    check_peq_hashed_fields(cx, items, trait_ref, ty);
+                    check_derivable_clone(cx, item, items, trait_ref, ty);
+                    check_hash_float_bits(cx, items, trait_ref, ty);
+                }
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::MethodCall(ref path, _, ref args, _) = expr.kind {
+            if path.ident.name != sym!(insert) {
+                return;
+            }
+            let recv_ty = walk_ptrs_ty(cx.typeck_results().expr_ty(&args[0]));
+            let collection = if is_type_diagnostic_item(cx, recv_ty, sym!(hashmap_type)) {
+                "HashMap"
+            } else if is_type_diagnostic_item(cx, recv_ty, sym!(hashset_type)) {
+                "HashSet"
+            } else {
+                return;
+            };
+            if let ty::Adt(def, _) = walk_ptrs_ty(cx.typeck_results().expr_ty(&args[1])).kind() {
+                if def.did.is_local() {
+                    self.hash_keys.entry(def.did).or_insert((expr.span, collection));
+                }
+            }
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>, _: &'tcx Crate<'_>) {
+        for (def_id, span, field) in &self.derived_float_eqs {
+            if let Some((key_span, collection)) = self.hash_keys.get(def_id) {
+                let hir_id = cx.tcx.hir().local_def_id_to_hir_id(def_id.expect_local());
+                span_lint_hir_and_then(
+                    cx,
+                    FLOAT_PARTIAL_EQ_HASH_KEY,
+                    hir_id,
+                    *span,
+                    &format!(
+                        "`{}` is used as a `{}` key, but its derived `PartialEq` compares the float field `{}` with `==`",
+                        cx.tcx.item_name(*def_id),
+                        collection,
+                        field
+                    ),
+                    |diag| {
+                        diag.span_note(*key_span, "used as a key here");
+                        diag.note("`NaN != NaN`, so a key containing `NaN` is never found again once inserted");
+                        diag.help(&format!(
+                            "implement `PartialEq` manually and compare `self.{}.to_bits()`, like a `Hash` implementation would hash it",
+                            field
+                        ));
+                    },
+                );
+            }
+        }
+    }
+}
+
+impl Derive {
+    /// Records a derived `PartialEq` implementation for the `FLOAT_PARTIAL_EQ_HASH_KEY` lint, which
+    /// is emitted once all `HashMap` and `HashSet` keys of the crate are known.
+    fn check_derived_float_eq<'tcx>(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        item: &Item<'_>,
+        trait_ref: &TraitRef<'_>,
+        ty: Ty<'tcx>,
+    ) {
+        if_chain! {
+            if trait_ref.trait_def_id() == cx.tcx.lang_items().eq_trait();
+            if let ty::Adt(def, substs) = ty.kind();
+            if def.is_struct() && def.did.is_local();
+            if let Some(path) = float_field_path(cx, def, substs, &mut FxHashSet::default());
+            then {
+                self.derived_float_eqs.push((def.did, item.span, path.join(".")));
             }
         }
     }
@@ -193,10 +583,9 @@ fn check_hash_peq<'tcx>(
     hash_is_automatically_derived: bool,
 ) {
     if_chain! {
-        if match_path(&trait_ref.path, &paths::HASH);
+        if let Some(hash_trait_def_id) = get_trait_def_id(cx, &paths::HASH_TRAIT);
+        if trait_ref.trait_def_id() == Some(hash_trait_def_id);
         if let Some(peq_trait_def_id) = cx.tcx.lang_items().eq_trait();
-        if let Some(def_id) = &trait_ref.trait_def_id();
-        if !def_id.is_local();
         then {
             // Look for the PartialEq implementations for `ty`
             cx.tcx.for_each_relevant_impl(peq_trait_def_id, ty, |impl_id| {
@@ -208,40 +597,60 @@ fn check_hash_peq<'tcx>(
 
                 let trait_ref = cx.tcx.impl_trait_ref(impl_id).expect("must be a trait implementation");
 
-                // Only care about `impl PartialEq<Foo> for Foo`
+                // Only care about `impl PartialEq<Foo> for Foo`, and about `impl PartialEq<Bar> for Foo`
+                // if `Foo: Borrow<Bar>`, derived implementations are always of the former kind.
                 // For `impl PartialEq<B> for A, input_types is [A, B]
-                if trait_ref.substs.type_at(1) == ty {
+                let other = trait_ref.substs.type_at(1);
+                let (mess, borrowed_note) = if other == ty {
                     let mess = if peq_is_automatically_derived {
                         "you are implementing `Hash` explicitly but have derived `PartialEq`"
                     } else {
                         "you are deriving `Hash` but have implemented `PartialEq` explicitly"
                     };
+                    (mess.to_string(), None)
+                } else if is_borrowed_as(cx, ty, other) {
+                    (
+                        format!("you are deriving `Hash` but have implemented `PartialEq<{}>` explicitly", other),
+                        Some(format!(
+                            "`{}` can be borrowed as `{}`, so values comparing equal must have equal hashes",
+                            ty, other
+                        )),
+                    )
+                } else {
+                    return;
+                };
 
-                    span_lint_and_then(
-                        cx,
-                        DERIVE_HASH_XOR_EQ,
-                        span,
-                        mess,
-                        |diag| {
-                            if let Some(local_def_id) = impl_id.as_local() {
-                                let hir_id = cx.tcx.hir().local_def_id_to_hir_id(local_def_id);
-                                diag.span_note(
-                                    cx.tcx.hir().span(hir_id),
-                                    "`PartialEq` implemented here"
-                                );
-                            }
+                span_lint_and_then(
+                    cx,
+                    DERIVE_HASH_XOR_EQ,
+                    span,
+                    &mess,
+                    |diag| {
+                        if let Some(local_def_id) = impl_id.as_local() {
+                            let hir_id = cx.tcx.hir().local_def_id_to_hir_id(local_def_id);
+                            diag.span_note(
+                                cx.tcx.hir().span(hir_id),
+                                "`PartialEq` implemented here"
+                            );
                         }
-                    );
-                }
+                        if let Some(note) = borrowed_note {
+                            diag.note(&note);
+                        }
+                    }
+                );
             });
         }
     }
 }
 
+fn is_borrowed_as<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>, other: Ty<'tcx>) -> bool {
+    get_trait_def_id(cx, &paths::BORROW_TRAIT).map_or(false, |borrow| implements_trait(cx, ty, borrow, &[other.into()]))
+}
+
 /// Implementation of the `DERIVE_ORD_XOR_PARTIAL_ORD` lint.
 fn check_ord_partial_ord<'tcx>(
     cx: &LateContext<'tcx>,
-    span: Span,
+    item: &Item<'_>,
     trait_ref: &TraitRef<'_>,
     ty: Ty<'tcx>,
     ord_is_automatically_derived: bool,
@@ -265,16 +674,28 @@ fn check_ord_partial_ord<'tcx>(
                 // Only care about `impl PartialOrd<Foo> for Foo`
                 // For `impl PartialOrd<B> for A, input_types is [A, B]
                 if trait_ref.substs.type_at(1) == ty {
-                    let mess = if partial_ord_is_automatically_derived {
-                        "you are implementing `Ord` explicitly but have derived `PartialOrd`"
+                    let (mess, delegates) = if partial_ord_is_automatically_derived {
+                        (
+                            "you are implementing `Ord` explicitly but have derived `PartialOrd`",
+                            delegates_to_derived(cx, item.hir_id, "cmp", is_unwrapped_partial_cmp),
+                        )
                     } else {
-                        "you are deriving `Ord` but have implemented `PartialOrd` explicitly"
+                        (
+                            "you are deriving `Ord` but have implemented `PartialOrd` explicitly",
+                            impl_id.as_local().map_or(false, |local_def_id| {
+                                let hir_id = cx.tcx.hir().local_def_id_to_hir_id(local_def_id);
+                                delegates_to_derived(cx, hir_id, "partial_cmp", is_canonical_partial_cmp)
+                            }),
+                        )
                     };
+                    if delegates {
+                        return;
+                    }
 
                     span_lint_and_then(
                         cx,
                         DERIVE_ORD_XOR_PARTIAL_ORD,
-                        span,
+                        item.span,
                         mess,
                         |diag| {
                             if let Some(local_def_id) = impl_id.as_local() {
@@ -284,6 +705,7 @@ fn check_ord_partial_ord<'tcx>(
                                     "`PartialOrd` implemented here"
                                 );
                             }
+                            diag.help("derive both `PartialOrd` and `Ord`, or implement both explicitly");
                         }
                     );
                 }
@@ -292,6 +714,26 @@ fn check_ord_partial_ord<'tcx>(
     }
 }
 
+/// Checks if the method `name` of the explicit implementation `impl_hir_id` only delegates to
+/// the derived implementation of the other trait, according to `is_delegation`.
+fn delegates_to_derived(
+    cx: &LateContext<'_>,
+    impl_hir_id: HirId,
+    name: &str,
+    is_delegation: fn(&LateContext<'_>, &TypeckResults<'_>, &Expr<'_>, HirId, HirId) -> bool,
+) -> bool {
+    if_chain! {
+        if let ItemKind::Impl { items, .. } = cx.tcx.hir().expect_item(impl_hir_id).kind;
+        if let Some((_, body)) = impl_method(cx, items, name);
+        if let Some((self_id, other_id)) = self_and_other_params(body);
+        then {
+            is_delegation(cx, cx.tcx.typeck_body(body.id()), &body.value, self_id, other_id)
+        } else {
+            false
+        }
+    }
+}
+
 /// Implementation of the `EXPL_IMPL_CLONE_ON_COPY` lint.
 fn check_copy_clone<'tcx>(cx: &LateContext<'tcx>, item: &Item<'_>, trait_ref: &TraitRef<'_>, ty: Ty<'tcx>) {
     if match_path(&trait_ref.path, &paths::CLONE_TRAIT) {
@@ -333,6 +775,719 @@ fn check_copy_clone<'tcx>(cx: &LateContext<'tcx>, item: &Item<'_>, trait_ref: &T
     }
 }
 
+/// Returns `true` if the trait of the impl `item` has no type parameters except `Rhs = Self`, e.g.
+/// `impl PartialEq for Foo` but not `impl PartialEq<Bar> for Foo`.
+fn is_impl_for_self<'tcx>(cx: &LateContext<'tcx>, item: &Item<'_>, ty: Ty<'tcx>) -> bool {
+    cx.tcx
+        .impl_trait_ref(cx.tcx.hir().local_def_id(item.hir_id))
+        .map_or(false, |trait_ref| trait_ref.substs.types().skip(1).all(|rhs| rhs == ty))
+}
+
+/// Returns the method `name` of an impl and its body.
+fn impl_method<'tcx>(
+    cx: &LateContext<'tcx>,
+    items: &[ImplItemRef<'_>],
+    name: &str,
+) -> Option<(&'tcx ImplItem<'tcx>, &'tcx Body<'tcx>)> {
+    items
+        .iter()
+        .find(|item| item.ident.name.as_str() == name)
+        .and_then(|item| {
+            let impl_item = cx.tcx.hir().impl_item(item.id);
+            match impl_item.kind {
+                ImplItemKind::Fn(_, body_id) => Some((impl_item, cx.tcx.hir().body(body_id))),
+                _ => None,
+            }
+        })
+}
+
+/// Returns the `HirId`s of the bindings of the `self` and `other` parameters of a method
+/// `fn(&self, other: &Self)`.
+fn self_and_other_params(body: &Body<'_>) -> Option<(HirId, HirId)> {
+    match body.params {
+        [self_param, other_param] => match (&self_param.pat.kind, &other_param.pat.kind) {
+            (PatKind::Binding(_, self_id, ..), PatKind::Binding(_, other_id, ..)) => Some((*self_id, *other_id)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn is_local(cx: &LateContext<'_>, expr: &Expr<'_>, id: HirId) -> bool {
+    if let ExprKind::Path(ref qpath) = expr.kind {
+        qpath_res(cx, qpath, expr.hir_id) == Res::Local(id)
+    } else {
+        false
+    }
+}
+
+/// Implementation of the `NON_CANONICAL_PARTIAL_ORD` lint.
+fn check_partial_ord_canonical<'tcx>(
+    cx: &LateContext<'tcx>,
+    items: &[ImplItemRef<'_>],
+    trait_ref: &TraitRef<'_>,
+    ty: Ty<'tcx>,
+) {
+    if_chain! {
+        if trait_ref.trait_def_id() == cx.tcx.lang_items().partial_ord_trait();
+        if let Some(ord_trait_def_id) = get_trait_def_id(cx, &paths::ORD);
+        if implements_trait(cx, ty, ord_trait_def_id, &[]);
+        if let Some((_, body)) = impl_method(cx, items, "partial_cmp");
+        if let Some((self_id, other_id)) = self_and_other_params(body);
+        if !is_canonical_partial_cmp(cx, cx.tcx.typeck_body(body.id()), &body.value, self_id, other_id);
+        // Suggesting to delegate to `cmp` would be an infinite recursion if `cmp` delegates to
+        // `partial_cmp`
+        if !cmp_calls_partial_cmp(cx, ty, ord_trait_def_id);
+        then {
+            let other = cx.tcx.hir().name(other_id);
+            span_lint_and_sugg(
+                cx,
+                NON_CANONICAL_PARTIAL_ORD,
+                body.value.span,
+                "non-canonical implementation of `partial_cmp` on an `Ord` type",
+                "delegate to `Ord::cmp` instead",
+                format!("{{ Some(self.cmp({})) }}", other),
+                Applicability::MaybeIncorrect,
+            );
+        }
+    }
+}
+
+/// Checks if `expr` is `Some(self.cmp(other))` or `Some(Ord::cmp(self, other))`, possibly in a
+/// block.
+fn is_canonical_partial_cmp(
+    cx: &LateContext<'_>,
+    typeck_results: &TypeckResults<'_>,
+    expr: &Expr<'_>,
+    self_id: HirId,
+    other_id: HirId,
+) -> bool {
+    if let ExprKind::Block(ref block, _) = expr.kind {
+        return match (block.stmts, block.expr) {
+            ([], Some(expr)) => is_canonical_partial_cmp(cx, typeck_results, expr, self_id, other_id),
+            _ => false,
+        };
+    }
+    if_chain! {
+        if let ExprKind::Call(ref some, ref args) = expr.kind;
+        if let ExprKind::Path(ref some_path) = some.kind;
+        if match_qpath(some_path, &paths::OPTION_SOME);
+        if let [cmp] = &**args;
+        then {
+            match cmp.kind {
+                ExprKind::MethodCall(ref path, _, ref args, _) => {
+                    path.ident.name == sym!(cmp)
+                        && typeck_results
+                            .type_dependent_def_id(cmp.hir_id)
+                            .and_then(|def_id| cx.tcx.trait_of_item(def_id))
+                            .map_or(false, |trait_id| match_def_path(cx, trait_id, &paths::ORD))
+                        && is_local(cx, &args[0], self_id)
+                        && is_local(cx, &args[1], other_id)
+                },
+                ExprKind::Call(ref func, ref args) => {
+                    if_chain! {
+                        if let ExprKind::Path(ref func_path) = func.kind;
+                        if let Res::Def(_, def_id) = qpath_res(cx, func_path, func.hir_id);
+                        if let Some(trait_id) = cx.tcx.trait_of_item(def_id);
+                        if match_def_path(cx, trait_id, &paths::ORD);
+                        if let [lhs, rhs] = &**args;
+                        then {
+                            is_local(cx, lhs, self_id) && is_local(cx, rhs, other_id)
+                        } else {
+                            false
+                        }
+                    }
+                },
+                _ => false,
+            }
+        } else {
+            false
+        }
+    }
+}
+
+/// Checks if `expr` is `self.partial_cmp(other).unwrap()`, possibly in a block.
+fn is_unwrapped_partial_cmp(
+    cx: &LateContext<'_>,
+    typeck_results: &TypeckResults<'_>,
+    expr: &Expr<'_>,
+    self_id: HirId,
+    other_id: HirId,
+) -> bool {
+    if let ExprKind::Block(ref block, _) = expr.kind {
+        return match (block.stmts, block.expr) {
+            ([], Some(expr)) => is_unwrapped_partial_cmp(cx, typeck_results, expr, self_id, other_id),
+            _ => false,
+        };
+    }
+    if_chain! {
+        if let ExprKind::MethodCall(ref unwrap, _, ref unwrap_args, _) = expr.kind;
+        if unwrap.ident.name == sym!(unwrap);
+        if let ExprKind::MethodCall(ref path, _, ref args, _) = unwrap_args[0].kind;
+        if path.ident.name == sym!(partial_cmp);
+        if let Some(def_id) = typeck_results.type_dependent_def_id(unwrap_args[0].hir_id);
+        if cx.tcx.trait_of_item(def_id) == cx.tcx.lang_items().partial_ord_trait();
+        then {
+            is_local(cx, &args[0], self_id) && is_local(cx, &args[1], other_id)
+        } else {
+            false
+        }
+    }
+}
+
+/// Checks if the local `Ord` implementation of `ty` calls `partial_cmp` in `cmp`.
+fn cmp_calls_partial_cmp<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>, ord_trait_def_id: DefId) -> bool {
+    let mut calls = false;
+    cx.tcx.for_each_relevant_impl(ord_trait_def_id, ty, |impl_id| {
+        if_chain! {
+            if let Some(local_def_id) = impl_id.as_local();
+            let hir_id = cx.tcx.hir().local_def_id_to_hir_id(local_def_id);
+            if let ItemKind::Impl { items, .. } = cx.tcx.hir().expect_item(hir_id).kind;
+            if let Some((_, body)) = impl_method(cx, items, "cmp");
+            if contains_name(sym!(partial_cmp), &body.value);
+            then {
+                calls = true;
+            }
+        }
+    });
+    calls
+}
+
+/// Implementation of the `PARTIAL_EQ_IGNORES_HASHED_FIELDS` lint.
+fn check_peq_hashed_fields<'tcx>(
+    cx: &LateContext<'tcx>,
+    items: &[ImplItemRef<'_>],
+    trait_ref: &TraitRef<'_>,
+    ty: Ty<'tcx>,
+) {
+    if_chain! {
+        if trait_ref.trait_def_id() == cx.tcx.lang_items().eq_trait();
+        if let ty::Adt(def, substs) = ty.kind();
+        if def.is_struct();
+        if let Some(hash_trait_def_id) = get_trait_def_id(cx, &paths::HASH_TRAIT);
+        if has_derived_impl(cx, hash_trait_def_id, ty);
+        if let Some((method, body)) = impl_method(cx, items, "eq");
+        if let Some((self_id, other_id)) = self_and_other_params(body);
+        if let Some(compared) = compared_fields(cx, body, self_id, other_id);
+        let ignored = def
+            .non_enum_variant()
+            .fields
+            .iter()
+            .filter(|field| !compared.contains(&field.ident.name))
+            .filter(|field| !matches!(field.ty(cx.tcx, substs).kind(),
+                ty::Adt(def, _) if Some(def.did) == cx.tcx.lang_items().phantom_data()))
+            .map(|field| format!("`{}`", field.ident))
+            .collect::<Vec<_>>();
+        if !ignored.is_empty();
+        then {
+            span_lint_and_then(
+                cx,
+                PARTIAL_EQ_IGNORES_HASHED_FIELDS,
+                method.span,
+                "this `PartialEq` implementation does not compare all fields hashed by the derived `Hash` implementation",
+                |diag| {
+                    diag.note(&format!("fields hashed but not compared: {}", ignored.join(", ")));
+                    diag.help("compare these fields too, or implement `Hash` manually to only hash the compared fields");
+                },
+            );
+        }
+    }
+}
+
+fn has_derived_impl<'tcx>(cx: &LateContext<'tcx>, trait_def_id: DefId, ty: Ty<'tcx>) -> bool {
+    let mut derived = false;
+    cx.tcx.for_each_relevant_impl(trait_def_id, ty, |impl_id| {
+        derived |= is_automatically_derived(&cx.tcx.get_attrs(impl_id));
+    });
+    derived
+}
+
+/// Returns the names of the fields of `self` and `other` read in `body`, or `None` if `self` or
+/// `other` are used in any other way.
+fn compared_fields(
+    cx: &LateContext<'_>,
+    body: &Body<'_>,
+    self_id: HirId,
+    other_id: HirId,
+) -> Option<FxHashSet<Symbol>> {
+    let mut visitor = ComparedFieldsVisitor {
+        cx,
+        params: [self_id, other_id],
+        fields: FxHashSet::default(),
+        opaque: false,
+    };
+    visitor.visit_expr(&body.value);
+    if visitor.opaque {
+        None
+    } else {
+        Some(visitor.fields)
+    }
+}
+
+struct ComparedFieldsVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    params: [HirId; 2],
+    fields: FxHashSet<Symbol>,
+    /// `self` or `other` are used other than by reading a field
+    opaque: bool,
+}
+
+impl<'a, 'tcx> ComparedFieldsVisitor<'a, 'tcx> {
+    fn is_param(&self, mut expr: &Expr<'_>) -> bool {
+        while let ExprKind::Unary(UnOp::UnDeref, ref inner) = expr.kind {
+            expr = inner;
+        }
+        self.params.iter().any(|id| is_local(self.cx, expr, *id))
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for ComparedFieldsVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Field(ref base, ident) = expr.kind {
+            if self.is_param(base) {
+                self.fields.insert(ident.name);
+                return;
+            }
+        }
+        if self.is_param(expr) {
+            self.opaque = true;
+            return;
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
+
+/// Implementation of the `FLOAT_BITS_HASH_XOR_EQ` lint.
+fn check_hash_float_bits<'tcx>(
+    cx: &LateContext<'tcx>,
+    items: &[ImplItemRef<'_>],
+    trait_ref: &TraitRef<'_>,
+    ty: Ty<'tcx>,
+) {
+    if_chain! {
+        if let Some(hash_trait_def_id) = get_trait_def_id(cx, &paths::HASH_TRAIT);
+        if trait_ref.trait_def_id() == Some(hash_trait_def_id);
+        if let Some(peq_trait_def_id) = cx.tcx.lang_items().eq_trait();
+        if let Some((_, body)) = impl_method(cx, items, "hash");
+        if let Some(PatKind::Binding(_, self_id, ..)) = body.params.first().map(|param| &param.pat.kind);
+        let hashed = float_bits_fields(cx, body, &[*self_id]);
+        if !hashed.is_empty();
+        then {
+            cx.tcx.for_each_relevant_impl(peq_trait_def_id, ty, |impl_id| {
+                let local_def_id = match impl_id.as_local() {
+                    Some(local_def_id) => local_def_id,
+                    None => return,
+                };
+                let peq_item = cx.tcx.hir().expect_item(cx.tcx.hir().local_def_id_to_hir_id(local_def_id));
+                let compared = if is_automatically_derived(&*peq_item.attrs) {
+                    FxHashSet::default()
+                } else {
+                    match peq_item.kind {
+                        ItemKind::Impl { items, .. } if is_impl_for_self(cx, peq_item, ty) => {
+                            match impl_method(cx, items, "eq") {
+                                Some((_, body)) => match self_and_other_params(body) {
+                                    Some((self_id, other_id)) => float_bits_fields(cx, body, &[self_id, other_id])
+                                        .into_iter()
+                                        .map(|(path, _)| path)
+                                        .collect(),
+                                    None => return,
+                                },
+                                None => return,
+                            }
+                        },
+                        _ => return,
+                    }
+                };
+
+                for (path, span) in &hashed {
+                    if compared.contains(path) {
+                        continue;
+                    }
+                    span_lint_and_then(
+                        cx,
+                        FLOAT_BITS_HASH_XOR_EQ,
+                        *span,
+                        &format!(
+                            "the bit pattern of the float field `{}` is hashed, but `PartialEq` does not compare it",
+                            path
+                        ),
+                        |diag| {
+                            diag.span_note(peq_item.span, "`PartialEq` implemented here");
+                            diag.note("`0.0 == -0.0` although their bit patterns differ, so equal values may have different hashes");
+                            diag.help(&format!("compare `self.{}.to_bits()` in `PartialEq` as well", path));
+                        },
+                    );
+                }
+            });
+        }
+    }
+}
+
+/// Returns the float fields of the `params` whose bit pattern is read in `body` with
+/// `to_bits()`, e.g. `pos.x` for `self.pos.x.to_bits()`, and the spans of the calls.
+fn float_bits_fields(cx: &LateContext<'_>, body: &Body<'_>, params: &[HirId]) -> Vec<(String, Span)> {
+    let mut visitor = FloatBitsVisitor {
+        cx,
+        typeck_results: cx.tcx.typeck_body(body.id()),
+        params,
+        fields: Vec::new(),
+    };
+    visitor.visit_expr(&body.value);
+    visitor.fields
+}
+
+struct FloatBitsVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    typeck_results: &'tcx TypeckResults<'tcx>,
+    params: &'a [HirId],
+    fields: Vec<(String, Span)>,
+}
+
+impl<'a, 'tcx> FloatBitsVisitor<'a, 'tcx> {
+    /// Returns the field path of `expr` if it is a (nested) field of one of the parameters.
+    fn field_path(&self, mut expr: &Expr<'_>) -> Option<String> {
+        let mut path = Vec::new();
+        while let ExprKind::Field(ref base, ident) = expr.kind {
+            path.push(ident.as_str().to_string());
+            expr = base;
+        }
+        while let ExprKind::Unary(UnOp::UnDeref, ref inner) = expr.kind {
+            expr = inner;
+        }
+        if path.is_empty() || !self.params.iter().any(|id| is_local(self.cx, expr, *id)) {
+            return None;
+        }
+        path.reverse();
+        Some(path.join("."))
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for FloatBitsVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if let ExprKind::MethodCall(ref path, _, ref args, _) = expr.kind;
+            if path.ident.name == sym!(to_bits);
+            if self.typeck_results.expr_ty(&args[0]).is_floating_point();
+            if let Some(field) = self.field_path(&args[0]);
+            then {
+                self.fields.push((field, expr.span));
+                return;
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
+
+/// Returns the path to a float field of a struct, looking into nested structs and tuples, e.g.
+/// `["pos", "0"]` for a field `pos: (f64, f64)`. Enums, arrays and references containing a float
+/// count as a float field themselves.
+fn float_field_path<'tcx>(
+    cx: &LateContext<'tcx>,
+    def: &'tcx AdtDef,
+    substs: SubstsRef<'tcx>,
+    visited: &mut FxHashSet<Ty<'tcx>>,
+) -> Option<Vec<String>> {
+    def.all_fields().find_map(|field| {
+        let mut path = float_path_in(cx, field.ty(cx.tcx, substs), visited)?;
+        path.insert(0, field.ident.as_str().to_string());
+        Some(path)
+    })
+}
+
+fn float_path_in<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>, visited: &mut FxHashSet<Ty<'tcx>>) -> Option<Vec<String>> {
+    // references may lead back to the same type
+    if !visited.insert(ty) {
+        return None;
+    }
+    match ty.kind() {
+        ty::Float(_) => Some(Vec::new()),
+        ty::Array(ty, _) | ty::Slice(ty) | ty::Ref(_, ty, _) => float_path_in(cx, ty, visited).map(|_| Vec::new()),
+        ty::Tuple(substs) => substs.types().enumerate().find_map(|(idx, ty)| {
+            let mut path = float_path_in(cx, ty, visited)?;
+            path.insert(0, idx.to_string());
+            Some(path)
+        }),
+        ty::Adt(def, substs) if def.is_struct() => float_field_path(cx, def, substs, visited),
+        ty::Adt(def, substs) if def.is_enum() => float_field_path(cx, def, substs, visited).map(|_| Vec::new()),
+        _ => None,
+    }
+}
+
+/// Implementation of the `CLONE_WITHOUT_COPY` lint.
+fn check_clone_without_copy(cx: &LateContext<'_>, item: &Item<'_>, size_limit: u64) {
+    let ty = cx.tcx.type_of(cx.tcx.hir().local_def_id(item.hir_id));
+    if_chain! {
+        if !item.span.from_expansion();
+        if let ty::Adt(def, substs) = ty.kind();
+        if let Some(clone_trait_def_id) = cx.tcx.lang_items().clone_trait();
+        if has_derived_impl(cx, clone_trait_def_id, ty);
+        if !is_copy(cx, ty);
+        // A `Drop` implementation rules out `Copy`
+        if !ty.needs_drop(cx.tcx, cx.param_env);
+        if def.all_fields().all(|field| is_copy(cx, field.ty(cx.tcx, substs)));
+        if let Ok(layout) = cx.layout_of(ty);
+        if layout.size.bytes() <= size_limit;
+        then {
+            span_lint_and_help(
+                cx,
+                CLONE_WITHOUT_COPY,
+                item.ident.span,
+                &format!("`{}` derives `Clone` but not `Copy`, although all its fields are `Copy`", item.ident),
+                None,
+                &format!("derive `Copy` as well, the type is only {} bytes large", layout.size.bytes()),
+            );
+        }
+    }
+}
+
+/// Implementation of the `DERIVABLE_CLONE_IMPL` lint.
+fn check_derivable_clone<'tcx>(
+    cx: &LateContext<'tcx>,
+    item: &Item<'_>,
+    items: &[ImplItemRef<'_>],
+    trait_ref: &TraitRef<'_>,
+    ty: Ty<'tcx>,
+) {
+    if_chain! {
+        if let Some(clone_trait_def_id) = cx.tcx.lang_items().clone_trait();
+        if trait_ref.trait_def_id() == Some(clone_trait_def_id);
+        if let ty::Adt(def, substs) = ty.kind();
+        if !def.is_union() && !def.repr.packed();
+        if def.did.is_local();
+        // The derived implementation only has `clone`, not `clone_from`
+        if let [_] = items;
+        if let Some((_, body)) = impl_method(cx, items, "clone");
+        if let [self_param] = body.params;
+        if let PatKind::Binding(_, self_id, ..) = self_param.pat.kind;
+        if has_derived_clone_bounds(cx, item, substs, clone_trait_def_id);
+        if is_derived_clone(cx, cx.tcx.typeck_body(body.id()), def, &body.value, self_id);
+        then {
+            span_lint_and_help(
+                cx,
+                DERIVABLE_CLONE_IMPL,
+                item.span,
+                "this `Clone` implementation is equivalent to `#[derive(Clone)]`",
+                None,
+                &format!("remove it and add `#[derive(Clone)]` to `{}`", cx.tcx.item_name(def.did)),
+            );
+        }
+    }
+}
+
+/// Checks whether the type arguments of the impl `item` are distinct type parameters of the impl,
+/// each bound by `Clone`, like in the derived implementation.
+fn has_derived_clone_bounds<'tcx>(
+    cx: &LateContext<'tcx>,
+    item: &Item<'_>,
+    substs: SubstsRef<'tcx>,
+    clone_trait_def_id: DefId,
+) -> bool {
+    let predicates = cx.tcx.predicates_of(cx.tcx.hir().local_def_id(item.hir_id));
+    let mut params = FxHashSet::default();
+    substs.types().all(|ty| {
+        matches!(ty.kind(), ty::Param(_))
+            && params.insert(ty)
+            && predicates.predicates.iter().any(|(pred, _)| {
+                matches!(
+                    pred.skip_binders(),
+                    ty::PredicateAtom::Trait(trait_pred, _)
+                        if trait_pred.def_id() == clone_trait_def_id && trait_pred.self_ty() == ty
+                )
+            })
+    })
+}
+
+/// Checks whether `expr` clones `self` like the derived `clone`: it constructs the struct from
+/// each field of `self` cloned, e.g. `Self { a: self.a.clone() }`, or it matches on `self` and
+/// constructs each variant from its bindings cloned, e.g. `Self::A(x) => Self::A(x.clone())`.
+fn is_derived_clone(
+    cx: &LateContext<'_>,
+    typeck_results: &TypeckResults<'_>,
+    def: &AdtDef,
+    mut expr: &Expr<'_>,
+    self_id: HirId,
+) -> bool {
+    while let ExprKind::Block(ref block, _) = expr.kind {
+        match (block.stmts, block.expr) {
+            ([], Some(inner)) => expr = inner,
+            _ => return false,
+        }
+    }
+
+    if def.is_struct() {
+        let variant = def.non_enum_variant();
+        return constructed_fields(typeck_results, def, variant, expr).map_or(false, |values| {
+            values.iter().zip(&variant.fields).all(|(value, field)| {
+                match cloned_value(cx, typeck_results, value).map(|cloned| &cloned.kind) {
+                    Some(ExprKind::Field(base, ident)) => {
+                        ident.name == field.ident.name && is_local(cx, peel_derefs(base), self_id)
+                    },
+                    _ => false,
+                }
+            })
+        });
+    }
+
+    if_chain! {
+        if let ExprKind::Match(ref scrutinee, arms, MatchSource::Normal) = expr.kind;
+        if is_local(cx, peel_derefs(scrutinee), self_id);
+        if arms.len() == def.variants.len();
+        then {
+            let mut matched = FxHashSet::default();
+            arms.iter().all(|arm| {
+                if_chain! {
+                    if arm.guard.is_none();
+                    if let Some((variant, bindings)) = variant_bindings(typeck_results, def, arm.pat);
+                    if matched.insert(variant.def_id);
+                    if let Some(values) = constructed_fields(typeck_results, def, variant, &arm.body);
+                    then {
+                        values.iter().zip(bindings).all(|(value, id)| {
+                            cloned_value(cx, typeck_results, value).map_or(false, |cloned| is_local(cx, cloned, id))
+                        })
+                    } else {
+                        false
+                    }
+                }
+            })
+        } else {
+            false
+        }
+    }
+}
+
+fn peel_derefs<'a>(mut expr: &'a Expr<'a>) -> &'a Expr<'a> {
+    while let ExprKind::Unary(UnOp::UnDeref, ref inner) = expr.kind {
+        expr = inner;
+    }
+    expr
+}
+
+/// Returns the receiver of `expr` if it is a call of `Clone::clone`.
+fn cloned_value<'a>(
+    cx: &LateContext<'_>,
+    typeck_results: &TypeckResults<'_>,
+    expr: &'a Expr<'a>,
+) -> Option<&'a Expr<'a>> {
+    if_chain! {
+        if let ExprKind::MethodCall(ref path, _, ref args, _) = expr.kind;
+        if path.ident.name == sym!(clone);
+        if let Some(def_id) = typeck_results.type_dependent_def_id(expr.hir_id);
+        if cx.tcx.trait_of_item(def_id) == cx.tcx.lang_items().clone_trait();
+        then {
+            Some(&args[0])
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the variant of `def` a path resolved to `res` refers to.
+fn resolved_variant<'a>(def: &'a AdtDef, res: Res) -> Option<&'a VariantDef> {
+    match res {
+        Res::Def(DefKind::Ctor(..) | DefKind::Variant, _) => Some(def.variant_of_res(res)),
+        Res::Def(DefKind::Struct | DefKind::TyAlias, _) | Res::SelfTy(..) | Res::SelfCtor(_) if def.is_struct() => {
+            Some(def.non_enum_variant())
+        },
+        _ => None,
+    }
+}
+
+/// Returns the values of the fields of `variant` in declaration order, if `expr` constructs it
+/// with a struct expression, a call of its constructor or a unit path.
+fn constructed_fields<'a>(
+    typeck_results: &TypeckResults<'_>,
+    def: &AdtDef,
+    variant: &VariantDef,
+    expr: &'a Expr<'a>,
+) -> Option<Vec<&'a Expr<'a>>> {
+    let is_variant = |qpath, hir_id| {
+        resolved_variant(def, typeck_results.qpath_res(qpath, hir_id)).map_or(false, |res| res.def_id == variant.def_id)
+    };
+    match expr.kind {
+        ExprKind::Struct(ref qpath, fields, None)
+            if fields.len() == variant.fields.len() && is_variant(qpath, expr.hir_id) =>
+        {
+            variant
+                .fields
+                .iter()
+                .map(|field| {
+                    fields
+                        .iter()
+                        .find(|value| value.ident.name == field.ident.name)
+                        .map(|value| &*value.expr)
+                })
+                .collect()
+        },
+        ExprKind::Call(ref func, args) if args.len() == variant.fields.len() => match func.kind {
+            ExprKind::Path(ref qpath) if is_variant(qpath, func.hir_id) => Some(args.iter().collect()),
+            _ => None,
+        },
+        ExprKind::Path(ref qpath) if variant.fields.is_empty() && is_variant(qpath, expr.hir_id) => Some(Vec::new()),
+        _ => None,
+    }
+}
+
+/// Returns the variant matched by `pat` and the bindings of its fields in declaration order, if
+/// each field is bound to a plain binding.
+fn variant_bindings<'a>(
+    typeck_results: &TypeckResults<'_>,
+    def: &'a AdtDef,
+    pat: &Pat<'_>,
+) -> Option<(&'a VariantDef, Vec<HirId>)> {
+    let binding = |pat: &Pat<'_>| match pat.kind {
+        PatKind::Binding(_, id, _, None) => Some(id),
+        _ => None,
+    };
+    match pat.kind {
+        PatKind::TupleStruct(ref qpath, pats, None) => {
+            let variant = resolved_variant(def, typeck_results.qpath_res(qpath, pat.hir_id))?;
+            if pats.len() != variant.fields.len() {
+                return None;
+            }
+            let bindings = pats.iter().map(binding).collect::<Option<_>>()?;
+            Some((variant, bindings))
+        },
+        PatKind::Struct(ref qpath, fields, false) => {
+            let variant = resolved_variant(def, typeck_results.qpath_res(qpath, pat.hir_id))?;
+            if fields.len() != variant.fields.len() {
+                return None;
+            }
+            let bindings = variant
+                .fields
+                .iter()
+                .map(|field| {
+                    fields
+                        .iter()
+                        .find(|field_pat| field_pat.ident.name == field.ident.name)
+                        .and_then(|field_pat| binding(&field_pat.pat))
+                })
+                .collect::<Option<_>>()?;
+            Some((variant, bindings))
+        },
+        PatKind::Path(ref qpath) => {
+            let variant = resolved_variant(def, typeck_results.qpath_res(qpath, pat.hir_id))?;
+            if variant.fields.is_empty() {
+                Some((variant, Vec::new()))
+            } else {
+                None
+            }
+        },
+        _ => None,
+    }
+}
+
 /// Implementation of the `UNSAFE_DERIVE_DESERIALIZE` lint.
 fn check_unsafe_derive_deserialize<'tcx>(
     cx: &LateContext<'tcx>,
@@ -0,0 +1,243 @@
+use crate::utils::conf::{conf_file_span, lookup_conf_file, DisallowedPath};
+use crate::utils::{match_def_path, path_to_res, qpath_res, span_lint_and_then};
+use rustc_data_structures::fx::FxHashMap;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::{DefId, LOCAL_CRATE};
+use rustc_hir::{Crate, Expr, ExprKind, QPath, Ty, TyKind};
+use rustc_lint::{LateContext, LateLintPass, Lint, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Denies the functions and methods configured in the
+    /// `disallowed-methods` list of `clippy.toml`.
+    ///
+    /// **Why is this bad?** Some functions are undesirable in some contexts, and it's beneficial
+    /// to lint for them as needed.
+    ///
+    /// **Known problems:** Paths are matched against the path of the definition, so functions
+    /// re-exported under another path, like `std::vec::Vec::new`, must be configured by the path
+    /// they are defined at, like `alloc::vec::Vec::new`, unless they can be resolved from a
+    /// dependency of the crate.
+    ///
+    /// **Example:**
+    ///
+    /// An example clippy.toml configuration:
+    /// ```toml
+    /// # clippy.toml
+    /// disallowed-methods = [
+    ///     "std::env::var",
+    ///     { path = "regex::Regex::new", reason = "use the precompiled REGEX static" },
+    /// ]
+    /// ```
+    ///
+    /// ```rust,ignore
+    /// let home = std::env::var("HOME");
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let home = CONFIG.home();
+    /// ```
+    pub DISALLOWED_METHODS,
+    nursery,
+    "use of a function or method disallowed in `clippy.toml`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Denies the types configured in the `disallowed-types` list of
+    /// `clippy.toml`.
+    ///
+    /// **Why is this bad?** Some types are undesirable in some contexts, and it's beneficial
+    /// to lint for them as needed.
+    ///
+    /// **Known problems:** The same as `disallowed_methods`. Types are only checked where they
+    /// are written out, like in fields, parameters, `let` annotations and turbofishes.
+    ///
+    /// **Example:**
+    ///
+    /// An example clippy.toml configuration:
+    /// ```toml
+    /// # clippy.toml
+    /// disallowed-types = [
+    ///     { path = "std::collections::HashMap", reason = "use `FxHashMap` instead" },
+    /// ]
+    /// ```
+    ///
+    /// ```rust,ignore
+    /// let map: HashMap<u32, u32> = HashMap::default();
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let map: FxHashMap<u32, u32> = FxHashMap::default();
+    /// ```
+    pub DISALLOWED_TYPES,
+    nursery,
+    "use of a type disallowed in `clippy.toml`"
+}
+
+/// The paths of one of the configuration lists, resolved once per crate.
+struct DisallowedPaths {
+    /// The name of the configuration key, like `disallowed-methods`.
+    key: &'static str,
+    /// The configured entries.
+    conf: Vec<DisallowedPath>,
+    /// The resolved paths, mapped to their index in `conf`.
+    def_ids: FxHashMap<DefId, usize>,
+    /// The indices of paths in the local crate, which are matched with `match_def_path`.
+    local: Vec<usize>,
+}
+
+impl DisallowedPaths {
+    fn new(key: &'static str, conf: Vec<DisallowedPath>) -> Self {
+        Self {
+            key,
+            conf,
+            def_ids: FxHashMap::default(),
+            local: Vec::new(),
+        }
+    }
+
+    /// Resolves the configured paths. Paths into dependencies which do not resolve are reported
+    /// as configuration errors, paths into crates which are not dependencies are ignored.
+    fn resolve(&mut self, cx: &LateContext<'_>) {
+        let local_name = cx.tcx.crate_name(LOCAL_CRATE).as_str();
+        for (index, entry) in self.conf.iter().enumerate() {
+            let segments = entry.path().split("::").collect::<Vec<_>>();
+            if segments[0] == &*local_name {
+                self.local.push(index);
+            } else if let Some(def_id) = resolve_path(cx, &segments) {
+                self.def_ids.insert(def_id, index);
+            } else if cx
+                .tcx
+                .crates()
+                .iter()
+                .any(|&krate| cx.tcx.crate_name(krate).as_str() == segments[0])
+            {
+                let msg = format!(
+                    "error reading Clippy's configuration file: `{}` in `{}` could not be resolved",
+                    entry.path(),
+                    self.key
+                );
+                match conf_entry_span(cx, entry.path()) {
+                    Some(span) => cx.sess().span_err(span, &msg),
+                    None => cx.sess().err(&msg),
+                }
+            }
+        }
+    }
+
+    fn find(&self, cx: &LateContext<'_>, def_id: DefId) -> Option<&DisallowedPath> {
+        if let Some(&index) = self.def_ids.get(&def_id) {
+            return Some(&self.conf[index]);
+        }
+        self.local.iter().map(|&index| &self.conf[index]).find(|entry| {
+            let segments = entry.path().split("::").collect::<Vec<_>>();
+            match_def_path(cx, def_id, &segments)
+        })
+    }
+
+    fn check(&self, cx: &LateContext<'_>, lint: &'static Lint, what: &str, def_id: DefId, span: Span) {
+        if in_external_macro(cx.sess(), span) {
+            return;
+        }
+        if let Some(entry) = self.find(cx, def_id) {
+            span_lint_and_then(
+                cx,
+                lint,
+                span,
+                &format!("use of a disallowed {} `{}`", what, entry.path()),
+                |diag| {
+                    if let Some(reason) = entry.reason() {
+                        diag.note(reason);
+                    }
+                },
+            );
+        }
+    }
+}
+
+/// Resolves a path into a dependency, including inherent methods like `regex::Regex::new`.
+fn resolve_path(cx: &LateContext<'_>, segments: &[&str]) -> Option<DefId> {
+    if let Some(res) = path_to_res(cx, segments) {
+        return res.opt_def_id();
+    }
+    let (name, ty_path) = segments.split_last()?;
+    match path_to_res(cx, ty_path)? {
+        Res::Def(DefKind::Struct | DefKind::Enum | DefKind::Union | DefKind::ForeignTy, ty_id) => cx
+            .tcx
+            .inherent_impls(ty_id)
+            .iter()
+            .flat_map(|&impl_id| cx.tcx.associated_items(impl_id).in_definition_order())
+            .find(|item| item.ident.name.as_str() == *name)
+            .map(|item| item.def_id),
+        _ => None,
+    }
+}
+
+/// Returns the span of the string `"entry"` in the configuration file, if it can be found.
+fn conf_entry_span(cx: &LateContext<'_>, entry: &str) -> Option<Span> {
+    let path = lookup_conf_file().ok().flatten()?;
+    conf_file_span(cx.sess(), &path, &format!("\"{}\"", entry))
+}
+
+pub struct DisallowedMethods {
+    paths: DisallowedPaths,
+}
+
+impl DisallowedMethods {
+    #[must_use]
+    pub fn new(conf: Vec<DisallowedPath>) -> Self {
+        Self {
+            paths: DisallowedPaths::new("disallowed-methods", conf),
+        }
+    }
+}
+
+impl_lint_pass!(DisallowedMethods => [DISALLOWED_METHODS]);
+
+impl<'tcx> LateLintPass<'tcx> for DisallowedMethods {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>, _: &'tcx Crate<'_>) {
+        self.paths.resolve(cx);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        let def_id = match expr.kind {
+            ExprKind::MethodCall(..) => cx.typeck_results().type_dependent_def_id(expr.hir_id),
+            ExprKind::Path(ref qpath) => qpath_res(cx, qpath, expr.hir_id).opt_def_id(),
+            _ => None,
+        };
+        if let Some(def_id) = def_id {
+            self.paths.check(cx, DISALLOWED_METHODS, "method", def_id, expr.span);
+        }
+    }
+}
+
+pub struct DisallowedTypes {
+    paths: DisallowedPaths,
+}
+
+impl DisallowedTypes {
+    #[must_use]
+    pub fn new(conf: Vec<DisallowedPath>) -> Self {
+        Self {
+            paths: DisallowedPaths::new("disallowed-types", conf),
+        }
+    }
+}
+
+impl_lint_pass!(DisallowedTypes => [DISALLOWED_TYPES]);
+
+impl<'tcx> LateLintPass<'tcx> for DisallowedTypes {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>, _: &'tcx Crate<'_>) {
+        self.paths.resolve(cx);
+    }
+
+    fn check_ty(&mut self, cx: &LateContext<'tcx>, ty: &'tcx Ty<'_>) {
+        if let TyKind::Path(QPath::Resolved(_, path)) = ty.kind {
+            if let Res::Def(_, def_id) = path.res {
+                self.paths.check(cx, DISALLOWED_TYPES, "type", def_id, ty.span);
+            }
+        }
+    }
+}
@@ -3,6 +3,7 @@ use if_chain::if_chain;
 use itertools::Itertools;
 use rustc_ast::ast::{Async, AttrKind, Attribute, FnRetTy, ItemKind};
 use rustc_ast::token::CommentKind;
+use rustc_attr as attr;
 use rustc_data_structures::fx::FxHashSet;
 use rustc_data_structures::sync::Lrc;
 use rustc_errors::emitter::EmitterWriter;
@@ -46,12 +47,15 @@ declare_clippy_lint! {
 
 declare_clippy_lint! {
     /// **What it does:** Checks for the doc comments of publicly visible
-    /// unsafe functions and warns if there is no `# Safety` section.
+    /// unsafe functions and unsafe traits and warns if there is no `# Safety`
+    /// section.
     ///
     /// **Why is this bad?** Unsafe functions should document their safety
     /// preconditions, so that users can be sure they are using them safely.
+    /// Unsafe traits should document what implementors have to uphold.
     ///
-    /// **Known problems:** None.
+    /// **Known problems:** Items marked `#[doc(hidden)]` and methods of trait
+    /// implementations are not checked.
     ///
     /// **Examples:**
     /// ```rust
@@ -75,7 +79,36 @@ declare_clippy_lint! {
     /// ```
     pub MISSING_SAFETY_DOC,
     style,
-    "`pub unsafe fn` without `# Safety` docs"
+    "`pub unsafe fn` or `pub unsafe trait` without `# Safety` docs"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for the doc comments of publicly visible
+    /// safe functions and traits and warns if there is a `# Safety` section.
+    ///
+    /// **Why is this bad?** Safe functions cannot be misused in ways that
+    /// cause undefined behavior, a `# Safety` section suggests otherwise and
+    /// is likely left over from an `unsafe fn` which has been made safe.
+    ///
+    /// **Known problems:** Items marked `#[doc(hidden)]` and methods of trait
+    /// implementations are not checked.
+    ///
+    /// **Examples:**
+    /// ```rust
+    /// /// # Safety
+    /// ///
+    /// /// This function used to be unsafe.
+    /// pub fn harmless() {}
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust
+    /// /// This function is harmless.
+    /// pub fn harmless() {}
+    /// ```
+    pub UNNECESSARY_SAFETY_DOC,
+    restriction,
+    "`pub fn` or `pub trait` with `# Safety` docs"
 }
 
 declare_clippy_lint! {
@@ -85,7 +118,8 @@ declare_clippy_lint! {
     /// **Why is this bad?** Documenting the type of errors that can be returned from a
     /// function can help callers write code to handle the errors appropriately.
     ///
-    /// **Known problems:** None.
+    /// **Known problems:** Items marked `#[doc(hidden)]` and methods of trait
+    /// implementations are not checked.
     ///
     /// **Examples:**
     ///
@@ -151,7 +185,13 @@ impl DocMarkdown {
     }
 }
 
-impl_lint_pass!(DocMarkdown => [DOC_MARKDOWN, MISSING_SAFETY_DOC, MISSING_ERRORS_DOC, NEEDLESS_DOCTEST_MAIN]);
+impl_lint_pass!(DocMarkdown => [
+    DOC_MARKDOWN,
+    MISSING_SAFETY_DOC,
+    UNNECESSARY_SAFETY_DOC,
+    MISSING_ERRORS_DOC,
+    NEEDLESS_DOCTEST_MAIN
+]);
 
 impl<'tcx> LateLintPass<'tcx> for DocMarkdown {
     fn check_crate(&mut self, cx: &LateContext<'tcx>, krate: &'tcx hir::Crate<'_>) {
@@ -163,11 +203,19 @@ impl<'tcx> LateLintPass<'tcx> for DocMarkdown {
         match item.kind {
             hir::ItemKind::Fn(ref sig, _, body_id) => {
                 if !(is_entrypoint_fn(cx, cx.tcx.hir().local_def_id(item.hir_id).to_def_id())
-                    || in_external_macro(cx.tcx.sess, item.span))
+                    || in_external_macro(cx.tcx.sess, item.span)
+                    || is_doc_hidden(&item.attrs))
                 {
                     lint_for_missing_headers(cx, item.hir_id, item.span, sig, headers, Some(body_id));
                 }
             },
+            hir::ItemKind::Trait(_, unsafety, ..) => {
+                if !(in_external_macro(cx.tcx.sess, item.span) || is_doc_hidden(&item.attrs))
+                    && cx.access_levels.is_exported(item.hir_id)
+                {
+                    lint_for_safety_header(cx, item.span, unsafety, headers, "trait");
+                }
+            },
             hir::ItemKind::Impl {
                 of_trait: ref trait_ref,
                 ..
@@ -187,7 +235,7 @@ impl<'tcx> LateLintPass<'tcx> for DocMarkdown {
     fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::TraitItem<'_>) {
         let headers = check_attrs(cx, &self.valid_idents, &item.attrs);
         if let hir::TraitItemKind::Fn(ref sig, ..) = item.kind {
-            if !in_external_macro(cx.tcx.sess, item.span) {
+            if !(in_external_macro(cx.tcx.sess, item.span) || is_doc_hidden(&item.attrs)) {
                 lint_for_missing_headers(cx, item.hir_id, item.span, sig, headers, None);
             }
         }
@@ -195,7 +243,7 @@ impl<'tcx> LateLintPass<'tcx> for DocMarkdown {
 
     fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::ImplItem<'_>) {
         let headers = check_attrs(cx, &self.valid_idents, &item.attrs);
-        if self.in_trait_impl || in_external_macro(cx.tcx.sess, item.span) {
+        if self.in_trait_impl || in_external_macro(cx.tcx.sess, item.span) || is_doc_hidden(&item.attrs) {
             return;
         }
         if let hir::ImplItemKind::Fn(ref sig, body_id) = item.kind {
@@ -215,14 +263,7 @@ fn lint_for_missing_headers<'tcx>(
     if !cx.access_levels.is_exported(hir_id) {
         return; // Private functions do not require doc comments
     }
-    if !headers.safety && sig.header.unsafety == hir::Unsafety::Unsafe {
-        span_lint(
-            cx,
-            MISSING_SAFETY_DOC,
-            span,
-            "unsafe function's docs miss `# Safety` section",
-        );
-    }
+    lint_for_safety_header(cx, span, sig.header.unsafety, headers, "function");
     if !headers.errors {
         if is_type_diagnostic_item(cx, return_ty(cx, hir_id), sym!(result_type)) {
             span_lint(
@@ -256,6 +297,40 @@ fn lint_for_missing_headers<'tcx>(
     }
 }
 
+fn lint_for_safety_header(
+    cx: &LateContext<'_>,
+    span: impl Into<MultiSpan> + Copy,
+    unsafety: hir::Unsafety,
+    headers: DocHeaders,
+    kind: &str,
+) {
+    match (unsafety, headers.safety) {
+        (hir::Unsafety::Unsafe, false) => span_lint(
+            cx,
+            MISSING_SAFETY_DOC,
+            span,
+            &format!("unsafe {}'s docs miss `# Safety` section", kind),
+        ),
+        (hir::Unsafety::Normal, true) => span_lint(
+            cx,
+            UNNECESSARY_SAFETY_DOC,
+            span,
+            &format!("safe {}'s docs have unnecessary `# Safety` section", kind),
+        ),
+        _ => (),
+    }
+}
+
+/// Returns `true` if the attributes contain `#[doc(hidden)]`.
+fn is_doc_hidden(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.has_name(sym!(doc))
+            && attr
+                .meta_item_list()
+                .map_or(false, |list| attr::list_contains_name(&list, sym!(hidden)))
+    })
+}
+
 /// Cleanup documentation decoration.
 ///
 /// We can't use `rustc_ast::attr::AttributeMethods::with_desugared_doc` or
@@ -314,6 +389,9 @@ struct DocHeaders {
 fn check_attrs<'a>(cx: &LateContext<'_>, valid_idents: &FxHashSet<String>, attrs: &'a [Attribute]) -> DocHeaders {
     let mut doc = String::new();
     let mut spans = vec![];
+    // `#[doc = ".."]` attributes don't give the position of their text, only the
+    // headers are checked if there are any
+    let mut precise_spans = true;
 
     for attr in attrs {
         if let AttrKind::DocComment(comment_kind, comment) = attr.kind {
@@ -321,12 +399,13 @@ fn check_attrs<'a>(cx: &LateContext<'_>, valid_idents: &FxHashSet<String>, attrs
             spans.extend_from_slice(&current_spans);
             doc.push_str(&comment);
         } else if attr.has_name(sym!(doc)) {
-            // ignore mix of sugared and non-sugared doc
-            // don't trigger the safety or errors check
-            return DocHeaders {
-                safety: true,
-                errors: true,
-            };
+            if let Some(comment) = attr.value_str() {
+                precise_spans = false;
+                let mut comment = comment.to_string();
+                comment.push('\n');
+                spans.push((comment.len(), attr.span));
+                doc.push_str(&comment);
+            }
         }
     }
 
@@ -361,7 +440,7 @@ fn check_attrs<'a>(cx: &LateContext<'_>, valid_idents: &FxHashSet<String>, attrs
             (previous, current) => Err(((previous, previous_range), (current, current_range))),
         }
     });
-    check_doc(cx, valid_idents, events, &spans)
+    check_doc(cx, valid_idents, events, &spans, precise_spans)
 }
 
 const RUST_CODE: &[&str] = &["rust", "no_run", "should_panic", "compile_fail", "edition2018"];
@@ -371,6 +450,7 @@ fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize
     valid_idents: &FxHashSet<String>,
     events: Events,
     spans: &[(usize, Span)],
+    precise_spans: bool,
 ) -> DocHeaders {
     // true if a safety header was found
     use pulldown_cmark::CodeBlockKind;
@@ -416,6 +496,9 @@ fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize
                 }
                 headers.safety |= in_heading && text.trim() == "Safety";
                 headers.errors |= in_heading && text.trim() == "Errors";
+                if !precise_spans {
+                    continue;
+                }
                 let index = match spans.binary_search_by(|c| c.0.cmp(&range.start)) {
                     Ok(o) => o,
                     Err(e) => e - 1,
@@ -0,0 +1,308 @@
+use crate::consts::constant;
+use crate::utils::{
+    higher, is_expn_of, is_type_diagnostic_item, match_def_path, match_function_call, paths, qpath_res,
+    span_lint_and_then, walk_ptrs_ty,
+};
+use if_chain::if_chain;
+use rustc_hir::def::Res;
+use rustc_hir::intravisit::{self, FnKind, NestedVisitorMap, Visitor};
+use rustc_hir::{BinOpKind, Block, Body, Expr, ExprKind, FnDecl, HirId, ItemKind, Node, PatKind, StmtKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for code in `Drop::drop` that can panic: `panic!` and
+    /// the macros built on it, `unwrap()` and `expect()` on `Option` and `Result`,
+    /// and indexing.
+    ///
+    /// **Why is this bad?** `drop` also runs while the thread is unwinding from
+    /// another panic. Panicking a second time aborts the whole process, and
+    /// wrapping the code in `catch_unwind` does not prevent that.
+    ///
+    /// **Known problems:** Only the body of `drop` itself is checked, functions it
+    /// calls are not. Code guarded by `if !std::thread::panicking() { .. }`, or
+    /// following an `if std::thread::panicking() { return; }`, is not linted.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct Buffer {
+    ///     data: Vec<u8>,
+    /// }
+    ///
+    /// impl Drop for Buffer {
+    ///     fn drop(&mut self) {
+    ///         assert_eq!(self.data[0], 0);
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # struct Buffer {
+    /// #     data: Vec<u8>,
+    /// # }
+    /// impl Drop for Buffer {
+    ///     fn drop(&mut self) {
+    ///         if !std::thread::panicking() {
+    ///             assert_eq!(self.data[0], 0);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub PANIC_IN_DROP,
+    pedantic,
+    "code that can panic inside `Drop::drop`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `std::mem::forget(self)` and
+    /// `ManuallyDrop::new(self)` inside `Drop::drop`.
+    ///
+    /// **Why is this bad?** `self` is a `&mut Self` in `drop`, so only the reference
+    /// is forgotten. This does not prevent the value from being dropped, which
+    /// has already started anyway.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct Handle;
+    ///
+    /// impl Drop for Handle {
+    ///     fn drop(&mut self) {
+    ///         std::mem::forget(self);
+    ///     }
+    /// }
+    /// ```
+    pub FORGET_SELF_IN_DROP,
+    correctness,
+    "forgetting `self` inside `Drop::drop`, which does not prevent the drop"
+}
+
+declare_lint_pass!(DropImpl => [PANIC_IN_DROP, FORGET_SELF_IN_DROP]);
+
+impl<'tcx> LateLintPass<'tcx> for DropImpl {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'_>,
+        body: &'tcx Body<'_>,
+        _: Span,
+        hir_id: HirId,
+    ) {
+        if_chain! {
+            if let FnKind::Method(ident, ..) = kind;
+            if ident.name == sym!(drop);
+            let parent = cx.tcx.hir().get_parent_item(hir_id);
+            if let Some(Node::Item(item)) = cx.tcx.hir().find(parent);
+            if let ItemKind::Impl { of_trait: Some(ref trait_ref), .. } = item.kind;
+            if trait_ref.trait_def_id() == cx.tcx.lang_items().drop_trait();
+            if let Some(param) = body.params.first();
+            if let PatKind::Binding(_, self_id, ..) = param.pat.kind;
+            then {
+                let mut visitor = DropVisitor {
+                    cx,
+                    self_id,
+                    guarded: false,
+                    panics: Vec::new(),
+                    forgets: Vec::new(),
+                };
+                visitor.visit_expr(&body.value);
+
+                for span in visitor.panics {
+                    span_lint_and_then(cx, PANIC_IN_DROP, span, "this can panic inside `Drop::drop`", |diag| {
+                        diag.note("panicking while the thread is already unwinding aborts the process");
+                        diag.help("if this is intended, guard it with `if !std::thread::panicking() { .. }`");
+                    });
+                }
+                for span in visitor.forgets {
+                    span_lint_and_then(
+                        cx,
+                        FORGET_SELF_IN_DROP,
+                        span,
+                        "forgetting `self` inside `Drop::drop` does not prevent it from being dropped",
+                        |diag| {
+                            diag.note("`self` is a `&mut` reference here, so only the reference is forgotten");
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+struct DropVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    self_id: HirId,
+    /// Whether the current expression only runs when the thread is not panicking.
+    guarded: bool,
+    panics: Vec<Span>,
+    forgets: Vec<Span>,
+}
+
+impl<'a, 'tcx> DropVisitor<'a, 'tcx> {
+    fn visit_guarded(&mut self, expr: &'tcx Expr<'_>) {
+        let guarded = std::mem::replace(&mut self.guarded, true);
+        self.visit_expr(expr);
+        self.guarded = guarded;
+    }
+
+    fn can_panic(&self, expr: &Expr<'_>) -> bool {
+        match expr.kind {
+            ExprKind::MethodCall(ref path, _, ref args, _) => {
+                let recv_ty = walk_ptrs_ty(self.cx.typeck_results().expr_ty(&args[0]));
+                [sym!(unwrap), sym!(expect)].contains(&path.ident.name)
+                    && (is_type_diagnostic_item(self.cx, recv_ty, sym!(option_type))
+                        || is_type_diagnostic_item(self.cx, recv_ty, sym!(result_type)))
+            },
+            ExprKind::Index(ref base, ref index) => {
+                if let Some(range) = higher::range(index) {
+                    // `x[..]` cannot panic
+                    range.start.is_some() || range.end.is_some()
+                } else {
+                    // Constant indexing of arrays is checked by rustc
+                    let base_ty = walk_ptrs_ty(self.cx.typeck_results().expr_ty(base));
+                    !matches!(base_ty.kind(), ty::Array(..))
+                        || constant(self.cx, self.cx.typeck_results(), index).is_none()
+                }
+            },
+            _ => false,
+        }
+    }
+
+    /// Returns `true` for `mem::forget(self)` and `ManuallyDrop::new(self)`.
+    fn is_forget_self(&self, expr: &'tcx Expr<'_>) -> bool {
+        [&paths::MEM_FORGET[..], &paths::MEM_MANUALLY_DROP_NEW[..]]
+            .iter()
+            .any(|path| match match_function_call(self.cx, expr, path) {
+                Some([arg]) => self.is_self(arg),
+                _ => false,
+            })
+    }
+
+    /// Returns `true` if `expr` is `self`, possibly reborrowed as `&mut *self` or `&*self`.
+    fn is_self(&self, expr: &Expr<'_>) -> bool {
+        match expr.kind {
+            ExprKind::AddrOf(_, _, ref inner) => match inner.kind {
+                ExprKind::Unary(UnOp::UnDeref, ref inner) => self.is_self(inner),
+                _ => false,
+            },
+            ExprKind::Path(ref qpath) => qpath_res(self.cx, qpath, expr.hir_id) == Res::Local(self.self_id),
+            _ => false,
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for DropVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
+        if self.is_forget_self(expr) {
+            self.forgets.push(expr.span);
+        }
+
+        if let Some((cond, then, els)) = higher::if_block(expr) {
+            self.visit_expr(cond);
+            if is_not_panicking(self.cx, cond) {
+                self.visit_guarded(then);
+                walk_opt_expr(self, els);
+            } else if is_panicking(self.cx, cond) {
+                self.visit_expr(then);
+                if let Some(els) = els {
+                    self.visit_guarded(els);
+                }
+            } else {
+                self.visit_expr(then);
+                walk_opt_expr(self, els);
+            }
+            return;
+        }
+
+        if !self.guarded {
+            // The whole expansion of `panic!` (and of `assert!`, `unreachable!`, ..) is reported once
+            if let Some(span) = is_expn_of(expr.span, "panic") {
+                self.panics.push(span.source_callsite());
+                return;
+            }
+            if self.can_panic(expr) {
+                self.panics.push(expr.span);
+            }
+        }
+
+        intravisit::walk_expr(self, expr);
+    }
+
+    fn visit_block(&mut self, block: &'tcx Block<'_>) {
+        let guarded = self.guarded;
+        for stmt in block.stmts {
+            self.visit_stmt(stmt);
+            // Everything after `if thread::panicking() { return; }` is guarded
+            if_chain! {
+                if let StmtKind::Semi(ref expr) | StmtKind::Expr(ref expr) = stmt.kind;
+                if let Some((cond, then, None)) = higher::if_block(expr);
+                if is_panicking(self.cx, cond);
+                if is_return(then);
+                then {
+                    self.guarded = true;
+                }
+            }
+        }
+        walk_opt_expr(self, block.expr);
+        self.guarded = guarded;
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
+
+fn walk_opt_expr<'tcx>(visitor: &mut DropVisitor<'_, 'tcx>, expr: Option<&'tcx Expr<'_>>) {
+    if let Some(expr) = expr {
+        visitor.visit_expr(expr);
+    }
+}
+
+/// Returns `true` for `thread::panicking()`.
+fn is_panicking(cx: &LateContext<'_>, cond: &Expr<'_>) -> bool {
+    if_chain! {
+        if let ExprKind::Call(ref func, ref args) = cond.kind;
+        if args.is_empty();
+        if let ExprKind::Path(ref qpath) = func.kind;
+        if let Some(def_id) = qpath_res(cx, qpath, func.hir_id).opt_def_id();
+        then {
+            match_def_path(cx, def_id, &paths::THREAD_PANICKING)
+        } else {
+            false
+        }
+    }
+}
+
+/// Returns `true` for `!thread::panicking()`, also as one operand of `&&`.
+fn is_not_panicking(cx: &LateContext<'_>, cond: &Expr<'_>) -> bool {
+    match cond.kind {
+        ExprKind::Unary(UnOp::UnNot, ref inner) => is_panicking(cx, inner),
+        ExprKind::Binary(op, ref lhs, ref rhs) if op.node == BinOpKind::And => {
+            is_not_panicking(cx, lhs) || is_not_panicking(cx, rhs)
+        },
+        _ => false,
+    }
+}
+
+/// Returns `true` for `{ return; }`.
+fn is_return(expr: &Expr<'_>) -> bool {
+    if let ExprKind::Block(ref block, _) = expr.kind {
+        match (block.stmts, block.expr) {
+            ([], Some(expr)) => matches!(expr.kind, ExprKind::Ret(_)),
+            ([stmt], None) => match stmt.kind {
+                StmtKind::Semi(ref expr) | StmtKind::Expr(ref expr) => matches!(expr.kind, ExprKind::Ret(_)),
+                _ => false,
+            },
+            _ => false,
+        }
+    } else {
+        false
+    }
+}
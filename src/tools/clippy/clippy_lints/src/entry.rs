@@ -1,10 +1,13 @@
+use crate::utils::sugg::DiagnosticBuilderExt;
 use crate::utils::SpanlessEq;
-use crate::utils::{get_item_name, higher, is_type_diagnostic_item, match_type, paths, snippet, snippet_opt};
+use crate::utils::{
+    first_line_of_span, get_item_name, higher, is_type_diagnostic_item, match_type, paths, snippet, snippet_opt,
+};
 use crate::utils::{snippet_with_applicability, span_lint_and_then, walk_ptrs_ty};
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
-use rustc_hir::{BorrowKind, Expr, ExprKind, UnOp};
+use rustc_hir::{BorrowKind, Expr, ExprKind, Node, UnOp};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::hir::map::Map;
 use rustc_session::{declare_lint_pass, declare_tool_lint};
@@ -72,6 +75,7 @@ impl<'tcx> LateLintPass<'tcx> for HashMapPass {
                     let mut visitor = InsertVisitor {
                         cx,
                         span: expr.span,
+                        stmt: parent_stmt_span(cx, expr),
                         ty,
                         map,
                         key,
@@ -85,6 +89,7 @@ impl<'tcx> LateLintPass<'tcx> for HashMapPass {
                     let mut visitor = InsertVisitor {
                         cx,
                         span: expr.span,
+                        stmt: None,
                         ty,
                         map,
                         key,
@@ -98,6 +103,15 @@ impl<'tcx> LateLintPass<'tcx> for HashMapPass {
     }
 }
 
+/// Returns the span of the statement made of `expr`, if any.
+fn parent_stmt_span(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<Span> {
+    let map = cx.tcx.hir();
+    match map.find(map.get_parent_node(expr.hir_id)) {
+        Some(Node::Stmt(stmt)) => Some(stmt.span),
+        _ => None,
+    }
+}
+
 fn check_cond<'a>(cx: &LateContext<'_>, check: &'a Expr<'a>) -> Option<(&'static str, &'a Expr<'a>, &'a Expr<'a>)> {
     if_chain! {
         if let ExprKind::MethodCall(ref path, _, ref params, _) = check.kind;
@@ -126,6 +140,8 @@ fn check_cond<'a>(cx: &LateContext<'_>, check: &'a Expr<'a>) -> Option<(&'static
 struct InsertVisitor<'a, 'tcx, 'b> {
     cx: &'a LateContext<'tcx>,
     span: Span,
+    /// The span of the statement of the `if`, which the suggestion replaces as a whole.
+    stmt: Option<Span>,
     ty: &'static str,
     map: &'b Expr<'b>,
     key: &'b Expr<'b>,
@@ -153,12 +169,17 @@ impl<'a, 'tcx, 'b> Visitor<'tcx> for InsertVisitor<'a, 'tcx, 'b> {
                                            snippet_with_applicability(self.cx, params[1].span, "..", &mut app),
                                            snippet_with_applicability(self.cx, params[2].span, "..", &mut app));
 
-                        diag.span_suggestion(
-                            self.span,
-                            "consider using",
-                            help,
-                            Applicability::MachineApplicable, // snippet
-                        );
+                        if let Some(stmt) = self.stmt {
+                            // a statement on its own line is replaced by a line of its own
+                            if first_line_of_span(self.cx, stmt).lo() == stmt.lo() {
+                                diag.suggest_insert_stmt_before(self.cx, stmt, "consider using", &help, app);
+                                diag.suggest_remove_stmt(self.cx, stmt, "instead of the check", app);
+                            } else {
+                                diag.suggest_replace_stmt(self.cx, stmt, "consider using", &help, app);
+                            }
+                        } else {
+                            diag.span_suggestion(self.span, "consider using", help, app);
+                        }
                     }
                     else {
                         let help = format!("consider using `{}.entry({})`",
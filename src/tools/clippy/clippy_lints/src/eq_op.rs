@@ -1,5 +1,6 @@
 use crate::utils::{
     eq_expr_value, implements_trait, in_macro, is_copy, multispan_sugg, snippet, span_lint, span_lint_and_then,
+    walk_ptrs_ty,
 };
 use rustc_errors::Applicability;
 use rustc_hir::{BinOp, BinOpKind, BorrowKind, Expr, ExprKind};
@@ -8,8 +9,8 @@ use rustc_session::{declare_lint_pass, declare_tool_lint};
 
 declare_clippy_lint! {
     /// **What it does:** Checks for equal operands to comparison, logical and
-    /// bitwise, difference and division binary operators (`==`, `>`, etc., `&&`,
-    /// `||`, `&`, `|`, `^`, `-` and `/`).
+    /// bitwise, difference, division and remainder binary operators (`==`, `>`, etc.,
+    /// `&&`, `||`, `&`, `|`, `^`, `-`, `/` and `%`).
     ///
     /// **Why is this bad?** This is usually just a typo or a copy and paste error.
     ///
@@ -17,6 +18,9 @@ declare_clippy_lint! {
     /// calls (notably [racer](https://github.com/phildawes/racer) had one instance
     /// of `x.pop() && x.pop()`), so we removed matching any function or method
     /// calls. We may introduce a list of known pure functions in the future.
+    /// Indexing of types other than arrays, slices and `Vec`s, and dereferencing raw
+    /// pointers are not matched either. The arithmetic and bitwise operators are only
+    /// checked on primitive types, as overloaded operators may behave differently.
     ///
     /// **Example:**
     /// ```rust
@@ -69,7 +73,7 @@ impl<'tcx> LateLintPass<'tcx> for EqOp {
             if macro_with_not_op(&left.kind) || macro_with_not_op(&right.kind) {
                 return;
             }
-            if is_valid_operator(op) && eq_expr_value(cx, left, right) {
+            if is_valid_operator(op) && !is_overloaded_arith_op(cx, op, left) && eq_expr_value(cx, left, right) {
                 span_lint(
                     cx,
                     EQ_OP,
@@ -218,6 +222,7 @@ fn is_valid_operator(op: BinOp) -> bool {
         op.node,
         BinOpKind::Sub
             | BinOpKind::Div
+            | BinOpKind::Rem
             | BinOpKind::Eq
             | BinOpKind::Lt
             | BinOpKind::Le
@@ -231,3 +236,12 @@ fn is_valid_operator(op: BinOp) -> bool {
             | BinOpKind::BitOr
     )
 }
+
+/// Returns `true` if `op` is an arithmetic or bitwise operator on a non-primitive type, which may
+/// be overloaded to behave differently from the primitive operators.
+fn is_overloaded_arith_op(cx: &LateContext<'_>, op: BinOp, left: &Expr<'_>) -> bool {
+    matches!(
+        op.node,
+        BinOpKind::Sub | BinOpKind::Div | BinOpKind::Rem | BinOpKind::BitXor | BinOpKind::BitAnd | BinOpKind::BitOr
+    ) && !walk_ptrs_ty(cx.typeck_results().expr_ty(left)).is_primitive()
+}
@@ -1,4 +1,4 @@
-use crate::utils::{is_entrypoint_fn, match_def_path, paths, qpath_res, span_lint};
+use crate::utils::{is_entrypoint_fn, is_test_function, match_def_path, paths, qpath_res, span_lint};
 use if_chain::if_chain;
 use rustc_hir::{Expr, ExprKind, Item, ItemKind, Node};
 use rustc_lint::{LateContext, LateLintPass};
@@ -6,7 +6,7 @@ use rustc_session::{declare_lint_pass, declare_tool_lint};
 
 declare_clippy_lint! {
     /// **What it does:** `exit()`  terminates the program and doesn't provide a
-    /// stack trace.
+    /// stack trace. Calls in `main` and in `#[test]` functions are not linted.
     ///
     /// **Why is this bad?** Ideally a program is terminated by finishing
     /// the main function.
@@ -33,14 +33,15 @@ impl<'tcx> LateLintPass<'tcx> for Exit {
             if match_def_path(cx, def_id, &paths::EXIT);
             then {
                 let parent = cx.tcx.hir().get_parent_item(e.hir_id);
-                if let Some(Node::Item(Item{kind: ItemKind::Fn(..), ..})) = cx.tcx.hir().find(parent) {
+                if let Some(Node::Item(Item{kind: ItemKind::Fn(..), ident, ..})) = cx.tcx.hir().find(parent) {
                     // If the next item up is a function we check if it is an entry point
-                    // and only then emit a linter warning
+                    // or a test, where exiting is fine
                     let def_id = cx.tcx.hir().local_def_id(parent);
-                    if !is_entrypoint_fn(cx, def_id.to_def_id()) {
-                        span_lint(cx, EXIT, e.span, "usage of `process::exit`");
+                    if is_entrypoint_fn(cx, def_id.to_def_id()) || is_test_function(cx, parent, ident.name) {
+                        return;
                     }
                 }
+                span_lint(cx, EXIT, e.span, "usage of `process::exit`");
             }
         }
     }
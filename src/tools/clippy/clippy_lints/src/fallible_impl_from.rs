@@ -1,21 +1,34 @@
+use crate::consts::{constant, Constant};
 use crate::utils::paths::{BEGIN_PANIC, BEGIN_PANIC_FMT, FROM_TRAIT};
 use crate::utils::{
-    is_expn_of, is_type_diagnostic_item, match_def_path, method_chain_args, span_lint_and_then, walk_ptrs_ty,
+    higher, is_expn_of, is_in_debug_assertion, is_type_diagnostic_item, match_def_path, method_chain_args, qpath_res,
+    span_lint_and_then, walk_ptrs_ty,
 };
 use if_chain::if_chain;
+use rustc_data_structures::fx::FxHashMap;
 use rustc_hir as hir;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::DefId;
+use rustc_hir::intravisit::{self, NestedVisitorMap, Visitor};
+use rustc_hir::{Block, Expr, ExprKind, HirId, ImplItemKind, QPath, StmtKind};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::hir::map::Map;
 use rustc_middle::ty;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::hygiene::{ExpnKind, MacroKind};
 use rustc_span::Span;
 
 declare_clippy_lint! {
-    /// **What it does:** Checks for impls of `From<..>` that contain `panic!()` or `unwrap()`
+    /// **What it does:** Checks for impls of `From<..>` that can panic: with `panic!()` and
+    /// the assertion macros, `unwrap()`, `expect()`, indexing and slicing, or calls to functions
+    /// of the same crate which can panic themselves.
     ///
     /// **Why is this bad?** `TryFrom` should be used if there's a possibility of failure.
     ///
-    /// **Known problems:** None.
+    /// **Known problems:** Functions called from the body are only checked one level deep.
+    /// An `unwrap()` or an indexing is not linted inside an `if` comparing one of the values it
+    /// uses, or after an `if` returning early from such a comparison, even if the comparison
+    /// does not rule out the failure.
     ///
     /// **Example:**
     /// ```rust
@@ -47,10 +60,16 @@ declare_clippy_lint! {
     /// ```
     pub FALLIBLE_IMPL_FROM,
     nursery,
-    "Warn on impls of `From<..>` that contain `panic!()` or `unwrap()`"
+    "Warn on impls of `From<..>` that can panic, e.g. with `panic!()` or `unwrap()`"
 }
 
-declare_lint_pass!(FallibleImplFrom => [FALLIBLE_IMPL_FROM]);
+/// Caches whether the functions called from `From` impls can panic.
+#[derive(Default)]
+pub struct FallibleImplFrom {
+    summaries: FxHashMap<DefId, bool>,
+}
+
+impl_lint_pass!(FallibleImplFrom => [FALLIBLE_IMPL_FROM]);
 
 impl<'tcx> LateLintPass<'tcx> for FallibleImplFrom {
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'_>) {
@@ -61,76 +80,39 @@ impl<'tcx> LateLintPass<'tcx> for FallibleImplFrom {
             if let Some(impl_trait_ref) = cx.tcx.impl_trait_ref(impl_def_id);
             if match_def_path(cx, impl_trait_ref.def_id, &FROM_TRAIT);
             then {
-                lint_impl_body(cx, item.span, impl_items);
+                lint_impl_body(cx, item.span, impl_items, &mut self.summaries);
             }
         }
     }
 }
 
-fn lint_impl_body<'tcx>(cx: &LateContext<'tcx>, impl_span: Span, impl_items: &[hir::ImplItemRef<'_>]) {
-    use rustc_hir::intravisit::{self, NestedVisitorMap, Visitor};
-    use rustc_hir::{Expr, ExprKind, ImplItemKind, QPath};
-
-    struct FindPanicUnwrap<'a, 'tcx> {
-        lcx: &'a LateContext<'tcx>,
-        typeck_results: &'tcx ty::TypeckResults<'tcx>,
-        result: Vec<Span>,
-    }
-
-    impl<'a, 'tcx> Visitor<'tcx> for FindPanicUnwrap<'a, 'tcx> {
-        type Map = Map<'tcx>;
-
-        fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
-            // check for `begin_panic`
-            if_chain! {
-                if let ExprKind::Call(ref func_expr, _) = expr.kind;
-                if let ExprKind::Path(QPath::Resolved(_, ref path)) = func_expr.kind;
-                if let Some(path_def_id) = path.res.opt_def_id();
-                if match_def_path(self.lcx, path_def_id, &BEGIN_PANIC) ||
-                    match_def_path(self.lcx, path_def_id, &BEGIN_PANIC_FMT);
-                if is_expn_of(expr.span, "unreachable").is_none();
-                then {
-                    self.result.push(expr.span);
-                }
-            }
-
-            // check for `unwrap`
-            if let Some(arglists) = method_chain_args(expr, &["unwrap"]) {
-                let reciever_ty = walk_ptrs_ty(self.typeck_results.expr_ty(&arglists[0][0]));
-                if is_type_diagnostic_item(self.lcx, reciever_ty, sym!(option_type))
-                    || is_type_diagnostic_item(self.lcx, reciever_ty, sym!(result_type))
-                {
-                    self.result.push(expr.span);
-                }
-            }
-
-            // and check sub-expressions
-            intravisit::walk_expr(self, expr);
-        }
-
-        fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
-            NestedVisitorMap::None
-        }
-    }
-
+fn lint_impl_body<'tcx>(
+    cx: &LateContext<'tcx>,
+    impl_span: Span,
+    impl_items: &[hir::ImplItemRef<'_>],
+    summaries: &mut FxHashMap<DefId, bool>,
+) {
     for impl_item in impl_items {
         if_chain! {
             if impl_item.ident.name == sym!(from);
             if let ImplItemKind::Fn(_, body_id) =
                 cx.tcx.hir().impl_item(impl_item.id).kind;
             then {
-                // check the body for `begin_panic` or `unwrap`
+                // check the body for panics, `unwrap` and the like
                 let body = cx.tcx.hir().body(body_id);
                 let impl_item_def_id = cx.tcx.hir().local_def_id(impl_item.id.hir_id);
                 let mut fpu = FindPanicUnwrap {
                     lcx: cx,
                     typeck_results: cx.tcx.typeck(impl_item_def_id),
+                    summaries: Some(summaries),
+                    guarded: Vec::new(),
                     result: Vec::new(),
                 };
                 fpu.visit_expr(&body.value);
 
                 // if we've found one, lint
                 if !fpu.result.is_empty() {
+                    let result = fpu.result;
                     span_lint_and_then(
                         cx,
                         FALLIBLE_IMPL_FROM,
@@ -140,10 +122,272 @@ fn lint_impl_body<'tcx>(cx: &LateContext<'tcx>, impl_span: Span, impl_items: &[h
                             diag.help(
                                 "`From` is intended for infallible conversions only. \
                                 Use `TryFrom` if there's a possibility for the conversion to fail.");
-                            diag.span_note(fpu.result, "potential failure(s)");
+                            for (span, what) in result {
+                                diag.span_note(span, &format!("potential failure: {}", what));
+                            }
                         });
                 }
             }
         }
     }
 }
+
+struct FindPanicUnwrap<'a, 'tcx> {
+    lcx: &'a LateContext<'tcx>,
+    typeck_results: &'tcx ty::TypeckResults<'tcx>,
+    /// Whether the functions of the crate called from the body can panic, `None` if the calls
+    /// are not looked into
+    summaries: Option<&'a mut FxHashMap<DefId, bool>>,
+    /// The locals compared in the conditions of the enclosing `if`s
+    guarded: Vec<HirId>,
+    /// The potential failures and their description
+    result: Vec<(Span, String)>,
+}
+
+impl<'a, 'tcx> FindPanicUnwrap<'a, 'tcx> {
+    fn push(&mut self, span: Span, what: String) {
+        if !self.result.iter().any(|(s, _)| *s == span) {
+            self.result.push((span, what));
+        }
+    }
+
+    /// Returns `true` if `expr` uses a local compared by an enclosing `if`.
+    fn is_guarded(&self, expr: &Expr<'_>) -> bool {
+        let mut locals = Vec::new();
+        collect_locals(self.lcx, expr, &mut locals);
+        locals.iter().any(|id| self.guarded.contains(id))
+    }
+
+    fn check_call(&mut self, expr: &Expr<'_>) {
+        let callee = match expr.kind {
+            ExprKind::Call(ref func, _) => match func.kind {
+                ExprKind::Path(ref qpath) => match self.typeck_results.qpath_res(qpath, func.hir_id) {
+                    Res::Def(DefKind::Fn | DefKind::AssocFn, def_id) => def_id,
+                    _ => return,
+                },
+                _ => return,
+            },
+            ExprKind::MethodCall(..) => match self.typeck_results.type_dependent_def_id(expr.hir_id) {
+                Some(def_id) => def_id,
+                None => return,
+            },
+            _ => return,
+        };
+        let lcx = self.lcx;
+        if let Some(summaries) = self.summaries.as_mut() {
+            if *summaries.entry(callee).or_insert_with(|| fn_can_panic(lcx, callee)) {
+                let what = format!("call to `{}`, which can panic", lcx.tcx.item_name(callee));
+                self.push(expr.span, what);
+            }
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for FindPanicUnwrap<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
+        // a comparison in the condition of an `if` guards both branches
+        if let Some((cond, then, els)) = higher::if_block(expr) {
+            self.visit_expr(cond);
+            let len = self.guarded.len();
+            compared_locals(self.lcx, cond, &mut self.guarded);
+            self.visit_expr(then);
+            if let Some(els) = els {
+                self.visit_expr(els);
+            }
+            self.guarded.truncate(len);
+            return;
+        }
+
+        // check for `begin_panic`
+        if_chain! {
+            if let ExprKind::Call(ref func_expr, _) = expr.kind;
+            if let ExprKind::Path(QPath::Resolved(_, ref path)) = func_expr.kind;
+            if let Some(path_def_id) = path.res.opt_def_id();
+            if match_def_path(self.lcx, path_def_id, &BEGIN_PANIC) ||
+                match_def_path(self.lcx, path_def_id, &BEGIN_PANIC_FMT);
+            if is_expn_of(expr.span, "unreachable").is_none();
+            if !is_in_debug_assertion(self.lcx, expr.span);
+            then {
+                let (span, what) = outermost_macro_call(expr.span);
+                self.push(span, what);
+                return;
+            }
+        }
+
+        // check for `unwrap` and `expect`
+        for method in &["unwrap", "expect"] {
+            if let Some(arglists) = method_chain_args(expr, &[method]) {
+                let reciever_ty = walk_ptrs_ty(self.typeck_results.expr_ty(&arglists[0][0]));
+                if (is_type_diagnostic_item(self.lcx, reciever_ty, sym!(option_type))
+                    || is_type_diagnostic_item(self.lcx, reciever_ty, sym!(result_type)))
+                    && !self.is_guarded(&arglists[0][0])
+                {
+                    self.push(expr.span, format!("`{}()`", method));
+                }
+            }
+        }
+
+        // check for indexing and slicing
+        if let ExprKind::Index(ref base, ref index) = expr.kind {
+            let what = match higher::range(index) {
+                Some(higher::Range {
+                    start: None, end: None, ..
+                }) => None,
+                Some(_) => Some("slicing"),
+                None if is_in_bounds(self.lcx, self.typeck_results, base, index) => None,
+                None => Some("indexing"),
+            };
+            if let Some(what) = what {
+                if !self.is_guarded(base) && !self.is_guarded(index) {
+                    self.push(expr.span, what.to_string());
+                }
+            }
+        }
+
+        self.check_call(expr);
+
+        // and check sub-expressions
+        intravisit::walk_expr(self, expr);
+    }
+
+    fn visit_block(&mut self, block: &'tcx Block<'_>) {
+        // an `if` returning early guards the rest of the block
+        let len = self.guarded.len();
+        for stmt in block.stmts {
+            self.visit_stmt(stmt);
+            if_chain! {
+                if let StmtKind::Expr(ref expr) | StmtKind::Semi(ref expr) = stmt.kind;
+                if let Some((cond, then, None)) = higher::if_block(expr);
+                if returns_early(then);
+                then {
+                    compared_locals(self.lcx, cond, &mut self.guarded);
+                }
+            }
+        }
+        if let Some(ref expr) = block.expr {
+            self.visit_expr(expr);
+        }
+        self.guarded.truncate(len);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
+
+/// Returns the call site of the outermost macro `span` was expanded from and a description
+/// of it, e.g. the call site of `assert!` for the `panic!` it expands to.
+fn outermost_macro_call(span: Span) -> (Span, String) {
+    let mut outermost = (span, "`panic!`".to_string());
+    let mut span = span;
+    while span.from_expansion() {
+        let data = span.ctxt().outer_expn_data();
+        if let ExpnKind::Macro(MacroKind::Bang, name) = data.kind {
+            outermost = (data.call_site, format!("`{}!`", name));
+        }
+        span = data.call_site;
+    }
+    outermost
+}
+
+/// Returns `true` if `fn_id` is a function of the crate whose body can panic. Its calls are
+/// not looked into.
+fn fn_can_panic(cx: &LateContext<'_>, fn_id: DefId) -> bool {
+    if_chain! {
+        if let Some(local_id) = fn_id.as_local();
+        let hir_id = cx.tcx.hir().local_def_id_to_hir_id(local_id);
+        if let Some(body_id) = cx.tcx.hir().maybe_body_owned_by(hir_id);
+        then {
+            let mut fpu = FindPanicUnwrap {
+                lcx: cx,
+                typeck_results: cx.tcx.typeck(local_id),
+                summaries: None,
+                guarded: Vec::new(),
+                result: Vec::new(),
+            };
+            fpu.visit_expr(&cx.tcx.hir().body(body_id).value);
+            !fpu.result.is_empty()
+        } else {
+            false
+        }
+    }
+}
+
+/// Returns `true` for a constant index into an array, which is in bounds.
+fn is_in_bounds<'tcx>(
+    cx: &LateContext<'tcx>,
+    typeck_results: &ty::TypeckResults<'tcx>,
+    base: &Expr<'_>,
+    index: &Expr<'_>,
+) -> bool {
+    if_chain! {
+        if let ty::Array(_, len) = walk_ptrs_ty(typeck_results.expr_ty(base)).kind();
+        if let Some(len) = len.try_eval_usize(cx.tcx, cx.param_env);
+        if let Some((Constant::Int(index), _)) = constant(cx, typeck_results, index);
+        then {
+            index < u128::from(len)
+        } else {
+            false
+        }
+    }
+}
+
+/// Returns `true` if the block ends with a `return`.
+fn returns_early(block: &Expr<'_>) -> bool {
+    if let ExprKind::Block(ref block, _) = block.kind {
+        let last = block.expr.or_else(|| {
+            block.stmts.last().and_then(|stmt| match stmt.kind {
+                StmtKind::Expr(ref expr) | StmtKind::Semi(ref expr) => Some(&**expr),
+                _ => None,
+            })
+        });
+        return matches!(last.map(|expr| &expr.kind), Some(ExprKind::Ret(_)));
+    }
+    false
+}
+
+/// Collects the locals used on either side of the comparisons in an `if` condition.
+fn compared_locals(cx: &LateContext<'_>, cond: &Expr<'_>, locals: &mut Vec<HirId>) {
+    match cond.kind {
+        ExprKind::Binary(op, ref lhs, ref rhs) if op.node.is_comparison() => {
+            collect_locals(cx, lhs, locals);
+            collect_locals(cx, rhs, locals);
+        },
+        ExprKind::Binary(op, ref lhs, ref rhs) if op.node.is_lazy() => {
+            compared_locals(cx, lhs, locals);
+            compared_locals(cx, rhs, locals);
+        },
+        ExprKind::Unary(hir::UnOp::UnNot, ref inner) | ExprKind::DropTemps(ref inner) => {
+            compared_locals(cx, inner, locals);
+        },
+        _ => {},
+    }
+}
+
+fn collect_locals(cx: &LateContext<'_>, expr: &Expr<'_>, locals: &mut Vec<HirId>) {
+    struct LocalsVisitor<'a, 'b, 'tcx> {
+        cx: &'a LateContext<'tcx>,
+        locals: &'b mut Vec<HirId>,
+    }
+
+    impl<'a, 'b, 'tcx> Visitor<'tcx> for LocalsVisitor<'a, 'b, 'tcx> {
+        type Map = Map<'tcx>;
+
+        fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
+            if let ExprKind::Path(ref qpath) = expr.kind {
+                if let Res::Local(id) = qpath_res(self.cx, qpath, expr.hir_id) {
+                    self.locals.push(id);
+                }
+            }
+            intravisit::walk_expr(self, expr);
+        }
+
+        fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+            NestedVisitorMap::None
+        }
+    }
+
+    LocalsVisitor { cx, locals }.visit_expr(expr);
+}
@@ -108,7 +108,7 @@ impl<'tcx> LateLintPass<'tcx> for FloatLiteral {
                             expr.span,
                             "literal cannot be represented as the underlying type without loss of precision",
                             "consider changing the type or replacing it with",
-                            numeric_literal::format(&float_str, type_suffix, true),
+                            numeric_literal::format(&float_str, type_suffix),
                             Applicability::MachineApplicable,
                         );
                     }
@@ -119,7 +119,7 @@ impl<'tcx> LateLintPass<'tcx> for FloatLiteral {
                         expr.span,
                         "float has excessive precision",
                         "consider changing the type or truncating it to",
-                        numeric_literal::format(&float_str, type_suffix, true),
+                        numeric_literal::format(&float_str, type_suffix),
                         Applicability::MachineApplicable,
                     );
                 }
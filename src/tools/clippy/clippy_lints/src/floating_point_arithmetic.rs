@@ -277,7 +277,7 @@ fn check_powf(cx: &LateContext<'_>, expr: &Expr<'_>, args: &[Expr<'_>]) {
                 format!(
                     "{}.powi({})",
                     Sugg::hir(cx, &args[0], ".."),
-                    numeric_literal::format(&exponent.to_string(), None, false)
+                    numeric_literal::format(&exponent.to_string(), None)
                 ),
             )
         } else {
@@ -1,5 +1,5 @@
 use crate::utils::{
-    attr_by_name, attrs::is_proc_macro, is_must_use_ty, is_trait_impl_item, iter_input_pats, match_def_path,
+    attr_by_name, attrs::is_proc_macro, in_macro, is_must_use_ty, is_trait_impl_item, iter_input_pats, match_def_path,
     must_use_attr, qpath_res, return_ty, snippet, snippet_opt, span_lint, span_lint_and_help, span_lint_and_then,
     trait_ref_of_method, type_is_unsafe_function,
 };
@@ -325,53 +325,17 @@ impl<'tcx> Functions {
     }
 
     fn check_line_number(self, cx: &LateContext<'_>, span: Span, body: &'tcx hir::Body<'_>) {
-        if in_external_macro(cx.sess(), span) {
+        // Functions defined by macros are skipped, their lines are not written by the user
+        if in_macro(span) {
             return;
         }
 
         let code_snippet = snippet(cx, body.value.span, "..");
-        let mut line_count: u64 = 0;
-        let mut in_comment = false;
-        let mut code_in_line;
 
         // Skip the surrounding function decl.
         let start_brace_idx = code_snippet.find('{').map_or(0, |i| i + 1);
         let end_brace_idx = code_snippet.rfind('}').unwrap_or_else(|| code_snippet.len());
-        let function_lines = code_snippet[start_brace_idx..end_brace_idx].lines();
-
-        for mut line in function_lines {
-            code_in_line = false;
-            loop {
-                line = line.trim_start();
-                if line.is_empty() {
-                    break;
-                }
-                if in_comment {
-                    match line.find("*/") {
-                        Some(i) => {
-                            line = &line[i + 2..];
-                            in_comment = false;
-                            continue;
-                        },
-                        None => break,
-                    }
-                } else {
-                    let multi_idx = line.find("/*").unwrap_or_else(|| line.len());
-                    let single_idx = line.find("//").unwrap_or_else(|| line.len());
-                    code_in_line |= multi_idx > 0 && single_idx > 0;
-                    // Implies multi_idx is below line.len()
-                    if multi_idx < single_idx {
-                        line = &line[multi_idx + 2..];
-                        in_comment = true;
-                        continue;
-                    }
-                    break;
-                }
-            }
-            if code_in_line {
-                line_count += 1;
-            }
-        }
+        let line_count = count_code_lines(&code_snippet[start_brace_idx..end_brace_idx]);
 
         if line_count > self.max_lines {
             span_lint(
@@ -411,6 +375,125 @@ impl<'tcx> Functions {
     }
 }
 
+/// The state of `count_code_lines` at the end of a line.
+#[derive(Clone, Copy)]
+enum LineState {
+    Code,
+    /// Inside a block comment, with its nesting depth.
+    BlockComment(usize),
+    /// Inside a string literal.
+    Str,
+    /// Inside a raw string literal, with the number of `#`s of its delimiters.
+    RawStr(usize),
+}
+
+/// Counts the lines of `code` which contain code. Blank lines and lines containing only comments
+/// are skipped. The contents of string literals count as code, and comment delimiters in them,
+/// like in `r"//"`, are not considered.
+fn count_code_lines(code: &str) -> u64 {
+    let mut state = LineState::Code;
+    let mut line_count = 0;
+
+    for line in code.lines() {
+        let bytes = line.as_bytes();
+        let mut code_in_line = false;
+        let mut i = 0;
+        while i < bytes.len() {
+            let rest = &bytes[i..];
+            match state {
+                LineState::BlockComment(depth) => {
+                    if rest.starts_with(b"*/") {
+                        state = if depth == 1 {
+                            LineState::Code
+                        } else {
+                            LineState::BlockComment(depth - 1)
+                        };
+                        i += 2;
+                    } else if rest.starts_with(b"/*") {
+                        state = LineState::BlockComment(depth + 1);
+                        i += 2;
+                    } else {
+                        i += 1;
+                    }
+                },
+                LineState::Str => {
+                    code_in_line |= !rest[0].is_ascii_whitespace();
+                    match rest[0] {
+                        b'\\' => i += 2,
+                        b'"' => {
+                            state = LineState::Code;
+                            i += 1;
+                        },
+                        _ => i += 1,
+                    }
+                },
+                LineState::RawStr(hashes) => {
+                    code_in_line |= !rest[0].is_ascii_whitespace();
+                    if rest[0] == b'"' && rest.get(1..=hashes).map_or(false, |h| h.iter().all(|&b| b == b'#')) {
+                        state = LineState::Code;
+                        i += 1 + hashes;
+                    } else {
+                        i += 1;
+                    }
+                },
+                LineState::Code => {
+                    if rest.starts_with(b"//") {
+                        break;
+                    } else if rest.starts_with(b"/*") {
+                        state = LineState::BlockComment(1);
+                        i += 2;
+                        continue;
+                    }
+
+                    code_in_line |= !rest[0].is_ascii_whitespace();
+                    if rest[0] == b'"' {
+                        state = LineState::Str;
+                        i += 1;
+                    } else if let Some((hashes, len)) = raw_str_start(bytes, i) {
+                        state = LineState::RawStr(hashes);
+                        i += len;
+                    } else if rest[0] == b'\'' {
+                        i += char_literal_len(rest);
+                    } else {
+                        i += 1;
+                    }
+                },
+            }
+        }
+        if code_in_line {
+            line_count += 1;
+        }
+    }
+
+    line_count
+}
+
+/// If a raw string literal like `r#"..."#` or `br"..."` starts at `i`, returns the number of `#`s
+/// and the length of its opening delimiter.
+fn raw_str_start(bytes: &[u8], i: usize) -> Option<(usize, usize)> {
+    let is_ident = |b: u8| b.is_ascii_alphanumeric() || b == b'_';
+    let r = if bytes[i] == b'b' { i + 1 } else { i };
+    if (i > 0 && is_ident(bytes[i - 1])) || bytes.get(r) != Some(&b'r') {
+        return None;
+    }
+    let hashes = bytes[r + 1..].iter().take_while(|&&b| b == b'#').count();
+    if bytes.get(r + 1 + hashes) == Some(&b'"') {
+        Some((hashes, r + 2 + hashes - i))
+    } else {
+        None
+    }
+}
+
+/// Returns the length of the char literal starting at the start of `rest`, or 1 if the `'`
+/// starts a lifetime or a label.
+fn char_literal_len(rest: &[u8]) -> usize {
+    match rest {
+        [b'\'', b'\\', ..] => rest.iter().skip(3).position(|&b| b == b'\'').map_or(1, |pos| pos + 4),
+        [b'\'', _, b'\'', ..] => 3,
+        _ => 1,
+    }
+}
+
 fn check_needless_must_use(
     cx: &LateContext<'_>,
     decl: &hir::FnDecl<'_>,
@@ -0,0 +1,282 @@
+use crate::utils::{
+    in_macro, indent_of, is_type_diagnostic_item, match_path, match_qpath, match_trait_method, paths, qpath_res,
+    span_lint_and_help, span_lint_and_then,
+};
+use if_chain::if_chain;
+use rustc_ast::ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::def::Res;
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{Body, Expr, ExprKind, HirId, ImplItemKind, ImplItemRef, Item, ItemKind, PatKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::symbol::Ident;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `Iterator` implementations whose `next` only delegates to an
+    /// iterator stored in a field of `self`, like `self.inner.next()` or
+    /// `self.inner.next().map(..)`, but which do not implement `size_hint`.
+    ///
+    /// **Why is this bad?** The default `size_hint` returns `(0, None)`, so consumers like
+    /// `collect` cannot preallocate, although the inner iterator knows how many items are left.
+    ///
+    /// **Known problems:** When `next` skips items, e.g. with `self.inner.find(..)` or
+    /// `self.inner.next().filter(..)`, only the upper bound of the inner iterator can be
+    /// forwarded, and the suggestion keeps a lower bound of 0.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct Names<I>(I);
+    ///
+    /// impl<I: Iterator<Item = u32>> Iterator for Names<I> {
+    ///     type Item = String;
+    ///
+    ///     fn next(&mut self) -> Option<String> {
+    ///         self.0.next().map(|id| format!("user{}", id))
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// struct Names<I>(I);
+    ///
+    /// impl<I: Iterator<Item = u32>> Iterator for Names<I> {
+    ///     type Item = String;
+    ///
+    ///     fn next(&mut self) -> Option<String> {
+    ///         self.0.next().map(|id| format!("user{}", id))
+    ///     }
+    ///
+    ///     fn size_hint(&self) -> (usize, Option<usize>) {
+    ///         self.0.size_hint()
+    ///     }
+    /// }
+    /// ```
+    pub MISSING_ITERATOR_SIZE_HINT,
+    nursery,
+    "`Iterator` implementations delegating `next` to a field without forwarding `size_hint`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `size_hint` implementations returning a tuple of integer
+    /// literals whose lower bound is greater than the upper bound, like `(3, Some(2))`.
+    ///
+    /// **Why is this bad?** The lower bound of a size hint must not exceed its upper bound.
+    /// Consumers relying on the hint, like `collect`, may misbehave.
+    ///
+    /// **Known problems:** Only literal bounds are checked.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct Pair;
+    ///
+    /// impl Iterator for Pair {
+    ///     type Item = u8;
+    ///
+    ///     fn next(&mut self) -> Option<u8> {
+    ///         None
+    ///     }
+    ///
+    ///     fn size_hint(&self) -> (usize, Option<usize>) {
+    ///         (2, Some(0))
+    ///     }
+    /// }
+    /// ```
+    pub INCONSISTENT_SIZE_HINT,
+    correctness,
+    "`size_hint` implementations returning a lower bound greater than the upper bound"
+}
+
+declare_lint_pass!(IteratorSizeHint => [MISSING_ITERATOR_SIZE_HINT, INCONSISTENT_SIZE_HINT]);
+
+impl<'tcx> LateLintPass<'tcx> for IteratorSizeHint {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        if_chain! {
+            if !in_macro(item.span);
+            if let ItemKind::Impl {
+                of_trait: Some(ref trait_ref),
+                items,
+                ..
+            } = item.kind;
+            if match_path(&trait_ref.path, &paths::ITERATOR);
+            then {
+                match items.iter().find(|item_ref| item_ref.ident.name == sym!(size_hint)) {
+                    Some(size_hint) => check_size_hint(cx, size_hint),
+                    None => {
+                        if let Some(next) = items.iter().find(|item_ref| item_ref.ident.name == sym!(next)) {
+                            check_next(cx, next);
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+fn check_size_hint(cx: &LateContext<'_>, size_hint: &ImplItemRef<'_>) {
+    if let ImplItemKind::Fn(_, body_id) = cx.tcx.hir().impl_item(size_hint.id).kind {
+        let mut visitor = BoundsVisitor { cx };
+        visitor.visit_expr(&cx.tcx.hir().body(body_id).value);
+    }
+}
+
+/// Finds `(lower, Some(upper))` tuples of integer literals with `lower > upper`.
+struct BoundsVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for BoundsVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if let ExprKind::Tup([lower, upper]) = expr.kind;
+            if let Some(lower) = int_lit(lower);
+            if let ExprKind::Call(some, [upper]) = upper.kind;
+            if let ExprKind::Path(ref some_path) = some.kind;
+            if match_qpath(some_path, &paths::OPTION_SOME);
+            if let Some(upper) = int_lit(upper);
+            if lower > upper;
+            if !in_macro(expr.span);
+            then {
+                span_lint_and_help(
+                    self.cx,
+                    INCONSISTENT_SIZE_HINT,
+                    expr.span,
+                    &format!(
+                        "this size hint has a lower bound of {}, greater than its upper bound of {}",
+                        lower, upper
+                    ),
+                    None,
+                    "the lower bound of a size hint must be less than or equal to its upper bound",
+                );
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
+
+fn int_lit(expr: &Expr<'_>) -> Option<u128> {
+    match expr.kind {
+        ExprKind::Lit(ref lit) => match lit.node {
+            LitKind::Int(value, _) => Some(value),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// How `next` delegates to the inner iterator.
+enum Delegation {
+    /// Every item of the inner iterator is yielded, like `self.inner.next().map(..)`
+    Exact(Ident),
+    /// Items of the inner iterator may be skipped, like `self.inner.find(..)`
+    Filtered(Ident),
+}
+
+fn check_next(cx: &LateContext<'_>, next: &ImplItemRef<'_>) {
+    let impl_item = cx.tcx.hir().impl_item(next.id);
+    if_chain! {
+        if let ImplItemKind::Fn(_, body_id) = impl_item.kind;
+        let body = cx.tcx.hir().body(body_id);
+        if let Some(delegation) = next_delegation(cx, body);
+        if let Some(indent) = indent_of(cx, impl_item.span);
+        then {
+            let (field, hint, help) = match delegation {
+                Delegation::Exact(field) => (
+                    field,
+                    format!("self.{}.size_hint()", field),
+                    format!("forward `size_hint` to `self.{}`", field),
+                ),
+                Delegation::Filtered(field) => (
+                    field,
+                    format!("(0, self.{}.size_hint().1)", field),
+                    format!("forward the upper bound of `self.{}.size_hint()`", field),
+                ),
+            };
+            let indent = " ".repeat(indent);
+            span_lint_and_then(
+                cx,
+                MISSING_ITERATOR_SIZE_HINT,
+                next.ident.span,
+                &format!("`next` delegates to `self.{}`, but `size_hint` is not forwarded", field),
+                |diag| {
+                    diag.span_suggestion(
+                        impl_item.span.shrink_to_hi(),
+                        &help,
+                        format!(
+                            "\n\n{0}fn size_hint(&self) -> (usize, Option<usize>) {{\n{0}    {1}\n{0}}}",
+                            indent, hint
+                        ),
+                        Applicability::MaybeIncorrect,
+                    );
+                },
+            );
+        }
+    }
+}
+
+/// Matches `next` bodies made of a single delegating expression.
+fn next_delegation(cx: &LateContext<'_>, body: &Body<'_>) -> Option<Delegation> {
+    let self_id = match body.params.get(0)?.pat.kind {
+        PatKind::Binding(_, id, ..) => id,
+        _ => return None,
+    };
+    let expr = match body.value.kind {
+        ExprKind::Block(ref block, _) if block.stmts.is_empty() => block.expr?,
+        _ => return None,
+    };
+    if in_macro(expr.span) {
+        return None;
+    }
+
+    if let ExprKind::MethodCall(ref path, _, ref args, _) = expr.kind {
+        let name = path.ident.as_str();
+        match (&*name, &**args) {
+            ("next", [recv]) if match_trait_method(cx, expr, &paths::ITERATOR) => {
+                return self_field(cx, recv, self_id).map(Delegation::Exact);
+            },
+            ("find" | "find_map", [recv, _]) if match_trait_method(cx, expr, &paths::ITERATOR) => {
+                return self_field(cx, recv, self_id).map(Delegation::Filtered);
+            },
+            ("map" | "filter" | "and_then", [recv, _])
+                if is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv), sym!(option_type)) =>
+            {
+                if_chain! {
+                    if let ExprKind::MethodCall(ref next_path, _, [inner], _) = recv.kind;
+                    if next_path.ident.name == sym!(next);
+                    if match_trait_method(cx, recv, &paths::ITERATOR);
+                    if let Some(field) = self_field(cx, inner, self_id);
+                    then {
+                        return Some(if &*name == "map" {
+                            Delegation::Exact(field)
+                        } else {
+                            Delegation::Filtered(field)
+                        });
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+    None
+}
+
+/// Returns the field name if `expr` is `self.<field>`.
+fn self_field(cx: &LateContext<'_>, expr: &Expr<'_>, self_id: HirId) -> Option<Ident> {
+    if_chain! {
+        if let ExprKind::Field(ref base, field) = expr.kind;
+        if let ExprKind::Path(ref qpath) = base.kind;
+        if qpath_res(cx, qpath, base.hir_id) == Res::Local(self_id);
+        then {
+            Some(field)
+        } else {
+            None
+        }
+    }
+}
@@ -6,6 +6,7 @@ use rustc_hir::{Item, ItemKind, VariantData};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_target::abi::LayoutOf;
+use std::cmp::Reverse;
 
 declare_clippy_lint! {
     /// **What it does:** Checks for large size differences between variants on
@@ -20,6 +21,8 @@ declare_clippy_lint! {
     /// the overhead is negligible and the boxing is counter-productive. Always
     /// measure the change this lint suggests.
     ///
+    /// Fields with a generic type are not counted, as their size is unknown.
+    ///
     /// **Example:**
     ///
     /// ```rust
@@ -56,79 +59,125 @@ impl LargeEnumVariant {
 
 impl_lint_pass!(LargeEnumVariant => [LARGE_ENUM_VARIANT]);
 
+struct FieldInfo {
+    ind: usize,
+    size: u64,
+}
+
+struct VariantInfo {
+    ind: usize,
+    /// The sum of the sizes of the fields with a known layout
+    size: u64,
+    /// Whether some fields have an unknown layout, e.g. because they are generic
+    has_unsized_fields: bool,
+    /// The fields with a known layout, largest first
+    fields: Vec<FieldInfo>,
+}
+
 impl<'tcx> LateLintPass<'tcx> for LargeEnumVariant {
     fn check_item(&mut self, cx: &LateContext<'_>, item: &Item<'_>) {
         let did = cx.tcx.hir().local_def_id(item.hir_id);
         if let ItemKind::Enum(ref def, _) = item.kind {
             let ty = cx.tcx.type_of(did);
             let adt = ty.ty_adt_def().expect("already checked whether this is an enum");
+            if adt.variants.len() <= 1 {
+                return;
+            }
 
-            let mut largest_variant: Option<(_, _)> = None;
-            let mut second_variant: Option<(_, _)> = None;
-
-            for (i, variant) in adt.variants.iter().enumerate() {
-                let size: u64 = variant
-                    .fields
-                    .iter()
-                    .filter_map(|f| {
-                        let ty = cx.tcx.type_of(f.did);
-                        // don't count generics by filtering out everything
-                        // that does not have a layout
-                        cx.layout_of(ty).ok().map(|l| l.size.bytes())
-                    })
-                    .sum();
-
-                let grouped = (size, (i, variant));
+            let mut variants: Vec<_> = adt
+                .variants
+                .iter()
+                .enumerate()
+                .map(|(ind, variant)| {
+                    let mut fields: Vec<_> = variant
+                        .fields
+                        .iter()
+                        .enumerate()
+                        .filter_map(|(field_ind, f)| {
+                            // don't count generics by filtering out everything
+                            // that does not have a layout
+                            cx.layout_of(cx.tcx.type_of(f.did)).ok().map(|l| FieldInfo {
+                                ind: field_ind,
+                                size: l.size.bytes(),
+                            })
+                        })
+                        .collect();
+                    fields.sort_by_key(|f| Reverse(f.size));
+                    VariantInfo {
+                        ind,
+                        size: fields.iter().map(|f| f.size).sum(),
+                        has_unsized_fields: fields.len() < variant.fields.len(),
+                        fields,
+                    }
+                })
+                .collect();
+            variants.sort_by_key(|v| Reverse(v.size));
 
-                if grouped.0 >= largest_variant.map_or(0, |x| x.0) {
-                    second_variant = largest_variant;
-                    largest_variant = Some(grouped);
-                }
+            let (largest, second) = (&variants[0], &variants[1]);
+            let difference = largest.size - second.size;
+            if difference <= self.maximum_size_difference_allowed {
+                return;
             }
 
-            if let (Some(largest), Some(second)) = (largest_variant, second_variant) {
-                let difference = largest.0 - second.0;
+            // Box the largest fields until the difference is small enough
+            let pointer_size = cx.tcx.data_layout.pointer_size.bytes();
+            let mut remaining = largest.size;
+            let fields_to_box: Vec<_> = largest
+                .fields
+                .iter()
+                .take_while(|field| {
+                    let box_it = remaining.saturating_sub(second.size) > self.maximum_size_difference_allowed;
+                    remaining = remaining - field.size + pointer_size;
+                    box_it
+                })
+                .filter(|field| field.size > pointer_size)
+                .collect();
 
-                if difference > self.maximum_size_difference_allowed {
-                    let (i, variant) = largest.1;
-
-                    let help_text = "consider boxing the large fields to reduce the total size of the enum";
-                    span_lint_and_then(
-                        cx,
-                        LARGE_ENUM_VARIANT,
-                        def.variants[i].span,
-                        "large size difference between variants",
-                        |diag| {
-                            diag.span_label(
-                                def.variants[(largest.1).0].span,
-                                &format!("this variant is {} bytes", largest.0),
-                            );
-                            diag.span_note(
-                                def.variants[(second.1).0].span,
-                                &format!("and the second-largest variant is {} bytes:", second.0),
-                            );
-                            if variant.fields.len() == 1 {
-                                let span = match def.variants[i].data {
-                                    VariantData::Struct(ref fields, ..) | VariantData::Tuple(ref fields, ..) => {
-                                        fields[0].ty.span
-                                    },
-                                    VariantData::Unit(..) => unreachable!(),
-                                };
-                                if let Some(snip) = snippet_opt(cx, span) {
-                                    diag.span_suggestion(
-                                        span,
-                                        help_text,
-                                        format!("Box<{}>", snip),
-                                        Applicability::MaybeIncorrect,
-                                    );
-                                    return;
-                                }
-                            }
-                            diag.span_help(def.variants[i].span, help_text);
+            let help_text = "consider boxing the large fields to reduce the total size of the enum";
+            span_lint_and_then(
+                cx,
+                LARGE_ENUM_VARIANT,
+                def.variants[largest.ind].span,
+                "large size difference between variants",
+                |diag| {
+                    diag.span_label(
+                        def.variants[largest.ind].span,
+                        &if largest.has_unsized_fields {
+                            format!("this variant is at least {} bytes", largest.size)
+                        } else {
+                            format!("this variant is {} bytes", largest.size)
                         },
                     );
-                }
-            }
+                    diag.span_note(
+                        def.variants[second.ind].span,
+                        &if second.has_unsized_fields {
+                            format!("and the second-largest variant is at least {} bytes:", second.size)
+                        } else {
+                            format!("and the second-largest variant is {} bytes:", second.size)
+                        },
+                    );
+
+                    let field_spans = match def.variants[largest.ind].data {
+                        VariantData::Struct(ref fields, ..) | VariantData::Tuple(ref fields, ..) => fields,
+                        VariantData::Unit(..) => unreachable!(),
+                    };
+                    let sugg: Option<Vec<_>> = fields_to_box
+                        .iter()
+                        .map(|field| {
+                            let span = field_spans[field.ind].ty.span;
+                            snippet_opt(cx, span).map(|snip| (span, format!("Box<{}>", snip)))
+                        })
+                        .collect();
+                    match sugg {
+                        Some(sugg) if !sugg.is_empty() => {
+                            diag.multipart_suggestion(help_text, sugg, Applicability::MaybeIncorrect);
+                        },
+                        _ => {
+                            diag.span_help(def.variants[largest.ind].span, help_text);
+                        },
+                    }
+                },
+            );
         }
     }
 }
@@ -11,7 +11,11 @@
 #![feature(stmt_expr_attributes)]
 #![recursion_limit = "512"]
 #![cfg_attr(feature = "deny-warnings", deny(warnings))]
-#![allow(clippy::missing_docs_in_private_items, clippy::must_use_candidate)]
+#![allow(
+    clippy::missing_docs_in_private_items,
+    clippy::must_use_candidate,
+    clippy::unsigned_sub_underflow
+)]
 #![warn(trivial_casts, trivial_numeric_casts)]
 // warn on lints, that are included in `rust-lang/rust`s bootstrap
 #![warn(rust_2018_idioms, unused_lifetimes)]
@@ -165,6 +169,12 @@ mod booleans;
 mod bytecount;
 mod cargo_common_metadata;
 mod checked_conversions;
+mod clone_before_drop_explicit;
+mod clone_before_serialize;
+mod clone_in_debug_assert;
+mod clone_on_borrowed_in_closure_capture_by_value;
+mod clone_on_static_ref;
+mod clone_to_satisfy_fnmut_reuse;
 mod cognitive_complexity;
 mod collapsible_if;
 mod comparison_chain;
@@ -175,11 +185,13 @@ mod dbg_macro;
 mod default_trait_access;
 mod dereference;
 mod derive;
+mod disallowed_paths;
 mod doc;
 mod double_comparison;
 mod double_parens;
 mod drop_bounds;
 mod drop_forget_ref;
+mod drop_impl;
 mod duration_subsec;
 mod else_if_without_else;
 mod empty_enum;
@@ -217,6 +229,7 @@ mod inline_fn_without_body;
 mod int_plus_one;
 mod integer_division;
 mod items_after_statements;
+mod iterator_size_hint;
 mod large_const_arrays;
 mod large_enum_variant;
 mod large_stack_arrays;
@@ -225,10 +238,12 @@ mod let_if_seq;
 mod let_underscore;
 mod lifetimes;
 mod literal_representation;
+mod lock_held_in_scrutinee;
 mod loops;
 mod macro_use;
 mod main_recursion;
 mod manual_async_fn;
+mod manual_empty_new;
 mod manual_non_exhaustive;
 mod map_clone;
 mod map_identity;
@@ -238,6 +253,7 @@ mod matches;
 mod mem_discriminant;
 mod mem_forget;
 mod mem_replace;
+mod mem_uninitialized;
 mod methods;
 mod minmax;
 mod misc;
@@ -256,6 +272,7 @@ mod needless_arbitrary_self_type;
 mod needless_bool;
 mod needless_borrow;
 mod needless_borrowed_ref;
+mod needless_clone_in_trait_default_method;
 mod needless_continue;
 mod needless_pass_by_value;
 mod needless_update;
@@ -269,6 +286,7 @@ mod open_options;
 mod option_env_unwrap;
 mod option_if_let_else;
 mod overflow_check_conditional;
+mod panic_in_result_fn;
 mod panic_unimplemented;
 mod partialeq_ne_impl;
 mod path_buf_push_overwrite;
@@ -278,6 +296,7 @@ mod ptr;
 mod ptr_offset_with_cast;
 mod question_mark;
 mod ranges;
+mod recursive_trait_impl;
 mod redundant_clone;
 mod redundant_closure_call;
 mod redundant_field_names;
@@ -290,9 +309,11 @@ mod returns;
 mod self_assignment;
 mod serde_api;
 mod shadow;
+mod shift_overflow;
 mod single_component_path_imports;
 mod slow_vector_initialization;
 mod stable_sort_primitive;
+mod static_mut;
 mod strings;
 mod suspicious_trait_impl;
 mod swap;
@@ -310,8 +331,11 @@ mod unicode;
 mod unit_return_expecting_ord;
 mod unnamed_address;
 mod unnecessary_sort_by;
+mod unnecessary_unsafe;
 mod unnested_or_patterns;
 mod unsafe_removed_from_name;
+mod unsigned_sub_underflow;
+mod unused_combinator;
 mod unused_io_amount;
 mod unused_self;
 mod unused_unit;
@@ -378,12 +402,15 @@ pub fn read_conf(args: &[rustc_ast::NestedMetaItem], sess: &Session) -> Conf {
 
             // all conf errors are non-fatal, we just use the default conf in case of error
             for error in errors {
-                sess.struct_err(&format!(
+                let msg = format!(
                     "error reading Clippy's configuration file `{}`: {}",
                     file_name.display(),
                     error
-                ))
-                .emit();
+                );
+                match error.key().and_then(|key| utils::conf::conf_file_span(sess, &file_name, key)) {
+                    Some(span) => sess.struct_span_err(span, &msg).emit(),
+                    None => sess.struct_err(&msg).emit(),
+                }
             }
 
             conf
@@ -506,6 +533,12 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &bytecount::NAIVE_BYTECOUNT,
         &cargo_common_metadata::CARGO_COMMON_METADATA,
         &checked_conversions::CHECKED_CONVERSIONS,
+        &clone_before_drop_explicit::CLONE_BEFORE_DROP_EXPLICIT,
+        &clone_before_serialize::CLONE_BEFORE_SERIALIZE,
+        &clone_in_debug_assert::CLONE_IN_DEBUG_ASSERT,
+        &clone_on_borrowed_in_closure_capture_by_value::CLONE_ON_BORROWED_IN_CLOSURE_CAPTURE_BY_VALUE,
+        &clone_on_static_ref::CLONE_ON_STATIC_REF,
+        &clone_to_satisfy_fnmut_reuse::CLONE_TO_SATISFY_FNMUT_REUSE,
         &cognitive_complexity::COGNITIVE_COMPLEXITY,
         &collapsible_if::COLLAPSIBLE_IF,
         &comparison_chain::COMPARISON_CHAIN,
@@ -518,14 +551,23 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &dbg_macro::DBG_MACRO,
         &default_trait_access::DEFAULT_TRAIT_ACCESS,
         &dereference::EXPLICIT_DEREF_METHODS,
+        &derive::CLONE_WITHOUT_COPY,
+        &derive::DERIVABLE_CLONE_IMPL,
         &derive::DERIVE_HASH_XOR_EQ,
         &derive::DERIVE_ORD_XOR_PARTIAL_ORD,
         &derive::EXPL_IMPL_CLONE_ON_COPY,
+        &derive::FLOAT_BITS_HASH_XOR_EQ,
+        &derive::FLOAT_PARTIAL_EQ_HASH_KEY,
+        &derive::NON_CANONICAL_PARTIAL_ORD,
+        &derive::PARTIAL_EQ_IGNORES_HASHED_FIELDS,
         &derive::UNSAFE_DERIVE_DESERIALIZE,
+        &disallowed_paths::DISALLOWED_METHODS,
+        &disallowed_paths::DISALLOWED_TYPES,
         &doc::DOC_MARKDOWN,
         &doc::MISSING_ERRORS_DOC,
         &doc::MISSING_SAFETY_DOC,
         &doc::NEEDLESS_DOCTEST_MAIN,
+        &doc::UNNECESSARY_SAFETY_DOC,
         &double_comparison::DOUBLE_COMPARISONS,
         &double_parens::DOUBLE_PARENS,
         &drop_bounds::DROP_BOUNDS,
@@ -533,6 +575,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &drop_forget_ref::DROP_REF,
         &drop_forget_ref::FORGET_COPY,
         &drop_forget_ref::FORGET_REF,
+        &drop_impl::FORGET_SELF_IN_DROP,
+        &drop_impl::PANIC_IN_DROP,
         &duration_subsec::DURATION_SUBSEC,
         &else_if_without_else::ELSE_IF_WITHOUT_ELSE,
         &empty_enum::EMPTY_ENUM,
@@ -590,6 +634,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &int_plus_one::INT_PLUS_ONE,
         &integer_division::INTEGER_DIVISION,
         &items_after_statements::ITEMS_AFTER_STATEMENTS,
+        &iterator_size_hint::INCONSISTENT_SIZE_HINT,
+        &iterator_size_hint::MISSING_ITERATOR_SIZE_HINT,
         &large_const_arrays::LARGE_CONST_ARRAYS,
         &large_enum_variant::LARGE_ENUM_VARIANT,
         &large_stack_arrays::LARGE_STACK_ARRAYS,
@@ -605,6 +651,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &literal_representation::LARGE_DIGIT_GROUPS,
         &literal_representation::MISTYPED_LITERAL_SUFFIXES,
         &literal_representation::UNREADABLE_LITERAL,
+        &lock_held_in_scrutinee::LOCK_HELD_IN_SCRUTINEE,
         &loops::EMPTY_LOOP,
         &loops::EXPLICIT_COUNTER_LOOP,
         &loops::EXPLICIT_INTO_ITER_LOOP,
@@ -617,6 +664,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &loops::NEEDLESS_COLLECT,
         &loops::NEEDLESS_RANGE_LOOP,
         &loops::NEVER_LOOP,
+        &loops::RESTARTED_ITER_IN_LOOP,
+        &loops::RESTARTED_ITER_IN_LOOP_CONDITION,
         &loops::SAME_ITEM_PUSH,
         &loops::WHILE_IMMUTABLE_CONDITION,
         &loops::WHILE_LET_LOOP,
@@ -624,6 +673,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &macro_use::MACRO_USE_IMPORTS,
         &main_recursion::MAIN_RECURSION,
         &manual_async_fn::MANUAL_ASYNC_FN,
+        &manual_empty_new::MANUAL_EMPTY_NEW,
         &manual_non_exhaustive::MANUAL_NON_EXHAUSTIVE,
         &map_clone::MAP_CLONE,
         &map_identity::MAP_IDENTITY,
@@ -650,11 +700,14 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &mem_replace::MEM_REPLACE_OPTION_WITH_NONE,
         &mem_replace::MEM_REPLACE_WITH_DEFAULT,
         &mem_replace::MEM_REPLACE_WITH_UNINIT,
+        &mem_replace::MEM_REPLACE_WITH_ZEROED,
+        &mem_uninitialized::MEM_UNINITIALIZED,
         &methods::BIND_INSTEAD_OF_MAP,
         &methods::CHARS_LAST_CMP,
         &methods::CHARS_NEXT_CMP,
         &methods::CLONE_DOUBLE_REF,
         &methods::CLONE_ON_COPY,
+        &methods::CLONE_ON_ITERATOR_ADAPTER,
         &methods::CLONE_ON_REF_PTR,
         &methods::EXPECT_FUN_CALL,
         &methods::EXPECT_USED,
@@ -684,6 +737,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &methods::RESULT_MAP_OR_INTO_OPTION,
         &methods::SEARCH_IS_SOME,
         &methods::SHOULD_IMPLEMENT_TRAIT,
+        &methods::SINGLE_CHAR_ADD_STR,
         &methods::SINGLE_CHAR_PATTERN,
         &methods::SINGLE_CHAR_PUSH_STR,
         &methods::SKIP_WHILE_NEXT,
@@ -722,6 +776,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &missing_doc::MISSING_DOCS_IN_PRIVATE_ITEMS,
         &missing_inline::MISSING_INLINE_IN_PUBLIC_ITEMS,
         &modulo_arithmetic::MODULO_ARITHMETIC,
+        &modulo_arithmetic::SIGNED_MODULO_INDEX,
         &multiple_crate_versions::MULTIPLE_CRATE_VERSIONS,
         &mut_key::MUTABLE_KEY_TYPE,
         &mut_mut::MUT_MUT,
@@ -734,11 +789,13 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &needless_bool::NEEDLESS_BOOL,
         &needless_borrow::NEEDLESS_BORROW,
         &needless_borrowed_ref::NEEDLESS_BORROWED_REFERENCE,
+        &needless_clone_in_trait_default_method::NEEDLESS_CLONE_IN_TRAIT_DEFAULT_METHOD,
         &needless_continue::NEEDLESS_CONTINUE,
         &needless_pass_by_value::NEEDLESS_PASS_BY_VALUE,
         &needless_update::NEEDLESS_UPDATE,
         &neg_cmp_op_on_partial_ord::NEG_CMP_OP_ON_PARTIAL_ORD,
         &neg_multiply::NEG_MULTIPLY,
+        &new_without_default::NEW_DIFFERS_FROM_DEFAULT,
         &new_without_default::NEW_WITHOUT_DEFAULT,
         &no_effect::NO_EFFECT,
         &no_effect::UNNECESSARY_OPERATION,
@@ -751,6 +808,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &option_env_unwrap::OPTION_ENV_UNWRAP,
         &option_if_let_else::OPTION_IF_LET_ELSE,
         &overflow_check_conditional::OVERFLOW_CHECK_CONDITIONAL,
+        &panic_in_result_fn::PANIC_IN_RESULT_FN,
         &panic_unimplemented::PANIC,
         &panic_unimplemented::PANIC_PARAMS,
         &panic_unimplemented::TODO,
@@ -769,7 +827,21 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &ranges::RANGE_PLUS_ONE,
         &ranges::RANGE_ZIP_WITH_LEN,
         &ranges::REVERSED_EMPTY_RANGES,
+        &recursive_trait_impl::RECURSIVE_TRAIT_IMPL,
+        &redundant_clone::CLONE_INSIDE_UNWRAP_OR_ELSE_CLOSURE,
+        &redundant_clone::CLONE_THEN_DROP_SAME_STATEMENT,
+        &redundant_clone::NEEDLESS_CLONE_FOR_INTO_PARAM,
         &redundant_clone::REDUNDANT_CLONE,
+        &redundant_clone::REDUNDANT_CLONE_ACROSS_CHANNEL_SEND,
+        &redundant_clone::REDUNDANT_CLONE_FOR_TRAIT_OBJECT_COERCION,
+        &redundant_clone::REDUNDANT_CLONE_INTO_OWNED_COLLECTION_FROM_ITER,
+        &redundant_clone::REDUNDANT_CLONE_IN_BUILDER_WITH_METHOD,
+        &redundant_clone::REDUNDANT_CLONE_IN_ENUM_VARIANT_PAYLOAD,
+        &redundant_clone::REDUNDANT_CLONE_IN_MAP_ENTRY,
+        &redundant_clone::REDUNDANT_CLONE_IN_RECURSIVE_CALL,
+        &redundant_clone::REDUNDANT_CLONE_IN_SORT_DEDUP,
+        &redundant_clone::REDUNDANT_CLONE_OF_LITERAL,
+        &redundant_clone::UNNECESSARY_CLONE_FOR_PATTERN_BINDING,
         &redundant_closure_call::REDUNDANT_CLOSURE_CALL,
         &redundant_field_names::REDUNDANT_FIELD_NAMES,
         &redundant_pub_crate::REDUNDANT_PUB_CRATE,
@@ -786,9 +858,16 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &shadow::SHADOW_REUSE,
         &shadow::SHADOW_SAME,
         &shadow::SHADOW_UNRELATED,
+        &shift_overflow::SHIFT_OVERFLOW,
+        &shift_overflow::UNCHECKED_SHIFT,
         &single_component_path_imports::SINGLE_COMPONENT_PATH_IMPORTS,
         &slow_vector_initialization::SLOW_VECTOR_INITIALIZATION,
         &stable_sort_primitive::STABLE_SORT_PRIMITIVE,
+        &static_mut::STATIC_MUT,
+        &static_mut::UNSAFE_SYNC_STATIC,
+        &strings::MANUAL_IGNORE_CASE_CMP,
+        &strings::MISMATCHED_CASE_CMP,
+        &strings::REDUNDANT_SLICING,
         &strings::STRING_ADD,
         &strings::STRING_ADD_ASSIGN,
         &strings::STRING_LIT_AS_BYTES,
@@ -804,6 +883,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &trait_bounds::TYPE_REPETITION_IN_BOUNDS,
         &transmute::CROSSPOINTER_TRANSMUTE,
         &transmute::TRANSMUTES_EXPRESSIBLE_AS_PTR_CASTS,
+        &transmute::TRANSMUTE_BYTES_TO_NUM,
         &transmute::TRANSMUTE_BYTES_TO_STR,
         &transmute::TRANSMUTE_FLOAT_TO_INT,
         &transmute::TRANSMUTE_INT_TO_BOOL,
@@ -820,6 +900,9 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &types::ABSURD_EXTREME_COMPARISONS,
         &types::BORROWED_BOX,
         &types::BOX_VEC,
+        &types::CAST_AWAY_CONSTNESS,
+        &types::CAST_CHAR_TRUNCATION,
+        &types::CAST_ENUM_TRUNCATION,
         &types::CAST_LOSSLESS,
         &types::CAST_POSSIBLE_TRUNCATION,
         &types::CAST_POSSIBLE_WRAP,
@@ -834,8 +917,11 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &types::INVALID_UPCAST_COMPARISONS,
         &types::LET_UNIT_VALUE,
         &types::LINKEDLIST,
+        &types::OPTION_OF_RESULT,
         &types::OPTION_OPTION,
         &types::REDUNDANT_ALLOCATION,
+        &types::RESULT_OPAQUE_ERR,
+        &types::RESULT_UNIT_ERR,
         &types::TYPE_COMPLEXITY,
         &types::UNIT_ARG,
         &types::UNIT_CMP,
@@ -846,10 +932,16 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &unicode::ZERO_WIDTH_SPACE,
         &unit_return_expecting_ord::UNIT_RETURN_EXPECTING_ORD,
         &unnamed_address::FN_ADDRESS_COMPARISONS,
+        &unnamed_address::SUSPICIOUS_ADDRESS_COMPARISONS,
         &unnamed_address::VTABLE_ADDRESS_COMPARISONS,
         &unnecessary_sort_by::UNNECESSARY_SORT_BY,
+        &unnecessary_unsafe::OVERSIZED_UNSAFE_BLOCK,
+        &unnecessary_unsafe::UNNECESSARY_UNSAFE,
         &unnested_or_patterns::UNNESTED_OR_PATTERNS,
         &unsafe_removed_from_name::UNSAFE_REMOVED_FROM_NAME,
+        &unsigned_sub_underflow::UNSIGNED_SUB_UNDERFLOW,
+        &unused_combinator::UNUSED_COMBINATOR_RESULT,
+        &unused_combinator::UNUSED_LAZY_VALUE,
         &unused_io_amount::UNUSED_IO_AMOUNT,
         &unused_self::UNUSED_SELF,
         &unused_unit::UNUSED_UNIT,
@@ -871,7 +963,9 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &wildcard_dependencies::WILDCARD_DEPENDENCIES,
         &wildcard_imports::ENUM_GLOB_USE,
         &wildcard_imports::WILDCARD_IMPORTS,
+        &write::FORMAT_LITERAL,
         &write::PRINTLN_EMPTY_STRING,
+        &write::PRINT_FOLLOWED_BY_NEWLINE,
         &write::PRINT_LITERAL,
         &write::PRINT_STDOUT,
         &write::PRINT_WITH_NEWLINE,
@@ -916,8 +1010,17 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|| box strings::StringAdd);
     store.register_late_pass(|| box implicit_return::ImplicitReturn);
     store.register_late_pass(|| box implicit_saturating_sub::ImplicitSaturatingSub);
-    store.register_late_pass(|| box methods::Methods);
-    store.register_late_pass(|| box map_clone::MapClone);
+    let msrv = conf.msrv.as_ref().and_then(|msrv| {
+        utils::msrvs::RustcVersion::parse(msrv).or_else(|| {
+            sess.err(&format!(
+                "error reading Clippy's configuration file: `{}` is not a valid Rust version",
+                msrv
+            ));
+            None
+        })
+    });
+    store.register_late_pass(move || box methods::Methods::new(msrv));
+    store.register_late_pass(move || box map_clone::MapClone::new(msrv));
     store.register_late_pass(|| box shadow::Shadow);
     store.register_late_pass(|| box types::LetUnitValue);
     store.register_late_pass(|| box types::UnitCmp);
@@ -925,8 +1028,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|| box main_recursion::MainRecursion::default());
     store.register_late_pass(|| box lifetimes::Lifetimes);
     store.register_late_pass(|| box entry::HashMapPass);
-    store.register_late_pass(|| box ranges::Ranges);
-    store.register_late_pass(|| box types::Casts);
+    store.register_late_pass(move || box ranges::Ranges::new(msrv));
+    store.register_late_pass(move || box types::Casts::new(msrv));
     let type_complexity_threshold = conf.type_complexity_threshold;
     store.register_late_pass(move || box types::TypeComplexity::new(type_complexity_threshold));
     store.register_late_pass(|| box matches::Matches::default());
@@ -947,7 +1050,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(move || box vec::UselessVec{too_large_for_stack});
     store.register_late_pass(|| box panic_unimplemented::PanicUnimplemented);
     store.register_late_pass(|| box strings::StringLitAsBytes);
-    store.register_late_pass(|| box derive::Derive);
+    let clone_without_copy_size_limit = conf.clone_without_copy_size_limit;
+    store.register_late_pass(move || box derive::Derive::new(clone_without_copy_size_limit));
     store.register_late_pass(|| box types::CharLitAsU8);
     store.register_late_pass(|| box drop_bounds::DropBounds);
     store.register_late_pass(|| box get_last_with_len::GetLastWithLen);
@@ -963,7 +1067,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|| box overflow_check_conditional::OverflowCheckConditional);
     store.register_late_pass(|| box new_without_default::NewWithoutDefault::default());
     let blacklisted_names = conf.blacklisted_names.iter().cloned().collect::<FxHashSet<_>>();
-    store.register_late_pass(move || box blacklisted_name::BlacklistedName::new(blacklisted_names.clone()));
+    let allowed_names = conf.allowed_names.clone();
+    store.register_late_pass(move || box blacklisted_name::BlacklistedName::new(blacklisted_names.clone(), allowed_names.clone()));
     let too_many_arguments_threshold1 = conf.too_many_arguments_threshold;
     let too_many_lines_threshold2 = conf.too_many_lines_threshold;
     store.register_late_pass(move || box functions::Functions::new(too_many_arguments_threshold1, too_many_lines_threshold2));
@@ -998,7 +1103,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|| box inline_fn_without_body::InlineFnWithoutBody);
     store.register_late_pass(|| box useless_conversion::UselessConversion::default());
     store.register_late_pass(|| box types::ImplicitHasher);
-    store.register_late_pass(|| box fallible_impl_from::FallibleImplFrom);
+    store.register_late_pass(|| box fallible_impl_from::FallibleImplFrom::default());
     store.register_late_pass(|| box types::UnitArg);
     store.register_late_pass(|| box double_comparison::DoubleComparisons);
     store.register_late_pass(|| box question_mark::QuestionMark);
@@ -1012,7 +1117,11 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|| box indexing_slicing::IndexingSlicing);
     store.register_late_pass(|| box non_copy_const::NonCopyConst);
     store.register_late_pass(|| box ptr_offset_with_cast::PtrOffsetWithCast);
-    store.register_late_pass(|| box redundant_clone::RedundantClone);
+    let redundant_clone_side_effect_types = conf.redundant_clone_side_effect_types.clone();
+    let redundant_clone_skip_tests = conf.redundant_clone_skip_tests;
+    store.register_late_pass(move || {
+        box redundant_clone::RedundantClone::new(redundant_clone_side_effect_types.clone(), redundant_clone_skip_tests)
+    });
     store.register_late_pass(|| box slow_vector_initialization::SlowVectorInit);
     store.register_late_pass(|| box unnecessary_sort_by::UnnecessarySortBy);
     store.register_late_pass(|| box types::RefToMut);
@@ -1020,13 +1129,14 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|| box missing_const_for_fn::MissingConstForFn);
     store.register_late_pass(|| box transmuting_null::TransmutingNull);
     store.register_late_pass(|| box path_buf_push_overwrite::PathBufPushOverwrite);
-    store.register_late_pass(|| box checked_conversions::CheckedConversions);
+    store.register_late_pass(move || box checked_conversions::CheckedConversions::new(msrv));
     store.register_late_pass(|| box integer_division::IntegerDivision);
     store.register_late_pass(|| box inherent_to_string::InherentToString);
     let max_trait_bounds = conf.max_trait_bounds;
     store.register_late_pass(move || box trait_bounds::TraitBounds::new(max_trait_bounds));
     store.register_late_pass(|| box comparison_chain::ComparisonChain);
-    store.register_late_pass(|| box mut_key::MutableKeyType);
+    let ignore_interior_mutability = conf.ignore_interior_mutability.clone();
+    store.register_late_pass(move || box mut_key::MutableKeyType::new(ignore_interior_mutability.clone()));
     store.register_late_pass(|| box modulo_arithmetic::ModuloArithmetic);
     store.register_early_pass(|| box reference::DerefAddrOf);
     store.register_early_pass(|| box reference::RefInDeref);
@@ -1052,7 +1162,16 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|| box cargo_common_metadata::CargoCommonMetadata);
     store.register_late_pass(|| box multiple_crate_versions::MultipleCrateVersions);
     store.register_late_pass(|| box wildcard_dependencies::WildcardDependencies);
-    store.register_early_pass(|| box literal_representation::LiteralDigitGrouping);
+    let binary_digit_group_size = conf.binary_digit_group_size;
+    let octal_digit_group_size = conf.octal_digit_group_size;
+    let decimal_digit_group_size = conf.decimal_digit_group_size;
+    let hexadecimal_digit_group_size = conf.hexadecimal_digit_group_size;
+    store.register_early_pass(move || box literal_representation::LiteralDigitGrouping::new(
+        binary_digit_group_size,
+        octal_digit_group_size,
+        decimal_digit_group_size,
+        hexadecimal_digit_group_size,
+    ));
     let literal_representation_threshold = conf.literal_representation_threshold;
     store.register_early_pass(move || box literal_representation::DecimalLiteralRepresentation::new(literal_representation_threshold));
     store.register_early_pass(|| box utils::internal_lints::ClippyLintsInternal);
@@ -1077,7 +1196,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_early_pass(move || box excessive_bools::ExcessiveBools::new(max_struct_bools, max_fn_params_bools));
     store.register_early_pass(|| box option_env_unwrap::OptionEnvUnwrap);
     let warn_on_all_wildcard_imports = conf.warn_on_all_wildcard_imports;
-    store.register_late_pass(move || box wildcard_imports::WildcardImports::new(warn_on_all_wildcard_imports));
+    let allowed_wildcard_imports = conf.allowed_wildcard_imports.clone();
+    store.register_late_pass(move || box wildcard_imports::WildcardImports::new(warn_on_all_wildcard_imports, allowed_wildcard_imports.clone()));
     store.register_late_pass(|| box verbose_file_reads::VerboseFileReads);
     store.register_late_pass(|| box redundant_pub_crate::RedundantPubCrate::default());
     store.register_late_pass(|| box unnamed_address::UnnamedAddress);
@@ -1092,8 +1212,10 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_early_pass(|| box redundant_field_names::RedundantFieldNames);
     store.register_late_pass(|| box vec_resize_to_zero::VecResizeToZero);
     let single_char_binding_names_threshold = conf.single_char_binding_names_threshold;
+    let allowed_names = conf.allowed_names.clone();
     store.register_early_pass(move || box non_expressive_names::NonExpressiveNames {
         single_char_binding_names_threshold,
+        allowed_names: allowed_names.clone(),
     });
     store.register_early_pass(|| box unnested_or_patterns::UnnestedOrPatterns);
     store.register_late_pass(|| box macro_use::MacroUseImports::default());
@@ -1105,6 +1227,33 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|| box self_assignment::SelfAssignment);
     store.register_late_pass(|| box float_equality_without_abs::FloatEqualityWithoutAbs);
     store.register_late_pass(|| box async_yields_async::AsyncYieldsAsync);
+    store.register_late_pass(|| box clone_before_drop_explicit::CloneBeforeDropExplicit);
+    store.register_late_pass(|| box unnecessary_unsafe::UnnecessaryUnsafe);
+    store.register_late_pass(|| box types::ResultErrType);
+    store.register_late_pass(|| box needless_clone_in_trait_default_method::NeedlessCloneInTraitDefaultMethod);
+    store.register_late_pass(|| box unsigned_sub_underflow::UnsignedSubUnderflow);
+    store.register_late_pass(|| box shift_overflow::ShiftOverflow);
+    store.register_late_pass(|| box manual_empty_new::ManualEmptyNew);
+    store.register_late_pass(|| box clone_on_borrowed_in_closure_capture_by_value::CloneOnBorrowedInClosureCaptureByValue);
+    store.register_late_pass(|| box clone_to_satisfy_fnmut_reuse::CloneToSatisfyFnmutReuse);
+    store.register_late_pass(|| box strings::CaseConversionCmp);
+    store.register_late_pass(|| box strings::RedundantSlicing);
+    store.register_late_pass(|| box drop_impl::DropImpl);
+    store.register_late_pass(|| box lock_held_in_scrutinee::LockHeldInScrutinee);
+    store.register_late_pass(|| box mem_uninitialized::MemUninitialized);
+    store.register_late_pass(|| box unused_combinator::UnusedCombinator);
+    store.register_late_pass(|| box clone_on_static_ref::CloneOnStaticRef);
+    let panic_in_result_fn_check_closures = conf.panic_in_result_fn_check_closures;
+    store.register_late_pass(move || box panic_in_result_fn::PanicInResultFn::new(panic_in_result_fn_check_closures));
+    store.register_late_pass(|| box recursive_trait_impl::RecursiveTraitImpl);
+    store.register_late_pass(|| box static_mut::StaticMut);
+    store.register_late_pass(|| box iterator_size_hint::IteratorSizeHint);
+    store.register_late_pass(|| box clone_before_serialize::CloneBeforeSerialize);
+    let disallowed_methods = conf.disallowed_methods.clone();
+    store.register_late_pass(move || box disallowed_paths::DisallowedMethods::new(disallowed_methods.clone()));
+    let disallowed_types = conf.disallowed_types.clone();
+    store.register_late_pass(move || box disallowed_paths::DisallowedTypes::new(disallowed_types.clone()));
+    store.register_late_pass(|| box clone_in_debug_assert::CloneInDebugAssert);
 
     store.register_group(true, "clippy::restriction", Some("clippy_restriction"), vec![
         LintId::of(&arithmetic::FLOAT_ARITHMETIC),
@@ -1112,6 +1261,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&as_conversions::AS_CONVERSIONS),
         LintId::of(&create_dir::CREATE_DIR),
         LintId::of(&dbg_macro::DBG_MACRO),
+        LintId::of(&doc::UNNECESSARY_SAFETY_DOC),
         LintId::of(&else_if_without_else::ELSE_IF_WITHOUT_ELSE),
         LintId::of(&exit::EXIT),
         LintId::of(&float_literal::LOSSY_FLOAT_LITERAL),
@@ -1135,6 +1285,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&missing_doc::MISSING_DOCS_IN_PRIVATE_ITEMS),
         LintId::of(&missing_inline::MISSING_INLINE_IN_PUBLIC_ITEMS),
         LintId::of(&modulo_arithmetic::MODULO_ARITHMETIC),
+        LintId::of(&panic_in_result_fn::PANIC_IN_RESULT_FN),
         LintId::of(&panic_unimplemented::PANIC),
         LintId::of(&panic_unimplemented::TODO),
         LintId::of(&panic_unimplemented::UNIMPLEMENTED),
@@ -1142,7 +1293,10 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&pattern_type_mismatch::PATTERN_TYPE_MISMATCH),
         LintId::of(&shadow::SHADOW_REUSE),
         LintId::of(&shadow::SHADOW_SAME),
+        LintId::of(&static_mut::STATIC_MUT),
         LintId::of(&strings::STRING_ADD),
+        LintId::of(&types::CAST_AWAY_CONSTNESS),
+        LintId::of(&types::RESULT_OPAQUE_ERR),
         LintId::of(&unwrap_in_result::UNWRAP_IN_RESULT),
         LintId::of(&verbose_file_reads::VERBOSE_FILE_READS),
         LintId::of(&write::PRINT_STDOUT),
@@ -1153,15 +1307,23 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&attrs::INLINE_ALWAYS),
         LintId::of(&await_holding_lock::AWAIT_HOLDING_LOCK),
         LintId::of(&checked_conversions::CHECKED_CONVERSIONS),
+        LintId::of(&clone_before_serialize::CLONE_BEFORE_SERIALIZE),
+        LintId::of(&clone_in_debug_assert::CLONE_IN_DEBUG_ASSERT),
+        LintId::of(&clone_on_static_ref::CLONE_ON_STATIC_REF),
         LintId::of(&copies::MATCH_SAME_ARMS),
         LintId::of(&copies::SAME_FUNCTIONS_IN_IF_CONDITION),
         LintId::of(&copy_iterator::COPY_ITERATOR),
         LintId::of(&default_trait_access::DEFAULT_TRAIT_ACCESS),
         LintId::of(&dereference::EXPLICIT_DEREF_METHODS),
+        LintId::of(&derive::CLONE_WITHOUT_COPY),
+        LintId::of(&derive::DERIVABLE_CLONE_IMPL),
         LintId::of(&derive::EXPL_IMPL_CLONE_ON_COPY),
+        LintId::of(&derive::FLOAT_BITS_HASH_XOR_EQ),
+        LintId::of(&derive::FLOAT_PARTIAL_EQ_HASH_KEY),
         LintId::of(&derive::UNSAFE_DERIVE_DESERIALIZE),
         LintId::of(&doc::DOC_MARKDOWN),
         LintId::of(&doc::MISSING_ERRORS_DOC),
+        LintId::of(&drop_impl::PANIC_IN_DROP),
         LintId::of(&empty_enum::EMPTY_ENUM),
         LintId::of(&enum_variants::MODULE_NAME_REPETITIONS),
         LintId::of(&enum_variants::PUB_ENUM_VARIANT_NAMES),
@@ -1185,26 +1347,35 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&matches::MATCH_WILDCARD_FOR_SINGLE_VARIANTS),
         LintId::of(&matches::MATCH_WILD_ERR_ARM),
         LintId::of(&matches::SINGLE_MATCH_ELSE),
+        LintId::of(&methods::CLONE_ON_ITERATOR_ADAPTER),
         LintId::of(&methods::FILTER_MAP),
         LintId::of(&methods::FILTER_MAP_NEXT),
         LintId::of(&methods::FIND_MAP),
         LintId::of(&methods::INEFFICIENT_TO_STRING),
         LintId::of(&methods::MAP_FLATTEN),
         LintId::of(&methods::MAP_UNWRAP_OR),
+        LintId::of(&methods::SINGLE_CHAR_ADD_STR),
         LintId::of(&misc::USED_UNDERSCORE_BINDING),
         LintId::of(&misc_early::UNSEPARATED_LITERAL_SUFFIX),
+        LintId::of(&modulo_arithmetic::SIGNED_MODULO_INDEX),
         LintId::of(&mut_mut::MUT_MUT),
+        LintId::of(&needless_clone_in_trait_default_method::NEEDLESS_CLONE_IN_TRAIT_DEFAULT_METHOD),
         LintId::of(&needless_continue::NEEDLESS_CONTINUE),
         LintId::of(&needless_pass_by_value::NEEDLESS_PASS_BY_VALUE),
+        LintId::of(&new_without_default::NEW_DIFFERS_FROM_DEFAULT),
         LintId::of(&non_expressive_names::SIMILAR_NAMES),
         LintId::of(&option_if_let_else::OPTION_IF_LET_ELSE),
         LintId::of(&ranges::RANGE_MINUS_ONE),
         LintId::of(&ranges::RANGE_PLUS_ONE),
         LintId::of(&shadow::SHADOW_UNRELATED),
+        LintId::of(&shift_overflow::UNCHECKED_SHIFT),
+        LintId::of(&static_mut::UNSAFE_SYNC_STATIC),
         LintId::of(&strings::STRING_ADD_ASSIGN),
         LintId::of(&trait_bounds::TRAIT_DUPLICATION_IN_BOUNDS),
         LintId::of(&trait_bounds::TYPE_REPETITION_IN_BOUNDS),
         LintId::of(&trivially_copy_pass_by_ref::TRIVIALLY_COPY_PASS_BY_REF),
+        LintId::of(&types::CAST_CHAR_TRUNCATION),
+        LintId::of(&types::CAST_ENUM_TRUNCATION),
         LintId::of(&types::CAST_LOSSLESS),
         LintId::of(&types::CAST_POSSIBLE_TRUNCATION),
         LintId::of(&types::CAST_POSSIBLE_WRAP),
@@ -1215,10 +1386,16 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&types::INVALID_UPCAST_COMPARISONS),
         LintId::of(&types::LET_UNIT_VALUE),
         LintId::of(&types::LINKEDLIST),
+        LintId::of(&types::OPTION_OF_RESULT),
         LintId::of(&types::OPTION_OPTION),
+        LintId::of(&types::RESULT_UNIT_ERR),
+        LintId::of(&types::VEC_BOX),
         LintId::of(&unicode::NON_ASCII_LITERAL),
         LintId::of(&unicode::UNICODE_NOT_NFC),
+        LintId::of(&unnecessary_unsafe::OVERSIZED_UNSAFE_BLOCK),
         LintId::of(&unnested_or_patterns::UNNESTED_OR_PATTERNS),
+        LintId::of(&unsigned_sub_underflow::UNSIGNED_SUB_UNDERFLOW),
+        LintId::of(&unused_combinator::UNUSED_COMBINATOR_RESULT),
         LintId::of(&unused_self::UNUSED_SELF),
         LintId::of(&wildcard_imports::ENUM_GLOB_USE),
         LintId::of(&wildcard_imports::WILDCARD_IMPORTS),
@@ -1255,12 +1432,15 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&booleans::LOGIC_BUG),
         LintId::of(&booleans::NONMINIMAL_BOOL),
         LintId::of(&bytecount::NAIVE_BYTECOUNT),
+        LintId::of(&clone_before_drop_explicit::CLONE_BEFORE_DROP_EXPLICIT),
         LintId::of(&collapsible_if::COLLAPSIBLE_IF),
         LintId::of(&comparison_chain::COMPARISON_CHAIN),
         LintId::of(&copies::IFS_SAME_COND),
         LintId::of(&copies::IF_SAME_THEN_ELSE),
         LintId::of(&derive::DERIVE_HASH_XOR_EQ),
         LintId::of(&derive::DERIVE_ORD_XOR_PARTIAL_ORD),
+        LintId::of(&derive::NON_CANONICAL_PARTIAL_ORD),
+        LintId::of(&derive::PARTIAL_EQ_IGNORES_HASHED_FIELDS),
         LintId::of(&doc::MISSING_SAFETY_DOC),
         LintId::of(&doc::NEEDLESS_DOCTEST_MAIN),
         LintId::of(&double_comparison::DOUBLE_COMPARISONS),
@@ -1270,6 +1450,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&drop_forget_ref::DROP_REF),
         LintId::of(&drop_forget_ref::FORGET_COPY),
         LintId::of(&drop_forget_ref::FORGET_REF),
+        LintId::of(&drop_impl::FORGET_SELF_IN_DROP),
         LintId::of(&duration_subsec::DURATION_SUBSEC),
         LintId::of(&entry::MAP_ENTRY),
         LintId::of(&enum_clike::ENUM_CLIKE_UNPORTABLE_VARIANT),
@@ -1304,6 +1485,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&inherent_to_string::INHERENT_TO_STRING_SHADOW_DISPLAY),
         LintId::of(&inline_fn_without_body::INLINE_FN_WITHOUT_BODY),
         LintId::of(&int_plus_one::INT_PLUS_ONE),
+        LintId::of(&iterator_size_hint::INCONSISTENT_SIZE_HINT),
         LintId::of(&large_const_arrays::LARGE_CONST_ARRAYS),
         LintId::of(&large_enum_variant::LARGE_ENUM_VARIANT),
         LintId::of(&len_zero::LEN_WITHOUT_IS_EMPTY),
@@ -1313,6 +1495,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&lifetimes::NEEDLESS_LIFETIMES),
         LintId::of(&literal_representation::INCONSISTENT_DIGIT_GROUPING),
         LintId::of(&literal_representation::MISTYPED_LITERAL_SUFFIXES),
+        LintId::of(&lock_held_in_scrutinee::LOCK_HELD_IN_SCRUTINEE),
         LintId::of(&loops::EMPTY_LOOP),
         LintId::of(&loops::EXPLICIT_COUNTER_LOOP),
         LintId::of(&loops::FOR_KV_MAP),
@@ -1323,12 +1506,15 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&loops::NEEDLESS_COLLECT),
         LintId::of(&loops::NEEDLESS_RANGE_LOOP),
         LintId::of(&loops::NEVER_LOOP),
+        LintId::of(&loops::RESTARTED_ITER_IN_LOOP),
+        LintId::of(&loops::RESTARTED_ITER_IN_LOOP_CONDITION),
         LintId::of(&loops::SAME_ITEM_PUSH),
         LintId::of(&loops::WHILE_IMMUTABLE_CONDITION),
         LintId::of(&loops::WHILE_LET_LOOP),
         LintId::of(&loops::WHILE_LET_ON_ITERATOR),
         LintId::of(&main_recursion::MAIN_RECURSION),
         LintId::of(&manual_async_fn::MANUAL_ASYNC_FN),
+        LintId::of(&manual_empty_new::MANUAL_EMPTY_NEW),
         LintId::of(&manual_non_exhaustive::MANUAL_NON_EXHAUSTIVE),
         LintId::of(&map_clone::MAP_CLONE),
         LintId::of(&map_identity::MAP_IDENTITY),
@@ -1347,6 +1533,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&mem_replace::MEM_REPLACE_OPTION_WITH_NONE),
         LintId::of(&mem_replace::MEM_REPLACE_WITH_DEFAULT),
         LintId::of(&mem_replace::MEM_REPLACE_WITH_UNINIT),
+        LintId::of(&mem_replace::MEM_REPLACE_WITH_ZEROED),
+        LintId::of(&mem_uninitialized::MEM_UNINITIALIZED),
         LintId::of(&methods::BIND_INSTEAD_OF_MAP),
         LintId::of(&methods::CHARS_LAST_CMP),
         LintId::of(&methods::CHARS_NEXT_CMP),
@@ -1429,7 +1617,21 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&question_mark::QUESTION_MARK),
         LintId::of(&ranges::RANGE_ZIP_WITH_LEN),
         LintId::of(&ranges::REVERSED_EMPTY_RANGES),
+        LintId::of(&recursive_trait_impl::RECURSIVE_TRAIT_IMPL),
+        LintId::of(&redundant_clone::CLONE_INSIDE_UNWRAP_OR_ELSE_CLOSURE),
+        LintId::of(&redundant_clone::CLONE_THEN_DROP_SAME_STATEMENT),
+        LintId::of(&redundant_clone::NEEDLESS_CLONE_FOR_INTO_PARAM),
         LintId::of(&redundant_clone::REDUNDANT_CLONE),
+        LintId::of(&redundant_clone::REDUNDANT_CLONE_ACROSS_CHANNEL_SEND),
+        LintId::of(&redundant_clone::REDUNDANT_CLONE_FOR_TRAIT_OBJECT_COERCION),
+        LintId::of(&redundant_clone::REDUNDANT_CLONE_INTO_OWNED_COLLECTION_FROM_ITER),
+        LintId::of(&redundant_clone::REDUNDANT_CLONE_IN_BUILDER_WITH_METHOD),
+        LintId::of(&redundant_clone::REDUNDANT_CLONE_IN_ENUM_VARIANT_PAYLOAD),
+        LintId::of(&redundant_clone::REDUNDANT_CLONE_IN_MAP_ENTRY),
+        LintId::of(&redundant_clone::REDUNDANT_CLONE_IN_RECURSIVE_CALL),
+        LintId::of(&redundant_clone::REDUNDANT_CLONE_IN_SORT_DEDUP),
+        LintId::of(&redundant_clone::REDUNDANT_CLONE_OF_LITERAL),
+        LintId::of(&redundant_clone::UNNECESSARY_CLONE_FOR_PATTERN_BINDING),
         LintId::of(&redundant_closure_call::REDUNDANT_CLOSURE_CALL),
         LintId::of(&redundant_field_names::REDUNDANT_FIELD_NAMES),
         LintId::of(&redundant_static_lifetimes::REDUNDANT_STATIC_LIFETIMES),
@@ -1442,9 +1644,13 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&returns::NEEDLESS_RETURN),
         LintId::of(&self_assignment::SELF_ASSIGNMENT),
         LintId::of(&serde_api::SERDE_API_MISUSE),
+        LintId::of(&shift_overflow::SHIFT_OVERFLOW),
         LintId::of(&single_component_path_imports::SINGLE_COMPONENT_PATH_IMPORTS),
         LintId::of(&slow_vector_initialization::SLOW_VECTOR_INITIALIZATION),
         LintId::of(&stable_sort_primitive::STABLE_SORT_PRIMITIVE),
+        LintId::of(&strings::MANUAL_IGNORE_CASE_CMP),
+        LintId::of(&strings::MISMATCHED_CASE_CMP),
+        LintId::of(&strings::REDUNDANT_SLICING),
         LintId::of(&strings::STRING_LIT_AS_BYTES),
         LintId::of(&suspicious_trait_impl::SUSPICIOUS_ARITHMETIC_IMPL),
         LintId::of(&suspicious_trait_impl::SUSPICIOUS_OP_ASSIGN_IMPL),
@@ -1456,6 +1662,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&to_string_in_display::TO_STRING_IN_DISPLAY),
         LintId::of(&transmute::CROSSPOINTER_TRANSMUTE),
         LintId::of(&transmute::TRANSMUTES_EXPRESSIBLE_AS_PTR_CASTS),
+        LintId::of(&transmute::TRANSMUTE_BYTES_TO_NUM),
         LintId::of(&transmute::TRANSMUTE_BYTES_TO_STR),
         LintId::of(&transmute::TRANSMUTE_FLOAT_TO_INT),
         LintId::of(&transmute::TRANSMUTE_INT_TO_BOOL),
@@ -1479,13 +1686,15 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&types::UNIT_ARG),
         LintId::of(&types::UNIT_CMP),
         LintId::of(&types::UNNECESSARY_CAST),
-        LintId::of(&types::VEC_BOX),
         LintId::of(&unicode::ZERO_WIDTH_SPACE),
         LintId::of(&unit_return_expecting_ord::UNIT_RETURN_EXPECTING_ORD),
         LintId::of(&unnamed_address::FN_ADDRESS_COMPARISONS),
+        LintId::of(&unnamed_address::SUSPICIOUS_ADDRESS_COMPARISONS),
         LintId::of(&unnamed_address::VTABLE_ADDRESS_COMPARISONS),
         LintId::of(&unnecessary_sort_by::UNNECESSARY_SORT_BY),
+        LintId::of(&unnecessary_unsafe::UNNECESSARY_UNSAFE),
         LintId::of(&unsafe_removed_from_name::UNSAFE_REMOVED_FROM_NAME),
+        LintId::of(&unused_combinator::UNUSED_LAZY_VALUE),
         LintId::of(&unused_io_amount::UNUSED_IO_AMOUNT),
         LintId::of(&unused_unit::UNUSED_UNIT),
         LintId::of(&unwrap::PANICKING_UNWRAP),
@@ -1493,7 +1702,9 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&useless_conversion::USELESS_CONVERSION),
         LintId::of(&vec::USELESS_VEC),
         LintId::of(&vec_resize_to_zero::VEC_RESIZE_TO_ZERO),
+        LintId::of(&write::FORMAT_LITERAL),
         LintId::of(&write::PRINTLN_EMPTY_STRING),
+        LintId::of(&write::PRINT_FOLLOWED_BY_NEWLINE),
         LintId::of(&write::PRINT_LITERAL),
         LintId::of(&write::PRINT_WITH_NEWLINE),
         LintId::of(&write::WRITELN_EMPTY_STRING),
@@ -1512,6 +1723,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&blocks_in_if_conditions::BLOCKS_IN_IF_CONDITIONS),
         LintId::of(&collapsible_if::COLLAPSIBLE_IF),
         LintId::of(&comparison_chain::COMPARISON_CHAIN),
+        LintId::of(&derive::NON_CANONICAL_PARTIAL_ORD),
         LintId::of(&doc::MISSING_SAFETY_DOC),
         LintId::of(&doc::NEEDLESS_DOCTEST_MAIN),
         LintId::of(&enum_variants::ENUM_VARIANT_NAMES),
@@ -1536,6 +1748,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&loops::WHILE_LET_ON_ITERATOR),
         LintId::of(&main_recursion::MAIN_RECURSION),
         LintId::of(&manual_async_fn::MANUAL_ASYNC_FN),
+        LintId::of(&manual_empty_new::MANUAL_EMPTY_NEW),
         LintId::of(&manual_non_exhaustive::MANUAL_NON_EXHAUSTIVE),
         LintId::of(&map_clone::MAP_CLONE),
         LintId::of(&matches::INFALLIBLE_DESTRUCTURING_MATCH),
@@ -1592,9 +1805,12 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&try_err::TRY_ERR),
         LintId::of(&types::FN_TO_NUMERIC_CAST),
         LintId::of(&types::FN_TO_NUMERIC_CAST_WITH_TRUNCATION),
+        LintId::of(&unnamed_address::SUSPICIOUS_ADDRESS_COMPARISONS),
         LintId::of(&unsafe_removed_from_name::UNSAFE_REMOVED_FROM_NAME),
         LintId::of(&unused_unit::UNUSED_UNIT),
+        LintId::of(&write::FORMAT_LITERAL),
         LintId::of(&write::PRINTLN_EMPTY_STRING),
+        LintId::of(&write::PRINT_FOLLOWED_BY_NEWLINE),
         LintId::of(&write::PRINT_LITERAL),
         LintId::of(&write::PRINT_WITH_NEWLINE),
         LintId::of(&write::WRITELN_EMPTY_STRING),
@@ -1621,6 +1837,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&lifetimes::NEEDLESS_LIFETIMES),
         LintId::of(&loops::EXPLICIT_COUNTER_LOOP),
         LintId::of(&loops::MUT_RANGE_BOUND),
+        LintId::of(&loops::RESTARTED_ITER_IN_LOOP),
         LintId::of(&loops::WHILE_LET_LOOP),
         LintId::of(&map_identity::MAP_IDENTITY),
         LintId::of(&map_unit_fn::OPTION_MAP_UNIT_FN),
@@ -1654,14 +1871,18 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&precedence::PRECEDENCE),
         LintId::of(&ptr_offset_with_cast::PTR_OFFSET_WITH_CAST),
         LintId::of(&ranges::RANGE_ZIP_WITH_LEN),
+        LintId::of(&redundant_clone::CLONE_THEN_DROP_SAME_STATEMENT),
+        LintId::of(&redundant_clone::REDUNDANT_CLONE_INTO_OWNED_COLLECTION_FROM_ITER),
         LintId::of(&redundant_closure_call::REDUNDANT_CLOSURE_CALL),
         LintId::of(&reference::DEREF_ADDROF),
         LintId::of(&reference::REF_IN_DEREF),
         LintId::of(&repeat_once::REPEAT_ONCE),
+        LintId::of(&strings::REDUNDANT_SLICING),
         LintId::of(&swap::MANUAL_SWAP),
         LintId::of(&temporary_assignment::TEMPORARY_ASSIGNMENT),
         LintId::of(&transmute::CROSSPOINTER_TRANSMUTE),
         LintId::of(&transmute::TRANSMUTES_EXPRESSIBLE_AS_PTR_CASTS),
+        LintId::of(&transmute::TRANSMUTE_BYTES_TO_NUM),
         LintId::of(&transmute::TRANSMUTE_BYTES_TO_STR),
         LintId::of(&transmute::TRANSMUTE_FLOAT_TO_INT),
         LintId::of(&transmute::TRANSMUTE_INT_TO_BOOL),
@@ -1674,8 +1895,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&types::TYPE_COMPLEXITY),
         LintId::of(&types::UNIT_ARG),
         LintId::of(&types::UNNECESSARY_CAST),
-        LintId::of(&types::VEC_BOX),
         LintId::of(&unnecessary_sort_by::UNNECESSARY_SORT_BY),
+        LintId::of(&unnecessary_unsafe::UNNECESSARY_UNSAFE),
         LintId::of(&unwrap::UNNECESSARY_UNWRAP),
         LintId::of(&useless_conversion::USELESS_CONVERSION),
         LintId::of(&zero_div_zero::ZERO_DIVIDED_BY_ZERO),
@@ -1695,11 +1916,13 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&copies::IF_SAME_THEN_ELSE),
         LintId::of(&derive::DERIVE_HASH_XOR_EQ),
         LintId::of(&derive::DERIVE_ORD_XOR_PARTIAL_ORD),
+        LintId::of(&derive::PARTIAL_EQ_IGNORES_HASHED_FIELDS),
         LintId::of(&drop_bounds::DROP_BOUNDS),
         LintId::of(&drop_forget_ref::DROP_COPY),
         LintId::of(&drop_forget_ref::DROP_REF),
         LintId::of(&drop_forget_ref::FORGET_COPY),
         LintId::of(&drop_forget_ref::FORGET_REF),
+        LintId::of(&drop_impl::FORGET_SELF_IN_DROP),
         LintId::of(&enum_clike::ENUM_CLIKE_UNPORTABLE_VARIANT),
         LintId::of(&eq_op::EQ_OP),
         LintId::of(&erasing_op::ERASING_OP),
@@ -1711,14 +1934,19 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&infinite_iter::INFINITE_ITER),
         LintId::of(&inherent_to_string::INHERENT_TO_STRING_SHADOW_DISPLAY),
         LintId::of(&inline_fn_without_body::INLINE_FN_WITHOUT_BODY),
+        LintId::of(&iterator_size_hint::INCONSISTENT_SIZE_HINT),
         LintId::of(&let_underscore::LET_UNDERSCORE_LOCK),
         LintId::of(&literal_representation::MISTYPED_LITERAL_SUFFIXES),
+        LintId::of(&lock_held_in_scrutinee::LOCK_HELD_IN_SCRUTINEE),
         LintId::of(&loops::FOR_LOOPS_OVER_FALLIBLES),
         LintId::of(&loops::ITER_NEXT_LOOP),
         LintId::of(&loops::NEVER_LOOP),
+        LintId::of(&loops::RESTARTED_ITER_IN_LOOP_CONDITION),
         LintId::of(&loops::WHILE_IMMUTABLE_CONDITION),
         LintId::of(&mem_discriminant::MEM_DISCRIMINANT_NON_ENUM),
         LintId::of(&mem_replace::MEM_REPLACE_WITH_UNINIT),
+        LintId::of(&mem_replace::MEM_REPLACE_WITH_ZEROED),
+        LintId::of(&mem_uninitialized::MEM_UNINITIALIZED),
         LintId::of(&methods::CLONE_DOUBLE_REF),
         LintId::of(&methods::ITERATOR_STEP_BY_ZERO),
         LintId::of(&methods::TEMPORARY_CSTRING_AS_PTR),
@@ -1735,9 +1963,12 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&option_env_unwrap::OPTION_ENV_UNWRAP),
         LintId::of(&ptr::MUT_FROM_REF),
         LintId::of(&ranges::REVERSED_EMPTY_RANGES),
+        LintId::of(&recursive_trait_impl::RECURSIVE_TRAIT_IMPL),
         LintId::of(&regex::INVALID_REGEX),
         LintId::of(&self_assignment::SELF_ASSIGNMENT),
         LintId::of(&serde_api::SERDE_API_MISUSE),
+        LintId::of(&shift_overflow::SHIFT_OVERFLOW),
+        LintId::of(&strings::MISMATCHED_CASE_CMP),
         LintId::of(&suspicious_trait_impl::SUSPICIOUS_ARITHMETIC_IMPL),
         LintId::of(&suspicious_trait_impl::SUSPICIOUS_OP_ASSIGN_IMPL),
         LintId::of(&swap::ALMOST_SWAPPED),
@@ -1752,6 +1983,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&unit_return_expecting_ord::UNIT_RETURN_EXPECTING_ORD),
         LintId::of(&unnamed_address::FN_ADDRESS_COMPARISONS),
         LintId::of(&unnamed_address::VTABLE_ADDRESS_COMPARISONS),
+        LintId::of(&unused_combinator::UNUSED_LAZY_VALUE),
         LintId::of(&unused_io_amount::UNUSED_IO_AMOUNT),
         LintId::of(&unwrap::PANICKING_UNWRAP),
         LintId::of(&vec_resize_to_zero::VEC_RESIZE_TO_ZERO),
@@ -1759,6 +1991,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
 
     store.register_group(true, "clippy::perf", Some("clippy_perf"), vec![
         LintId::of(&bytecount::NAIVE_BYTECOUNT),
+        LintId::of(&clone_before_drop_explicit::CLONE_BEFORE_DROP_EXPLICIT),
         LintId::of(&entry::MAP_ENTRY),
         LintId::of(&escape::BOXED_LOCAL),
         LintId::of(&large_const_arrays::LARGE_CONST_ARRAYS),
@@ -1771,9 +2004,21 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&methods::SINGLE_CHAR_PATTERN),
         LintId::of(&misc::CMP_OWNED),
         LintId::of(&mutex_atomic::MUTEX_ATOMIC),
+        LintId::of(&redundant_clone::CLONE_INSIDE_UNWRAP_OR_ELSE_CLOSURE),
+        LintId::of(&redundant_clone::NEEDLESS_CLONE_FOR_INTO_PARAM),
         LintId::of(&redundant_clone::REDUNDANT_CLONE),
+        LintId::of(&redundant_clone::REDUNDANT_CLONE_ACROSS_CHANNEL_SEND),
+        LintId::of(&redundant_clone::REDUNDANT_CLONE_FOR_TRAIT_OBJECT_COERCION),
+        LintId::of(&redundant_clone::REDUNDANT_CLONE_IN_BUILDER_WITH_METHOD),
+        LintId::of(&redundant_clone::REDUNDANT_CLONE_IN_ENUM_VARIANT_PAYLOAD),
+        LintId::of(&redundant_clone::REDUNDANT_CLONE_IN_MAP_ENTRY),
+        LintId::of(&redundant_clone::REDUNDANT_CLONE_IN_RECURSIVE_CALL),
+        LintId::of(&redundant_clone::REDUNDANT_CLONE_IN_SORT_DEDUP),
+        LintId::of(&redundant_clone::REDUNDANT_CLONE_OF_LITERAL),
+        LintId::of(&redundant_clone::UNNECESSARY_CLONE_FOR_PATTERN_BINDING),
         LintId::of(&slow_vector_initialization::SLOW_VECTOR_INITIALIZATION),
         LintId::of(&stable_sort_primitive::STABLE_SORT_PRIMITIVE),
+        LintId::of(&strings::MANUAL_IGNORE_CASE_CMP),
         LintId::of(&types::BOX_VEC),
         LintId::of(&types::REDUNDANT_ALLOCATION),
         LintId::of(&vec::USELESS_VEC),
@@ -1787,11 +2032,16 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
 
     store.register_group(true, "clippy::nursery", Some("clippy_nursery"), vec![
         LintId::of(&attrs::EMPTY_LINE_AFTER_OUTER_ATTR),
+        LintId::of(&clone_on_borrowed_in_closure_capture_by_value::CLONE_ON_BORROWED_IN_CLOSURE_CAPTURE_BY_VALUE),
+        LintId::of(&clone_to_satisfy_fnmut_reuse::CLONE_TO_SATISFY_FNMUT_REUSE),
         LintId::of(&cognitive_complexity::COGNITIVE_COMPLEXITY),
+        LintId::of(&disallowed_paths::DISALLOWED_METHODS),
+        LintId::of(&disallowed_paths::DISALLOWED_TYPES),
         LintId::of(&fallible_impl_from::FALLIBLE_IMPL_FROM),
         LintId::of(&floating_point_arithmetic::IMPRECISE_FLOPS),
         LintId::of(&floating_point_arithmetic::SUBOPTIMAL_FLOPS),
         LintId::of(&future_not_send::FUTURE_NOT_SEND),
+        LintId::of(&iterator_size_hint::MISSING_ITERATOR_SIZE_HINT),
         LintId::of(&let_if_seq::USELESS_LET_IF_SEQ),
         LintId::of(&missing_const_for_fn::MISSING_CONST_FOR_FN),
         LintId::of(&mutable_debug_assertion::DEBUG_ASSERT_WITH_MUT_CALL),
@@ -11,11 +11,13 @@ use rustc_ast::ast::{Expr, ExprKind, Lit, LitKind};
 use rustc_errors::Applicability;
 use rustc_lint::{EarlyContext, EarlyLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
-use rustc_session::{declare_lint_pass, declare_tool_lint, impl_lint_pass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use std::convert::TryFrom;
 
 declare_clippy_lint! {
     /// **What it does:** Warns if a long integral or floating-point constant does
-    /// not contain underscores.
+    /// not contain underscores. The digits are grouped by the sizes configured for
+    /// each radix, the exponent of a float is left alone.
     ///
     /// **Why is this bad?** Reading long numbers is difficult without separators.
     ///
@@ -179,7 +181,16 @@ impl WarningType {
     }
 }
 
-declare_lint_pass!(LiteralDigitGrouping => [
+#[allow(clippy::module_name_repetitions)]
+#[derive(Copy, Clone)]
+pub struct LiteralDigitGrouping {
+    binary_group_size: usize,
+    octal_group_size: usize,
+    decimal_group_size: usize,
+    hexadecimal_group_size: usize,
+}
+
+impl_lint_pass!(LiteralDigitGrouping => [
     UNREADABLE_LITERAL,
     INCONSISTENT_DIGIT_GROUPING,
     LARGE_DIGIT_GROUPS,
@@ -193,7 +204,7 @@ impl EarlyLintPass for LiteralDigitGrouping {
         }
 
         if let ExprKind::Lit(ref lit) = expr.kind {
-            Self::check_lit(cx, lit)
+            self.check_lit(cx, lit)
         }
     }
 }
@@ -202,12 +213,40 @@ impl EarlyLintPass for LiteralDigitGrouping {
 const UUID_GROUP_LENS: [usize; 5] = [8, 4, 4, 4, 12];
 
 impl LiteralDigitGrouping {
-    fn check_lit(cx: &EarlyContext<'_>, lit: &Lit) {
+    #[must_use]
+    pub fn new(
+        binary_group_size: u64,
+        octal_group_size: u64,
+        decimal_group_size: u64,
+        hexadecimal_group_size: u64,
+    ) -> Self {
+        // Empty groups make no sense, treat them like groups of one digit
+        let group_size = |size| usize::try_from(size).unwrap_or(1).max(1);
+        Self {
+            binary_group_size: group_size(binary_group_size),
+            octal_group_size: group_size(octal_group_size),
+            decimal_group_size: group_size(decimal_group_size),
+            hexadecimal_group_size: group_size(hexadecimal_group_size),
+        }
+    }
+
+    fn group_size(self, radix: &Radix) -> usize {
+        match radix {
+            Radix::Binary => self.binary_group_size,
+            Radix::Octal => self.octal_group_size,
+            Radix::Decimal => self.decimal_group_size,
+            Radix::Hexadecimal => self.hexadecimal_group_size,
+        }
+    }
+
+    fn check_lit(self, cx: &EarlyContext<'_>, lit: &Lit) {
         if_chain! {
             if let Some(src) = snippet_opt(cx, lit.span);
             if let Some(mut num_lit) = NumericLiteral::from_lit(&src, &lit);
             then {
-                if !Self::check_for_mistyped_suffix(cx, lit.span, &mut num_lit) {
+                let group_size = self.group_size(&num_lit.radix);
+
+                if !Self::check_for_mistyped_suffix(cx, lit.span, &mut num_lit, group_size) {
                     return;
                 }
 
@@ -217,9 +256,9 @@ impl LiteralDigitGrouping {
 
                 let result = (|| {
 
-                    let integral_group_size = Self::get_group_size(num_lit.integer.split('_'))?;
+                    let integral_group_size = Self::get_group_size(num_lit.integer.split('_'), group_size)?;
                     if let Some(fraction) = num_lit.fraction {
-                        let fractional_group_size = Self::get_group_size(fraction.rsplit('_'))?;
+                        let fractional_group_size = Self::get_group_size(fraction.rsplit('_'), group_size)?;
 
                         let consistent = Self::parts_consistent(integral_group_size,
                                                                 fractional_group_size,
@@ -245,7 +284,7 @@ impl LiteralDigitGrouping {
                         }
                     };
                     if should_warn {
-                        warning_type.display(num_lit.format(), cx, lit.span)
+                        warning_type.display(num_lit.format_grouped(group_size), cx, lit.span)
                     }
                 }
             }
@@ -257,8 +296,10 @@ impl LiteralDigitGrouping {
         cx: &EarlyContext<'_>,
         span: rustc_span::Span,
         num_lit: &mut NumericLiteral<'_>,
+        group_size: usize,
     ) -> bool {
-        if num_lit.suffix.is_some() {
+        // Digits like `_32` are a valid group of hexadecimal or octal digits
+        if num_lit.suffix.is_some() || !num_lit.is_decimal() {
             return true;
         }
 
@@ -276,8 +317,8 @@ impl LiteralDigitGrouping {
         let mut split = part.rsplit('_');
         let last_group = split.next().expect("At least one group");
         if split.next().is_some() && mistyped_suffixes.contains(&last_group) {
-            *part = &part[..part.len() - last_group.len()];
-            let mut sugg = num_lit.format();
+            *part = part[..part.len() - last_group.len()].trim_end_matches('_');
+            let mut sugg = num_lit.format_grouped(group_size);
             sugg.push('_');
             sugg.push(missing_char);
             sugg.push_str(last_group);
@@ -333,8 +374,12 @@ impl LiteralDigitGrouping {
     }
 
     /// Returns the size of the digit groups (or None if ungrouped) if successful,
-    /// otherwise returns a `WarningType` for linting.
-    fn get_group_size<'a>(groups: impl Iterator<Item = &'a str>) -> Result<Option<usize>, WarningType> {
+    /// otherwise returns a `WarningType` for linting. Groups of up to four digits, or up to
+    /// `group_size` digits if that is larger, are fine.
+    fn get_group_size<'a>(
+        groups: impl Iterator<Item = &'a str>,
+        group_size: usize,
+    ) -> Result<Option<usize>, WarningType> {
         let mut groups = groups.map(str::len);
 
         let first = groups.next().expect("At least one group");
@@ -342,12 +387,12 @@ impl LiteralDigitGrouping {
         if let Some(second) = groups.next() {
             if !groups.all(|x| x == second) || first > second {
                 Err(WarningType::InconsistentDigitGrouping)
-            } else if second > 4 {
+            } else if second > group_size.max(4) {
                 Err(WarningType::LargeDigitGroups)
             } else {
                 Ok(Some(second))
             }
-        } else if first > 5 {
+        } else if first > group_size.max(5) {
             Err(WarningType::UnreadableLiteral)
         } else {
             Ok(None)
@@ -390,7 +435,7 @@ impl DecimalLiteralRepresentation {
             if val >= u128::from(self.threshold);
             then {
                 let hex = format!("{:#X}", val);
-                let num_lit = NumericLiteral::new(&hex, num_lit.suffix, false);
+                let num_lit = NumericLiteral::new(&hex, num_lit.suffix);
                 let _ = Self::do_lint(num_lit.integer).map_err(|warning_type| {
                     warning_type.display(num_lit.format(), cx, lit.span)
                 });
@@ -0,0 +1,287 @@
+use crate::utils::{
+    get_parent_expr, in_macro, match_def_path, match_function_call, match_type, paths, qpath_res, span_lint_and_then,
+    SpanlessEq,
+};
+use if_chain::if_chain;
+use rustc_hir::def::Res;
+use rustc_hir::intravisit::{self as visit, NestedVisitorMap, Visitor};
+use rustc_hir::{Arm, Expr, ExprKind, HirId, MatchSource};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_middle::ty::adjustment::Adjust;
+use rustc_middle::ty::subst::GenericArgKind;
+use rustc_middle::ty::{self, Ty};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `match`, `if let` and `while let` expressions whose
+    /// scrutinee locks a `Mutex` or `RwLock` or borrows a `RefCell`, while the body locks or
+    /// borrows the same cell again.
+    ///
+    /// **Why is this bad?** Temporaries of the scrutinee, like the guard returned by
+    /// `lock()` or `borrow_mut()`, live until the end of the whole expression, including
+    /// all of its arms. Acquiring the same lock again deadlocks, and a conflicting
+    /// `RefCell` borrow panics.
+    ///
+    /// **Known problems:** Only calls written directly in the arms are checked, not the
+    /// functions they call. An `if let` with an `else` block on a `Mutex` is left to the
+    /// `if_let_mutex` lint.
+    ///
+    /// **Example:**
+    /// ```rust,no_run
+    /// # use std::cell::RefCell;
+    /// let stack = RefCell::new(vec![1, 2, 3]);
+    /// while let Some(x) = stack.borrow_mut().pop() {
+    ///     stack.borrow_mut().push(x - 1);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,no_run
+    /// # use std::cell::RefCell;
+    /// let stack = RefCell::new(vec![1, 2, 3]);
+    /// loop {
+    ///     let top = stack.borrow_mut().pop();
+    ///     if let Some(x) = top {
+    ///         stack.borrow_mut().push(x - 1);
+    ///     } else {
+    ///         break;
+    ///     }
+    /// }
+    /// ```
+    pub LOCK_HELD_IN_SCRUTINEE,
+    correctness,
+    "locking or borrowing a cell again while the guard from the `match` scrutinee is still held"
+}
+
+declare_lint_pass!(LockHeldInScrutinee => [LOCK_HELD_IN_SCRUTINEE]);
+
+const GUARD_PATHS: [&[&str]; 5] = [
+    &paths::MUTEX_GUARD,
+    &paths::RWLOCK_READ_GUARD,
+    &paths::RWLOCK_WRITE_GUARD,
+    &paths::REFCELL_REF,
+    &paths::REFCELL_REFMUT,
+];
+
+impl<'tcx> LateLintPass<'tcx> for LockHeldInScrutinee {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        let (scrutinee, arms, source) = match expr.kind {
+            ExprKind::Match(ref scrutinee, arms, source) => (scrutinee, arms, source),
+            _ => return,
+        };
+        let (kind, has_else) = match source {
+            MatchSource::Normal => ("match", false),
+            MatchSource::IfLetDesugar { contains_else_clause } => ("if let", contains_else_clause),
+            MatchSource::WhileLetDesugar => ("while let", false),
+            _ => return,
+        };
+        if in_macro(scrutinee.span) {
+            return;
+        }
+
+        let mut finder = LockCallFinder { cx, calls: Vec::new() };
+        finder.visit_expr(scrutinee);
+
+        for outer in finder.calls {
+            // Reported by `if_let_mutex`
+            if outer.is_mutex && has_else {
+                continue;
+            }
+            let temporary = match guard_lifetime(cx, outer.expr, scrutinee) {
+                Some(temporary) => temporary,
+                None => continue,
+            };
+
+            for arm in arms {
+                let mut visitor = ArmVisitor {
+                    cx,
+                    outer: &outer,
+                    guard_bindings: if temporary { Vec::new() } else { guard_bindings(cx, arm) },
+                    released: false,
+                    conflicts: Vec::new(),
+                };
+                visitor.visit_arm(arm);
+
+                for inner in visitor.conflicts {
+                    let msg = format!(
+                        "`{}()` is called while the guard from the `{}` scrutinee is still held",
+                        inner.name, kind
+                    );
+                    span_lint_and_then(cx, LOCK_HELD_IN_SCRUTINEE, inner.expr.span, &msg, |diag| {
+                        diag.span_note(
+                            outer.expr.span,
+                            &format!("the guard returned here lives until the end of the `{}`", kind),
+                        );
+                        diag.note(if outer.is_refcell {
+                            "this panics with a `BorrowMutError` or `BorrowError` at runtime"
+                        } else {
+                            "this deadlocks at runtime"
+                        });
+                        diag.help(&format!(
+                            "bind the result of `{}()` with `let` before the `{}`, or move it into a separate scope",
+                            outer.name, kind
+                        ));
+                    });
+                }
+            }
+        }
+    }
+}
+
+struct LockCall<'tcx> {
+    expr: &'tcx Expr<'tcx>,
+    receiver: &'tcx Expr<'tcx>,
+    name: &'static str,
+    exclusive: bool,
+    is_mutex: bool,
+    is_refcell: bool,
+}
+
+impl LockCall<'_> {
+    fn conflicts_with(&self, other: &Self) -> bool {
+        self.exclusive || other.exclusive
+    }
+}
+
+/// Returns the call if `expr` is `Mutex::lock`, `RwLock::read`, `RwLock::write`,
+/// `RefCell::borrow` or `RefCell::borrow_mut`.
+fn lock_call<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<LockCall<'tcx>> {
+    if_chain! {
+        if let ExprKind::MethodCall(_, _, ref args, _) = expr.kind;
+        if let Some(def_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id);
+        then {
+            let (name, exclusive, is_mutex, is_refcell) = if match_def_path(cx, def_id, &paths::MUTEX_LOCK) {
+                ("lock", true, true, false)
+            } else if match_def_path(cx, def_id, &paths::RWLOCK_READ) {
+                ("read", false, false, false)
+            } else if match_def_path(cx, def_id, &paths::RWLOCK_WRITE) {
+                ("write", true, false, false)
+            } else if match_def_path(cx, def_id, &paths::REFCELL_BORROW) {
+                ("borrow", false, false, true)
+            } else if match_def_path(cx, def_id, &paths::REFCELL_BORROW_MUT) {
+                ("borrow_mut", true, false, true)
+            } else {
+                return None;
+            };
+            Some(LockCall {
+                expr,
+                receiver: &args[0],
+                name,
+                exclusive,
+                is_mutex,
+                is_refcell,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+fn contains_guard<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+    ty.walk().any(|inner| match inner.unpack() {
+        GenericArgKind::Type(inner_ty) => GUARD_PATHS.iter().any(|path| match_type(cx, inner_ty, path)),
+        GenericArgKind::Lifetime(_) | GenericArgKind::Const(_) => false,
+    })
+}
+
+/// Follows the guard returned by `call` up to the `scrutinee`. Returns `None` if it is consumed
+/// on the way, `Some(true)` if it ends up in a temporary of the scrutinee, which lives until the
+/// end of the `match`, and `Some(false)` if it is moved into the matched value.
+fn guard_lifetime<'tcx>(cx: &LateContext<'tcx>, call: &'tcx Expr<'_>, scrutinee: &Expr<'_>) -> Option<bool> {
+    let mut expr = call;
+    loop {
+        // Borrowing the guard, e.g. through auto-ref or `Deref`, leaves it in a temporary
+        let borrowed = cx
+            .typeck_results()
+            .expr_adjustments(expr)
+            .iter()
+            .any(|adjust| matches!(adjust.kind, Adjust::Borrow(_) | Adjust::Deref(Some(_))));
+        if borrowed {
+            return Some(true);
+        }
+        if expr.hir_id == scrutinee.hir_id {
+            return Some(false);
+        }
+
+        let parent = get_parent_expr(cx, expr)?;
+        match parent.kind {
+            ExprKind::AddrOf(..) | ExprKind::Field(..) | ExprKind::Index(..) | ExprKind::Unary(..) => {
+                return Some(true);
+            },
+            _ if contains_guard(cx, cx.typeck_results().expr_ty(parent)) => expr = parent,
+            _ => return None,
+        }
+    }
+}
+
+/// Collects the bindings of `arm` which take ownership of a guard.
+fn guard_bindings(cx: &LateContext<'_>, arm: &Arm<'_>) -> Vec<HirId> {
+    let mut bindings = Vec::new();
+    arm.pat.each_binding(|_, hir_id, _, _| {
+        let ty = cx.typeck_results().node_type(hir_id);
+        if !matches!(ty.kind(), ty::Ref(..)) && contains_guard(cx, ty) {
+            bindings.push(hir_id);
+        }
+    });
+    bindings
+}
+
+/// Collects the lock calls in the scrutinee, not looking into closures.
+struct LockCallFinder<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    calls: Vec<LockCall<'tcx>>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for LockCallFinder<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
+        if let Some(call) = lock_call(self.cx, expr) {
+            self.calls.push(call);
+        }
+        visit::walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
+
+/// Finds conflicting lock calls in an arm, until the guard is explicitly dropped.
+struct ArmVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    outer: &'a LockCall<'tcx>,
+    guard_bindings: Vec<HirId>,
+    released: bool,
+    conflicts: Vec<LockCall<'tcx>>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for ArmVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
+        if self.released {
+            return;
+        }
+        if_chain! {
+            if let Some([arg]) = match_function_call(self.cx, expr, &paths::DROP);
+            if let ExprKind::Path(ref qpath) = arg.kind;
+            if let Res::Local(id) = qpath_res(self.cx, qpath, arg.hir_id);
+            if self.guard_bindings.contains(&id);
+            then {
+                self.released = true;
+                return;
+            }
+        }
+        if let Some(call) = lock_call(self.cx, expr) {
+            if call.conflicts_with(self.outer) && SpanlessEq::new(self.cx).eq_expr(call.receiver, self.outer.receiver) {
+                self.conflicts.push(call);
+            }
+        }
+        visit::walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
@@ -453,6 +453,69 @@ declare_clippy_lint! {
     "the same item is pushed inside of a for loop"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for loop conditions which create an iterator and take its
+    /// first element, like `while v.iter().next().is_some()` or
+    /// `while let Some(c) = s.chars().next()`, when the collection is not changed in the loop.
+    ///
+    /// **Why is this bad?** A fresh iterator is created on every iteration, so `next()`
+    /// always returns the same element and the condition never changes. The loop either
+    /// never runs or never terminates, unless it is left with `break` or `return`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// let v = vec![1, 2, 3];
+    /// while let Some(x) = v.iter().next() {
+    ///     println!("{}", x);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let v = vec![1, 2, 3];
+    /// let mut iter = v.iter();
+    /// while let Some(x) = iter.next() {
+    ///     println!("{}", x);
+    /// }
+    /// ```
+    pub RESTARTED_ITER_IN_LOOP_CONDITION,
+    correctness,
+    "a loop condition taking the first element of a new iterator on every iteration"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `.iter().next()`, `.into_iter().next()` and similar
+    /// calls inside a loop body when the collection is not changed in the loop.
+    ///
+    /// **Why is this bad?** A fresh iterator is created on every iteration, so the same
+    /// element is returned every time. Usually the iterator was meant to be created once,
+    /// outside the loop.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let v = vec![1, 2, 3];
+    /// for _ in 0..3 {
+    ///     let x = v.iter().next();
+    ///     println!("{:?}", x);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let v = vec![1, 2, 3];
+    /// let mut iter = v.iter();
+    /// for _ in 0..3 {
+    ///     let x = iter.next();
+    ///     println!("{:?}", x);
+    /// }
+    /// ```
+    pub RESTARTED_ITER_IN_LOOP,
+    complexity,
+    "taking the first element of a new iterator on every iteration of a loop"
+}
+
 declare_lint_pass!(Loops => [
     MANUAL_MEMCPY,
     NEEDLESS_RANGE_LOOP,
@@ -470,6 +533,8 @@ declare_lint_pass!(Loops => [
     MUT_RANGE_BOUND,
     WHILE_IMMUTABLE_CONDITION,
     SAME_ITEM_PUSH,
+    RESTARTED_ITER_IN_LOOP_CONDITION,
+    RESTARTED_ITER_IN_LOOP,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Loops {
@@ -483,6 +548,7 @@ impl<'tcx> LateLintPass<'tcx> for Loops {
                 return;
             }
             check_for_loop(cx, pat, arg, body, expr);
+            check_restarted_iters(cx, expr, None, body);
         }
 
         // we don't want to check expanded macros
@@ -556,7 +622,15 @@ impl<'tcx> LateLintPass<'tcx> for Loops {
                 }
             }
         }
+        if let ExprKind::Loop(ref block, _, LoopSource::Loop) = expr.kind {
+            let mut visitor = RestartedIterVisitor { cx, found: Vec::new() };
+            visitor.visit_block(block);
+            lint_restarted_iters(cx, expr, Vec::new(), visitor.found);
+        }
+
         if let ExprKind::Match(ref match_expr, ref arms, MatchSource::WhileLetDesugar) = expr.kind {
+            check_restarted_iters(cx, expr, Some(match_expr), &arms[0].body);
+
             let pat = &arms[0].pat.kind;
             if let (
                 &PatKind::TupleStruct(ref qpath, ref pat_args, _),
@@ -607,6 +681,7 @@ impl<'tcx> LateLintPass<'tcx> for Loops {
 
         if let Some((cond, body)) = higher::while_loop(&expr) {
             check_infinite_loop(cx, cond, body);
+            check_restarted_iters(cx, expr, Some(cond), body);
         }
 
         check_needless_collect(expr, cx);
@@ -920,7 +995,7 @@ fn build_manual_memcpy_suggestion<'tcx>(
     src_var: FixedOffsetVar<'_>,
 ) -> String {
     fn print_sum(arg1: &str, arg2: &Offset) -> String {
-        match (arg1, &arg2.value[..], arg2.sign) {
+        match (arg1, arg2.value.as_str(), arg2.sign) {
             ("0", "0", _) => "0".into(),
             ("0", x, OffsetSign::Positive) | (x, "0", _) => x.into(),
             ("0", x, OffsetSign::Negative) => format!("-{}", x),
@@ -2549,6 +2624,127 @@ impl<'a, 'tcx> Visitor<'tcx> for VarCollectorVisitor<'a, 'tcx> {
     }
 }
 
+/// Methods creating an iterator over a collection, which `next()` restarts when called
+/// on the result directly.
+const RESTARTED_ITER_METHODS: [&str; 5] = ["bytes", "chars", "into_iter", "iter", "iter_mut"];
+
+/// A `recv.iter().next()`-like call found in a loop.
+struct RestartedIter<'tcx> {
+    /// The `next()` call
+    expr: &'tcx Expr<'tcx>,
+    /// The local the receiver of the iterator method is based on
+    local: HirId,
+}
+
+/// Collects the `recv.iter().next()`-like calls in a loop condition or body, without
+/// entering nested loops or closures.
+struct RestartedIterVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    found: Vec<RestartedIter<'tcx>>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for RestartedIterVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Loop(..) = expr.kind {
+            // nested loops are checked on their own
+            return;
+        }
+        if_chain! {
+            if let ExprKind::MethodCall(ref method, _, ref args, _) = expr.kind;
+            if method.ident.name == sym!(next);
+            if let ExprKind::MethodCall(ref iter_method, _, ref iter_args, _) = args[0].kind;
+            if RESTARTED_ITER_METHODS.contains(&&*iter_method.ident.as_str());
+            if match_trait_method(self.cx, expr, &paths::ITERATOR);
+            if let Some(local) = base_local(self.cx, &iter_args[0]);
+            then {
+                self.found.push(RestartedIter { expr, local });
+                return;
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
+
+/// Returns the local `expr` is a field, dereference or reference of, if any.
+fn base_local(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<HirId> {
+    match expr.kind {
+        ExprKind::Field(ref base, _) | ExprKind::Unary(_, ref base) | ExprKind::AddrOf(_, _, ref base) => {
+            base_local(cx, base)
+        },
+        ExprKind::Path(ref qpath) => match qpath_res(cx, qpath, expr.hir_id) {
+            Res::Local(hir_id) => Some(hir_id),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn check_restarted_iters<'tcx>(
+    cx: &LateContext<'tcx>,
+    loop_expr: &'tcx Expr<'_>,
+    cond: Option<&'tcx Expr<'_>>,
+    body: &'tcx Expr<'_>,
+) {
+    let mut visitor = RestartedIterVisitor { cx, found: Vec::new() };
+    if let Some(cond) = cond {
+        visitor.visit_expr(cond);
+    }
+    let in_cond = mem::replace(&mut visitor.found, Vec::new());
+    visitor.visit_expr(body);
+    lint_restarted_iters(cx, loop_expr, in_cond, visitor.found);
+}
+
+/// Lints the restarted iterators whose receiver is defined outside of `loop_expr` and
+/// not mutated in it.
+fn lint_restarted_iters<'tcx>(
+    cx: &LateContext<'tcx>,
+    loop_expr: &'tcx Expr<'_>,
+    in_cond: Vec<RestartedIter<'tcx>>,
+    in_body: Vec<RestartedIter<'tcx>>,
+) {
+    if in_cond.is_empty() && in_body.is_empty() {
+        return;
+    }
+    let mutated = match mutated_variables(loop_expr, cx) {
+        Some(mutated) => mutated,
+        None => return,
+    };
+    let is_invariant = |iter: &RestartedIter<'_>| {
+        !mutated.contains(&iter.local) && !loop_expr.span.contains(cx.tcx.hir().span(iter.local))
+    };
+
+    for iter in in_cond.iter().filter(|iter| is_invariant(iter)) {
+        span_lint_and_then(
+            cx,
+            RESTARTED_ITER_IN_LOOP_CONDITION,
+            iter.expr.span,
+            "this creates a new iterator and takes its first element on every iteration",
+            |diag| {
+                diag.note("the same element is returned every time, so the loop condition never changes");
+                diag.help("create the iterator once before the loop, or remove elements with `pop()`");
+            },
+        );
+    }
+    for iter in in_body.iter().filter(|iter| is_invariant(iter)) {
+        span_lint_and_then(
+            cx,
+            RESTARTED_ITER_IN_LOOP,
+            iter.expr.span,
+            "this creates a new iterator and takes its first element on every iteration",
+            |diag| {
+                diag.note("the same element is returned every time");
+                diag.help("create the iterator once before the loop");
+            },
+        );
+    }
+}
+
 const NEEDLESS_COLLECT_MSG: &str = "avoid using `collect()` when not needed";
 
 fn check_needless_collect<'tcx>(expr: &'tcx Expr<'_>, cx: &LateContext<'tcx>) {
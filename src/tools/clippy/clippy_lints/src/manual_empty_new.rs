@@ -0,0 +1,145 @@
+use crate::utils::{
+    in_macro, is_expn_of, is_type_diagnostic_item, match_function_call, paths, snippet, span_lint_and_sugg,
+};
+use if_chain::if_chain;
+use rustc_ast::ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::def::Res;
+use rustc_hir::{Block, BorrowKind, Expr, ExprKind, MatchSource, PatKind, QPath, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::source_map::Span;
+use rustc_span::BytePos;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for empty `String`s created with `"".to_string()`,
+    /// `"".to_owned()`, `String::from("")` or `format!("")`, and for empty `Vec`s created with
+    /// `[].to_vec()` or with a `vec![]` which is immediately extended.
+    ///
+    /// **Why is this bad?** `String::new()` and `Vec::new()` state the intent more clearly and
+    /// do not go through a conversion.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let s = "".to_string();
+    /// let v: Vec<u8> = [].to_vec();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let s = String::new();
+    /// let v: Vec<u8> = Vec::new();
+    /// ```
+    pub MANUAL_EMPTY_NEW,
+    style,
+    "creating an empty `String` or `Vec` without using `new()`"
+}
+
+declare_lint_pass!(ManualEmptyNew => [MANUAL_EMPTY_NEW]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualEmptyNew {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let Some(span) = is_expn_of(expr.span, "format") {
+            if !span.from_expansion() && is_empty_format_args(cx, expr) {
+                lint_format(cx, span.source_callsite());
+            }
+            return;
+        }
+        if in_macro(expr.span) {
+            return;
+        }
+
+        match expr.kind {
+            ExprKind::MethodCall(ref path, _, ref args, _) if args.len() == 1 => {
+                let name = path.ident.as_str();
+                if (name == "to_string" || name == "to_owned") && is_empty_str_lit(&args[0]) {
+                    lint(cx, expr.span, "String");
+                } else if name == "to_vec" && matches!(args[0].kind, ExprKind::Array([])) {
+                    lint(cx, expr.span, "Vec");
+                }
+            },
+            ExprKind::Call(..) => {
+                if_chain! {
+                    if let Some(args) = match_function_call(cx, expr, &paths::FROM_FROM);
+                    if let [arg] = args;
+                    if is_empty_str_lit(arg);
+                    if is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(expr), sym!(string_type));
+                    then {
+                        lint(cx, expr.span, "String");
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'_>) {
+        // `let mut v: Vec<u8> = vec![]; v.extend(..);`
+        for w in block.stmts.windows(2) {
+            if_chain! {
+                if let StmtKind::Local(ref local) = w[0].kind;
+                if local.ty.is_some();
+                if let PatKind::Binding(_, binding_id, ..) = local.pat.kind;
+                if let Some(ref init) = local.init;
+                if let Some(span) = is_expn_of(init.span, "vec");
+                if !span.from_expansion();
+                if let Some([]) = match_function_call(cx, init, &paths::VEC_NEW);
+                if let StmtKind::Semi(ref e) | StmtKind::Expr(ref e) = w[1].kind;
+                if let ExprKind::MethodCall(ref path, _, ref args, _) = e.kind;
+                if path.ident.name == sym!(extend);
+                if let ExprKind::Path(QPath::Resolved(None, ref recv)) = args[0].kind;
+                if let Res::Local(id) = recv.res;
+                if id == binding_id;
+                then {
+                    lint(cx, span, "Vec");
+                }
+            }
+        }
+    }
+}
+
+fn lint(cx: &LateContext<'_>, span: Span, ty: &str) {
+    span_lint_and_sugg(
+        cx,
+        MANUAL_EMPTY_NEW,
+        span,
+        &format!("manually creating an empty `{}`", ty),
+        "try",
+        format!("{}::new()", ty),
+        Applicability::MachineApplicable,
+    );
+}
+
+fn lint_format(cx: &LateContext<'_>, span: Span) {
+    // The callsite span of a `format!` in statement position contains the semicolon
+    let span = if snippet(cx, span, "..").ends_with(';') {
+        span.with_hi(span.hi() - BytePos(1))
+    } else {
+        span
+    };
+    lint(cx, span, "String");
+}
+
+fn is_empty_str_lit(expr: &Expr<'_>) -> bool {
+    matches!(expr.kind, ExprKind::Lit(ref lit) if matches!(lit.node, LitKind::Str(s, _) if s.as_str().is_empty()))
+}
+
+/// Checks whether `expr` is the `Arguments` of `format!("")`, which has neither pieces nor
+/// arguments.
+fn is_empty_format_args<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) -> bool {
+    if_chain! {
+        if let Some([pieces, args]) = match_function_call(cx, expr, &paths::FMT_ARGUMENTS_NEW_V1);
+        if let ExprKind::AddrOf(BorrowKind::Ref, _, ref arr) = pieces.kind;
+        if let ExprKind::Array(ref elems) = arr.kind;
+        if elems.is_empty();
+        if let ExprKind::AddrOf(BorrowKind::Ref, _, ref arg1) = args.kind;
+        if let ExprKind::Match(ref matchee, _, MatchSource::Normal) = arg1.kind;
+        if let ExprKind::Tup([]) = matchee.kind;
+        then {
+            true
+        } else {
+            false
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::utils::msrvs::{self, meets_msrv, update_msrv_from_attrs, RustcVersion};
 use crate::utils::paths;
 use crate::utils::{
     is_copy, is_type_diagnostic_item, match_trait_method, remove_blocks, snippet_with_applicability, span_lint_and_sugg,
@@ -5,10 +6,10 @@ use crate::utils::{
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir as hir;
-use rustc_lint::{LateContext, LateLintPass};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::mir::Mutability;
 use rustc_middle::ty;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::symbol::Ident;
 use rustc_span::Span;
 
@@ -40,9 +41,24 @@ declare_clippy_lint! {
     "using `iterator.map(|x| x.clone())`, or dereferencing closures for `Copy` types"
 }
 
-declare_lint_pass!(MapClone => [MAP_CLONE]);
+pub struct MapClone {
+    msrv: Option<RustcVersion>,
+}
+
+impl MapClone {
+    #[must_use]
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(MapClone => [MAP_CLONE]);
 
 impl<'tcx> LateLintPass<'tcx> for MapClone {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>, krate: &'tcx hir::Crate<'_>) {
+        update_msrv_from_attrs(&mut self.msrv, cx.sess(), &krate.item.attrs);
+    }
+
     fn check_expr(&mut self, cx: &LateContext<'_>, e: &hir::Expr<'_>) {
         if e.span.from_expansion() {
             return;
@@ -58,12 +74,18 @@ impl<'tcx> LateLintPass<'tcx> for MapClone {
             let closure_body = cx.tcx.hir().body(body_id);
             let closure_expr = remove_blocks(&closure_body.value);
             then {
+                let copied_msrv = if is_type_diagnostic_item(cx, ty, sym!(option_type)) {
+                    msrvs::OPTION_COPIED
+                } else {
+                    msrvs::ITERATOR_COPIED
+                };
+                let can_copy = meets_msrv(self.msrv, copied_msrv);
                 match closure_body.params[0].pat.kind {
                     hir::PatKind::Ref(ref inner, hir::Mutability::Not) => if let hir::PatKind::Binding(
                         hir::BindingAnnotation::Unannotated, .., name, None
                     ) = inner.kind {
                         if ident_eq(name, closure_expr) {
-                            lint(cx, e.span, args[0].span, true);
+                            lint(cx, e.span, args[0].span, can_copy);
                         }
                     },
                     hir::PatKind::Binding(hir::BindingAnnotation::Unannotated, .., name, None) => {
@@ -71,7 +93,7 @@ impl<'tcx> LateLintPass<'tcx> for MapClone {
                             hir::ExprKind::Unary(hir::UnOp::UnDeref, ref inner) => {
                                 if ident_eq(name, inner) {
                                     if let ty::Ref(.., Mutability::Not) = cx.typeck_results().expr_ty(inner).kind() {
-                                        lint(cx, e.span, args[0].span, true);
+                                        lint(cx, e.span, args[0].span, can_copy);
                                     }
                                 }
                             },
@@ -81,7 +103,7 @@ impl<'tcx> LateLintPass<'tcx> for MapClone {
 
                                     let obj_ty = cx.typeck_results().expr_ty(&obj[0]);
                                     if let ty::Ref(_, ty, _) = obj_ty.kind() {
-                                        let copy = is_copy(cx, ty);
+                                        let copy = can_copy && is_copy(cx, ty);
                                         lint(cx, e.span, args[0].span, copy);
                                     } else {
                                         lint_needless_cloning(cx, e.span, args[0].span);
@@ -1185,7 +1185,7 @@ fn check_match_single_binding<'a>(cx: &LateContext<'a>, ex: &Expr<'a>, arms: &[A
             } else {
                 // If we are in closure, we need curly braces around suggestion
                 let mut indent = " ".repeat(indent_of(cx, ex.span).unwrap_or(0));
-                let (mut cbrace_start, mut cbrace_end) = ("".to_string(), "".to_string());
+                let (mut cbrace_start, mut cbrace_end) = (String::new(), String::new());
                 if let Some(parent_expr) = get_parent_expr(cx, expr) {
                     if let ExprKind::Closure(..) = parent_expr.kind {
                         cbrace_end = format!("\n{}}}", indent);
@@ -1,15 +1,17 @@
 use crate::utils::{
-    in_macro, match_def_path, match_qpath, paths, snippet, snippet_with_applicability, span_lint_and_help,
-    span_lint_and_sugg, span_lint_and_then,
+    in_macro, match_def_path, match_qpath, paths, snippet, snippet_with_applicability, span_lint_and_sugg,
+    span_lint_and_then,
 };
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir::{BorrowKind, Expr, ExprKind, Mutability, QPath};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, Ty};
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 use rustc_span::source_map::Span;
 use rustc_span::symbol::sym;
+use std::ops::Bound;
 
 declare_clippy_lint! {
     /// **What it does:** Checks for `mem::replace()` on an `Option` with
@@ -40,7 +42,7 @@ declare_clippy_lint! {
 
 declare_clippy_lint! {
     /// **What it does:** Checks for `mem::replace(&mut _, mem::uninitialized())`
-    /// and `mem::replace(&mut _, mem::zeroed())`.
+    /// and `mem::replace(&mut _, MaybeUninit::uninit().assume_init())`.
     ///
     /// **Why is this bad?** This will lead to undefined behavior even if the
     /// value is overwritten later, because the uninitialized value may be
@@ -67,7 +69,46 @@ declare_clippy_lint! {
     /// on panic, to ensure that the uninitialized value cannot be observed.
     pub MEM_REPLACE_WITH_UNINIT,
     correctness,
-    "`mem::replace(&mut _, mem::uninitialized())` or `mem::replace(&mut _, mem::zeroed())`"
+    "`mem::replace(&mut _, mem::uninitialized())` or `mem::replace(&mut _, MaybeUninit::uninit().assume_init())`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `mem::replace(&mut _, mem::zeroed())` where the
+    /// replaced type has no valid all-zero value, like references, `Box`, `NonNull`,
+    /// the `NonZero*` integers, or fieldless enums without a variant for `0`.
+    ///
+    /// **Why is this bad?** Creating such a value is undefined behavior, even if it is
+    /// overwritten later.
+    ///
+    /// **Known problems:** Enums with fields and generic types are not checked, as the
+    /// validity of their all-zero value depends on the layout chosen by the compiler.
+    ///
+    /// **Example:**
+    ///
+    /// ```
+    /// use std::mem;
+    ///# fn may_panic(v: Vec<i32>) -> Vec<i32> { v }
+    ///
+    /// #[allow(invalid_value)]
+    /// fn myfunc (v: &mut Vec<i32>) {
+    ///     let taken_v = unsafe { mem::replace(v, mem::zeroed()) };
+    ///     let new_v = may_panic(taken_v); // undefined behavior
+    ///     mem::forget(mem::replace(v, new_v));
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```
+    /// use std::mem;
+    ///# fn may_panic(v: Vec<i32>) -> Vec<i32> { v }
+    ///
+    /// fn myfunc (v: &mut Vec<i32>) {
+    ///     let taken_v = mem::take(v);
+    ///     *v = may_panic(taken_v);
+    /// }
+    /// ```
+    pub MEM_REPLACE_WITH_ZEROED,
+    correctness,
+    "`mem::replace(&mut _, mem::zeroed())` on a type which has no valid all-zero value"
 }
 
 declare_clippy_lint! {
@@ -95,7 +136,7 @@ declare_clippy_lint! {
 }
 
 declare_lint_pass!(MemReplace =>
-    [MEM_REPLACE_OPTION_WITH_NONE, MEM_REPLACE_WITH_UNINIT, MEM_REPLACE_WITH_DEFAULT]);
+    [MEM_REPLACE_OPTION_WITH_NONE, MEM_REPLACE_WITH_UNINIT, MEM_REPLACE_WITH_ZEROED, MEM_REPLACE_WITH_DEFAULT]);
 
 fn check_replace_option_with_none(cx: &LateContext<'_>, src: &Expr<'_>, dest: &Expr<'_>, expr_span: Span) {
     if let ExprKind::Path(ref replacement_qpath) = src.kind {
@@ -178,21 +219,54 @@ fn check_replace_with_uninit(cx: &LateContext<'_>, src: &Expr<'_>, dest: &Expr<'
                     ),
                     applicability,
                 );
-            } else if cx.tcx.is_diagnostic_item(sym::mem_zeroed, repl_def_id) &&
-                    !cx.typeck_results().expr_ty(src).is_primitive() {
-                span_lint_and_help(
-                    cx,
-                    MEM_REPLACE_WITH_UNINIT,
-                    expr_span,
-                    "replacing with `mem::zeroed()`",
-                    None,
-                    "consider using a default value or the `take_mut` crate instead",
-                );
+            } else if cx.tcx.is_diagnostic_item(sym::mem_zeroed, repl_def_id) {
+                let ty = cx.typeck_results().expr_ty(src);
+                if is_zero_invalid(cx, ty) {
+                    span_lint_and_then(
+                        cx,
+                        MEM_REPLACE_WITH_ZEROED,
+                        expr_span,
+                        "replacing with `mem::zeroed()`",
+                        |diag| {
+                            diag.note(&format!("`{}` has no valid all-zero value", ty));
+                            diag.help("consider using a default value or the `take_mut` crate instead");
+                        },
+                    );
+                }
             }
         }
     }
 }
 
+/// Returns `true` if the all-zero bit pattern is known to be invalid for `ty`. Types whose layout
+/// is not known in advance, like type parameters and enums with fields, are assumed to be valid.
+fn is_zero_invalid<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+    match ty.kind() {
+        ty::Ref(..) | ty::FnPtr(..) | ty::Never => true,
+        ty::Adt(..) if ty.is_box() => true,
+        ty::Adt(adt, substs) if !adt.is_union() => {
+            // `NonNull`, `NonZeroU32`, ..
+            if let (Bound::Included(start), _) = cx.tcx.layout_scalar_valid_range(adt.did) {
+                if start > 0 {
+                    return true;
+                }
+            }
+            if adt.is_enum() {
+                adt.variants.iter().all(|variant| variant.fields.is_empty())
+                    && !adt.discriminants(cx.tcx).any(|(_, discr)| discr.val == 0)
+            } else {
+                adt.all_fields()
+                    .any(|field| is_zero_invalid(cx, field.ty(cx.tcx, substs)))
+            }
+        },
+        ty::Tuple(..) => ty.tuple_fields().any(|field| is_zero_invalid(cx, field)),
+        ty::Array(elem, len) => {
+            len.try_eval_usize(cx.tcx, cx.param_env).map_or(false, |len| len > 0) && is_zero_invalid(cx, elem)
+        },
+        _ => false,
+    }
+}
+
 fn check_replace_with_default(cx: &LateContext<'_>, src: &Expr<'_>, dest: &Expr<'_>, expr_span: Span) {
     if let ExprKind::Call(ref repl_func, _) = src.kind {
         if_chain! {
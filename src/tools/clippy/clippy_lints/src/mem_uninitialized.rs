@@ -0,0 +1,75 @@
+use crate::utils::{get_parent_expr, match_def_path, paths, span_lint_and_help};
+use if_chain::if_chain;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::symbol::sym;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `std::mem::uninitialized()`.
+    ///
+    /// **Why is this bad?** The function is deprecated, because it is undefined
+    /// behavior for almost all types: the returned value is considered initialized
+    /// by the compiler, even though it contains arbitrary bits.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # #![allow(deprecated, invalid_value)]
+    /// let mut buf: [u8; 16] = unsafe { std::mem::uninitialized() };
+    /// # buf[0] = 1;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use std::mem::MaybeUninit;
+    /// let mut buf = MaybeUninit::<[u8; 16]>::uninit();
+    /// unsafe { buf.as_mut_ptr().write([0; 16]) };
+    /// let buf = unsafe { buf.assume_init() };
+    /// ```
+    pub MEM_UNINITIALIZED,
+    correctness,
+    "use of `mem::uninitialized()`, which is undefined behavior for almost all types"
+}
+
+declare_lint_pass!(MemUninitialized => [MEM_UNINITIALIZED]);
+
+impl<'tcx> LateLintPass<'tcx> for MemUninitialized {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if let ExprKind::Call(ref func, ref args) = expr.kind;
+            if args.is_empty();
+            if let ExprKind::Path(ref qpath) = func.kind;
+            if let Some(def_id) = cx.qpath_res(qpath, func.hir_id).opt_def_id();
+            if cx.tcx.is_diagnostic_item(sym::mem_uninitialized, def_id);
+            if !in_external_macro(cx.tcx.sess, expr.span);
+            // `mem::replace(_, mem::uninitialized())` is reported by `mem_replace_with_uninit`
+            if !is_replace_arg(cx, expr);
+            then {
+                span_lint_and_help(
+                    cx,
+                    MEM_UNINITIALIZED,
+                    expr.span,
+                    "use of `mem::uninitialized()`",
+                    None,
+                    "use `MaybeUninit::<T>::uninit()` and initialize the value before calling `assume_init()`",
+                );
+            }
+        }
+    }
+}
+
+fn is_replace_arg(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    if_chain! {
+        if let Some(parent) = get_parent_expr(cx, expr);
+        if let ExprKind::Call(ref func, _) = parent.kind;
+        if let ExprKind::Path(ref qpath) = func.kind;
+        if let Some(def_id) = cx.qpath_res(qpath, func.hir_id).opt_def_id();
+        then {
+            match_def_path(cx, def_id, &paths::MEM_REPLACE)
+        } else {
+            false
+        }
+    }
+}
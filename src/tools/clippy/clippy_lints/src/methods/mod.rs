@@ -20,15 +20,16 @@ use rustc_lint::{LateContext, LateLintPass, Lint, LintContext};
 use rustc_middle::hir::map::Map;
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty::{self, TraitRef, Ty, TyS};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::source_map::Span;
 use rustc_span::symbol::{sym, SymbolStr};
 
-use crate::consts::{constant, Constant};
+use crate::consts::{constant, constant_simple, Constant};
+use crate::utils::msrvs::{self, meets_msrv, update_msrv_from_attrs, RustcVersion};
 use crate::utils::usage::mutated_variables;
 use crate::utils::{
-    contains_ty, get_arg_name, get_parent_expr, get_trait_def_id, has_iter_method, higher, implements_trait, in_macro,
-    is_copy, is_ctor_or_promotable_const_function, is_expn_of, is_type_diagnostic_item, iter_input_pats,
+    constants, contains_ty, get_arg_name, get_parent_expr, get_trait_def_id, has_iter_method, higher, implements_trait,
+    in_macro, is_copy, is_ctor_or_promotable_const_function, is_expn_of, is_type_diagnostic_item, iter_input_pats,
     last_path_segment, match_def_path, match_qpath, match_trait_method, match_type, match_var, method_calls,
     method_chain_args, paths, remove_blocks, return_ty, single_segment_path, snippet, snippet_with_applicability,
     snippet_with_macro_callsite, span_lint, span_lint_and_help, span_lint_and_note, span_lint_and_sugg,
@@ -692,6 +693,36 @@ declare_clippy_lint! {
     "using `clone` on `&&T`"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `.clone()` on a lazy iterator adapter, like the
+    /// result of `map` or `filter`.
+    ///
+    /// **Why is this bad?** Iterator adapters are lazy, cloning one clones the state of the
+    /// adapter (the underlying iterator and closures), not the elements it yields. Iterating over
+    /// the clone runs the whole chain again, which is often not what was intended.
+    ///
+    /// **Known problems:** Cloning an adapter is sometimes exactly what is wanted, e.g. to look
+    /// ahead without consuming the original.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let v = vec![1, 2, 3];
+    /// let doubled = v.iter().map(|x| x * 2);
+    /// let sum: i32 = doubled.clone().sum();
+    /// let max = doubled.max();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let v = vec![1, 2, 3];
+    /// let doubled: Vec<i32> = v.iter().map(|x| x * 2).collect();
+    /// let sum: i32 = doubled.iter().sum();
+    /// let max = doubled.iter().max();
+    /// ```
+    pub CLONE_ON_ITERATOR_ADAPTER,
+    pedantic,
+    "using `clone` on a lazy iterator adapter"
+}
+
 declare_clippy_lint! {
     /// **What it does:** Checks for usage of `.to_string()` on an `&&T` where
     /// `T` implements `ToString` directly (like `&&str` or `&&String`).
@@ -778,12 +809,12 @@ declare_clippy_lint! {
 
 declare_clippy_lint! {
     /// **What it does:** Checks for string methods that receive a single-character
-    /// `str` as an argument, e.g., `_.split("x")`.
+    /// `str`, or a constant holding one, as an argument, e.g., `_.split("x")`.
     ///
     /// **Why is this bad?** Performing these methods using a `char` is faster than
     /// using a `str`.
     ///
-    /// **Known problems:** Does not catch multi-byte unicode characters.
+    /// **Known problems:** None.
     ///
     /// **Example:**
     /// ```rust,ignore
@@ -1324,8 +1355,8 @@ declare_clippy_lint! {
 }
 
 declare_clippy_lint! {
-    /// **What it does:** Warns when using `push_str` with a single-character string literal,
-    /// and `push` with a `char` would work fine.
+    /// **What it does:** Warns when using `push_str` with a single-character string literal or
+    /// with `&c.to_string()` for a `char` `c`, and `push` with a `char` would work fine.
     ///
     /// **Why is this bad?** It's less clear that we are pushing a single character.
     ///
@@ -1335,17 +1366,42 @@ declare_clippy_lint! {
     /// ```rust
     /// let mut string = String::new();
     /// string.push_str("R");
+    /// string.push_str(&'S'.to_string());
     /// ```
     /// Could be written as
     /// ```rust
     /// let mut string = String::new();
     /// string.push('R');
+    /// string.push('S');
     /// ```
     pub SINGLE_CHAR_PUSH_STR,
     style,
     "`push_str()` used with a single-character string literal as parameter"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Warns when appending a single-character string literal to a `String`
+    /// with `+=`.
+    ///
+    /// **Why is this bad?** It's less clear that we are pushing a single character.
+    ///
+    /// **Known problems:** None
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let mut string = String::new();
+    /// string += "R";
+    /// ```
+    /// Could be written as
+    /// ```rust
+    /// let mut string = String::new();
+    /// string.push('R');
+    /// ```
+    pub SINGLE_CHAR_ADD_STR,
+    pedantic,
+    "`+=` used to append a single-character string literal to a `String`"
+}
+
 declare_clippy_lint! {
     /// **What it does:** As the counterpart to `or_fun_call`, this lint looks for unnecessary
     /// lazily evaluated closures on `Option` and `Result`.
@@ -1382,7 +1438,18 @@ declare_clippy_lint! {
     "using unnecessary lazy evaluation, which can be replaced with simpler eager evaluation"
 }
 
-declare_lint_pass!(Methods => [
+pub struct Methods {
+    msrv: Option<RustcVersion>,
+}
+
+impl Methods {
+    #[must_use]
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(Methods => [
     UNWRAP_USED,
     EXPECT_USED,
     SHOULD_IMPLEMENT_TRAIT,
@@ -1400,10 +1467,12 @@ declare_lint_pass!(Methods => [
     CLONE_ON_COPY,
     CLONE_ON_REF_PTR,
     CLONE_DOUBLE_REF,
+    CLONE_ON_ITERATOR_ADAPTER,
     INEFFICIENT_TO_STRING,
     NEW_RET_NO_SELF,
     SINGLE_CHAR_PATTERN,
     SINGLE_CHAR_PUSH_STR,
+    SINGLE_CHAR_ADD_STR,
     SEARCH_IS_SOME,
     TEMPORARY_CSTRING_AS_PTR,
     FILTER_NEXT,
@@ -1435,6 +1504,10 @@ declare_lint_pass!(Methods => [
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Methods {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>, krate: &'tcx hir::Crate<'_>) {
+        update_msrv_from_attrs(&mut self.msrv, cx.sess(), &krate.item.attrs);
+    }
+
     #[allow(clippy::too_many_lines)]
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
         if in_macro(expr.span) {
@@ -1506,8 +1579,8 @@ impl<'tcx> LateLintPass<'tcx> for Methods {
                 check_pointer_offset(cx, expr, arg_lists[0])
             },
             ["is_file", ..] => lint_filetype_is_file(cx, expr, arg_lists[0]),
-            ["map", "as_ref"] => lint_option_as_ref_deref(cx, expr, arg_lists[1], arg_lists[0], false),
-            ["map", "as_mut"] => lint_option_as_ref_deref(cx, expr, arg_lists[1], arg_lists[0], true),
+            ["map", "as_ref"] => lint_option_as_ref_deref(cx, expr, arg_lists[1], arg_lists[0], false, self.msrv),
+            ["map", "as_mut"] => lint_option_as_ref_deref(cx, expr, arg_lists[1], arg_lists[0], true, self.msrv),
             ["unwrap_or_else", ..] => unnecessary_lazy_eval::lint(cx, expr, arg_lists[0], true, "unwrap_or"),
             ["get_or_insert_with", ..] => unnecessary_lazy_eval::lint(cx, expr, arg_lists[0], true, "get_or_insert"),
             ["ok_or_else", ..] => unnecessary_lazy_eval::lint(cx, expr, arg_lists[0], true, "ok_or"),
@@ -1523,6 +1596,7 @@ impl<'tcx> LateLintPass<'tcx> for Methods {
                 if args.len() == 1 && method_call.ident.name == sym!(clone) {
                     lint_clone_on_copy(cx, expr, &args[0], self_ty);
                     lint_clone_on_ref_ptr(cx, expr, &args[0]);
+                    lint_clone_on_iterator_adapter(cx, expr);
                 }
                 if args.len() == 1 && method_call.ident.name == sym!(to_string) {
                     inefficient_to_string::lint(cx, expr, &args[0], self_ty);
@@ -1536,7 +1610,7 @@ impl<'tcx> LateLintPass<'tcx> for Methods {
 
                 match self_ty.kind() {
                     ty::Ref(_, ty, _) if *ty.kind() == ty::Str => {
-                        for &(method, pos) in &PATTERN_METHODS {
+                        for &(method, pos) in constants::STR_PATTERN_METHODS {
                             if method_call.ident.name.as_str() == method && args.len() > pos {
                                 lint_single_char_pattern(cx, expr, &args[pos]);
                             }
@@ -1559,6 +1633,9 @@ impl<'tcx> LateLintPass<'tcx> for Methods {
                 };
                 lint_binary_expr_with_method_call(cx, &mut info);
             }
+            hir::ExprKind::AssignOp(op, ref lhs, ref rhs) if op.node == hir::BinOpKind::Add => {
+                lint_single_char_add_str(cx, expr, lhs, rhs);
+            },
             _ => (),
         }
     }
@@ -2197,6 +2274,31 @@ fn lint_clone_on_ref_ptr(cx: &LateContext<'_>, expr: &hir::Expr<'_>, arg: &hir::
     }
 }
 
+fn lint_clone_on_iterator_adapter(cx: &LateContext<'_>, expr: &hir::Expr<'_>) {
+    let ty = cx.typeck_results().expr_ty(expr);
+    if_chain! {
+        if !expr.span.from_expansion();
+        if match_trait_method(cx, expr, &paths::CLONE_TRAIT);
+        // The adapters of `core::iter`, like `Map`, `Filter` or `Chain`, but not sources like `Repeat`
+        if let ty::Adt(def, _) = ty.kind();
+        if cx.get_def_path(def.did).starts_with(&[sym::core, sym::iter, sym!(adapters)]);
+        if !is_copy(cx, ty);
+        if let Some(iter_id) = get_trait_def_id(cx, &paths::ITERATOR);
+        if implements_trait(cx, ty, iter_id, &[]);
+        then {
+            span_lint_and_help(
+                cx,
+                CLONE_ON_ITERATOR_ADAPTER,
+                expr.span,
+                "using `clone` on a lazy iterator adapter",
+                None,
+                "this clones the state of the adapter, not its elements; consider collecting the elements \
+                 if they are needed more than once",
+            );
+        }
+    }
+}
+
 fn lint_string_extend(cx: &LateContext<'_>, expr: &hir::Expr<'_>, args: &[hir::Expr<'_>]) {
     let arg = &args[1];
     if let Some(arglists) = method_chain_args(arg, &["chars"]) {
@@ -3252,6 +3354,11 @@ fn lint_chars_last_cmp_with_unwrap<'tcx>(cx: &LateContext<'tcx>, info: &BinaryEx
     }
 }
 
+/// Returns the `char` literal for the string literal `arg` if it contains exactly one `char`.
+///
+/// Escape sequences of regular string literals are kept as written, any other `char` which needs
+/// to be escaped in a `char` literal (like a quote or a backslash in a raw string literal) is
+/// escaped.
 fn get_hint_if_single_char_arg(
     cx: &LateContext<'_>,
     arg: &hir::Expr<'_>,
@@ -3261,18 +3368,15 @@ fn get_hint_if_single_char_arg(
         if let hir::ExprKind::Lit(lit) = &arg.kind;
         if let ast::LitKind::Str(r, style) = lit.node;
         let string = r.as_str();
-        if string.len() == 1;
+        let mut chars = string.chars();
+        if let (Some(ch), None) = (chars.next(), chars.next());
         then {
             let snip = snippet_with_applicability(cx, arg.span, &string, applicability);
-            let ch = if let ast::StrStyle::Raw(nhash) = style {
-                let nhash = nhash as usize;
-                // for raw string: r##"a"##
-                &snip[(nhash + 2)..(snip.len() - 1 - nhash)]
-            } else {
-                // for regular string: "a"
-                &snip[1..(snip.len() - 1)]
+            let hint = match style {
+                // `"\x52"` becomes `'\x52'`
+                ast::StrStyle::Cooked if snip.starts_with("\"\\") => format!("'{}'", &snip[1..snip.len() - 1]),
+                _ => format!("'{}'", ch.escape_debug()),
             };
-            let hint = format!("'{}'", if ch == "'" { "\\'" } else { ch });
             Some(hint)
         } else {
             None
@@ -3280,10 +3384,35 @@ fn get_hint_if_single_char_arg(
     }
 }
 
-/// lint for length-1 `str`s for methods in `PATTERN_METHODS`
+/// Returns the `char` literal for a path to a `const` string of exactly one `char`, or for a
+/// reference to such a path.
+fn get_hint_if_single_char_const(cx: &LateContext<'_>, arg: &hir::Expr<'_>) -> Option<String> {
+    let arg = match arg.kind {
+        hir::ExprKind::AddrOf(hir::BorrowKind::Ref, _, ref inner) => inner,
+        _ => arg,
+    };
+    if_chain! {
+        if !in_macro(arg.span);
+        if let hir::ExprKind::Path(ref qpath) = arg.kind;
+        if let hir::def::Res::Def(hir::def::DefKind::Const | hir::def::DefKind::AssocConst, _) =
+            cx.qpath_res(qpath, arg.hir_id);
+        if let Some(Constant::Str(string)) = constant_simple(cx, cx.typeck_results(), arg);
+        let mut chars = string.chars();
+        if let (Some(ch), None) = (chars.next(), chars.next());
+        then {
+            Some(format!("'{}'", ch.escape_debug()))
+        } else {
+            None
+        }
+    }
+}
+
+/// lint for length-1 `str`s, or constants thereof, for methods in `STR_PATTERN_METHODS`
 fn lint_single_char_pattern(cx: &LateContext<'_>, _expr: &hir::Expr<'_>, arg: &hir::Expr<'_>) {
     let mut applicability = Applicability::MachineApplicable;
-    if let Some(hint) = get_hint_if_single_char_arg(cx, arg, &mut applicability) {
+    if let Some(hint) =
+        get_hint_if_single_char_arg(cx, arg, &mut applicability).or_else(|| get_hint_if_single_char_const(cx, arg))
+    {
         span_lint_and_sugg(
             cx,
             SINGLE_CHAR_PATTERN,
@@ -3296,17 +3425,59 @@ fn lint_single_char_pattern(cx: &LateContext<'_>, _expr: &hir::Expr<'_>, arg: &h
     }
 }
 
-/// lint for length-1 `str`s as argument for `push_str`
+/// lint for length-1 `str`s and `&c.to_string()` as argument for `push_str`
 fn lint_single_char_push_string(cx: &LateContext<'_>, expr: &hir::Expr<'_>, args: &[hir::Expr<'_>]) {
     let mut applicability = Applicability::MachineApplicable;
-    if let Some(extension_string) = get_hint_if_single_char_arg(cx, &args[1], &mut applicability) {
-        let base_string_snippet = snippet_with_applicability(cx, args[0].span, "_", &mut applicability);
-        let sugg = format!("{}.push({})", base_string_snippet, extension_string);
+    let (extension_string, msg, help) =
+        if let Some(hint) = get_hint_if_single_char_arg(cx, &args[1], &mut applicability) {
+            (
+                hint,
+                "calling `push_str()` using a single-character string literal",
+                "consider using `push` with a character literal",
+            )
+        } else if let Some(ch) = char_to_string_arg(cx, &args[1]) {
+            (
+                snippet_with_applicability(cx, ch.span, "_", &mut applicability).into_owned(),
+                "calling `push_str()` using a `char` converted to a string",
+                "consider using `push` with the `char`",
+            )
+        } else {
+            return;
+        };
+    let base_string_snippet = snippet_with_applicability(cx, args[0].span, "_", &mut applicability);
+    let sugg = format!("{}.push({})", base_string_snippet, extension_string);
+    span_lint_and_sugg(cx, SINGLE_CHAR_PUSH_STR, expr.span, msg, help, sugg, applicability);
+}
+
+/// Returns `c` if `arg` is `&c.to_string()` for a `char` `c`.
+fn char_to_string_arg<'tcx>(cx: &LateContext<'_>, arg: &'tcx hir::Expr<'tcx>) -> Option<&'tcx hir::Expr<'tcx>> {
+    if_chain! {
+        if let hir::ExprKind::AddrOf(hir::BorrowKind::Ref, _, ref inner) = arg.kind;
+        if let hir::ExprKind::MethodCall(ref path, _, ref args, _) = inner.kind;
+        if path.ident.name == sym!(to_string) && args.len() == 1;
+        if *cx.typeck_results().expr_ty(&args[0]).kind() == ty::Char;
+        then {
+            Some(&args[0])
+        } else {
+            None
+        }
+    }
+}
+
+/// lint for length-1 `str`s appended to a `String` with `+=`
+fn lint_single_char_add_str(cx: &LateContext<'_>, expr: &hir::Expr<'_>, lhs: &hir::Expr<'_>, rhs: &hir::Expr<'_>) {
+    if !is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(lhs), sym!(string_type)) {
+        return;
+    }
+    let mut applicability = Applicability::MachineApplicable;
+    if let Some(extension_string) = get_hint_if_single_char_arg(cx, rhs, &mut applicability) {
+        let base_string = sugg::Sugg::hir_with_applicability(cx, lhs, "_", &mut applicability).maybe_par();
+        let sugg = format!("{}.push({})", base_string, extension_string);
         span_lint_and_sugg(
             cx,
-            SINGLE_CHAR_PUSH_STR,
+            SINGLE_CHAR_ADD_STR,
             expr.span,
-            "calling `push_str()` using a single-character string literal",
+            "appending a single-character string literal with `+=`",
             "consider using `push` with a character literal",
             sugg,
             applicability,
@@ -3430,7 +3601,12 @@ fn lint_option_as_ref_deref<'tcx>(
     as_ref_args: &[hir::Expr<'_>],
     map_args: &[hir::Expr<'_>],
     is_mut: bool,
+    msrv: Option<RustcVersion>,
 ) {
+    if !meets_msrv(msrv, msrvs::OPTION_AS_DEREF) {
+        return;
+    }
+
     let same_mutability = |m| (is_mut && m == &hir::Mutability::Mut) || (!is_mut && m == &hir::Mutability::Not);
 
     let option_ty = cx.typeck_results().expr_ty(&as_ref_args[0]);
@@ -3648,27 +3824,6 @@ const TRAIT_METHODS: [ShouldImplTraitCase; 30] = [
     ShouldImplTraitCase::new("std::ops::Sub", "sub",  2,  FN_HEADER,  SelfKind::Value,  OutType::Any, true),
 ];
 
-#[rustfmt::skip]
-const PATTERN_METHODS: [(&str, usize); 17] = [
-    ("contains", 1),
-    ("starts_with", 1),
-    ("ends_with", 1),
-    ("find", 1),
-    ("rfind", 1),
-    ("split", 1),
-    ("rsplit", 1),
-    ("split_terminator", 1),
-    ("rsplit_terminator", 1),
-    ("splitn", 2),
-    ("rsplitn", 2),
-    ("matches", 1),
-    ("rmatches", 1),
-    ("match_indices", 1),
-    ("rmatch_indices", 1),
-    ("trim_start_matches", 1),
-    ("trim_end_matches", 1),
-];
-
 #[derive(Clone, Copy, PartialEq, Debug)]
 enum SelfKind {
     Value,
@@ -74,7 +74,7 @@ pub(super) fn lint<'tcx>(
                     map_span,
                     String::from(if unwrap_snippet_none { "and_then" } else { "map_or" }),
                 ),
-                (expr.span.with_lo(unwrap_args[0].span.hi()), String::from("")),
+                (expr.span.with_lo(unwrap_args[0].span.hi()), String::new()),
             ];
 
             if !unwrap_snippet_none {
@@ -1,12 +1,12 @@
 use if_chain::if_chain;
-use rustc_ast::ast::LitKind;
+use rustc_ast::ast::{FloatTy, LitKind};
 use rustc_errors::Applicability;
 use rustc_hir::intravisit::FnKind;
 use rustc_hir::{
-    self as hir, def, BinOpKind, BindingAnnotation, Body, Expr, ExprKind, FnDecl, HirId, Mutability, PatKind, Stmt,
-    StmtKind, TyKind, UnOp,
+    self as hir, def, BinOpKind, BindingAnnotation, Body, BorrowKind, Expr, ExprKind, FnDecl, HirId, Mutability,
+    PatKind, Stmt, StmtKind, TyKind, UnOp,
 };
-use rustc_lint::{LateContext, LateLintPass};
+use rustc_lint::{LateContext, LateLintPass, Lint};
 use rustc_middle::ty::{self, Ty};
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 use rustc_span::hygiene::DesugaringKind;
@@ -79,8 +79,12 @@ declare_clippy_lint! {
 
 declare_clippy_lint! {
     /// **What it does:** Checks for (in-)equality comparisons on floating-point
-    /// values (apart from zero), except in functions called `*eq*` (which probably
-    /// implement equality for a type involving floats).
+    /// values, arrays and tuples of them, and for `contains` on slices of them
+    /// (apart from zero, infinity, `1.0` and `-1.0`), except in functions called
+    /// `*eq*` (which probably implement equality for a type involving floats).
+    ///
+    /// Comparisons with named constants are left to `float_cmp_const`, unless the
+    /// value of the constant is not exactly representable (like `0.1`).
     ///
     /// **Why is this bad?** Floating point calculations are usually imprecise, so
     /// asking if two values are *exactly* equal is asking for trouble. For a good
@@ -200,7 +204,8 @@ declare_clippy_lint! {
 }
 
 declare_clippy_lint! {
-    /// **What it does:** Catch casts from `0` to some pointer type
+    /// **What it does:** Catch casts from `0` to some pointer type, also through
+    /// intermediate integer casts like `0 as usize as *const T`.
     ///
     /// **Why is this bad?** This generally means `null` and is better expressed as
     /// {`std`, `core`}`::ptr::`{`null`, `null_mut`}.
@@ -224,7 +229,9 @@ declare_clippy_lint! {
 declare_clippy_lint! {
     /// **What it does:** Checks for (in-)equality comparisons on floating-point
     /// value and constant, except in functions called `*eq*` (which probably
-    /// implement equality for a type involving floats).
+    /// implement equality for a type involving floats). This is stricter than
+    /// `float_cmp`, it also lints comparisons with constants which are exactly
+    /// representable, like `1.0` or `0.5`.
     ///
     /// **Why is this bad?** Floating point calculations are usually imprecise, so
     /// asking if two values are *exactly* equal is asking for trouble. For a good
@@ -379,53 +386,14 @@ impl<'tcx> LateLintPass<'tcx> for MiscLints {
                     check_to_owned(cx, right, left, false);
                 }
                 if (op == BinOpKind::Eq || op == BinOpKind::Ne) && (is_float(cx, left) || is_float(cx, right)) {
-                    if is_allowed(cx, left) || is_allowed(cx, right) {
-                        return;
-                    }
-
-                    // Allow comparing the results of signum()
-                    if is_signum(cx, left) && is_signum(cx, right) {
-                        return;
-                    }
-
-                    if let Some(name) = get_item_name(cx, expr) {
-                        let name = name.as_str();
-                        if name == "eq"
-                            || name == "ne"
-                            || name == "is_nan"
-                            || name.starts_with("eq_")
-                            || name.ends_with("_eq")
-                        {
-                            return;
-                        }
-                    }
-                    let is_comparing_arrays = is_array(cx, left) || is_array(cx, right);
-                    let (lint, msg) = get_lint_and_message(
-                        is_named_constant(cx, left) || is_named_constant(cx, right),
-                        is_comparing_arrays,
-                    );
-                    span_lint_and_then(cx, lint, expr.span, msg, |diag| {
-                        let lhs = Sugg::hir(cx, left, "..");
-                        let rhs = Sugg::hir(cx, right, "..");
-
-                        if !is_comparing_arrays {
-                            diag.span_suggestion(
-                                expr.span,
-                                "consider comparing them within some error",
-                                format!(
-                                    "({}).abs() {} error",
-                                    lhs - rhs,
-                                    if op == BinOpKind::Eq { '<' } else { '>' }
-                                ),
-                                Applicability::HasPlaceholders, // snippet
-                            );
-                        }
-                        diag.note("`f32::EPSILON` and `f64::EPSILON` are available for the `error`");
-                    });
+                    check_float_cmp(cx, expr, op, left, right);
                 } else if op == BinOpKind::Rem && is_integer_const(cx, right, 1) {
                     span_lint(cx, MODULO_ONE, expr.span, "any number modulo 1 will be 0");
                 }
             },
+            ExprKind::MethodCall(ref path, _, ref args, _) if path.ident.name == sym!(contains) && args.len() == 2 => {
+                check_float_contains(cx, expr, &args[0], &args[1]);
+            },
             _ => {},
         }
         if in_attributes_expansion(expr) || expr.span.is_desugaring(DesugaringKind::Await) {
@@ -472,28 +440,200 @@ impl<'tcx> LateLintPass<'tcx> for MiscLints {
     }
 }
 
-fn get_lint_and_message(
-    is_comparing_constants: bool,
-    is_comparing_arrays: bool,
-) -> (&'static rustc_lint::Lint, &'static str) {
-    if is_comparing_constants {
-        (
-            FLOAT_CMP_CONST,
-            if is_comparing_arrays {
-                "strict comparison of `f32` or `f64` constant arrays"
-            } else {
-                "strict comparison of `f32` or `f64` constant"
-            },
-        )
+/// What is compared by a strict float comparison.
+#[derive(Clone, Copy, PartialEq)]
+enum FloatCmpKind {
+    Value,
+    Array,
+    Tuple,
+    /// The needle of `contains` with the elements of a slice
+    Contains,
+}
+
+impl FloatCmpKind {
+    fn of(cx: &LateContext<'_>, left: &Expr<'_>, right: &Expr<'_>) -> Self {
+        let kinds = [left, right]
+            .iter()
+            .map(|e| walk_ptrs_ty(cx.typeck_results().expr_ty(e)).kind());
+        kinds.fold(Self::Value, |kind, ty_kind| match ty_kind {
+            ty::Array(..) | ty::Slice(..) => Self::Array,
+            ty::Tuple(..) => Self::Tuple,
+            _ => kind,
+        })
+    }
+
+    fn message(self, is_comparing_constants: bool) -> &'static str {
+        match (self, is_comparing_constants) {
+            (Self::Value, false) => "strict comparison of `f32` or `f64`",
+            (Self::Array, false) => "strict comparison of `f32` or `f64` arrays",
+            (Self::Tuple, false) => "strict comparison of `f32` or `f64` tuples",
+            (Self::Contains, false) => "strict comparison of `f32` or `f64` in `contains`",
+            (Self::Value, true) => "strict comparison of `f32` or `f64` constant",
+            (Self::Array, true) => "strict comparison of `f32` or `f64` constant arrays",
+            (Self::Tuple, true) => "strict comparison of `f32` or `f64` constant tuples",
+            (Self::Contains, true) => "strict comparison of `f32` or `f64` constant in `contains`",
+        }
+    }
+}
+
+/// Returns the lint for a strict comparison of `exprs`, and whether it compares named constants,
+/// or `None` if the comparison is fine.
+///
+/// Comparisons with zero or infinity are always fine. Comparisons with named constants are linted
+/// by `FLOAT_CMP_CONST`, unless the value of a constant is not exactly representable. Otherwise
+/// comparisons with `1.0` and `-1.0` are fine.
+fn float_cmp_lint(cx: &LateContext<'_>, exprs: &[&Expr<'_>]) -> Option<(&'static Lint, bool)> {
+    let constants: Vec<_> = exprs
+        .iter()
+        .map(|expr| constant(cx, cx.typeck_results(), expr))
+        .collect();
+    if constants
+        .iter()
+        .flatten()
+        .any(|(c, _)| all_floats(c, |f| f == 0.0 || f.is_infinite()))
+    {
+        return None;
+    }
+    let mut named = constants.iter().flatten().filter(|(_, named)| *named).peekable();
+    if named.peek().is_some() {
+        if named.all(|(c, _)| all_floats(c, is_exactly_representable)) {
+            Some((FLOAT_CMP_CONST, true))
+        } else {
+            Some((FLOAT_CMP, true))
+        }
+    } else if constants
+        .iter()
+        .flatten()
+        .any(|(c, _)| all_floats(c, |f| f.abs() == 1.0))
+    {
+        None
     } else {
-        (
-            FLOAT_CMP,
-            if is_comparing_arrays {
-                "strict comparison of `f32` or `f64` arrays"
-            } else {
-                "strict comparison of `f32` or `f64`"
-            },
-        )
+        Some((FLOAT_CMP, false))
+    }
+}
+
+/// Returns `true` if `c` is a float, or an array or tuple of floats, for which `pred` holds.
+fn all_floats(c: &Constant, pred: impl Fn(f64) -> bool + Copy) -> bool {
+    match c {
+        Constant::F32(f) => pred(f64::from(*f)),
+        Constant::F64(f) => pred(*f),
+        Constant::Repeat(c, _) => all_floats(c, pred),
+        Constant::Vec(cs) | Constant::Tuple(cs) => cs.iter().all(|c| all_floats(c, pred)),
+        _ => false,
+    }
+}
+
+/// Returns `true` if `f` is a binary fraction with few fractional digits, like `0.5` or `2.25`,
+/// which is exactly represented by its literal, unlike `0.1`.
+fn is_exactly_representable(f: f64) -> bool {
+    (f * 65536.0).fract() == 0.0
+}
+
+/// Returns `true` if the comparison is inside a function implementing equality.
+fn in_eq_fn(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    get_item_name(cx, expr).map_or(false, |name| {
+        let name = name.as_str();
+        name == "eq" || name == "ne" || name == "is_nan" || name.starts_with("eq_") || name.ends_with("_eq")
+    })
+}
+
+/// Returns the name of the float type of `ty`, or of its elements for arrays, slices and tuples.
+fn float_ty_name(ty: Ty<'_>) -> Option<&'static str> {
+    match walk_ptrs_ty(ty).kind() {
+        ty::Float(FloatTy::F32) => Some("f32"),
+        ty::Float(FloatTy::F64) => Some("f64"),
+        ty::Array(elem_ty, _) | ty::Slice(elem_ty) => float_ty_name(elem_ty),
+        ty::Tuple(..) => walk_ptrs_ty(ty).tuple_fields().find_map(float_ty_name),
+        _ => None,
+    }
+}
+
+fn epsilon_note(float_ty: &str) -> String {
+    format!(
+        "`{}::EPSILON` is only a sensible error for values close to `1.0`, scale it for other values",
+        float_ty
+    )
+}
+
+fn check_float_cmp<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    op: BinOpKind,
+    left: &'tcx Expr<'_>,
+    right: &'tcx Expr<'_>,
+) {
+    // Allow comparing the results of signum()
+    if is_signum(cx, left) && is_signum(cx, right) || in_eq_fn(cx, expr) {
+        return;
+    }
+    let (lint, is_comparing_constants) = match float_cmp_lint(cx, &[left, right]) {
+        Some(lint) => lint,
+        None => return,
+    };
+    let kind = FloatCmpKind::of(cx, left, right);
+    let float_ty = float_ty_name(cx.typeck_results().expr_ty(left))
+        .or_else(|| float_ty_name(cx.typeck_results().expr_ty(right)))
+        .unwrap_or("f64");
+    span_lint_and_then(cx, lint, expr.span, kind.message(is_comparing_constants), |diag| {
+        if kind == FloatCmpKind::Value {
+            let lhs = Sugg::hir(cx, left, "..");
+            let rhs = Sugg::hir(cx, right, "..");
+            diag.span_suggestion(
+                expr.span,
+                "consider comparing them within some error",
+                format!(
+                    "({}).abs() {} {}::EPSILON",
+                    lhs - rhs,
+                    if op == BinOpKind::Eq { '<' } else { '>' },
+                    float_ty
+                ),
+                Applicability::MaybeIncorrect,
+            );
+        } else {
+            diag.help(&format!(
+                "consider comparing the elements within some error, like `(a - b).abs() < {}::EPSILON`",
+                float_ty
+            ));
+        }
+        diag.note(&epsilon_note(float_ty));
+    });
+}
+
+/// Checks for `slice.contains(&x)` where the elements are floats.
+fn check_float_contains<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, recv: &Expr<'_>, needle: &Expr<'_>) {
+    let needle = match needle.kind {
+        ExprKind::AddrOf(BorrowKind::Ref, _, ref inner) => inner,
+        _ => needle,
+    };
+    if_chain! {
+        if let ty::Ref(_, recv_ty, _) = cx.typeck_results().expr_ty_adjusted(recv).kind();
+        if let ty::Slice(elem_ty) | ty::Array(elem_ty, _) = recv_ty.kind();
+        if let ty::Float(_) = elem_ty.kind();
+        if !in_eq_fn(cx, expr);
+        if let Some((lint, is_comparing_constants)) = float_cmp_lint(cx, &[needle]);
+        then {
+            let float_ty = float_ty_name(elem_ty).unwrap_or("f64");
+            span_lint_and_then(
+                cx,
+                lint,
+                expr.span,
+                FloatCmpKind::Contains.message(is_comparing_constants),
+                |diag| {
+                    diag.span_suggestion(
+                        expr.span,
+                        "consider comparing the elements within some error",
+                        format!(
+                            "{}.iter().any(|v| ({}).abs() < {}::EPSILON)",
+                            Sugg::hir(cx, recv, "..").maybe_par(),
+                            Sugg::NonParen("v".into()) - Sugg::hir(cx, needle, ".."),
+                            float_ty
+                        ),
+                        Applicability::MaybeIncorrect,
+                    );
+                    diag.note(&epsilon_note(float_ty));
+                },
+            );
+        }
     }
 }
 
@@ -520,27 +660,6 @@ fn check_nan(cx: &LateContext<'_>, expr: &Expr<'_>, cmp_expr: &Expr<'_>) {
     }
 }
 
-fn is_named_constant<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) -> bool {
-    if let Some((_, res)) = constant(cx, cx.typeck_results(), expr) {
-        res
-    } else {
-        false
-    }
-}
-
-fn is_allowed<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) -> bool {
-    match constant(cx, cx.typeck_results(), expr) {
-        Some((Constant::F32(f), _)) => f == 0.0 || f.is_infinite(),
-        Some((Constant::F64(f), _)) => f == 0.0 || f.is_infinite(),
-        Some((Constant::Vec(vec), _)) => vec.iter().all(|f| match f {
-            Constant::F32(f) => *f == 0.0 || (*f).is_infinite(),
-            Constant::F64(f) => *f == 0.0 || (*f).is_infinite(),
-            _ => false,
-        }),
-        _ => false,
-    }
-}
-
 // Return true if `expr` is the result of `signum()` invoked on a float value.
 fn is_signum(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
     // The negation of a signum is still a signum
@@ -561,17 +680,7 @@ fn is_signum(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
 }
 
 fn is_float(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
-    let value = &walk_ptrs_ty(cx.typeck_results().expr_ty(expr)).kind();
-
-    if let ty::Array(arr_ty, _) = value {
-        return matches!(arr_ty.kind(), ty::Float(_));
-    };
-
-    matches!(value, ty::Float(_))
-}
-
-fn is_array(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
-    matches!(&walk_ptrs_ty(cx.typeck_results().expr_ty(expr)).kind(), ty::Array(_, _))
+    float_ty_name(cx.typeck_results().expr_ty(expr)).is_some()
 }
 
 fn check_to_owned(cx: &LateContext<'_>, expr: &Expr<'_>, other: &Expr<'_>, left: bool) {
@@ -713,10 +822,21 @@ fn non_macro_local(cx: &LateContext<'_>, res: def::Res) -> bool {
     }
 }
 
+/// Peels off casts between integer types, e.g. returns `0` for `0 as usize`.
+fn peel_int_casts<'a>(cx: &LateContext<'_>, mut e: &'a Expr<'a>) -> &'a Expr<'a> {
+    while let ExprKind::Cast(ref inner, _) = e.kind {
+        if !cx.typeck_results().expr_ty(inner).is_integral() {
+            break;
+        }
+        e = inner;
+    }
+    e
+}
+
 fn check_cast(cx: &LateContext<'_>, span: Span, e: &Expr<'_>, ty: &hir::Ty<'_>) {
     if_chain! {
         if let TyKind::Ptr(ref mut_ty) = ty.kind;
-        if let ExprKind::Lit(ref lit) = e.kind;
+        if let ExprKind::Lit(ref lit) = peel_int_casts(cx, e).kind;
         if let LitKind::Int(0, _) = lit.node;
         if !in_constant(cx, e.hir_id);
         then {
@@ -536,7 +536,7 @@ fn check_unneeded_wildcard_pattern(cx: &EarlyContext<'_>, pat: &Pat) {
                     "these patterns are unneeded as the `..` pattern can match those elements"
                 },
                 if only_one { "remove it" } else { "remove them" },
-                "".to_string(),
+                String::new(),
                 Applicability::MachineApplicable,
             );
         }
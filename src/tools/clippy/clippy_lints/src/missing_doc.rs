@@ -108,7 +108,7 @@ impl<'tcx> LateLintPass<'tcx> for MissingDoc {
                 attr.has_name(sym!(doc))
                     && match attr.meta_item_list() {
                         None => false,
-                        Some(l) => attr::list_contains_name(&l[..], sym!(hidden)),
+                        Some(l) => attr::list_contains_name(&l, sym!(hidden)),
                     }
             });
         self.doc_hidden_stack.push(doc_hidden);
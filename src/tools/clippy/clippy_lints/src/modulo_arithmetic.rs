@@ -1,9 +1,14 @@
 use crate::consts::{constant, Constant};
-use crate::utils::{sext, span_lint_and_then};
+use crate::utils::sugg::Sugg;
+use crate::utils::{
+    get_parent_expr, in_constant, is_guarded_by, sext, snippet_with_applicability, span_lint_and_then, SpanlessEq,
+};
 use if_chain::if_chain;
+use rustc_ast::{IntTy, UintTy};
+use rustc_errors::Applicability;
 use rustc_hir::{BinOpKind, Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_middle::ty::{self};
+use rustc_middle::ty;
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 use std::fmt::Display;
 
@@ -28,7 +33,34 @@ declare_clippy_lint! {
     "any modulo arithmetic statement"
 }
 
-declare_lint_pass!(ModuloArithmetic => [MODULO_ARITHMETIC]);
+declare_clippy_lint! {
+    /// **What it does:** Checks for the remainder of a signed integer cast to `usize`, e.g. to
+    /// index a slice or to select a bucket.
+    ///
+    /// **Why is this bad?** `%` is the remainder, not the modulus: `x % n` is negative for a
+    /// negative `x`, and the cast wraps it to a huge index. `rem_euclid` is never negative.
+    ///
+    /// **Known problems:** The dividend may be known to be non-negative, only constants and
+    /// enclosing `if` conditions like `x >= 0` are checked for that.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let buckets = vec![0; 16];
+    /// # let hash: i32 = -7;
+    /// let bucket = buckets[(hash % 16) as usize];
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let buckets = vec![0; 16];
+    /// # let hash: i32 = -7;
+    /// let bucket = buckets[hash.rem_euclid(16) as usize];
+    /// ```
+    pub SIGNED_MODULO_INDEX,
+    pedantic,
+    "the remainder of a signed integer cast to `usize`"
+}
+
+declare_lint_pass!(ModuloArithmetic => [MODULO_ARITHMETIC, SIGNED_MODULO_INDEX]);
 
 struct OperandInfo {
     string_representation: Option<String>,
@@ -123,11 +155,77 @@ fn check_non_const_operands<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>,
     }
 }
 
+/// Checks for `(lhs % rhs) as usize` where `lhs` is a signed integer which is neither a
+/// non-negative constant nor checked to be non-negative by an enclosing `if`.
+fn check_signed_modulo_index<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, lhs: &Expr<'_>, rhs: &Expr<'_>) {
+    if_chain! {
+        if let ty::Int(ity) = *cx.typeck_results().expr_ty(expr).kind();
+        if let Some(cast) = get_parent_expr(cx, expr);
+        if let ExprKind::Cast(_, ref cast_ty) = cast.kind;
+        if !cast.span.from_expansion();
+        if let ty::Uint(UintTy::Usize) = cx.typeck_results().expr_ty(cast).kind();
+        if !is_non_negative_constant(cx, lhs, ity);
+        if !is_guarded_by(cx, expr, |cond| guards(cx, cond, lhs));
+        then {
+            span_lint_and_then(
+                cx,
+                SIGNED_MODULO_INDEX,
+                cast.span,
+                "casting the remainder of a signed integer to `usize`",
+                |diag| {
+                    let mut app = Applicability::MachineApplicable;
+                    let in_const = in_constant(cx, expr.hir_id);
+                    // the remainder may be wanted for a negative divisor, so only be sure for positive ones
+                    if in_const || !is_positive_constant(cx, rhs, ity) {
+                        app = Applicability::MaybeIncorrect;
+                    }
+                    let sugg = format!(
+                        "{}.rem_euclid({}) as {}",
+                        Sugg::hir_with_applicability(cx, lhs, "..", &mut app).maybe_par(),
+                        snippet_with_applicability(cx, rhs.span, "..", &mut app),
+                        snippet_with_applicability(cx, cast_ty.span, "..", &mut app),
+                    );
+                    diag.span_suggestion(cast.span, "try `rem_euclid`, which is never negative", sugg, app);
+                    if in_const {
+                        diag.note("`rem_euclid` is not stable in constant contexts yet");
+                    }
+                },
+            );
+        }
+    }
+}
+
+fn is_non_negative_constant(cx: &LateContext<'_>, expr: &Expr<'_>, ity: IntTy) -> bool {
+    matches!(constant(cx, cx.typeck_results(), expr), Some((Constant::Int(v), _)) if sext(cx.tcx, v, ity) >= 0)
+}
+
+fn is_positive_constant(cx: &LateContext<'_>, expr: &Expr<'_>, ity: IntTy) -> bool {
+    matches!(constant(cx, cx.typeck_results(), expr), Some((Constant::Int(v), _)) if sext(cx.tcx, v, ity) > 0)
+}
+
+/// Checks whether `cond` makes sure that `dividend` is non-negative, e.g. `dividend >= 0`.
+fn guards(cx: &LateContext<'_>, cond: &Expr<'_>, dividend: &Expr<'_>) -> bool {
+    if let ExprKind::Binary(ref op, ref left, ref right) = cond.kind {
+        let (small, big) = match op.node {
+            BinOpKind::Lt | BinOpKind::Le => (left, right),
+            BinOpKind::Gt | BinOpKind::Ge => (right, left),
+            _ => return false,
+        };
+        SpanlessEq::new(cx).eq_expr(big, dividend)
+            && matches!(constant(cx, cx.typeck_results(), small), Some((Constant::Int(0), _)))
+    } else {
+        false
+    }
+}
+
 impl<'tcx> LateLintPass<'tcx> for ModuloArithmetic {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         match &expr.kind {
             ExprKind::Binary(op, lhs, rhs) | ExprKind::AssignOp(op, lhs, rhs) => {
                 if let BinOpKind::Rem = op.node {
+                    if let ExprKind::Binary(..) = expr.kind {
+                        check_signed_modulo_index(cx, expr, lhs, rhs);
+                    }
                     let lhs_operand = analyze_operand(lhs, cx, expr);
                     let rhs_operand = analyze_operand(rhs, cx, expr);
                     if_chain! {
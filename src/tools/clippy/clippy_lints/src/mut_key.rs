@@ -1,19 +1,26 @@
 use crate::utils::{match_def_path, paths, span_lint, trait_ref_of_method, walk_ptrs_ty};
+use rustc_data_structures::fx::FxHashSet;
 use rustc_hir as hir;
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty::{Adt, Array, RawPtr, Ref, Slice, Tuple, Ty, TypeAndMut};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::source_map::Span;
 
 declare_clippy_lint! {
-    /// **What it does:** Checks for sets/maps with mutable key types.
+    /// **What it does:** Checks for sets/maps with mutable key types, in function
+    /// signatures, local variables, struct fields, statics and constants, and at
+    /// `insert` calls. A key type is mutable if it transitively contains interior
+    /// mutability (`Cell`, `RefCell`, `Mutex`, `RwLock`, atomics, ...), also
+    /// behind a pointer like `Rc<RefCell<T>>`.
     ///
     /// **Why is this bad?** All of `HashMap`, `HashSet`, `BTreeMap` and
     /// `BtreeSet` rely on either the hash or the order of keys be unchanging,
     /// so having types with interior mutability is a bad idea.
     ///
-    /// **Known problems:** We don't currently account for `Rc` or `Arc`, so
-    /// this may yield false positives.
+    /// **Known problems:** Types whose `Hash` and `Ord` implementations do not
+    /// touch their interior mutability (e.g. `bytes::Bytes`) yield false
+    /// positives. Such types can be listed in the `ignore-interior-mutability`
+    /// configuration option.
     ///
     /// **Example:**
     /// ```rust
@@ -49,76 +56,172 @@ declare_clippy_lint! {
     "Check for mutable `Map`/`Set` key type"
 }
 
-declare_lint_pass!(MutableKeyType => [ MUTABLE_KEY_TYPE ]);
+pub struct MutableKeyType {
+    ignore_interior_mutability: Vec<String>,
+}
+
+impl_lint_pass!(MutableKeyType => [ MUTABLE_KEY_TYPE ]);
+
+impl MutableKeyType {
+    pub fn new(ignore_interior_mutability: Vec<String>) -> Self {
+        Self {
+            ignore_interior_mutability,
+        }
+    }
+
+    fn check_sig<'tcx>(&self, cx: &LateContext<'tcx>, item_hir_id: hir::HirId, decl: &hir::FnDecl<'_>) {
+        let fn_def_id = cx.tcx.hir().local_def_id(item_hir_id);
+        let fn_sig = cx.tcx.fn_sig(fn_def_id);
+        for (hir_ty, ty) in decl.inputs.iter().zip(fn_sig.inputs().skip_binder().iter()) {
+            self.check_ty(cx, hir_ty.span, ty);
+        }
+        self.check_ty(
+            cx,
+            decl.output.span(),
+            cx.tcx.erase_late_bound_regions(&fn_sig.output()),
+        );
+    }
+
+    // We want to lint 1. sets or maps with 2. not immutable key types and 3. no unerased
+    // generics (because the compiler cannot ensure immutability for unknown types).
+    // The set or map may be nested in another type, e.g. `Option<HashSet<K>>`.
+    fn check_ty<'tcx>(&self, cx: &LateContext<'tcx>, span: Span, ty: Ty<'tcx>) {
+        if ty
+            .walk()
+            .filter_map(|arg| arg.as_type())
+            .any(|ty| self.is_mutable_key_map(cx, ty))
+        {
+            span_lint(cx, MUTABLE_KEY_TYPE, span, "mutable key type");
+        }
+    }
+
+    /// Returns `true` if `ty` is a (reference to a) set or map with a mutable key type.
+    fn is_mutable_key_map<'tcx>(&self, cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+        if let Adt(def, substs) = walk_ptrs_ty(ty).kind() {
+            [&paths::HASHMAP, &paths::BTREEMAP, &paths::HASHSET, &paths::BTREESET]
+                .iter()
+                .any(|path| match_def_path(cx, def.did, &**path))
+                && self.is_mutable_type(cx, substs.type_at(0), &mut FxHashSet::default())
+        } else {
+            false
+        }
+    }
+
+    fn is_mutable_type<'tcx>(&self, cx: &LateContext<'tcx>, ty: Ty<'tcx>, visited: &mut FxHashSet<Ty<'tcx>>) -> bool {
+        match *ty.kind() {
+            RawPtr(TypeAndMut { ty: inner_ty, mutbl }) | Ref(_, inner_ty, mutbl) => {
+                mutbl == hir::Mutability::Mut || self.is_mutable_type(cx, inner_ty, visited)
+            },
+            Slice(inner_ty) => self.is_mutable_type(cx, inner_ty, visited),
+            Array(inner_ty, size) => {
+                size.try_eval_usize(cx.tcx, cx.param_env).map_or(true, |u| u != 0)
+                    && self.is_mutable_type(cx, inner_ty, visited)
+            },
+            Tuple(..) => ty.tuple_fields().any(|ty| self.is_mutable_type(cx, ty, visited)),
+            Adt(def, substs) => {
+                // Recursive types are only walked once
+                if !visited.insert(ty)
+                    || self
+                        .ignore_interior_mutability
+                        .iter()
+                        .any(|path| match_def_path(cx, def.did, &path.split("::").collect::<Vec<_>>()))
+                {
+                    return false;
+                }
+                if cx.tcx.lang_items().unsafe_cell_type() == Some(def.did) {
+                    return true;
+                }
+                // The reference count is not part of the hash or order of `Rc` and `Arc`, but the
+                // pointee is
+                if [&paths::RC, &paths::ARC, &paths::WEAK_RC, &paths::WEAK_ARC]
+                    .iter()
+                    .any(|path| match_def_path(cx, def.did, &**path))
+                {
+                    return self.is_mutable_type(cx, substs.type_at(0), visited);
+                }
+                def.all_fields()
+                    .any(|field| self.is_mutable_type(cx, field.ty(cx.tcx, substs), visited))
+            },
+            _ => false,
+        }
+    }
+
+    /// Checks `insert` calls on maps which are not linted through their type already, e.g. the
+    /// field of a generic struct instantiated with a mutable key type.
+    fn check_insert<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
+        if let hir::ExprKind::MethodCall(ref path, _, ref args, _) = expr.kind {
+            if path.ident.name != sym!(insert) {
+                return;
+            }
+            let mut receiver = &args[0];
+            while let hir::ExprKind::AddrOf(_, _, ref inner) | hir::ExprKind::Unary(hir::UnOp::UnDeref, ref inner) =
+                receiver.kind
+            {
+                receiver = inner;
+            }
+            match receiver.kind {
+                // Locals and parameters are linted at their declaration
+                hir::ExprKind::Path(_) => return,
+                // So are fields whose declared type has a mutable key type
+                hir::ExprKind::Field(ref base, ident) => {
+                    if let Adt(def, _) = walk_ptrs_ty(cx.typeck_results().expr_ty_adjusted(base)).kind() {
+                        if def.is_struct()
+                            && def.non_enum_variant().fields.iter().any(|field| {
+                                field.ident == ident && self.is_mutable_key_map(cx, cx.tcx.type_of(field.did))
+                            })
+                        {
+                            return;
+                        }
+                    }
+                },
+                _ => {},
+            }
+            if self.is_mutable_key_map(cx, cx.typeck_results().expr_ty_adjusted(receiver)) {
+                span_lint(cx, MUTABLE_KEY_TYPE, expr.span, "mutable key type");
+            }
+        }
+    }
+}
 
 impl<'tcx> LateLintPass<'tcx> for MutableKeyType {
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
-        if let hir::ItemKind::Fn(ref sig, ..) = item.kind {
-            check_sig(cx, item.hir_id, &sig.decl);
+        match item.kind {
+            hir::ItemKind::Fn(ref sig, ..) => self.check_sig(cx, item.hir_id, &sig.decl),
+            hir::ItemKind::Static(ref hir_ty, ..) | hir::ItemKind::Const(ref hir_ty, _) => {
+                let ty = cx.tcx.type_of(cx.tcx.hir().local_def_id(item.hir_id));
+                self.check_ty(cx, hir_ty.span, ty);
+            },
+            _ => {},
         }
     }
 
     fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::ImplItem<'tcx>) {
         if let hir::ImplItemKind::Fn(ref sig, ..) = item.kind {
             if trait_ref_of_method(cx, item.hir_id).is_none() {
-                check_sig(cx, item.hir_id, &sig.decl);
+                self.check_sig(cx, item.hir_id, &sig.decl);
             }
         }
     }
 
     fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::TraitItem<'tcx>) {
         if let hir::TraitItemKind::Fn(ref sig, ..) = item.kind {
-            check_sig(cx, item.hir_id, &sig.decl);
+            self.check_sig(cx, item.hir_id, &sig.decl);
         }
     }
 
+    fn check_struct_field(&mut self, cx: &LateContext<'tcx>, field: &'tcx hir::StructField<'tcx>) {
+        let ty = cx.tcx.type_of(cx.tcx.hir().local_def_id(field.hir_id));
+        self.check_ty(cx, field.ty.span, ty);
+    }
+
     fn check_local(&mut self, cx: &LateContext<'_>, local: &hir::Local<'_>) {
         if let hir::PatKind::Wild = local.pat.kind {
             return;
         }
-        check_ty(cx, local.span, cx.typeck_results().pat_ty(&*local.pat));
+        self.check_ty(cx, local.span, cx.typeck_results().pat_ty(&*local.pat));
     }
-}
-
-fn check_sig<'tcx>(cx: &LateContext<'tcx>, item_hir_id: hir::HirId, decl: &hir::FnDecl<'_>) {
-    let fn_def_id = cx.tcx.hir().local_def_id(item_hir_id);
-    let fn_sig = cx.tcx.fn_sig(fn_def_id);
-    for (hir_ty, ty) in decl.inputs.iter().zip(fn_sig.inputs().skip_binder().iter()) {
-        check_ty(cx, hir_ty.span, ty);
-    }
-    check_ty(
-        cx,
-        decl.output.span(),
-        cx.tcx.erase_late_bound_regions(&fn_sig.output()),
-    );
-}
-
-// We want to lint 1. sets or maps with 2. not immutable key types and 3. no unerased
-// generics (because the compiler cannot ensure immutability for unknown types).
-fn check_ty<'tcx>(cx: &LateContext<'tcx>, span: Span, ty: Ty<'tcx>) {
-    let ty = walk_ptrs_ty(ty);
-    if let Adt(def, substs) = ty.kind() {
-        if [&paths::HASHMAP, &paths::BTREEMAP, &paths::HASHSET, &paths::BTREESET]
-            .iter()
-            .any(|path| match_def_path(cx, def.did, &**path))
-            && is_mutable_type(cx, substs.type_at(0), span)
-        {
-            span_lint(cx, MUTABLE_KEY_TYPE, span, "mutable key type");
-        }
-    }
-}
 
-fn is_mutable_type<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>, span: Span) -> bool {
-    match *ty.kind() {
-        RawPtr(TypeAndMut { ty: inner_ty, mutbl }) | Ref(_, inner_ty, mutbl) => {
-            mutbl == hir::Mutability::Mut || is_mutable_type(cx, inner_ty, span)
-        },
-        Slice(inner_ty) => is_mutable_type(cx, inner_ty, span),
-        Array(inner_ty, size) => {
-            size.try_eval_usize(cx.tcx, cx.param_env).map_or(true, |u| u != 0) && is_mutable_type(cx, inner_ty, span)
-        },
-        Tuple(..) => ty.tuple_fields().any(|ty| is_mutable_type(cx, ty, span)),
-        Adt(..) => cx.tcx.layout_of(cx.param_env.and(ty)).is_ok() && !ty.is_freeze(cx.tcx.at(span), cx.param_env),
-        _ => false,
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
+        self.check_insert(cx, expr);
     }
 }
@@ -66,7 +66,7 @@ enum Mode {
 
 fn check_param_inner(cx: &EarlyContext<'_>, path: &Path, span: Span, binding_mode: &Mode, mutbl: Mutability) {
     if_chain! {
-        if let [segment] = &path.segments[..];
+        if let [segment] = path.segments.as_slice();
         if segment.ident.name == kw::SelfUpper;
         then {
             let self_param = match (binding_mode, mutbl) {
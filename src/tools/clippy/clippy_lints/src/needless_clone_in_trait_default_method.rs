@@ -0,0 +1,134 @@
+use crate::utils::{is_copy, is_type_diagnostic_item, match_trait_method, paths, qpath_res, span_lint_and_help};
+use if_chain::if_chain;
+use rustc_hir::def::Res;
+use rustc_hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
+use rustc_hir::{Body, Expr, ExprKind, FnDecl, HirId, ImplicitSelfKind, Node, PatKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::source_map::Span;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for default methods of traits that return a clone of
+    /// something borrowed from `&self` or `&mut self`. Clones of `Copy` types and of `Rc`
+    /// or `Arc` are cheap and are not linted.
+    ///
+    /// **Why is this bad?** Every implementor and every caller pays for the clone, even
+    /// callers that only need to look at the value. Exposing a borrow instead leaves the
+    /// decision to clone to the caller.
+    ///
+    /// **Known problems:** This is a heuristic: it only looks at the returned expression,
+    /// and returning an owned value may be required by the design of the trait.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// trait Named {
+    ///     fn name_ref(&self) -> &String;
+    ///
+    ///     fn name(&self) -> String {
+    ///         self.name_ref().clone()
+    ///     }
+    /// }
+    /// ```
+    /// Could be written as:
+    /// ```rust
+    /// trait Named {
+    ///     fn name_ref(&self) -> &String;
+    ///
+    ///     fn name(&self) -> &str {
+    ///         self.name_ref()
+    ///     }
+    /// }
+    /// ```
+    pub NEEDLESS_CLONE_IN_TRAIT_DEFAULT_METHOD,
+    pedantic,
+    "default trait methods returning a clone of something borrowed from `self`"
+}
+
+declare_lint_pass!(NeedlessCloneInTraitDefaultMethod => [NEEDLESS_CLONE_IN_TRAIT_DEFAULT_METHOD]);
+
+impl<'tcx> LateLintPass<'tcx> for NeedlessCloneInTraitDefaultMethod {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl<'tcx>,
+        body: &'tcx Body<'tcx>,
+        span: Span,
+        hir_id: HirId,
+    ) {
+        if_chain! {
+            if let FnKind::Method(..) = kind;
+            if !span.from_expansion();
+            if let Some(Node::TraitItem(_)) = cx.tcx.hir().find(hir_id);
+            if let ImplicitSelfKind::ImmRef | ImplicitSelfKind::MutRef = decl.implicit_self;
+            if let PatKind::Binding(_, self_id, ..) = body.params[0].pat.kind;
+            then {
+                let mut visitor = ReturnedExprsVisitor { returned: Vec::new() };
+                visitor.visit_expr(&body.value);
+                if let ExprKind::Block(ref block, _) = body.value.kind {
+                    visitor.returned.extend(block.expr);
+                }
+
+                for expr in visitor.returned {
+                    if_chain! {
+                        if let ExprKind::MethodCall(ref path, _, ref args, _) = expr.kind;
+                        if path.ident.name == sym!(clone);
+                        if match_trait_method(cx, expr, &paths::CLONE_TRAIT);
+                        let ty = cx.typeck_results().expr_ty(expr);
+                        if !is_copy(cx, ty);
+                        if !is_type_diagnostic_item(cx, ty, sym::Rc) && !is_type_diagnostic_item(cx, ty, sym::Arc);
+                        if is_borrowed_from(cx, &args[0], self_id);
+                        then {
+                            span_lint_and_help(
+                                cx,
+                                NEEDLESS_CLONE_IN_TRAIT_DEFAULT_METHOD,
+                                expr.span,
+                                "this default method clones a value borrowed from `self` only to return it",
+                                None,
+                                "consider returning a reference and letting callers clone it if they need to",
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns `true` if `expr` is `self`, or a field, method call result, index or dereference of it.
+fn is_borrowed_from(cx: &LateContext<'_>, mut expr: &Expr<'_>, self_id: HirId) -> bool {
+    loop {
+        match expr.kind {
+            ExprKind::MethodCall(_, _, ref args, _) => expr = &args[0],
+            ExprKind::Field(ref base, _)
+            | ExprKind::Index(ref base, _)
+            | ExprKind::AddrOf(_, _, ref base)
+            | ExprKind::Unary(UnOp::UnDeref, ref base) => expr = base,
+            ExprKind::Path(ref qpath) => return qpath_res(cx, qpath, expr.hir_id) == Res::Local(self_id),
+            _ => return false,
+        }
+    }
+}
+
+/// Collects the operands of the `return` expressions of a body. Closures are nested bodies and
+/// are not visited, so a `return` in a closure is not collected.
+struct ReturnedExprsVisitor<'tcx> {
+    returned: Vec<&'tcx Expr<'tcx>>,
+}
+
+impl<'tcx> Visitor<'tcx> for ReturnedExprsVisitor<'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Ret(Some(ref ret)) = expr.kind {
+            self.returned.push(ret);
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
@@ -1,14 +1,16 @@
 use crate::utils::paths;
 use crate::utils::sugg::DiagnosticBuilderExt;
-use crate::utils::{get_trait_def_id, return_ty, span_lint_hir_and_then};
+use crate::utils::{get_trait_def_id, return_ty, snippet_opt, span_lint_hir_and_then, SpanlessEq};
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir as hir;
-use rustc_hir::HirIdSet;
+use rustc_hir::def_id::DefId;
+use rustc_hir::HirIdMap;
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty::{Ty, TyS};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::Span;
 
 declare_clippy_lint! {
     /// **What it does:** Checks for types with a `fn new() -> Self` method and no
@@ -49,18 +51,73 @@ declare_clippy_lint! {
     "`fn new() -> Self` method without `Default` implementation"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for types with both a `fn new() -> Self` method and a
+    /// `Default` implementation, where the two set a field to different literal values.
+    ///
+    /// **Why is this bad?** Users expect `T::new()` and `T::default()` to create equal values.
+    ///
+    /// **Known problems:** Only struct expressions and tuple struct constructors returned
+    /// directly by both functions are compared, and only fields set to a literal in both.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// pub struct Config {
+    ///     retries: u32,
+    /// }
+    ///
+    /// impl Config {
+    ///     pub fn new() -> Self {
+    ///         Config { retries: 3 }
+    ///     }
+    /// }
+    ///
+    /// impl Default for Config {
+    ///     fn default() -> Self {
+    ///         Config { retries: 5 }
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// pub struct Config {
+    ///     retries: u32,
+    /// }
+    ///
+    /// impl Config {
+    ///     pub fn new() -> Self {
+    ///         Config { retries: 3 }
+    ///     }
+    /// }
+    ///
+    /// impl Default for Config {
+    ///     fn default() -> Self {
+    ///         Self::new()
+    ///     }
+    /// }
+    /// ```
+    pub NEW_DIFFERS_FROM_DEFAULT,
+    pedantic,
+    "`fn new() -> Self` and `Default::default()` setting a field to different values"
+}
+
 #[derive(Clone, Default)]
 pub struct NewWithoutDefault {
-    impling_types: Option<HirIdSet>,
+    /// The types implementing `Default`, with their implementation
+    impling_types: Option<HirIdMap<DefId>>,
 }
 
-impl_lint_pass!(NewWithoutDefault => [NEW_WITHOUT_DEFAULT]);
+impl_lint_pass!(NewWithoutDefault => [NEW_WITHOUT_DEFAULT, NEW_DIFFERS_FROM_DEFAULT]);
 
 impl<'tcx> LateLintPass<'tcx> for NewWithoutDefault {
     #[allow(clippy::too_many_lines)]
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'_>) {
         if let hir::ItemKind::Impl {
-            of_trait: None, items, ..
+            of_trait: None,
+            items,
+            ref generics,
+            self_ty: impl_self_ty,
+            ..
         } = item.kind
         {
             for assoc_item in items {
@@ -69,7 +126,7 @@ impl<'tcx> LateLintPass<'tcx> for NewWithoutDefault {
                     if in_external_macro(cx.sess(), impl_item.span) {
                         return;
                     }
-                    if let hir::ImplItemKind::Fn(ref sig, _) = impl_item.kind {
+                    if let hir::ImplItemKind::Fn(ref sig, body_id) = impl_item.kind {
                         let name = impl_item.ident.name;
                         let id = impl_item.hir_id;
                         if sig.header.constness == hir::Constness::Const {
@@ -91,7 +148,7 @@ impl<'tcx> LateLintPass<'tcx> for NewWithoutDefault {
                             // impl of `Default`
                             return;
                         }
-                        if sig.decl.inputs.is_empty() && name == sym!(new) && cx.access_levels.is_reachable(id) {
+                        if sig.decl.inputs.is_empty() && name == sym!(new) {
                             let self_def_id = cx.tcx.hir().local_def_id(cx.tcx.hir().get_parent_item(id));
                             let self_ty = cx.tcx.type_of(self_def_id);
                             if_chain! {
@@ -99,11 +156,13 @@ impl<'tcx> LateLintPass<'tcx> for NewWithoutDefault {
                                 if let Some(default_trait_id) = get_trait_def_id(cx, &paths::DEFAULT_TRAIT);
                                 then {
                                     if self.impling_types.is_none() {
-                                        let mut impls = HirIdSet::default();
+                                        let mut impls = HirIdMap::default();
                                         cx.tcx.for_each_impl(default_trait_id, |d| {
                                             if let Some(ty_def) = cx.tcx.type_of(d).ty_adt_def() {
                                                 if let Some(local_def_id) = ty_def.did.as_local() {
-                                                    impls.insert(cx.tcx.hir().local_def_id_to_hir_id(local_def_id));
+                                                    impls
+                                                        .entry(cx.tcx.hir().local_def_id_to_hir_id(local_def_id))
+                                                        .or_insert(d);
                                                 }
                                             }
                                         });
@@ -118,12 +177,17 @@ impl<'tcx> LateLintPass<'tcx> for NewWithoutDefault {
                                         if let Some(self_local_did) = self_def.did.as_local();
                                         then {
                                             let self_id = cx.tcx.hir().local_def_id_to_hir_id(self_local_did);
-                                            if impling_types.contains(&self_id) {
+                                            if let Some(&default_impl_id) = impling_types.get(&self_id) {
+                                                check_new_against_default(cx, id, body_id, self_ty, default_impl_id);
                                                 return;
                                             }
                                         }
                                     }
 
+                                    if !cx.access_levels.is_reachable(id) {
+                                        continue;
+                                    }
+
                                     span_lint_hir_and_then(
                                         cx,
                                         NEW_WITHOUT_DEFAULT,
@@ -134,13 +198,17 @@ impl<'tcx> LateLintPass<'tcx> for NewWithoutDefault {
                                             self_ty
                                         ),
                                         |diag| {
-                                            diag.suggest_prepend_item(
-                                                cx,
-                                                item.span,
-                                                "try this",
-                                                &create_new_without_default_suggest_msg(self_ty),
-                                                Applicability::MaybeIncorrect,
-                                            );
+                                            if let Some(default_impl) =
+                                                create_new_without_default_suggest_msg(cx, generics, impl_self_ty)
+                                            {
+                                                diag.suggest_append_item(
+                                                    cx,
+                                                    item.span,
+                                                    "try this",
+                                                    &default_impl,
+                                                    Applicability::MachineApplicable,
+                                                );
+                                            }
                                         },
                                     );
                                 }
@@ -153,12 +221,164 @@ impl<'tcx> LateLintPass<'tcx> for NewWithoutDefault {
     }
 }
 
-fn create_new_without_default_suggest_msg(ty: Ty<'_>) -> String {
+/// Creates an `impl Default` delegating to `new`, with the generics, the self type and the
+/// `where` clause of the inherent impl.
+fn create_new_without_default_suggest_msg(
+    cx: &LateContext<'_>,
+    generics: &hir::Generics<'_>,
+    self_ty: &hir::Ty<'_>,
+) -> Option<String> {
+    let params = if generics.params.is_empty() {
+        String::new()
+    } else {
+        snippet_opt(cx, generics.span)?
+    };
+    let self_ty = snippet_opt(cx, self_ty.span)?;
+    let header = match generics.where_clause.span() {
+        Some(where_span) => format!(
+            "impl{} Default for {}\n{}\n{{",
+            params,
+            self_ty,
+            snippet_opt(cx, where_span)?
+        ),
+        None => format!("impl{} Default for {} {{", params, self_ty),
+    };
     #[rustfmt::skip]
-    format!(
-"impl Default for {} {{
+    let default_impl = format!(
+"{}
     fn default() -> Self {{
         Self::new()
     }}
-}}", ty)
+}}", header);
+    Some(default_impl)
+}
+
+/// Lints the fields which `new` and `default` set to different literals, unless one of them
+/// calls the other.
+fn check_new_against_default(
+    cx: &LateContext<'_>,
+    new_id: hir::HirId,
+    new_body_id: hir::BodyId,
+    self_ty: Ty<'_>,
+    default_impl_id: DefId,
+) {
+    let default_body_id = match default_fn_body(cx, default_impl_id) {
+        Some(body_id) => body_id,
+        None => return,
+    };
+    let (new_expr, default_expr) = match (
+        returned_expr(cx.tcx.hir().body(new_body_id)),
+        returned_expr(cx.tcx.hir().body(default_body_id)),
+    ) {
+        (Some(new_expr), Some(default_expr)) => (new_expr, default_expr),
+        _ => return,
+    };
+    if is_call_to(default_expr, sym!(new))
+        || is_call_to(new_expr, sym!(default))
+        || SpanlessEq::new(cx).eq_expr(new_expr, default_expr)
+    {
+        return;
+    }
+
+    let mut differences: Vec<(String, Span, Span)> = Vec::new();
+    match (&new_expr.kind, &default_expr.kind) {
+        (hir::ExprKind::Struct(_, new_fields, _), hir::ExprKind::Struct(_, default_fields, _)) => {
+            for new_field in *new_fields {
+                if let Some(default_field) = default_fields
+                    .iter()
+                    .find(|field| field.ident.name == new_field.ident.name)
+                {
+                    if differ(cx, &new_field.expr, &default_field.expr) {
+                        differences.push((
+                            new_field.ident.to_string(),
+                            new_field.expr.span,
+                            default_field.expr.span,
+                        ));
+                    }
+                }
+            }
+        },
+        (hir::ExprKind::Call(_, new_args), hir::ExprKind::Call(_, default_args))
+            if new_args.len() == default_args.len() =>
+        {
+            for (idx, (new_arg, default_arg)) in new_args.iter().zip(default_args.iter()).enumerate() {
+                if differ(cx, new_arg, default_arg) {
+                    differences.push((idx.to_string(), new_arg.span, default_arg.span));
+                }
+            }
+        },
+        _ => {},
+    }
+
+    for (field, new_span, default_span) in differences {
+        span_lint_hir_and_then(
+            cx,
+            NEW_DIFFERS_FROM_DEFAULT,
+            new_id,
+            new_span,
+            &format!(
+                "`{0}::new()` sets the field `{1}` to a different value than `{0}::default()`",
+                self_ty, field
+            ),
+            |diag| {
+                diag.span_note(default_span, "`default()` sets it here");
+                diag.help("consider calling `Self::new()` from `default()`, or the other way around");
+            },
+        );
+    }
+}
+
+/// Returns the body of the `default` method of a `Default` implementation, unless the
+/// implementation is derived.
+fn default_fn_body(cx: &LateContext<'_>, default_impl_id: DefId) -> Option<hir::BodyId> {
+    let hir_id = cx.tcx.hir().local_def_id_to_hir_id(default_impl_id.as_local()?);
+    let item = cx.tcx.hir().expect_item(hir_id);
+    if item.span.from_expansion() {
+        return None;
+    }
+    if let hir::ItemKind::Impl { items, .. } = item.kind {
+        let default_ref = items.iter().find(|item_ref| item_ref.ident.name == sym!(default))?;
+        if let hir::ImplItemKind::Fn(_, body_id) = cx.tcx.hir().impl_item(default_ref.id).kind {
+            return Some(body_id);
+        }
+    }
+    None
+}
+
+/// Returns the expression of a body made of a single expression.
+fn returned_expr<'tcx>(body: &'tcx hir::Body<'tcx>) -> Option<&'tcx hir::Expr<'tcx>> {
+    match body.value.kind {
+        hir::ExprKind::Block(ref block, _) if block.stmts.is_empty() => block.expr,
+        _ => None,
+    }
+}
+
+/// Returns `true` for calls like `Self::new()` or `Default::default()`.
+fn is_call_to(expr: &hir::Expr<'_>, name: rustc_span::Symbol) -> bool {
+    if_chain! {
+        if let hir::ExprKind::Call(ref func, []) = expr.kind;
+        if let hir::ExprKind::Path(ref qpath) = func.kind;
+        then {
+            match qpath {
+                hir::QPath::Resolved(_, path) => path.segments.last().map_or(false, |seg| seg.ident.name == name),
+                hir::QPath::TypeRelative(_, seg) => seg.ident.name == name,
+                hir::QPath::LangItem(..) => false,
+            }
+        } else {
+            false
+        }
+    }
+}
+
+/// Returns `true` if both expressions are different literals.
+fn differ(cx: &LateContext<'_>, new_expr: &hir::Expr<'_>, default_expr: &hir::Expr<'_>) -> bool {
+    is_literal(new_expr) && is_literal(default_expr) && !SpanlessEq::new(cx).eq_expr(new_expr, default_expr)
+}
+
+fn is_literal(expr: &hir::Expr<'_>) -> bool {
+    match expr.kind {
+        hir::ExprKind::Lit(_) => true,
+        hir::ExprKind::Unary(hir::UnOp::UnNeg, ref inner) => matches!(inner.kind, hir::ExprKind::Lit(_)),
+        _ => false,
+    }
 }
@@ -1,4 +1,4 @@
-use crate::utils::{span_lint, span_lint_and_then};
+use crate::utils::{glob, span_lint, span_lint_and_then};
 use rustc_ast::ast::{
     Arm, AssocItem, AssocItemKind, Attribute, Block, FnDecl, Item, ItemKind, Local, MacCall, Pat, PatKind,
 };
@@ -66,9 +66,11 @@ declare_clippy_lint! {
     "unclear name"
 }
 
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 pub struct NonExpressiveNames {
     pub single_char_binding_names_threshold: u64,
+    /// The names matching the `allowed-names` configuration, which are not checked.
+    pub allowed_names: Vec<String>,
 }
 
 impl_lint_pass!(NonExpressiveNames => [SIMILAR_NAMES, MANY_SINGLE_CHAR_NAMES, JUST_UNDERSCORES_AND_DIGITS]);
@@ -191,7 +193,9 @@ impl<'a, 'tcx, 'b> SimilarNamesNameVisitor<'a, 'tcx, 'b> {
     #[allow(clippy::too_many_lines)]
     fn check_ident(&mut self, ident: Ident) {
         let interned_name = ident.name.as_str();
-        if interned_name.chars().any(char::is_uppercase) {
+        if interned_name.chars().any(char::is_uppercase)
+            || glob::matches_any(&self.0.lint.allowed_names, &interned_name)
+        {
             return;
         }
         if interned_name.chars().all(|c| c.is_digit(10) || c == '_') {
@@ -0,0 +1,157 @@
+use crate::utils::{
+    in_macro, is_in_debug_assertion, is_panic_expn, is_type_diagnostic_item, return_ty, span_lint_and_help,
+};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
+use rustc_hir::{Body, Expr, ExprKind, FnDecl, HirId};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::hygiene::{ExpnKind, MacroKind};
+use rustc_span::source_map::Span;
+use rustc_span::symbol::Symbol;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `panic!`, `unimplemented!`, `todo!`, `unreachable!`,
+    /// assertions, `unwrap()` and `expect()` in functions returning `Result`.
+    ///
+    /// **Why is this bad?** The signature of a function returning `Result` promises to report
+    /// its errors to the caller, which cannot handle a panic.
+    ///
+    /// **Known problems:** Functions called from the body are not checked. `debug_assert!`
+    /// and its relatives are not linted, as they are not checked in release builds. The bodies
+    /// of closures are only checked with the `panic-in-result-fn-check-closures` configuration
+    /// option.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn parse(s: &str) -> Result<u32, String> {
+    ///     Ok(s.parse().unwrap())
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn parse(s: &str) -> Result<u32, String> {
+    ///     s.parse().map_err(|e| format!("{}", e))
+    /// }
+    /// ```
+    pub PANIC_IN_RESULT_FN,
+    restriction,
+    "functions returning `Result` which contain `panic!()`, `todo!()`, `unwrap()` or assertions"
+}
+
+pub struct PanicInResultFn {
+    check_closures: bool,
+}
+
+impl PanicInResultFn {
+    pub fn new(check_closures: bool) -> Self {
+        Self { check_closures }
+    }
+}
+
+impl_lint_pass!(PanicInResultFn => [PANIC_IN_RESULT_FN]);
+
+impl<'tcx> LateLintPass<'tcx> for PanicInResultFn {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'_>,
+        body: &'tcx Body<'_>,
+        _: Span,
+        hir_id: HirId,
+    ) {
+        if let FnKind::Closure(_) = kind {
+            return;
+        }
+        if !is_type_diagnostic_item(cx, return_ty(cx, hir_id), sym!(result_type)) {
+            return;
+        }
+
+        let mut visitor = PanicVisitor {
+            cx,
+            check_closures: self.check_closures,
+            macro_calls: FxHashSet::default(),
+            panics: Vec::new(),
+        };
+        visitor.visit_expr(&body.value);
+
+        for (span, what) in visitor.panics {
+            span_lint_and_help(
+                cx,
+                PANIC_IN_RESULT_FN,
+                span,
+                &format!("{} in a function that returns `Result`", what),
+                None,
+                "return an `Err` instead, the caller cannot handle a panic",
+            );
+        }
+    }
+}
+
+/// Collects the panicking macro calls and the `unwrap()` and `expect()` calls in a body, with a
+/// description of each.
+struct PanicVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    check_closures: bool,
+    /// The call sites of the macros already found
+    macro_calls: FxHashSet<Span>,
+    panics: Vec<(Span, String)>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for PanicVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
+        if let Some((call_site, name)) = panicking_macro_call(self.cx, expr.span) {
+            if self.macro_calls.insert(call_site) {
+                self.panics.push((call_site, format!("`{}!`", name)));
+            }
+            return;
+        }
+
+        if let ExprKind::MethodCall(ref path, _, ref args, _) = expr.kind {
+            let name = path.ident.name;
+            if (name == sym!(unwrap) || name == sym!(expect)) && !in_macro(expr.span) {
+                let recv_ty = self.cx.typeck_results().expr_ty(&args[0]);
+                if is_type_diagnostic_item(self.cx, recv_ty, sym!(option_type))
+                    || is_type_diagnostic_item(self.cx, recv_ty, sym!(result_type))
+                {
+                    self.panics.push((expr.span, format!("`{}()`", name)));
+                }
+            }
+        }
+
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        if self.check_closures {
+            NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+        } else {
+            NestedVisitorMap::None
+        }
+    }
+}
+
+/// If the span comes from an expansion of a panicking macro, returns the call site of the
+/// outermost macro and its name, e.g. the call site of `assert!` for its `panic!`. The macros
+/// are resolved, a macro named `panic` defined elsewhere does not count.
+fn panicking_macro_call(cx: &LateContext<'_>, span: Span) -> Option<(Span, Symbol)> {
+    // `todo!`, `unimplemented!`, `unreachable!` and assertions all expand to `panic!`
+    if !is_panic_expn(cx, span) || is_in_debug_assertion(cx, span) {
+        return None;
+    }
+
+    let mut outermost = None;
+    let mut span = span;
+    while span.from_expansion() {
+        let data = span.ctxt().outer_expn_data();
+        if let ExpnKind::Macro(MacroKind::Bang, name) = data.kind {
+            outermost = Some((data.call_site, name));
+        }
+        span = data.call_site;
+    }
+    outermost
+}
@@ -1,4 +1,6 @@
-use crate::utils::{is_direct_expn_of, is_expn_of, match_function_call, paths, span_lint};
+use crate::utils::{
+    is_direct_expn_of, is_expn_of_def_path, is_in_debug_assertion, is_panic_expn, match_function_call, paths, span_lint,
+};
 use if_chain::if_chain;
 use rustc_ast::ast::LitKind;
 use rustc_hir::{Expr, ExprKind};
@@ -26,7 +28,8 @@ declare_clippy_lint! {
 }
 
 declare_clippy_lint! {
-    /// **What it does:** Checks for usage of `panic!`.
+    /// **What it does:** Checks for usage of `panic!`, including the panics of assertions.
+    /// `debug_assert!` and its relatives are not linted, as they are not checked in release builds.
     ///
     /// **Why is this bad?** `panic!` will stop the execution of the executable
     ///
@@ -100,16 +103,16 @@ impl<'tcx> LateLintPass<'tcx> for PanicUnimplemented {
                 .or_else(|| match_function_call(cx, ex, &paths::BEGIN_PANIC_FMT));
             then {
                 let span = get_outer_span(expr);
-                if is_expn_of(expr.span, "unimplemented").is_some() {
+                if is_expn_of_def_path(cx, expr.span, &paths::UNIMPLEMENTED_MACRO).is_some() {
                     span_lint(cx, UNIMPLEMENTED, span,
                               "`unimplemented` should not be present in production code");
-                } else if is_expn_of(expr.span, "todo").is_some() {
+                } else if is_expn_of_def_path(cx, expr.span, &paths::TODO_MACRO).is_some() {
                     span_lint(cx, TODO, span,
                               "`todo` should not be present in production code");
-                } else if is_expn_of(expr.span, "unreachable").is_some() {
+                } else if is_expn_of_def_path(cx, expr.span, &paths::UNREACHABLE_MACRO).is_some() {
                     span_lint(cx, UNREACHABLE, span,
                               "`unreachable` should not be present in production code");
-                } else if is_expn_of(expr.span, "panic").is_some() {
+                } else if is_panic_expn(cx, expr.span) && !is_in_debug_assertion(cx, expr.span) {
                     span_lint(cx, PANIC, span,
                               "`panic` should not be present in production code");
                     match_panic(params, expr, cx);
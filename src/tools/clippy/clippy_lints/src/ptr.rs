@@ -1,32 +1,43 @@
-//! Checks for usage of  `&Vec[_]` and `&String`.
+//! Checks for usage of  `&Vec[_]`, `&String`, `&PathBuf` and `&OsString`.
 
 use crate::utils::ptr::get_spans;
 use crate::utils::{
-    is_allowed, is_type_diagnostic_item, match_qpath, match_type, paths, snippet_opt, span_lint, span_lint_and_sugg,
-    span_lint_and_then, walk_ptrs_hir_ty,
+    attr_by_name, get_pat_name, in_macro, is_allowed, is_type_diagnostic_item, match_qpath, match_type, paths, snippet,
+    snippet_opt, span_lint, span_lint_and_then, walk_ptrs_hir_ty,
 };
 use if_chain::if_chain;
-use rustc_errors::Applicability;
+use rustc_ast::ast::Attribute;
+use rustc_errors::{Applicability, DiagnosticBuilder};
+use rustc_hir::def::Res;
+use rustc_hir::def_id::DefId;
+use rustc_hir::intravisit::{walk_body, walk_crate, walk_expr, NestedVisitorMap, Visitor};
 use rustc_hir::{
-    BinOpKind, BodyId, Expr, ExprKind, FnDecl, FnRetTy, GenericArg, HirId, ImplItem, ImplItemKind, Item, ItemKind,
-    Lifetime, MutTy, Mutability, Node, PathSegment, QPath, TraitFn, TraitItem, TraitItemKind, Ty, TyKind,
+    BinOpKind, Body, BodyId, Expr, ExprKind, FnDecl, FnRetTy, FnSig, GenericArg, HirId, ImplItem, ImplItemKind, Item,
+    ItemKind, Lifetime, MutTy, Mutability, Node, PathSegment, QPath, TraitFn, TraitItem, TraitItemKind, Ty, TyKind,
 };
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_middle::ty;
+use rustc_middle::hir::map::Map;
+use rustc_middle::ty::{self, TypeckResults};
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 use rustc_span::source_map::Span;
+use rustc_span::symbol::kw;
 use rustc_span::MultiSpan;
+use rustc_target::spec::abi::Abi;
 use std::borrow::Cow;
 
 declare_clippy_lint! {
-    /// **What it does:** This lint checks for function arguments of type `&String`
-    /// or `&Vec` unless the references are mutable. It will also suggest you
-    /// replace `.clone()` calls with the appropriate `.to_owned()`/`to_string()`
-    /// calls.
+    /// **What it does:** This lint checks for function arguments of type `&String`,
+    /// `&Vec`, `&PathBuf`, `&OsString` or `&Cow` unless the references are mutable.
+    /// It will also suggest you replace `.clone()` calls with the appropriate
+    /// `.to_owned()`/`to_string()` calls, and, if the function is not exported,
+    /// wrap the uses of the function as a value (like `iter.map(f)`) in a closure.
+    ///
+    /// `extern` and `#[no_mangle]` functions are not linted.
     ///
     /// **Why is this bad?** Requiring the argument to be of the specific size
-    /// makes the function less useful for no benefit; slices in the form of `&[T]`
-    /// or `&str` usually suffice and can be obtained from other types, too.
+    /// makes the function less useful for no benefit; slices in the form of `&[T]`,
+    /// `&str`, `&Path` or `&OsStr` usually suffice and can be obtained from other
+    /// types, too.
     ///
     /// **Known problems:** The lint does not follow data. So if you have an
     /// argument `x` and write `let y = x; y.clone()` the lint will not suggest
@@ -49,6 +60,8 @@ declare_clippy_lint! {
     /// }
     /// ```
     ///
+    /// `&Box<T>` is left to the `borrowed_box` lint.
+    ///
     /// Also there may be `fn(&Vec)`-typed references pointing to your function.
     /// If you have them, you will get a compiler error after applying this lint's
     /// suggestions. You then have the choice to undo your changes or change the
@@ -68,7 +81,7 @@ declare_clippy_lint! {
     /// ```
     pub PTR_ARG,
     style,
-    "fn arguments of the type `&Vec<...>` or `&String`, suggesting to use `&[...]` or `&str` instead, respectively"
+    "fn arguments of the type `&Vec<...>`, `&String`, `&PathBuf`, `&OsString` or `&Cow<T>`, suggesting to use `&[...]`, `&str`, `&Path`, `&OsStr` or `&T` instead, respectively"
 }
 
 declare_clippy_lint! {
@@ -124,7 +137,7 @@ declare_lint_pass!(Ptr => [PTR_ARG, CMP_NULL, MUT_FROM_REF]);
 impl<'tcx> LateLintPass<'tcx> for Ptr {
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
         if let ItemKind::Fn(ref sig, _, body_id) = item.kind {
-            check_fn(cx, &sig.decl, item.hir_id, Some(body_id));
+            check_fn(cx, sig, &item.attrs, item.hir_id, Some(body_id));
         }
     }
 
@@ -136,7 +149,7 @@ impl<'tcx> LateLintPass<'tcx> for Ptr {
                     return; // ignore trait impls
                 }
             }
-            check_fn(cx, &sig.decl, item.hir_id, Some(body_id));
+            check_fn(cx, sig, &item.attrs, item.hir_id, Some(body_id));
         }
     }
 
@@ -147,7 +160,7 @@ impl<'tcx> LateLintPass<'tcx> for Ptr {
             } else {
                 None
             };
-            check_fn(cx, &sig.decl, item.hir_id, body_id);
+            check_fn(cx, sig, &item.attrs, item.hir_id, body_id);
         }
     }
 
@@ -165,12 +178,47 @@ impl<'tcx> LateLintPass<'tcx> for Ptr {
     }
 }
 
+fn check_fn(cx: &LateContext<'_>, sig: &FnSig<'_>, attrs: &[Attribute], fn_id: HirId, opt_body_id: Option<BodyId>) {
+    // the signature of `extern` and `#[no_mangle]` functions is dictated by their callers
+    if sig.header.abi == Abi::Rust && attr_by_name(attrs, "no_mangle").is_none() {
+        check_ptr_args(cx, &sig.decl, fn_id, opt_body_id);
+    }
+    check_mut_from_ref(cx, &sig.decl);
+}
+
+/// A reference to an owned type which derefs to a borrowed type, like `&String` to `&str`.
+struct OwnedRef {
+    owned: &'static str,
+    borrowed: &'static str,
+    /// Methods called on the parameter to rewrite, with the suffix replacing the call
+    replacements: &'static [(&'static str, &'static str)],
+}
+
+const STRING_REF: OwnedRef = OwnedRef {
+    owned: "&String",
+    borrowed: "&str",
+    replacements: &[("clone", ".to_string()"), ("as_str", "")],
+};
+
+const PATH_BUF_REF: OwnedRef = OwnedRef {
+    owned: "&PathBuf",
+    borrowed: "&Path",
+    replacements: &[("clone", ".to_path_buf()"), ("as_path", "")],
+};
+
+const OS_STRING_REF: OwnedRef = OwnedRef {
+    owned: "&OsString",
+    borrowed: "&OsStr",
+    replacements: &[("clone", ".to_os_string()"), ("as_os_str", "")],
+};
+
 #[allow(clippy::too_many_lines)]
-fn check_fn(cx: &LateContext<'_>, decl: &FnDecl<'_>, fn_id: HirId, opt_body_id: Option<BodyId>) {
+fn check_ptr_args(cx: &LateContext<'_>, decl: &FnDecl<'_>, fn_id: HirId, opt_body_id: Option<BodyId>) {
     let fn_def_id = cx.tcx.hir().local_def_id(fn_id);
     let sig = cx.tcx.fn_sig(fn_def_id);
     let fn_ty = sig.skip_binder();
     let body = opt_body_id.map(|id| cx.tcx.hir().body(id));
+    let mut value_uses = None;
 
     for (idx, (arg, ty)) in decl.inputs.iter().zip(fn_ty.inputs()).enumerate() {
         // Honor the allow attribute on parameters. See issue 5644.
@@ -197,6 +245,7 @@ fn check_fn(cx: &LateContext<'_>, decl: &FnDecl<'_>, fn_id: HirId, opt_body_id:
                     }
                 };
                 if let Some(spans) = get_spans(cx, opt_body_id, idx, &[("clone", ".to_owned()")]) {
+                    let value_uses = fn_value_uses(cx, decl, fn_id, body, &mut value_uses);
                     span_lint_and_then(
                         cx,
                         PTR_ARG,
@@ -222,18 +271,28 @@ fn check_fn(cx: &LateContext<'_>, decl: &FnDecl<'_>, fn_id: HirId, opt_body_id:
                                     Applicability::Unspecified,
                                 );
                             }
+                            suggest_closures(diag, value_uses);
                         },
                     );
                 }
-            } else if is_type_diagnostic_item(cx, ty, sym!(string_type)) {
-                if let Some(spans) = get_spans(cx, opt_body_id, idx, &[("clone", ".to_string()"), ("as_str", "")]) {
+            } else if let Some(owned_ref) = owned_ref(cx, ty) {
+                if let Some(spans) = get_spans(cx, opt_body_id, idx, owned_ref.replacements) {
+                    let value_uses = fn_value_uses(cx, decl, fn_id, body, &mut value_uses);
                     span_lint_and_then(
                         cx,
                         PTR_ARG,
                         arg.span,
-                        "writing `&String` instead of `&str` involves a new object where a slice will do.",
+                        &format!(
+                            "writing `{}` instead of `{}` involves a new object where a slice will do.",
+                            owned_ref.owned, owned_ref.borrowed
+                        ),
                         |diag| {
-                            diag.span_suggestion(arg.span, "change this to", "&str".into(), Applicability::Unspecified);
+                            diag.span_suggestion(
+                                arg.span,
+                                "change this to",
+                                owned_ref.borrowed.into(),
+                                Applicability::Unspecified,
+                            );
                             for (clonespan, suggestion) in spans {
                                 diag.span_suggestion_short(
                                     clonespan,
@@ -244,6 +303,7 @@ fn check_fn(cx: &LateContext<'_>, decl: &FnDecl<'_>, fn_id: HirId, opt_body_id:
                                     Applicability::Unspecified,
                                 );
                             }
+                            suggest_closures(diag, value_uses);
                         },
                     );
                 }
@@ -259,25 +319,142 @@ fn check_fn(cx: &LateContext<'_>, decl: &FnDecl<'_>, fn_id: HirId, opt_body_id:
                         GenericArg::Type(ty) => Some(ty),
                         _ => None,
                     });
+                    if let Some(r) = snippet_opt(cx, inner.span);
                     then {
-                        let replacement = snippet_opt(cx, inner.span);
-                        if let Some(r) = replacement {
-                            span_lint_and_sugg(
-                                cx,
-                                PTR_ARG,
-                                arg.span,
-                                "using a reference to `Cow` is not recommended.",
-                                "change this to",
-                                "&".to_owned() + &r,
-                                Applicability::Unspecified,
-                            );
-                        }
+                        let value_uses = fn_value_uses(cx, decl, fn_id, body, &mut value_uses);
+                        span_lint_and_then(
+                            cx,
+                            PTR_ARG,
+                            arg.span,
+                            "using a reference to `Cow` is not recommended.",
+                            |diag| {
+                                diag.span_suggestion(
+                                    arg.span,
+                                    "change this to",
+                                    "&".to_owned() + &r,
+                                    Applicability::Unspecified,
+                                );
+                                suggest_closures(diag, value_uses);
+                            },
+                        );
                     }
                 }
             }
         }
     }
+}
+
+/// Returns the borrowed type to suggest for a reference to `ty`, if it's a `String`, a `PathBuf`
+/// or an `OsString`.
+fn owned_ref(cx: &LateContext<'_>, ty: ty::Ty<'_>) -> Option<&'static OwnedRef> {
+    if is_type_diagnostic_item(cx, ty, sym!(string_type)) {
+        Some(&STRING_REF)
+    } else if match_type(cx, ty, &paths::PATH_BUF) {
+        Some(&PATH_BUF_REF)
+    } else if match_type(cx, ty, &paths::OS_STRING) {
+        Some(&OS_STRING_REF)
+    } else {
+        None
+    }
+}
+
+/// Suggests wrapping the uses of the function as a value in a closure. Direct calls don't need
+/// to change as the arguments are coerced, but a function item taking `&str` does not implement
+/// `Fn(&String)`.
+fn suggest_closures(diag: &mut DiagnosticBuilder<'_>, value_uses: &[(Span, String)]) {
+    for (span, closure) in value_uses {
+        diag.span_suggestion(
+            *span,
+            "and wrap this use in a closure",
+            closure.clone(),
+            Applicability::Unspecified,
+        );
+    }
+}
+
+/// Returns the uses of the function as a value within the crate, like `iter.map(f)`, with a
+/// closure calling the function to replace them with. The uses of exported functions are not
+/// searched, as they may be used outside of the crate anyway.
+fn fn_value_uses<'a>(
+    cx: &LateContext<'_>,
+    decl: &FnDecl<'_>,
+    fn_id: HirId,
+    body: Option<&Body<'_>>,
+    cache: &'a mut Option<Vec<(Span, String)>>,
+) -> &'a [(Span, String)] {
+    cache.get_or_insert_with(|| {
+        if cx.access_levels.is_exported(fn_id) {
+            return vec![];
+        }
+        let mut visitor = FnValueVisitor {
+            cx,
+            def_id: cx.tcx.hir().local_def_id(fn_id).to_def_id(),
+            maybe_typeck_results: cx.maybe_typeck_results(),
+            spans: vec![],
+        };
+        walk_crate(&mut visitor, cx.tcx.hir().krate());
+
+        let params = (0..decl.inputs.len())
+            .map(|idx| {
+                body.and_then(|body| get_pat_name(&body.params[idx].pat))
+                    .filter(|&name| name != kw::SelfLower)
+                    .map_or_else(|| format!("arg{}", idx), |name| name.to_string())
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        visitor
+            .spans
+            .into_iter()
+            .map(|span| (span, format!("|{}| {}({})", params, snippet(cx, span, ".."), params)))
+            .collect()
+    })
+}
+
+/// Collects the paths to a function which are not the callee of a call.
+struct FnValueVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    def_id: DefId,
+    maybe_typeck_results: Option<&'tcx TypeckResults<'tcx>>,
+    spans: Vec<Span>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for FnValueVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_body(&mut self, body: &'tcx Body<'_>) {
+        let old_maybe_typeck_results = self.maybe_typeck_results.replace(self.cx.tcx.typeck_body(body.id()));
+        walk_body(self, body);
+        self.maybe_typeck_results = old_maybe_typeck_results;
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
+        match expr.kind {
+            ExprKind::Call(ref callee, args) if matches!(callee.kind, ExprKind::Path(_)) => {
+                for arg in args {
+                    self.visit_expr(arg);
+                }
+            },
+            ExprKind::Path(ref qpath) => {
+                if_chain! {
+                    if !in_macro(expr.span);
+                    if let Some(typeck_results) = self.maybe_typeck_results;
+                    if let Res::Def(_, def_id) = typeck_results.qpath_res(qpath, expr.hir_id);
+                    if def_id == self.def_id;
+                    then {
+                        self.spans.push(expr.span);
+                    }
+                }
+            },
+            _ => walk_expr(self, expr),
+        }
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::All(self.cx.tcx.hir())
+    }
+}
 
+fn check_mut_from_ref(cx: &LateContext<'_>, decl: &FnDecl<'_>) {
     if let FnRetTy::Return(ref ty) = decl.output {
         if let Some((out, Mutability::Mut, _)) = get_rptr_lm(ty) {
             let mut immutables = vec![];
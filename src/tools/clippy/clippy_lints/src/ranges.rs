@@ -2,13 +2,14 @@ use crate::consts::{constant, Constant};
 use if_chain::if_chain;
 use rustc_ast::ast::RangeLimits;
 use rustc_errors::Applicability;
-use rustc_hir::{BinOpKind, Expr, ExprKind, QPath};
-use rustc_lint::{LateContext, LateLintPass};
+use rustc_hir::{BinOpKind, Crate, Expr, ExprKind, QPath};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::ty;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::source_map::Spanned;
 use std::cmp::Ordering;
 
+use crate::utils::msrvs::{self, meets_msrv, update_msrv_from_attrs, RustcVersion};
 use crate::utils::sugg::Sugg;
 use crate::utils::{get_parent_expr, is_integer_const, snippet, snippet_opt, span_lint, span_lint_and_then};
 use crate::utils::{higher, SpanlessEq};
@@ -128,7 +129,18 @@ declare_clippy_lint! {
     "reversing the limits of range expressions, resulting in empty ranges"
 }
 
-declare_lint_pass!(Ranges => [
+pub struct Ranges {
+    msrv: Option<RustcVersion>,
+}
+
+impl Ranges {
+    #[must_use]
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(Ranges => [
     RANGE_ZIP_WITH_LEN,
     RANGE_PLUS_ONE,
     RANGE_MINUS_ONE,
@@ -136,6 +148,10 @@ declare_lint_pass!(Ranges => [
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Ranges {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>, krate: &'tcx Crate<'_>) {
+        update_msrv_from_attrs(&mut self.msrv, cx.sess(), &krate.item.attrs);
+    }
+
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if let ExprKind::MethodCall(ref path, _, ref args, _) = expr.kind {
             let name = path.ident.as_str();
@@ -167,7 +183,9 @@ impl<'tcx> LateLintPass<'tcx> for Ranges {
             }
         }
 
-        check_exclusive_range_plus_one(cx, expr);
+        if meets_msrv(self.msrv, msrvs::RANGE_INCLUSIVE) {
+            check_exclusive_range_plus_one(cx, expr);
+        }
         check_inclusive_range_minus_one(cx, expr);
         check_reversed_empty_range(cx, expr);
     }
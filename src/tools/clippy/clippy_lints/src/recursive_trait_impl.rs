@@ -0,0 +1,404 @@
+use crate::utils::{match_def_path, paths, qpath_res, span_lint_hir, span_lint_hir_and_then, walk_ptrs_ty};
+use if_chain::if_chain;
+use rustc_hir::def::Res;
+use rustc_hir::def_id::DefId;
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{
+    AssocItemKind, Body, BorrowKind, Expr, ExprKind, HirId, ImplItemKind, ImplItemRef, Item, ItemKind, MatchSource,
+    Mutability, PatKind, UnOp,
+};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_middle::ty::adjustment::Adjust;
+use rustc_middle::ty::{self, Ty, TyS};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for implementations of `Display`, `Debug`, `From`, `Into`,
+    /// `Hash` and `Deref` which call themselves again through another route:
+    /// * `fmt` formatting `self` with the trait being implemented, like `write!(f, "{}", self)`
+    /// in `Display`, or calling a helper which calls `self.to_string()` in `Display`
+    /// * `from` or `into` calling `.into()` for the same conversion
+    /// * `hash` hashing `self` again, like `Hash::hash(self, state)`
+    /// * `deref` dereferencing a value of the type it is implemented for
+    ///
+    /// **Why is this bad?** Each of these calls ends up in the implementation being defined,
+    /// which recurses infinitely and overflows the stack.
+    ///
+    /// **Known problems:** Only recursion through the blanket implementations of the standard
+    /// library and through one helper function is recognized. Calling `self.to_string()`
+    /// directly in `Display` is left to the `to_string_in_display` lint.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use std::fmt;
+    ///
+    /// struct Name(String);
+    ///
+    /// impl fmt::Display for Name {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "{}", self)
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use std::fmt;
+    ///
+    /// struct Name(String);
+    ///
+    /// impl fmt::Display for Name {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "{}", self.0)
+    ///     }
+    /// }
+    /// ```
+    pub RECURSIVE_TRAIT_IMPL,
+    correctness,
+    "trait implementations which call themselves again, like `Display` formatting `self` with `{}`"
+}
+
+declare_lint_pass!(RecursiveTraitImpl => [RECURSIVE_TRAIT_IMPL]);
+
+impl<'tcx> LateLintPass<'tcx> for RecursiveTraitImpl {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        let impl_def_id = cx.tcx.hir().local_def_id(item.hir_id);
+        if_chain! {
+            if let ItemKind::Impl { items: impl_items, .. } = item.kind;
+            // Derived implementations are fine
+            if !item.span.from_expansion();
+            if let Some(trait_ref) = cx.tcx.impl_trait_ref(impl_def_id);
+            if let Some(checked) = CheckedTrait::of(cx, trait_ref);
+            then {
+                for impl_item in impl_items {
+                    if let ImplItemKind::Fn(_, body_id) = cx.tcx.hir().impl_item(impl_item.id).kind {
+                        let body = cx.tcx.hir().body(body_id);
+                        let mut visitor = RecursionVisitor {
+                            cx,
+                            typeck_results: cx.tcx.typeck_body(body_id),
+                            checked,
+                            self_ty: trait_ref.self_ty(),
+                            self_param: self_param(impl_item, body),
+                        };
+                        visitor.visit_expr(&body.value);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A trait whose implementations are checked.
+#[derive(Clone, Copy)]
+enum CheckedTrait<'tcx> {
+    /// `Display` or `Debug`, with the path of their `fmt` method
+    Format(&'static str, &'static [&'static str]),
+    /// `From` or `Into`, converting the first type into the second one
+    Conversion(&'static str, Ty<'tcx>, Ty<'tcx>),
+    Hash,
+    Deref,
+}
+
+impl<'tcx> CheckedTrait<'tcx> {
+    fn of(cx: &LateContext<'tcx>, trait_ref: ty::TraitRef<'tcx>) -> Option<Self> {
+        let trait_id = trait_ref.def_id;
+        if match_def_path(cx, trait_id, &paths::DISPLAY_TRAIT) {
+            Some(Self::Format("Display", &paths::DISPLAY_FMT_METHOD))
+        } else if match_def_path(cx, trait_id, &paths::DEBUG_TRAIT) {
+            Some(Self::Format("Debug", &paths::DEBUG_FMT_METHOD))
+        } else if match_def_path(cx, trait_id, &paths::FROM_TRAIT) {
+            Some(Self::Conversion(
+                "From",
+                trait_ref.substs.type_at(1),
+                trait_ref.self_ty(),
+            ))
+        } else if match_def_path(cx, trait_id, &paths::INTO) {
+            Some(Self::Conversion(
+                "Into",
+                trait_ref.self_ty(),
+                trait_ref.substs.type_at(1),
+            ))
+        } else if match_def_path(cx, trait_id, &paths::HASH_TRAIT) {
+            Some(Self::Hash)
+        } else if cx.tcx.lang_items().deref_trait() == Some(trait_id) {
+            Some(Self::Deref)
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the `HirId` of the `self` parameter of a method.
+fn self_param(impl_item: &ImplItemRef<'_>, body: &Body<'_>) -> Option<HirId> {
+    match impl_item.kind {
+        AssocItemKind::Fn { has_self: true } => body.params.first().map(|param| param.pat.hir_id),
+        _ => None,
+    }
+}
+
+struct RecursionVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    typeck_results: &'tcx ty::TypeckResults<'tcx>,
+    checked: CheckedTrait<'tcx>,
+    /// The type the trait is implemented for
+    self_ty: Ty<'tcx>,
+    self_param: Option<HirId>,
+}
+
+impl<'a, 'tcx> RecursionVisitor<'a, 'tcx> {
+    /// Checks whether `expr` is the `self` parameter, possibly borrowed or dereferenced, as a value
+    /// of the type the trait is implemented for. Dereferencing it with `Deref`, like `**self`,
+    /// yields the target type instead.
+    fn is_self(&self, expr: &Expr<'_>) -> bool {
+        if !TyS::same_type(
+            walk_ptrs_ty(self.typeck_results.expr_ty(expr)),
+            walk_ptrs_ty(self.self_ty),
+        ) {
+            return false;
+        }
+        let mut expr = expr;
+        while let ExprKind::AddrOf(BorrowKind::Ref, _, ref inner) | ExprKind::Unary(UnOp::UnDeref, ref inner) =
+            expr.kind
+        {
+            expr = inner;
+        }
+        match expr.kind {
+            ExprKind::Path(ref qpath) => self
+                .self_param
+                .map_or(false, |id| qpath_res(self.cx, qpath, expr.hir_id) == Res::Local(id)),
+            _ => false,
+        }
+    }
+
+    /// Returns the function called by `expr` and its arguments, including the receiver of a
+    /// method call.
+    fn callee(&self, expr: &'tcx Expr<'tcx>) -> Option<(DefId, &'tcx [Expr<'tcx>])> {
+        match expr.kind {
+            ExprKind::MethodCall(_, _, args, _) => {
+                Some((self.typeck_results.type_dependent_def_id(expr.hir_id)?, args))
+            },
+            ExprKind::Call(ref func, args) => match func.kind {
+                ExprKind::Path(ref qpath) => Some((qpath_res(self.cx, qpath, func.hir_id).opt_def_id()?, args)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn is_trait_method(&self, def_id: DefId, trait_path: &[&str], name: &str) -> bool {
+        self.cx.tcx.item_name(def_id).as_str() == name
+            && self
+                .cx
+                .tcx
+                .trait_of_item(def_id)
+                .map_or(false, |trait_id| match_def_path(self.cx, trait_id, trait_path))
+    }
+
+    /// Checks the arguments of `format_args!`, expanded to
+    /// `match (&a, &b) { (arg0, arg1) => [ArgumentV1::new(arg0, Display::fmt), ..] }`.
+    fn check_format_args(&self, expr: &'tcx Expr<'tcx>, trait_name: &str, fmt_path: &[&str]) {
+        if_chain! {
+            if let ExprKind::Match(ref scrutinee, [ref arm], MatchSource::Normal) = expr.kind;
+            if let ExprKind::Tup(values) = scrutinee.kind;
+            if let PatKind::Tuple(pats, None) = arm.pat.kind;
+            if let ExprKind::Array(args) = arm.body.kind;
+            then {
+                for arg in args {
+                    if_chain! {
+                        if let Some((new_id, [value, fmt])) = self.callee(arg);
+                        if match_def_path(self.cx, new_id, &paths::FMT_ARGUMENTV1_NEW);
+                        if let ExprKind::Path(ref fmt_qpath) = fmt.kind;
+                        if let Some(fmt_id) = qpath_res(self.cx, fmt_qpath, fmt.hir_id).opt_def_id();
+                        if match_def_path(self.cx, fmt_id, fmt_path);
+                        if let ExprKind::Path(ref value_qpath) = value.kind;
+                        if let Res::Local(binding_id) = qpath_res(self.cx, value_qpath, value.hir_id);
+                        if let Some(idx) = pats.iter().position(|pat| pat.hir_id == binding_id);
+                        if self.is_self(&values[idx]);
+                        then {
+                            span_lint_hir(
+                                self.cx,
+                                RECURSIVE_TRAIT_IMPL,
+                                expr.hir_id,
+                                expr.span.source_callsite(),
+                                &format!(
+                                    "`self` is formatted with this `{}` implementation, which recurses infinitely",
+                                    trait_name
+                                ),
+                            );
+                            return;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Checks for a call of a local function passing `self`, which calls `to_string()` on it.
+    fn check_helper_to_string(&self, expr: &'tcx Expr<'tcx>) {
+        if_chain! {
+            if let Some((callee_id, args)) = self.callee(expr);
+            if let Some(callee_id) = callee_id.as_local();
+            let hir = self.cx.tcx.hir();
+            if let Some(callee_body_id) = hir.maybe_body_owned_by(hir.local_def_id_to_hir_id(callee_id));
+            let callee_body = hir.body(callee_body_id);
+            if let Some(idx) = args.iter().position(|arg| self.is_self(arg));
+            if let Some(param) = callee_body.params.get(idx);
+            then {
+                let mut finder = ToStringFinder {
+                    cx: self.cx,
+                    typeck_results: self.cx.tcx.typeck_body(callee_body_id),
+                    param: param.pat.hir_id,
+                    found: None,
+                };
+                finder.visit_expr(&callee_body.value);
+                if let Some(to_string_span) = finder.found {
+                    span_lint_hir_and_then(
+                        self.cx,
+                        RECURSIVE_TRAIT_IMPL,
+                        expr.hir_id,
+                        expr.span,
+                        &format!(
+                            "`{}` converts `self` to a string with this `Display` implementation, which recurses \
+                            infinitely",
+                            self.cx.tcx.item_name(callee_id.to_def_id()),
+                        ),
+                        |diag| {
+                            diag.span_note(to_string_span, "`to_string()` is called here");
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    /// Checks for a dereference of a value of the type `Deref` is implemented for, either
+    /// explicit or by an implicit adjustment.
+    fn check_deref(&self, expr: &'tcx Expr<'tcx>) {
+        let explicit = match expr.kind {
+            ExprKind::Unary(UnOp::UnDeref, ref inner) => {
+                self.typeck_results.is_method_call(expr)
+                    && TyS::same_type(self.typeck_results.expr_ty(inner), self.self_ty)
+            },
+            _ => false,
+        };
+        let mut source = self.typeck_results.expr_ty(expr);
+        let mut implicit = false;
+        for adjustment in self.typeck_results.expr_adjustments(expr) {
+            if let Adjust::Deref(Some(ref overloaded)) = adjustment.kind {
+                implicit |= overloaded.mutbl == Mutability::Not && TyS::same_type(source, self.self_ty);
+            }
+            source = adjustment.target;
+        }
+        if explicit || implicit {
+            span_lint_hir(
+                self.cx,
+                RECURSIVE_TRAIT_IMPL,
+                expr.hir_id,
+                expr.span,
+                "this dereferences a value with this `Deref` implementation, which recurses infinitely",
+            );
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for RecursionVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        match self.checked {
+            CheckedTrait::Format(trait_name, fmt_path) => {
+                self.check_format_args(expr, trait_name, fmt_path);
+                if trait_name == "Display" {
+                    self.check_helper_to_string(expr);
+                }
+            },
+            CheckedTrait::Conversion(trait_name, from, into) => {
+                if_chain! {
+                    if let ExprKind::MethodCall(_, _, args, _) = expr.kind;
+                    if let Some(method_id) = self.typeck_results.type_dependent_def_id(expr.hir_id);
+                    if self.is_trait_method(method_id, &paths::INTO, "into");
+                    if TyS::same_type(self.typeck_results.expr_ty(&args[0]), from);
+                    if TyS::same_type(self.typeck_results.expr_ty(expr), into);
+                    then {
+                        span_lint_hir(
+                            self.cx,
+                            RECURSIVE_TRAIT_IMPL,
+                            expr.hir_id,
+                            expr.span,
+                            &format!(
+                                "`into()` converts with this `{}` implementation, which recurses infinitely",
+                                trait_name
+                            ),
+                        );
+                    }
+                }
+            },
+            CheckedTrait::Hash => {
+                if_chain! {
+                    if let Some((callee_id, args)) = self.callee(expr);
+                    if self.is_trait_method(callee_id, &paths::HASH_TRAIT, "hash");
+                    if args.first().map_or(false, |arg| self.is_self(arg));
+                    then {
+                        span_lint_hir(
+                            self.cx,
+                            RECURSIVE_TRAIT_IMPL,
+                            expr.hir_id,
+                            expr.span,
+                            "`self` is hashed with this `Hash` implementation, which recurses infinitely",
+                        );
+                    }
+                }
+            },
+            CheckedTrait::Deref => self.check_deref(expr),
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
+
+/// Finds a `to_string()` call on the parameter `param`, possibly borrowed or dereferenced.
+struct ToStringFinder<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    typeck_results: &'tcx ty::TypeckResults<'tcx>,
+    param: HirId,
+    found: Option<rustc_span::Span>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for ToStringFinder<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if self.found.is_some() {
+            return;
+        }
+        if_chain! {
+            if let ExprKind::MethodCall(_, _, args, _) = expr.kind;
+            if let Some(method_id) = self.typeck_results.type_dependent_def_id(expr.hir_id);
+            if self.cx.tcx.item_name(method_id).as_str() == "to_string";
+            if let Some(trait_id) = self.cx.tcx.trait_of_item(method_id);
+            if match_def_path(self.cx, trait_id, &paths::TO_STRING);
+            then {
+                let mut recv = &args[0];
+                while let ExprKind::AddrOf(BorrowKind::Ref, _, ref inner) | ExprKind::Unary(UnOp::UnDeref, ref inner) =
+                    recv.kind
+                {
+                    recv = inner;
+                }
+                if let ExprKind::Path(ref qpath) = recv.kind {
+                    if qpath_res(self.cx, qpath, recv.hir_id) == Res::Local(self.param) {
+                        self.found = Some(expr.span);
+                        return;
+                    }
+                }
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
@@ -1,23 +1,46 @@
 use crate::utils::{
-    fn_has_unsatisfiable_preds, has_drop, is_copy, is_type_diagnostic_item, match_def_path, match_type, paths,
-    snippet_opt, span_lint_hir, span_lint_hir_and_then, walk_ptrs_ty_depth,
+    attrs::get_attr, fn_has_unsatisfiable_preds, get_parent_expr, get_trait_def_id, has_drop, implements_trait,
+    is_copy, is_expn_of, is_in_test_code, is_type_diagnostic_item, match_def_path, match_function_call,
+    match_trait_method, match_type, paths, qpath_res, snippet_with_applicability, span_lint_hir,
+    span_lint_hir_and_then, sugg::Sugg, usage::is_local_used, walk_ptrs_ty_depth,
 };
 use if_chain::if_chain;
-use rustc_data_structures::{fx::FxHashMap, transitive_relation::TransitiveRelation};
+use rustc_data_structures::{
+    fx::{FxHashMap, FxHashSet},
+    transitive_relation::TransitiveRelation,
+};
 use rustc_errors::Applicability;
-use rustc_hir::intravisit::FnKind;
-use rustc_hir::{def_id, Body, FnDecl, HirId};
+use rustc_hir::def::Res;
+use rustc_hir::intravisit::{walk_expr, walk_local, walk_stmt, FnKind, NestedVisitorMap, Visitor};
+use rustc_hir::{
+    def_id, BindingAnnotation, Body, BodyId, BorrowKind, Crate, Expr, ExprKind, FnDecl, HirId, Local, MatchSource,
+    Mutability, Node, Pat, PatKind, QPath, Stmt, StmtKind, UnOp,
+};
 use rustc_index::bit_set::{BitSet, HybridBitSet};
-use rustc_lint::{LateContext, LateLintPass};
+use rustc_lint::{LateContext, LateLintPass, Lint, LintContext};
+use rustc_middle::hir::map::Map;
 use rustc_middle::mir::{
     self, traversal,
     visit::{MutatingUseContext, NonMutatingUseContext, PlaceContext, Visitor as _},
 };
-use rustc_middle::ty::{self, fold::TypeVisitor, Ty};
+use rustc_middle::ty::{
+    self,
+    adjustment::PointerCast,
+    fold::{TypeFoldable, TypeVisitor},
+    Ty, TyS,
+};
+use rustc_mir::dataflow::impls::MaybeLiveLocals;
 use rustc_mir::dataflow::{Analysis, AnalysisDomain, GenKill, GenKillAnalysis, ResultsCursor};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
-use rustc_span::source_map::{BytePos, Span};
-use std::convert::TryFrom;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::source_map::Span;
+use rustc_span::symbol::Symbol;
+use rustc_target::abi::LayoutOf;
+use std::borrow::Cow;
+use std::fs::{self, OpenOptions};
+use std::io::Write as _;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use std::{env, iter};
 
 macro_rules! unwrap_or_continue {
     ($x:expr) => {
@@ -29,8 +52,9 @@ macro_rules! unwrap_or_continue {
 }
 
 declare_clippy_lint! {
-    /// **What it does:** Checks for a redundant `clone()` (and its relatives) which clones an owned
-    /// value that is going to be dropped without further use.
+    /// **What it does:** Checks for a redundant `clone()` (and its relatives, like `to_owned()` or
+    /// `as_ref().cloned()` of an `Option`) which clones an owned value that is going to be dropped
+    /// without further use.
     ///
     /// **Why is this bad?** It is not always possible for the compiler to eliminate useless
     /// allocations and deallocations generated by redundant `clone()`s.
@@ -38,6 +62,17 @@ declare_clippy_lint! {
     /// **Known problems:**
     ///
     /// False-negatives: analysis performed by this lint is conservative and limited.
+    /// Clones passed to formatting macros like `format!`, `write!` or `println!`, used as
+    /// the scrutinee of `matches!`, only borrowed through `as_str()`, `as_ref()` or
+    /// `as_slice()` (e.g. `x.clone().as_str()`), or reborrowed (e.g. `&*x.clone()`) are always
+    /// linted, as they are only borrowed.
+    ///
+    /// Types whose `Clone` implementation has side effects (e.g. registering a handle
+    /// somewhere) can be listed in the `redundant-clone-side-effect-types` configuration
+    /// option to silence this lint for them. A single intentional clone can be kept by
+    /// annotating its statement (or, with `#![feature(stmt_expr_attributes)]`, the
+    /// expression itself) with `#[clippy::keep_clone]`. Setting the `redundant-clone-skip-tests`
+    /// configuration option skips `#[test]` and `#[bench]` functions and `#[cfg(test)]` items.
     ///
     /// **Example:**
     /// ```rust
@@ -63,10 +98,788 @@ declare_clippy_lint! {
     "`clone()` of an owned value that is going to be dropped immediately"
 }
 
-declare_lint_pass!(RedundantClone => [REDUNDANT_CLONE]);
+declare_clippy_lint! {
+    /// **What it does:** Checks for a `clone()` (and its relatives) of an owned value which is
+    /// passed to an enum variant constructor, while the original value is not used afterwards.
+    ///
+    /// **Why is this bad?** The original value can be moved into the variant instead, saving the
+    /// allocation of the clone and the deallocation of the original.
+    ///
+    /// **Known problems:** The same false-negatives as `redundant_clone` apply. `Option` and
+    /// `Result` are covered by `redundant_clone`.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// enum Message {
+    ///     Text(String),
+    /// }
+    ///
+    /// fn text() -> Message {
+    ///     let s = String::from("hello");
+    ///     Message::Text(s.clone())
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # enum Message {
+    /// #     Text(String),
+    /// # }
+    /// fn text() -> Message {
+    ///     let s = String::from("hello");
+    ///     Message::Text(s)
+    /// }
+    /// ```
+    pub REDUNDANT_CLONE_IN_ENUM_VARIANT_PAYLOAD,
+    perf,
+    "`clone()` of an owned value moved into an enum variant while the original is dropped"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for a `clone()` (and its relatives) of an owned value which is
+    /// immediately destructured by a pattern, while the original value is not used afterwards.
+    ///
+    /// **Why is this bad?** The whole value is cloned only to move some of its fields out of the
+    /// clone, the original value can be destructured instead.
+    ///
+    /// **Known problems:** The same false-negatives as `redundant_clone` apply.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// #[derive(Clone)]
+    /// struct Point(String, String);
+    ///
+    /// fn first(p: Point) -> String {
+    ///     let Point(x, _) = p.clone();
+    ///     x
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # #[derive(Clone)]
+    /// # struct Point(String, String);
+    /// fn first(p: Point) -> String {
+    ///     let Point(x, _) = p;
+    ///     x
+    /// }
+    /// ```
+    pub UNNECESSARY_CLONE_FOR_PATTERN_BINDING,
+    perf,
+    "`clone()` of an owned value which is immediately destructured while the original is dropped"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for a `clone()` of a temporary value, which was just created in
+    /// the same expression, like `"x".to_owned().clone()`, `String::new().clone()` or
+    /// `Vec::default().clone()`.
+    ///
+    /// **Why is this bad?** Nothing else can use the temporary, so it can be used directly
+    /// instead of being cloned and dropped.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let s = "x".to_owned().clone();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let s = "x".to_owned();
+    /// ```
+    pub REDUNDANT_CLONE_OF_LITERAL,
+    perf,
+    "`clone()` of a freshly created temporary value"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for a `clone()` (and its relatives) of an owned value which is
+    /// sent through a `std::sync::mpsc` channel, while the original value is not used afterwards.
+    ///
+    /// **Why is this bad?** `send` takes ownership of the value, so the original can be sent
+    /// instead of a clone of it.
+    ///
+    /// **Known problems:** The same false-negatives as `redundant_clone` apply. Only the `send`
+    /// methods of `std::sync::mpsc::Sender` and `std::sync::mpsc::SyncSender` are checked.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::sync::mpsc;
+    /// let (tx, rx) = mpsc::channel();
+    /// let s = String::from("hello");
+    /// tx.send(s.clone()).unwrap();
+    /// # rx.recv().unwrap();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::sync::mpsc;
+    /// let (tx, rx) = mpsc::channel();
+    /// let s = String::from("hello");
+    /// tx.send(s).unwrap();
+    /// # rx.recv().unwrap();
+    /// ```
+    pub REDUNDANT_CLONE_ACROSS_CHANNEL_SEND,
+    perf,
+    "`clone()` of an owned value sent through a channel while the original is dropped"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for a `clone()` (and its relatives) of an owned value which is
+    /// passed to `Entry::or_insert` of a `HashMap` or `BTreeMap`, while the original value is not
+    /// used afterwards.
+    ///
+    /// **Why is this bad?** `or_insert` takes ownership of its argument, which is evaluated
+    /// eagerly whether or not the entry is vacant. The original can be moved in instead of a
+    /// clone of it.
+    ///
+    /// **Known problems:** The same false-negatives as `redundant_clone` apply.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// let mut map = HashMap::new();
+    /// let v = vec![1, 2, 3];
+    /// map.entry("key").or_insert(v.clone());
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// let mut map = HashMap::new();
+    /// let v = vec![1, 2, 3];
+    /// map.entry("key").or_insert(v);
+    /// ```
+    pub REDUNDANT_CLONE_IN_MAP_ENTRY,
+    perf,
+    "`clone()` of an owned value passed to `Entry::or_insert` while the original is dropped"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for a `clone()` (or `to_owned()`) used as a statement on its
+    /// own, like `x.clone();`.
+    ///
+    /// **Why is this bad?** The clone is dropped right away at the end of the statement, so
+    /// the statement does nothing but allocate and free memory. It is usually left over from
+    /// a refactoring or meant to be assigned to something.
+    ///
+    /// **Known problems:** The types listed in the `redundant-clone-side-effect-types`
+    /// configuration are not linted.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let v = vec![1, 2, 3];
+    /// v.clone();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let v = vec![1, 2, 3];
+    /// ```
+    pub CLONE_THEN_DROP_SAME_STATEMENT,
+    complexity,
+    "`clone()` whose result is dropped at the end of the same statement"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for a `clone()` (and its relatives) of an owned value into a
+    /// new binding, which is then sorted or deduplicated in place, while the original value is
+    /// not used afterwards.
+    ///
+    /// **Why is this bad?** Sorting and deduplication mutate the clone in place. As the
+    /// original is dropped without further use, it can be moved into the binding and mutated
+    /// instead.
+    ///
+    /// **Known problems:** The same false-negatives as `redundant_clone` apply. Only a call to
+    /// one of the `sort*` or `dedup*` methods of `Vec` in the statement right after the binding
+    /// is recognized.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn unique(orig: Vec<u32>) -> Vec<u32> {
+    ///     let mut v = orig.clone();
+    ///     v.sort();
+    ///     v.dedup();
+    ///     v
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn unique(orig: Vec<u32>) -> Vec<u32> {
+    ///     let mut v = orig;
+    ///     v.sort();
+    ///     v.dedup();
+    ///     v
+    /// }
+    /// ```
+    pub REDUNDANT_CLONE_IN_SORT_DEDUP,
+    perf,
+    "`clone()` of an owned value which is sorted or deduplicated while the original is dropped"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for a `clone()` (and its relatives) of an owned value which is
+    /// passed to a generic parameter bounded by `Into`, like `x: impl Into<String>`, while the
+    /// original value is not used afterwards.
+    ///
+    /// **Why is this bad?** The parameter takes ownership of its argument to convert it. The
+    /// original can be moved in instead of a clone of it.
+    ///
+    /// **Known problems:** The same false-negatives as `redundant_clone` apply.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn greet(name: impl Into<String>) -> String {
+    ///     format!("hello {}", name.into())
+    /// }
+    ///
+    /// let name = String::from("world");
+    /// greet(name.clone());
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn greet(name: impl Into<String>) -> String {
+    ///     format!("hello {}", name.into())
+    /// }
+    ///
+    /// let name = String::from("world");
+    /// greet(name);
+    /// ```
+    pub NEEDLESS_CLONE_FOR_INTO_PARAM,
+    perf,
+    "`clone()` of an owned value passed to an `Into`-bounded parameter while the original is dropped"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for a `clone()` (and its relatives) of an owned value which is
+    /// passed to a recursive call of the function itself, while the original value is not used
+    /// afterwards.
+    ///
+    /// **Why is this bad?** The original is dropped once the recursive call returns, so it can
+    /// be moved into the call instead of a clone of it. As the clone is made at each level of
+    /// the recursion, this can add up quickly.
+    ///
+    /// **Known problems:** The same false-negatives as `redundant_clone` apply. Only direct
+    /// recursion is recognized, not calls through other functions or closures.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn count_down(n: u32, path: Vec<u32>) -> Vec<u32> {
+    ///     if n == 0 {
+    ///         return path;
+    ///     }
+    ///     let mut path = path;
+    ///     path.push(n);
+    ///     count_down(n - 1, path.clone())
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn count_down(n: u32, path: Vec<u32>) -> Vec<u32> {
+    ///     if n == 0 {
+    ///         return path;
+    ///     }
+    ///     let mut path = path;
+    ///     path.push(n);
+    ///     count_down(n - 1, path)
+    /// }
+    /// ```
+    pub REDUNDANT_CLONE_IN_RECURSIVE_CALL,
+    perf,
+    "`clone()` of an owned value passed to a recursive call while the original is dropped"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `unwrap_or_else` calls on `Option` or `Result` whose closure
+    /// only clones a local, like `opt.unwrap_or_else(|| x.clone())`, while the local is not used
+    /// afterwards.
+    ///
+    /// **Why is this bad?** The closure defers the clone until it is needed, but the local is
+    /// dropped anyway. Passing it to `unwrap_or` moves it without cloning, and evaluating a local
+    /// eagerly costs nothing.
+    ///
+    /// **Known problems:** Only closures consisting of a single `clone()` of a local are
+    /// recognized, anything else may be expensive or have side effects and is better evaluated
+    /// lazily. Calls in loops are not linted. A borrow of the local which is still alive at the
+    /// call is not detected, the suggestion does not compile then.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn name(opt: Option<String>) -> String {
+    ///     let default = String::from("anonymous");
+    ///     opt.unwrap_or_else(|| default.clone())
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn name(opt: Option<String>) -> String {
+    ///     let default = String::from("anonymous");
+    ///     opt.unwrap_or(default)
+    /// }
+    /// ```
+    pub CLONE_INSIDE_UNWRAP_OR_ELSE_CLOSURE,
+    perf,
+    "`unwrap_or_else` with a closure cloning a local which is dropped afterwards"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for a `clone()` (and its relatives) of an owned value which is
+    /// coerced to a trait object, like `Box::new(x.clone()) as Box<dyn Trait>`, while the
+    /// original value is not used afterwards.
+    ///
+    /// **Why is this bad?** The original is dropped right after the clone, so it can be moved
+    /// into the trait object instead.
+    ///
+    /// **Known problems:** The same false-negatives as `redundant_clone` apply. Only values
+    /// coerced directly, or right after being passed to a constructor like `Box::new` or
+    /// `Rc::new`, are recognized.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::fmt::Display;
+    /// fn boxed(name: String) -> Box<dyn Display> {
+    ///     Box::new(name.clone())
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::fmt::Display;
+    /// fn boxed(name: String) -> Box<dyn Display> {
+    ///     Box::new(name)
+    /// }
+    /// ```
+    pub REDUNDANT_CLONE_FOR_TRAIT_OBJECT_COERCION,
+    perf,
+    "`clone()` of an owned value coerced to a trait object while the original is dropped"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for a `clone()` (and its relatives) of an owned value which is
+    /// passed by value to a builder method named `with_*` or `set_*`, like
+    /// `builder.with_name(name.clone())`, while the original value is not used afterwards.
+    ///
+    /// **Why is this bad?** The builder method takes ownership of its argument, so the original
+    /// can be moved into it instead of being dropped right after the clone.
+    ///
+    /// **Known problems:** The same false-negatives as `redundant_clone` apply. Builder methods
+    /// are only recognized by their name.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # #[derive(Default)]
+    /// # struct Request { url: String }
+    /// # impl Request {
+    /// #     fn with_url(mut self, url: String) -> Self { self.url = url; self }
+    /// # }
+    /// fn request(url: String) -> Request {
+    ///     Request::default().with_url(url.clone())
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # #[derive(Default)]
+    /// # struct Request { url: String }
+    /// # impl Request {
+    /// #     fn with_url(mut self, url: String) -> Self { self.url = url; self }
+    /// # }
+    /// fn request(url: String) -> Request {
+    ///     Request::default().with_url(url)
+    /// }
+    /// ```
+    pub REDUNDANT_CLONE_IN_BUILDER_WITH_METHOD,
+    perf,
+    "`clone()` of an owned value passed to a `with_*` or `set_*` builder method while the original is dropped"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for collections built by cloning every element of a collection
+    /// of the same type, like `v.iter().map(|x| x.clone()).collect::<Vec<_>>()`.
+    ///
+    /// **Why is this bad?** This is a roundabout way to clone the collection. If the original
+    /// collection is not used afterwards, it can even be moved instead of cloned.
+    ///
+    /// **Known problems:** Cloning a `HashSet` keeps its hasher, while collecting into a new one
+    /// creates a new hasher.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn names(names: &Vec<String>) -> Vec<String> {
+    ///     names.iter().map(|name| name.clone()).collect()
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn names(names: &Vec<String>) -> Vec<String> {
+    ///     names.clone()
+    /// }
+    /// ```
+    pub REDUNDANT_CLONE_INTO_OWNED_COLLECTION_FROM_ITER,
+    complexity,
+    "collecting the clones of the elements of a collection into a collection of the same type"
+}
+
+pub struct RedundantClone {
+    side_effect_types: Vec<String>,
+    skip_tests: bool,
+    profile: Option<Profile>,
+}
+
+impl RedundantClone {
+    pub fn new(side_effect_types: Vec<String>, skip_tests: bool) -> Self {
+        Self {
+            side_effect_types,
+            skip_tests,
+            profile: env::var_os("CLIPPY_PROFILE_REDUNDANT_CLONE").map(|path| Profile {
+                path: path.into(),
+                bodies: Vec::new(),
+            }),
+        }
+    }
+
+    /// Whether the `clone` of `def_id` has side effects according to the configuration.
+    fn has_side_effects(&self, cx: &LateContext<'_>, def_id: def_id::DefId) -> bool {
+        self.side_effect_types
+            .iter()
+            .any(|path| match_def_path(cx, def_id, &path.split("::").collect::<Vec<_>>()))
+    }
+
+    /// Finds the value cloned by a `clone`-like call terminating `bb`, if the call clones a value
+    /// which can be moved instead: no other borrow of it is alive at the call.
+    fn find_cloned_local<'tcx>(
+        &self,
+        cx: &LateContext<'tcx>,
+        mir: &'tcx mir::Body<'tcx>,
+        possible_borrower: &mut PossibleBorrowerMap<'_, 'tcx>,
+        bb: mir::BasicBlock,
+        (fn_def_id, arg, arg_ty, clone_ret): (def_id::DefId, mir::Local, Ty<'tcx>, mir::Local),
+    ) -> Option<ClonedLocal<'tcx>> {
+        let from_borrow = match_def_path(cx, fn_def_id, &paths::CLONE_TRAIT_METHOD)
+            || match_def_path(cx, fn_def_id, &paths::TO_OWNED_METHOD)
+            || (match_def_path(cx, fn_def_id, &paths::TO_STRING_METHOD)
+                && is_type_diagnostic_item(cx, arg_ty, sym!(string_type)));
+
+        let from_deref = !from_borrow
+            && (match_def_path(cx, fn_def_id, &paths::PATH_TO_PATH_BUF)
+                || match_def_path(cx, fn_def_id, &paths::OS_STR_TO_OS_STRING));
+
+        let from_as_ref = match_def_path(cx, fn_def_id, &paths::OPTION_CLONED);
+
+        if !from_borrow && !from_deref && !from_as_ref {
+            return None;
+        }
+
+        if let ty::Adt(ref def, _) = arg_ty.kind() {
+            if match_def_path(cx, def.did, &paths::MEM_MANUALLY_DROP) || self.has_side_effects(cx, def.did) {
+                return None;
+            }
+        }
+
+        // `{ cloned = &arg; clone(move cloned); }` or `{ cloned = &arg; to_path_buf(cloned); }`, while
+        // for `opt.as_ref().cloned()` the argument is the result of `as_ref` in the previous block
+        let (cloned, upvar, cannot_move_out, temporaries) = if from_as_ref {
+            (arg, None, false, Vec::new())
+        } else {
+            find_stmt_assigns_to(cx, mir, arg, from_borrow, bb)?
+        };
+
+        // `Local` to be cloned, and a local of `clone` call's destination
+        let (local, upvar, ret_local) = if from_borrow {
+            // `res = clone(arg)` can be turned into `res = move arg;`
+            // if `arg` is the only borrow of `cloned` at this point.
+
+            let loc = mir::Location {
+                block: bb,
+                statement_index: mir.basic_blocks()[bb].statements.len(),
+            };
+            let borrowers = iter::once(arg).chain(temporaries).collect::<Vec<_>>();
+            if cannot_move_out || !possible_borrower.only_borrowers(&borrowers, cloned, loc) {
+                return None;
+            }
+
+            (cloned, upvar, clone_ret)
+        } else {
+            find_deref_source(cx, mir, possible_borrower, bb, from_as_ref, (arg, cloned, clone_ret))?
+        };
+
+        Some(ClonedLocal {
+            fn_def_id,
+            ty: arg_ty,
+            local,
+            upvar,
+            ret_local,
+            clone_ret,
+        })
+    }
+
+    /// Lints clones passed as arguments to the formatting macros, as the scrutinee of `matches!`
+    /// or as the receiver of a borrowing method, which only borrow them. Returns the spans of the
+    /// linted `clone` calls.
+    fn lint_only_borrowed_clones<'tcx>(
+        &self,
+        cx: &LateContext<'tcx>,
+        body: &'tcx Body<'_>,
+        keep_clone_spans: &[Span],
+    ) -> FxHashSet<Span> {
+        let mut visitor = OnlyBorrowedVisitor { cx, args: Vec::new() };
+        visitor.visit_expr(&body.value);
+
+        let mut linted = FxHashSet::default();
+        for (arg, kind) in visitor.args {
+            if_chain! {
+                if !arg.span.from_expansion();
+                if !keep_clone_spans.iter().any(|span| span.contains(arg.span));
+                if let ExprKind::MethodCall(_, _, ref args, _) = arg.kind;
+                if !args[0].span.from_expansion();
+                if let Some(fn_def_id) = cx.typeck_results().type_dependent_def_id(arg.hir_id);
+                if match_def_path(cx, fn_def_id, &paths::CLONE_TRAIT_METHOD)
+                    || match_def_path(cx, fn_def_id, &paths::TO_OWNED_METHOD);
+                let ty = cx.typeck_results().expr_ty(arg);
+                if !is_copy(cx, ty);
+                if !matches!(ty.kind(), ty::Adt(def, _)
+                    if match_def_path(cx, def.did, &paths::MEM_MANUALLY_DROP) || self.has_side_effects(cx, def.did));
+                then {
+                    let mut app = match (kind, cx.typeck_results().expr_ty(&args[0]).kind()) {
+                        // Patterns matching on `T` may not match on `&T`
+                        (OnlyBorrowed::MatchesScrutinee, ty::Ref(..)) => Applicability::MaybeIncorrect,
+                        // The original may be moved or mutably borrowed later in the statement, while
+                        // the borrow of the clone is still alive
+                        (OnlyBorrowed::BorrowingMethodReceiver, _) | (OnlyBorrowed::Reborrowed, _)
+                            if is_used_elsewhere_in_stmt(cx, arg, &args[0]) =>
+                        {
+                            Applicability::MaybeIncorrect
+                        },
+                        _ => Applicability::MachineApplicable,
+                    };
+                    let (sugg_span, sugg_msg, sugg) = if let OnlyBorrowed::Reborrowed = kind {
+                        unwrap_or_continue!(reborrow_sugg(cx, arg, &args[0], &mut app))
+                    } else {
+                        (arg.span.with_lo(args[0].span.hi()), "remove this", String::new())
+                    };
+                    let estimate = allocation_estimate(cx, ty);
+                    span_lint_hir_and_then(cx, REDUNDANT_CLONE, arg.hir_id, sugg_span, "redundant clone", |diag| {
+                        diag.span_suggestion(sugg_span, sugg_msg, sugg, app);
+                        if let Some(estimate) = estimate {
+                            diag.note(&format!("removing the clone avoids allocating {}", estimate));
+                        }
+                        diag.span_note(arg.span, kind.note());
+                    });
+                    linted.insert(arg.span);
+                }
+            }
+        }
+        linted
+    }
+
+    /// Lints clones of values freshly created by `Default::default()`, like
+    /// `Vec::default().clone()`, which are not in `linted` yet. Adds the spans of the linted
+    /// `clone` calls to `linted`.
+    fn lint_default_clones<'tcx>(
+        &self,
+        cx: &LateContext<'tcx>,
+        body: &'tcx Body<'_>,
+        keep_clone_spans: &[Span],
+        linted: &mut FxHashSet<Span>,
+    ) {
+        let mut visitor = DefaultCloneVisitor { cx, clones: Vec::new() };
+        visitor.visit_expr(&body.value);
+
+        for clone in visitor.clones {
+            if_chain! {
+                if !linted.contains(&clone.span);
+                if !keep_clone_spans.iter().any(|span| span.contains(clone.span));
+                if let ExprKind::MethodCall(_, _, ref args, _) = clone.kind;
+                let ty = cx.typeck_results().expr_ty(clone);
+                if !is_copy(cx, ty);
+                if !matches!(ty.kind(), ty::Adt(def, _) if self.has_side_effects(cx, def.did));
+                then {
+                    let sugg_span = clone.span.with_lo(args[0].span.hi());
+                    span_lint_hir_and_then(
+                        cx,
+                        REDUNDANT_CLONE_OF_LITERAL,
+                        clone.hir_id,
+                        sugg_span,
+                        "redundant clone",
+                        |diag| {
+                            diag.span_suggestion(
+                                sugg_span,
+                                "remove this",
+                                String::new(),
+                                Applicability::MachineApplicable,
+                            );
+                            diag.span_note(args[0].span, "this value is freshly created by `Default::default()`");
+                        },
+                    );
+                    linted.insert(clone.span);
+                }
+            }
+        }
+    }
+
+    /// Lints `x.clone();` statements, which are not in `linted` yet. Adds the spans of the linted
+    /// `clone` calls to `linted`.
+    fn lint_dropped_clones<'tcx>(
+        &self,
+        cx: &LateContext<'tcx>,
+        body: &'tcx Body<'_>,
+        keep_clone_spans: &[Span],
+        linted: &mut FxHashSet<Span>,
+    ) {
+        let mut visitor = DroppedCloneVisitor { cx, stmts: Vec::new() };
+        visitor.visit_expr(&body.value);
+
+        for (stmt, clone) in visitor.stmts {
+            if_chain! {
+                if !linted.contains(&clone.span);
+                if !keep_clone_spans.iter().any(|span| span.contains(clone.span));
+                if let ExprKind::MethodCall(_, _, ref args, _) = clone.kind;
+                let ty = cx.typeck_results().expr_ty(clone);
+                if !is_copy(cx, ty);
+                if !matches!(ty.kind(), ty::Adt(def, _) if self.has_side_effects(cx, def.did));
+                then {
+                    // Removing the statement also removes the evaluation of the receiver
+                    let app = if is_side_effect_free_place(&args[0]) {
+                        Applicability::MachineApplicable
+                    } else {
+                        Applicability::MaybeIncorrect
+                    };
+                    span_lint_hir_and_then(
+                        cx,
+                        CLONE_THEN_DROP_SAME_STATEMENT,
+                        clone.hir_id,
+                        stmt.span,
+                        "the result of this clone is dropped immediately",
+                        |diag| {
+                            diag.span_suggestion(stmt.span, "remove this statement", String::new(), app);
+                        },
+                    );
+                    linted.insert(clone.span);
+                }
+            }
+        }
+    }
+
+    /// Lints `unwrap_or_else` calls whose closure only clones a local, which is not used after
+    /// the call.
+    fn lint_unwrap_or_else_clones<'tcx>(
+        &self,
+        cx: &LateContext<'tcx>,
+        body: &'tcx Body<'_>,
+        keep_clone_spans: &[Span],
+    ) {
+        let mut visitor = UnwrapOrElseCloneVisitor { cx, calls: Vec::new() };
+        visitor.visit_expr(&body.value);
+
+        let body_owner = cx.tcx.hir().body_owner(body.id());
+        for (call, clone, local_id) in visitor.calls {
+            if_chain! {
+                if !keep_clone_spans.iter().any(|span| span.contains(clone.span));
+                if let ExprKind::MethodCall(ref path, _, ref args, _) = call.kind;
+                let ty = cx.typeck_results().expr_ty(clone);
+                if !is_copy(cx, ty);
+                if !matches!(ty.kind(), ty::Adt(def, _)
+                    if match_def_path(cx, def.did, &paths::MEM_MANUALLY_DROP) || self.has_side_effects(cx, def.did));
+                // Locals of an enclosing function are captured by the closure being checked
+                if cx.tcx.hir().enclosing_body_owner(local_id) == body_owner;
+                // The receiver must not borrow the local, which is moved by `unwrap_or`
+                if !is_local_used(cx, &args[0], local_id);
+                if !is_in_loop(cx, call);
+                if !is_local_used_after(cx, body, call, local_id);
+                then {
+                    let sugg_span = call.span.with_lo(path.ident.span.lo());
+                    let mut app = Applicability::MachineApplicable;
+                    let local = snippet_with_applicability(cx, clone_receiver(clone).span, "..", &mut app);
+                    span_lint_hir_and_then(
+                        cx,
+                        CLONE_INSIDE_UNWRAP_OR_ELSE_CLOSURE,
+                        call.hir_id,
+                        sugg_span,
+                        "redundant clone inside the closure of `unwrap_or_else`",
+                        |diag| {
+                            diag.span_suggestion(
+                                sugg_span,
+                                "move the value into `unwrap_or` instead",
+                                format!("unwrap_or({})", local),
+                                app,
+                            );
+                            diag.span_note(clone_receiver(clone).span, "this value is dropped without further use");
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    /// Lints `v.iter().map(|x| x.clone()).collect()` into the type of `v`, which is `v.clone()`,
+    /// or `v` if the collection is an owned local which is not used afterwards.
+    fn lint_cloned_collections<'tcx>(&self, cx: &LateContext<'tcx>, body: &'tcx Body<'_>, keep_clone_spans: &[Span]) {
+        let mut visitor = ClonedCollectionVisitor {
+            cx,
+            collects: Vec::new(),
+        };
+        visitor.visit_expr(&body.value);
+
+        let body_owner = cx.tcx.hir().body_owner(body.id());
+        for (collect, collection) in visitor.collects {
+            let ty = cx.typeck_results().expr_ty(collect);
+            if keep_clone_spans.iter().any(|span| span.contains(collect.span))
+                || matches!(ty.kind(), ty::Adt(def, _) if self.has_side_effects(cx, def.did))
+            {
+                continue;
+            }
+
+            let moved = match collection.kind {
+                ExprKind::Path(ref qpath) => match qpath_res(cx, qpath, collection.hir_id) {
+                    Res::Local(local_id) => {
+                        !matches!(cx.typeck_results().expr_ty(collection).kind(), ty::Ref(..))
+                            && cx.tcx.hir().enclosing_body_owner(local_id) == body_owner
+                            && !is_in_loop(cx, collect)
+                            && !is_local_used_after(cx, body, collect, local_id)
+                    },
+                    _ => false,
+                },
+                _ => false,
+            };
+
+            let mut app = Applicability::MachineApplicable;
+            let collection_snip = snippet_with_applicability(cx, collection.span, "..", &mut app);
+            let (help, sugg) = if moved {
+                (
+                    format!("`{}` is not used afterwards, move it instead", collection_snip),
+                    collection_snip.to_string(),
+                )
+            } else {
+                (
+                    "clone the collection instead".to_string(),
+                    format!("{}.clone()", collection_snip),
+                )
+            };
+            span_lint_hir_and_then(
+                cx,
+                REDUNDANT_CLONE_INTO_OWNED_COLLECTION_FROM_ITER,
+                collect.hir_id,
+                collect.span,
+                "collecting the clones of all the elements of a collection into a collection of the same type",
+                |diag| {
+                    diag.span_suggestion(collect.span, &help, sugg, app);
+                },
+            );
+        }
+    }
+}
+
+impl_lint_pass!(RedundantClone => [
+    REDUNDANT_CLONE,
+    REDUNDANT_CLONE_IN_ENUM_VARIANT_PAYLOAD,
+    UNNECESSARY_CLONE_FOR_PATTERN_BINDING,
+    REDUNDANT_CLONE_OF_LITERAL,
+    REDUNDANT_CLONE_ACROSS_CHANNEL_SEND,
+    REDUNDANT_CLONE_IN_MAP_ENTRY,
+    CLONE_THEN_DROP_SAME_STATEMENT,
+    REDUNDANT_CLONE_IN_SORT_DEDUP,
+    NEEDLESS_CLONE_FOR_INTO_PARAM,
+    REDUNDANT_CLONE_IN_RECURSIVE_CALL,
+    CLONE_INSIDE_UNWRAP_OR_ELSE_CLOSURE,
+    REDUNDANT_CLONE_FOR_TRAIT_OBJECT_COERCION,
+    REDUNDANT_CLONE_IN_BUILDER_WITH_METHOD,
+    REDUNDANT_CLONE_INTO_OWNED_COLLECTION_FROM_ITER,
+]);
 
 impl<'tcx> LateLintPass<'tcx> for RedundantClone {
-    #[allow(clippy::too_many_lines)]
     fn check_fn(
         &mut self,
         cx: &LateContext<'tcx>,
@@ -74,8 +887,12 @@ impl<'tcx> LateLintPass<'tcx> for RedundantClone {
         _: &'tcx FnDecl<'_>,
         body: &'tcx Body<'_>,
         _: Span,
-        _: HirId,
+        hir_id: HirId,
     ) {
+        if self.skip_tests && is_in_test_code(cx, hir_id) {
+            return;
+        }
+
         let def_id = cx.tcx.hir().body_owner_def_id(body.id());
 
         // Building MIR for `fn`s with unsatisfiable preds results in ICE.
@@ -83,200 +900,1138 @@ impl<'tcx> LateLintPass<'tcx> for RedundantClone {
             return;
         }
 
+        let start = self.profile.as_ref().map(|_| Instant::now());
+        let mut candidates = 0;
+
+        let keep_clone_spans = keep_clone_spans(cx, def_id.to_def_id());
+
+        // Already linted, the MIR analysis below must not report them a second time
+        let mut linted_clones = self.lint_only_borrowed_clones(cx, body, &keep_clone_spans);
+        self.lint_default_clones(cx, body, &keep_clone_spans, &mut linted_clones);
+        self.lint_dropped_clones(cx, body, &keep_clone_spans, &mut linted_clones);
+        self.lint_unwrap_or_else_clones(cx, body, &keep_clone_spans);
+        self.lint_cloned_collections(cx, body, &keep_clone_spans);
+
         let mir = cx.tcx.optimized_mir(def_id.to_def_id());
 
         let maybe_storage_live_result = MaybeStorageLive
             .into_engine(cx.tcx, mir, def_id.to_def_id())
             .iterate_to_fixpoint()
             .into_results_cursor(mir);
+        let maybe_live_result = MaybeLiveLocals
+            .into_engine(cx.tcx, mir, def_id.to_def_id())
+            .iterate_to_fixpoint()
+            .into_results_cursor(mir);
         let mut possible_borrower = {
             let mut vis = PossibleBorrowerVisitor::new(cx, mir);
             vis.visit_body(&mir);
-            vis.into_map(cx, maybe_storage_live_result)
+            vis.into_map(cx, maybe_storage_live_result, maybe_live_result)
+        };
+
+        let mut found = Vec::new();
+        for (bb, bbdata) in mir.basic_blocks().iter_enumerated() {
+            let terminator = bbdata.terminator();
+
+            if terminator.source_info.span.from_expansion()
+                || linted_clones.contains(&terminator.source_info.span)
+                || keep_clone_spans
+                    .iter()
+                    .any(|span| span.contains(terminator.source_info.span))
+            {
+                continue;
+            }
+
+            // Give up on loops
+            if terminator.successors().any(|s| *s == bb) {
+                continue;
+            }
+
+            let call =
+                is_call_with_ref_arg(cx, mir, &terminator.kind).or_else(|| is_option_cloned(cx, mir, &terminator.kind));
+            let call = unwrap_or_continue!(call);
+            candidates += 1;
+
+            let cloned = unwrap_or_continue!(self.find_cloned_local(cx, mir, &mut possible_borrower, bb, call));
+            let (used, consumed_or_mutated) = find_uses_after_clone(mir, bb, &cloned);
+
+            if !used || !consumed_or_mutated {
+                let span = terminator.source_info.span;
+                let scope = terminator.source_info.scope;
+                found.push(FoundClone {
+                    local: cloned.local,
+                    node: scope_lint_root(&mir.source_scopes[scope].local_data, hir_id),
+                    span,
+                    call: clone_call(cx, body.id(), span),
+                    clone_use: CloneUse::of(cx, mir, body.id(), terminator, &cloned, used),
+                    estimate: allocation_estimate(cx, mir.local_decls[cloned.clone_ret].ty),
+                });
+            }
+        }
+
+        emit_redundant_clones(cx, &found);
+
+        if let (Some(profile), Some(start)) = (&mut self.profile, start) {
+            profile.bodies.push(BodyProfile {
+                name: cx.tcx.def_path_str(def_id.to_def_id()),
+                duration: start.elapsed(),
+                candidates,
+            });
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>, _: &'tcx Crate<'_>) {
+        if let Some(profile) = &mut self.profile {
+            profile.write(cx);
+        }
+    }
+}
+
+/// Instrumentation of the analysis, to find the bodies it is slow on. It is enabled by setting the
+/// `CLIPPY_PROFILE_REDUNDANT_CLONE` environment variable to the path of a file, to which a summary
+/// is appended once the crate is checked. The file and its directory are created if needed.
+struct Profile {
+    path: PathBuf,
+    bodies: Vec<BodyProfile>,
+}
+
+/// The time spent on a body and the number of `clone` calls considered in its MIR.
+struct BodyProfile {
+    name: String,
+    duration: Duration,
+    candidates: usize,
+}
+
+impl Profile {
+    /// Appends the summary to the file, slowest bodies first. Diagnostics are not affected, a
+    /// failure to write is only reported as a warning.
+    fn write(&mut self, cx: &LateContext<'_>) {
+        self.bodies.sort_by(|a, b| b.duration.cmp(&a.duration));
+        let mut summary = format!(
+            "redundant_clone profile of `{}`: {} bodies, {} candidates, {:.3}ms\n",
+            cx.tcx.crate_name(def_id::LOCAL_CRATE),
+            self.bodies.len(),
+            self.bodies.iter().map(|body| body.candidates).sum::<usize>(),
+            millis(self.bodies.iter().map(|body| body.duration).sum()),
+        );
+        for body in &self.bodies {
+            summary.push_str(&format!(
+                "{:>12.3}ms {:>6} candidates  {}\n",
+                millis(body.duration),
+                body.candidates,
+                body.name
+            ));
+        }
+
+        let written = self
+            .path
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| OpenOptions::new().create(true).append(true).open(&self.path))
+            .and_then(|mut file| file.write_all(summary.as_bytes()));
+        if let Err(err) = written {
+            cx.sess().warn(&format!(
+                "could not write the `redundant_clone` profile to `{}`: {}",
+                self.path.display(),
+                err
+            ));
+        }
+        self.bodies.clear();
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+/// Returns the node whose lint levels apply in a source scope. The scope data is cleared in MIR
+/// loaded from other crates, the lint levels of `fallback`, the function, are used then.
+fn scope_lint_root(local_data: &mir::ClearCrossCrate<mir::SourceScopeLocalData>, fallback: HirId) -> HirId {
+    match local_data {
+        mir::ClearCrossCrate::Set(data) => data.lint_root,
+        mir::ClearCrossCrate::Clear => fallback,
+    }
+}
+
+/// The value cloned by a `clone`-like call in MIR, which can be moved instead.
+struct ClonedLocal<'tcx> {
+    /// The `clone`-like function
+    fn_def_id: def_id::DefId,
+    /// The type of the cloned value
+    ty: Ty<'tcx>,
+    /// The cloned local
+    local: mir::Local,
+    /// If `local` is a closure environment, the cloned upvar
+    upvar: Upvar,
+    /// The clone, whose uses are checked. This is the result of `deref()` for `to_path_buf()` and
+    /// `to_os_string()`.
+    ret_local: mir::Local,
+    /// The destination of the call
+    clone_ret: mir::Local,
+}
+
+/// For `to_path_buf()` and `to_os_string()` of a value which is `deref()`ed in the predecessor
+/// block, or for `opt.as_ref().cloned()`, finds the receiver of `deref()` or `as_ref()`. Returns
+/// it if it can be moved instead, along with the local whose uses are checked.
+fn find_deref_source<'tcx>(
+    cx: &LateContext<'tcx>,
+    mir: &'tcx mir::Body<'tcx>,
+    possible_borrower: &mut PossibleBorrowerMap<'_, 'tcx>,
+    bb: mir::BasicBlock,
+    from_as_ref: bool,
+    (arg, cloned, clone_ret): (mir::Local, mir::Local, mir::Local),
+) -> Option<(mir::Local, Upvar, mir::Local)> {
+    // `arg` is a reference as it is `.deref()`ed in the previous block, or an `Option` of
+    // a reference returned by `.as_ref()`. Look into the predecessor block and find out
+    // the source of deref.
+
+    let ps = &mir.predecessors()[bb];
+    if ps.len() != 1 {
+        return None;
+    }
+    let pred_terminator = mir[ps[0]].terminator();
+
+    // receiver of the `deref()` or `as_ref()` call
+    let (pred_arg, deref_clone_ret) = if_chain! {
+        if let Some((pred_fn_def_id, pred_arg, pred_arg_ty, res)) =
+            is_call_with_ref_arg(cx, mir, &pred_terminator.kind);
+        if res == cloned;
+        if (from_as_ref && match_def_path(cx, pred_fn_def_id, &paths::OPTION_AS_REF))
+            || (!from_as_ref
+                && match_def_path(cx, pred_fn_def_id, &paths::DEREF_TRAIT_METHOD)
+                && (match_type(cx, pred_arg_ty, &paths::PATH_BUF)
+                    || match_type(cx, pred_arg_ty, &paths::OS_STRING)));
+        then {
+            (pred_arg, if from_as_ref { clone_ret } else { res })
+        } else {
+            return None;
+        }
+    };
+
+    let (local, upvar, cannot_move_out, temporaries) = find_stmt_assigns_to(cx, mir, pred_arg, true, ps[0])?;
+    let loc = mir::Location {
+        block: bb,
+        statement_index: mir.basic_blocks()[bb].statements.len(),
+    };
+
+    // This can be turned into `res = move local` if `arg` and `cloned` are not borrowed
+    // at the last statement:
+    //
+    // ```
+    // pred_arg = &local;
+    // cloned = deref(pred_arg);
+    // arg = &cloned;
+    // StorageDead(pred_arg);
+    // res = to_path_buf(cloned);
+    // ```
+    let borrowers = [arg, cloned].iter().copied().chain(temporaries).collect::<Vec<_>>();
+    if cannot_move_out || !possible_borrower.only_borrowers(&borrowers, local, loc) {
+        return None;
+    }
+
+    Some((local, upvar, deref_clone_ret))
+}
+
+/// Returns whether the cloned local is used after the `clone` call terminating `bb`, and whether
+/// the clone is consumed or mutated.
+///
+/// 1. The cloned local can be moved out if it is not used later. Every block reachable from the
+/// `clone` call is visited, so a use on any path, e.g. in only one branch of an `if`, counts.
+/// 2. If the clone is a temporary and is neither consumed nor mutated, the `clone` call can be
+/// removed anyway.
+fn find_uses_after_clone(mir: &mir::Body<'_>, bb: mir::BasicBlock, cloned: &ClonedLocal<'_>) -> (bool, bool) {
+    let bbdata = &mir.basic_blocks()[bb];
+    let is_temp = mir.local_kind(cloned.ret_local) == mir::LocalKind::Temp;
+    traversal::ReversePostorder::new(mir, bb)
+        .skip(1)
+        .fold((false, !is_temp), |(used, consumed), (tbb, tdata)| {
+            // Short-circuit
+            if used && consumed {
+                return (true, true);
+            }
+
+            let mut vis = LocalUseVisitor {
+                used: (cloned.local, false),
+                upvar: cloned.upvar,
+                consumed_or_mutated: (cloned.ret_local, false),
+                overwritten: false,
+            };
+            vis.visit_basic_block_data(tbb, tdata);
+
+            // A back-edge of a loop re-enters the block of the `clone` call, whose statements
+            // come before the call and are not visited otherwise. Paths which leave the loop,
+            // e.g. with `break x.clone()`, have no such edge.
+            if tdata.terminator().successors().any(|s| *s == bb) {
+                for (statement_index, statement) in bbdata.statements.iter().enumerate() {
+                    vis.visit_statement(
+                        statement,
+                        mir::Location {
+                            block: bb,
+                            statement_index,
+                        },
+                    );
+                }
+            }
+
+            (used || vis.used.1, consumed || vis.consumed_or_mutated.1)
+        })
+}
+
+/// How the clone found by a `ClonedLocal` is used. Apart from `NotConsumed`, the original value is
+/// dropped without further use, and the note tells how it can be used instead of the clone.
+enum CloneUse {
+    /// The clone is neither consumed nor mutated
+    NotConsumed,
+    /// `"x".to_owned().clone()`, nothing else can use the temporary
+    OfTemporary,
+    /// Moved into a field of an enum variant
+    Payload(def_id::DefId, Symbol),
+    /// Immediately destructured by a pattern
+    Destructured,
+    /// Sent through a `std::sync::mpsc` channel
+    Sent,
+    /// Passed to `Entry::or_insert` of a `HashMap` or `BTreeMap`
+    Inserted,
+    /// Passed to `mem::replace`
+    Replaced,
+    /// The left-hand side of a `+` concatenating strings
+    Concatenated,
+    /// Sorted or deduplicated in place
+    Sorted,
+    /// Passed to an `Into`-bounded parameter
+    Converted,
+    /// Passed to a recursive call of the function itself
+    Recursive,
+    /// Coerced to a trait object
+    Coerced,
+    /// Passed to a `with_*` or `set_*` builder method
+    Builder(Symbol),
+    /// Nothing more specific applies
+    Dropped,
+}
+
+impl CloneUse {
+    fn of<'tcx>(
+        cx: &LateContext<'tcx>,
+        mir: &'tcx mir::Body<'tcx>,
+        body_id: BodyId,
+        terminator: &'tcx mir::Terminator<'tcx>,
+        cloned: &ClonedLocal<'tcx>,
+        used: bool,
+    ) -> Self {
+        let kind = &terminator.kind;
+        let ret_local = cloned.ret_local;
+        if used {
+            Self::NotConsumed
+        } else if match_def_path(cx, cloned.fn_def_id, &paths::CLONE_TRAIT_METHOD)
+            && mir.local_kind(cloned.local) == mir::LocalKind::Temp
+        {
+            Self::OfTemporary
+        } else if let Some((variant, field)) = moved_into_enum_variant(cx, mir, kind, ret_local) {
+            Self::Payload(variant, field)
+        } else if is_destructured(mir, kind, ret_local) {
+            Self::Destructured
+        } else if is_moved_into_call(
+            cx,
+            mir,
+            kind,
+            ret_local,
+            &[&paths::SENDER_SEND, &paths::SYNC_SENDER_SEND],
+        ) {
+            Self::Sent
+        } else if is_moved_into_call(
+            cx,
+            mir,
+            kind,
+            ret_local,
+            &[&paths::HASHMAP_ENTRY_OR_INSERT, &paths::BTREEMAP_ENTRY_OR_INSERT],
+        ) {
+            Self::Inserted
+        } else if is_moved_into_call(cx, mir, kind, ret_local, &[&paths::MEM_REPLACE]) {
+            Self::Replaced
+        } else if is_type_diagnostic_item(cx, cloned.ty, sym!(string_type)) && is_concat_lhs(cx, mir, kind, ret_local) {
+            Self::Concatenated
+        } else if is_sorted_or_deduped(cx, body_id, terminator.source_info.span) {
+            Self::Sorted
+        } else if is_moved_into_into_param(cx, mir, kind, ret_local) {
+            Self::Converted
+        } else if moved_into_callee(cx, mir, kind, ret_local)
+            == Some(cx.tcx.hir().body_owner_def_id(body_id).to_def_id())
+        {
+            Self::Recursive
+        } else if is_coerced_to_trait_object(mir, kind, ret_local) {
+            Self::Coerced
+        } else if let Some(method) = moved_into_builder_method(cx, mir, kind, ret_local) {
+            Self::Builder(method)
+        } else {
+            Self::Dropped
+        }
+    }
+
+    fn lint(&self) -> &'static Lint {
+        match *self {
+            Self::OfTemporary => REDUNDANT_CLONE_OF_LITERAL,
+            Self::Payload(..) => REDUNDANT_CLONE_IN_ENUM_VARIANT_PAYLOAD,
+            Self::Destructured => UNNECESSARY_CLONE_FOR_PATTERN_BINDING,
+            Self::Sent => REDUNDANT_CLONE_ACROSS_CHANNEL_SEND,
+            Self::Inserted => REDUNDANT_CLONE_IN_MAP_ENTRY,
+            Self::Sorted => REDUNDANT_CLONE_IN_SORT_DEDUP,
+            Self::Converted => NEEDLESS_CLONE_FOR_INTO_PARAM,
+            Self::Recursive => REDUNDANT_CLONE_IN_RECURSIVE_CALL,
+            Self::Coerced => REDUNDANT_CLONE_FOR_TRAIT_OBJECT_COERCION,
+            Self::Builder(_) => REDUNDANT_CLONE_IN_BUILDER_WITH_METHOD,
+            Self::NotConsumed | Self::Replaced | Self::Concatenated | Self::Dropped => REDUNDANT_CLONE,
+        }
+    }
+
+    fn note(&self, cx: &LateContext<'_>) -> Cow<'static, str> {
+        match *self {
+            Self::NotConsumed => "cloned value is neither consumed nor mutated".into(),
+            Self::OfTemporary => "this temporary value is not used anywhere else".into(),
+            Self::Payload(variant, field) => format!(
+                "this value is dropped without further use, move it into field `{}` of `{}`",
+                field,
+                cx.tcx.def_path_str(variant),
+            )
+            .into(),
+            Self::Destructured => "this value is dropped without further use, destructure it directly".into(),
+            Self::Sent => "this value is dropped without further use, send it through the channel directly".into(),
+            Self::Inserted => "this value is dropped without further use, insert it into the entry directly".into(),
+            Self::Replaced => "this value is dropped without further use, move it into `mem::replace` directly".into(),
+            Self::Concatenated => {
+                "this value is dropped without further use, `+` can take ownership of it directly".into()
+            },
+            Self::Sorted => "this value is dropped without further use, sort or deduplicate it in place".into(),
+            Self::Converted => {
+                "this value is dropped without further use, the parameter can take ownership of it directly".into()
+            },
+            Self::Recursive => "this value is dropped without further use, move it into the recursive call".into(),
+            Self::Coerced => "this value is dropped without further use, move it into the trait object".into(),
+            Self::Builder(method) => format!(
+                "this value is dropped without further use, move it into `{}` directly",
+                method
+            )
+            .into(),
+            Self::Dropped => "this value is dropped without further use".into(),
+        }
+    }
+}
+
+/// A redundant `clone`-like call found in MIR.
+struct FoundClone {
+    /// The cloned local
+    local: mir::Local,
+    /// The lint root of the call
+    node: HirId,
+    span: Span,
+    call: Option<CloneCall>,
+    clone_use: CloneUse,
+    /// The size of the allocation avoided by removing the clone, if it is known
+    estimate: Option<String>,
+}
+
+/// Emits the redundant clones found in a body, in order. Several clones of a value which is
+/// dropped after each of them, like in the branches of an `if`, are reported together.
+fn emit_redundant_clones(cx: &LateContext<'_>, found: &[FoundClone]) {
+    let mut grouped = FxHashSet::default();
+    for (idx, clone) in found.iter().enumerate() {
+        let call = if let Some(ref call) = clone.call {
+            call
+        } else {
+            span_lint_hir(cx, clone.clone_use.lint(), clone.node, clone.span, "redundant clone");
+            continue;
         };
 
-        for (bb, bbdata) in mir.basic_blocks().iter_enumerated() {
-            let terminator = bbdata.terminator();
+        if matches!(clone.clone_use, CloneUse::Dropped) {
+            if !grouped.insert(clone.local) {
+                continue;
+            }
+            let group: Vec<_> = found[idx..]
+                .iter()
+                .filter(|other| matches!(other.clone_use, CloneUse::Dropped) && other.local == clone.local)
+                .filter_map(|other| other.call.as_ref())
+                .collect();
+            if group.len() > 1 {
+                span_lint_hir_and_then(
+                    cx,
+                    REDUNDANT_CLONE,
+                    clone.node,
+                    call.sugg_span,
+                    "redundant clones of the same value",
+                    |diag| {
+                        for call in &group {
+                            diag.span_suggestion(call.sugg_span, call.sugg_msg, call.sugg.clone(), call.app);
+                        }
+                        if let Some(ref estimate) = clone.estimate {
+                            diag.note(&format!("removing each clone avoids allocating {}", estimate));
+                        }
+                        let note = format!(
+                            "this value is dropped without further use after each of these {} clones",
+                            group.len()
+                        );
+                        diag.span_note(call.value_span, &note);
+                    },
+                );
+                continue;
+            }
+        }
+
+        span_lint_hir_and_then(
+            cx,
+            clone.clone_use.lint(),
+            clone.node,
+            call.sugg_span,
+            "redundant clone",
+            |diag| {
+                diag.span_suggestion(call.sugg_span, call.sugg_msg, call.sugg.clone(), call.app);
+                if let Some(ref estimate) = clone.estimate {
+                    diag.note(&format!("removing the clone avoids allocating {}", estimate));
+                }
+                let note_span = if let CloneUse::NotConsumed = clone.clone_use {
+                    clone.span
+                } else {
+                    call.value_span
+                };
+                diag.span_note(note_span, &clone.clone_use.note(cx));
+            },
+        );
+    }
+}
+
+/// Describes the size of the heap allocation made by cloning a value of type `ty`, if it is
+/// known statically: the size of the contents of a `Box`, or the size of each element of a `Vec`
+/// or `VecDeque`, e.g. `12 bytes per element`. The size of a `String` only depends on its length,
+/// so there is nothing to tell.
+fn allocation_estimate<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<String> {
+    let (inner_ty, per_element) = match ty.kind() {
+        ty::Adt(def, _) if def.is_box() => (ty.boxed_ty(), false),
+        ty::Adt(_, substs)
+            if is_type_diagnostic_item(cx, ty, sym!(vec_type)) || match_type(cx, ty, &paths::VEC_DEQUE) =>
+        {
+            (substs.type_at(0), true)
+        },
+        _ => return None,
+    };
+    let layout = cx.layout_of(inner_ty).ok()?;
+    let size = layout.size.bytes();
+    // Zero-sized values are not allocated, and unsized ones have no static size
+    if size == 0 || layout.abi.is_unsized() {
+        return None;
+    }
+    Some(format!(
+        "{} byte{}{}",
+        size,
+        if size == 1 { "" } else { "s" },
+        if per_element { " per element" } else { "" },
+    ))
+}
+
+/// A `clone`-like call found in MIR, resolved to its HIR expression.
+struct CloneCall {
+    /// The span to replace to remove the call, `.clone()` for a method call or the whole call
+    /// for a function call.
+    sugg_span: Span,
+    sugg_msg: &'static str,
+    sugg: String,
+    /// The span of the cloned value
+    value_span: Span,
+    app: Applicability,
+}
+
+/// Maps the span of a `clone`-like call terminator back to its HIR expression in the body
+/// `body_id`, which is either a method call with the cloned value as receiver, like `x.clone()`,
+/// or a function call with a reference to it as argument, like `Clone::clone(&x)`.
+fn clone_call(cx: &LateContext<'_>, body_id: BodyId, span: Span) -> Option<CloneCall> {
+    let call = find_call(cx, body_id, span)?;
+    match call.kind {
+        ExprKind::MethodCall(ref path, _, ref args, _) if args[0].span.ctxt() == span.ctxt() => {
+            // `opt.as_ref().cloned()`, the cloned value is the receiver of `as_ref`
+            let value = match args[0].kind {
+                ExprKind::MethodCall(ref as_ref, _, ref as_ref_args, _)
+                    if path.ident.name == sym!(cloned) && as_ref.ident.name == sym!(as_ref) =>
+                {
+                    &as_ref_args[0]
+                },
+                _ => &args[0],
+            };
+            Some(CloneCall {
+                sugg_span: span.with_lo(value.span.hi()),
+                sugg_msg: "remove this",
+                sugg: String::new(),
+                value_span: value.span,
+                app: Applicability::MachineApplicable,
+            })
+        },
+        ExprKind::Call(_, [ref arg]) => {
+            if let ExprKind::AddrOf(BorrowKind::Ref, _, ref value) = arg.kind {
+                let mut app = Applicability::MachineApplicable;
+                let sugg = snippet_with_applicability(cx, value.span, "..", &mut app).into_owned();
+                Some(CloneCall {
+                    sugg_span: span,
+                    sugg_msg: "remove this call",
+                    sugg,
+                    value_span: value.span,
+                    app,
+                })
+            } else {
+                None
+            }
+        },
+        _ => None,
+    }
+}
+
+/// Finds the call expression with the span `span` in the body `body_id`.
+fn find_call<'tcx>(cx: &LateContext<'tcx>, body_id: BodyId, span: Span) -> Option<&'tcx Expr<'tcx>> {
+    let mut visitor = CallFinder { span, call: None };
+    visitor.visit_body(cx.tcx.hir().body(body_id));
+    visitor.call
+}
+
+/// The methods of `Vec` and slices which sort or deduplicate the elements in place.
+const SORT_DEDUP_METHODS: [&str; 10] = [
+    "dedup",
+    "dedup_by",
+    "dedup_by_key",
+    "sort",
+    "sort_by",
+    "sort_by_cached_key",
+    "sort_by_key",
+    "sort_unstable",
+    "sort_unstable_by",
+    "sort_unstable_by_key",
+];
+
+/// Checks whether the `clone`-like call with the span `span` initializes a binding, which is
+/// sorted or deduplicated in the next statement, like `let mut v = x.clone(); v.sort();`.
+fn is_sorted_or_deduped(cx: &LateContext<'_>, body_id: BodyId, span: Span) -> bool {
+    let map = cx.tcx.hir();
+    if_chain! {
+        if let Some(call) = find_call(cx, body_id, span);
+        if let Some(Node::Local(local)) = map.find(map.get_parent_node(call.hir_id));
+        if let PatKind::Binding(_, binding_id, ..) = local.pat.kind;
+        if let Some(Node::Stmt(stmt)) = map.find(map.get_parent_node(local.hir_id));
+        if let Some(Node::Block(block)) = map.find(map.get_parent_node(stmt.hir_id));
+        if let Some(pos) = block.stmts.iter().position(|s| s.hir_id == stmt.hir_id);
+        if let Some(next) = block.stmts.get(pos + 1);
+        if let StmtKind::Semi(ref expr) = next.kind;
+        if let ExprKind::MethodCall(ref path, _, ref args, _) = expr.kind;
+        if SORT_DEDUP_METHODS.contains(&&*path.ident.as_str());
+        if let ExprKind::Path(ref qpath) = args[0].kind;
+        if qpath_res(cx, qpath, args[0].hir_id) == Res::Local(binding_id);
+        then {
+            is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(&args[0]), sym!(vec_type))
+        } else {
+            false
+        }
+    }
+}
+
+struct CallFinder<'tcx> {
+    span: Span,
+    call: Option<&'tcx Expr<'tcx>>,
+}
+
+impl<'tcx> Visitor<'tcx> for CallFinder<'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if self.call.is_some() {
+            return;
+        }
+        if expr.span == self.span && matches!(expr.kind, ExprKind::MethodCall(..) | ExprKind::Call(..)) {
+            self.call = Some(expr);
+        } else {
+            walk_expr(self, expr);
+        }
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
+
+/// Returns the spans of the statements and expressions annotated with `#[clippy::keep_clone]` in
+/// the body of the function containing `def_id`, which may be a closure.
+fn keep_clone_spans(cx: &LateContext<'_>, def_id: def_id::DefId) -> Vec<Span> {
+    let fn_def_id = cx.tcx.closure_base_def_id(def_id).expect_local();
+    let body_id = cx
+        .tcx
+        .hir()
+        .body_owned_by(cx.tcx.hir().local_def_id_to_hir_id(fn_def_id));
+    let mut visitor = KeepCloneVisitor { cx, spans: Vec::new() };
+    visitor.visit_body(cx.tcx.hir().body(body_id));
+    visitor.spans
+}
+
+struct KeepCloneVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    spans: Vec<Span>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for KeepCloneVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_local(&mut self, local: &'tcx Local<'tcx>) {
+        if get_attr(self.cx.sess(), &local.attrs, "keep_clone").count() > 0 {
+            self.spans.push(local.span);
+        }
+        walk_local(self, local);
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if get_attr(self.cx.sess(), &expr.attrs, "keep_clone").count() > 0 {
+            self.spans.push(expr.span);
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
+
+/// Macros whose arguments are passed to `format_args!`.
+const FORMAT_MACROS: [&str; 7] = ["format", "print", "println", "eprint", "eprintln", "write", "writeln"];
+
+/// Methods which only borrow their receiver. The borrow of a temporary receiver cannot outlive the
+/// statement, so cloning the receiver first is never needed.
+const BORROWING_METHODS: [&str; 3] = ["as_str", "as_ref", "as_slice"];
+
+#[derive(Clone, Copy)]
+enum OnlyBorrowed {
+    /// An argument of one of the `FORMAT_MACROS`
+    FormatArg,
+    /// The scrutinee of `matches!`
+    MatchesScrutinee,
+    /// The receiver of one of the `BORROWING_METHODS`
+    BorrowingMethodReceiver,
+    /// The operand of a reborrow, `&*x.clone()`
+    Reborrowed,
+}
+
+impl OnlyBorrowed {
+    fn note(self) -> &'static str {
+        match self {
+            Self::FormatArg => "formatting macros only borrow their arguments",
+            Self::MatchesScrutinee => "`matches!` only inspects its scrutinee",
+            Self::BorrowingMethodReceiver => "this temporary is only borrowed and dropped at the end of the statement",
+            Self::Reborrowed => "this temporary is only dereferenced to be borrowed again",
+        }
+    }
+}
+
+/// Collects the expressions in a body which are only borrowed, by a macro or by a method call.
+/// Closures are not visited, they are checked on their own.
+struct OnlyBorrowedVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    args: Vec<(&'tcx Expr<'tcx>, OnlyBorrowed)>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for OnlyBorrowedVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        // `Arguments::new_v1(&[..], &match (&arg0, &arg1) { .. })`
+        if_chain! {
+            if FORMAT_MACROS.iter().any(|name| is_expn_of(expr.span, name).is_some());
+            if let Some(args) = match_function_call(self.cx, expr, &paths::FMT_ARGUMENTS_NEW_V1)
+                .or_else(|| match_function_call(self.cx, expr, &paths::FMT_ARGUMENTS_NEW_V1_FORMATTED));
+            if let ExprKind::AddrOf(BorrowKind::Ref, _, ref arg_match) = args[1].kind;
+            if let ExprKind::Match(ref matchee, _, MatchSource::Normal) = arg_match.kind;
+            if let ExprKind::Tup(ref tup) = matchee.kind;
+            then {
+                self.args.extend(tup.iter().filter_map(|e| match e.kind {
+                    ExprKind::AddrOf(BorrowKind::Ref, _, ref arg) => Some((&**arg, OnlyBorrowed::FormatArg)),
+                    _ => None,
+                }));
+            }
+        }
+        // `matches!(scrutinee, pat)` is `match scrutinee { pat => true, _ => false }`
+        if_chain! {
+            if let ExprKind::Match(ref scrutinee, ref arms, MatchSource::Normal) = expr.kind;
+            if is_expn_of(expr.span, "matches").is_some();
+            if !arms.iter().any(|arm| binds_by_move(self.cx, &arm.pat));
+            then {
+                self.args.push((scrutinee, OnlyBorrowed::MatchesScrutinee));
+            }
+        }
+        // `x.clone().as_str()`
+        if_chain! {
+            if let ExprKind::MethodCall(ref path, _, ref args, _) = expr.kind;
+            if BORROWING_METHODS.iter().any(|name| path.ident.name.as_str() == *name);
+            if let ExprKind::MethodCall(..) = args[0].kind;
+            then {
+                self.args.push((&args[0], OnlyBorrowed::BorrowingMethodReceiver));
+            }
+        }
+        // `&*x.clone()`
+        if_chain! {
+            if let ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, ref inner) = expr.kind;
+            if let ExprKind::Unary(UnOp::UnDeref, ref arg) = inner.kind;
+            if let ExprKind::MethodCall(..) = arg.kind;
+            then {
+                self.args.push((arg, OnlyBorrowed::Reborrowed));
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
+
+/// Collects the `clone()` calls in a body whose receiver is a call of `Default::default()`.
+/// Closures are not visited, they are checked on their own.
+struct DefaultCloneVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    clones: Vec<&'tcx Expr<'tcx>>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for DefaultCloneVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if_chain! {
+            if let ExprKind::MethodCall(_, _, ref args, _) = expr.kind;
+            if !expr.span.from_expansion() && !args[0].span.from_expansion();
+            if let Some(fn_def_id) = self.cx.typeck_results().type_dependent_def_id(expr.hir_id);
+            if match_def_path(self.cx, fn_def_id, &paths::CLONE_TRAIT_METHOD);
+            if let ExprKind::Call(ref func, []) = args[0].kind;
+            if let ExprKind::Path(ref qpath) = func.kind;
+            if let Some(def_id) = qpath_res(self.cx, qpath, func.hir_id).opt_def_id();
+            if match_def_path(self.cx, def_id, &paths::DEFAULT_TRAIT_METHOD);
+            then {
+                self.clones.push(expr);
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
+
+/// Collects the statements consisting only of a `clone()` or `to_owned()` call, with the call.
+struct DroppedCloneVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    stmts: Vec<(&'tcx Stmt<'tcx>, &'tcx Expr<'tcx>)>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for DroppedCloneVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_stmt(&mut self, stmt: &'tcx Stmt<'tcx>) {
+        if_chain! {
+            if let StmtKind::Semi(ref expr) = stmt.kind;
+            if let ExprKind::MethodCall(_, _, ref args, _) = expr.kind;
+            if !stmt.span.from_expansion() && !args[0].span.from_expansion();
+            if let Some(fn_def_id) = self.cx.typeck_results().type_dependent_def_id(expr.hir_id);
+            if match_def_path(self.cx, fn_def_id, &paths::CLONE_TRAIT_METHOD)
+                || match_def_path(self.cx, fn_def_id, &paths::TO_OWNED_METHOD);
+            then {
+                self.stmts.push((stmt, expr));
+            }
+        }
+        walk_stmt(self, stmt);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
 
-            if terminator.source_info.span.from_expansion() {
-                continue;
-            }
+/// Collects the `unwrap_or_else` calls on `Option` or `Result` whose closure only clones a local,
+/// with the `clone` call and the local.
+struct UnwrapOrElseCloneVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    calls: Vec<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>, HirId)>,
+}
 
-            // Give up on loops
-            if terminator.successors().any(|s| *s == bb) {
-                continue;
+impl<'a, 'tcx> Visitor<'tcx> for UnwrapOrElseCloneVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if_chain! {
+            if let ExprKind::MethodCall(ref path, _, ref args, _) = expr.kind;
+            if path.ident.name == sym!(unwrap_or_else) && !expr.span.from_expansion();
+            let recv_ty = self.cx.typeck_results().expr_ty(&args[0]);
+            if is_type_diagnostic_item(self.cx, recv_ty, sym!(option_type))
+                || is_type_diagnostic_item(self.cx, recv_ty, sym!(result_type));
+            if let ExprKind::Closure(_, _, body_id, _, _) = args[1].kind;
+            let closure_body = self.cx.tcx.hir().body(body_id);
+            if let Some(clone) = closure_value(&closure_body.value);
+            if let ExprKind::MethodCall(_, _, ref clone_args, _) = clone.kind;
+            if !clone.span.from_expansion();
+            let closure_typeck = self.cx.tcx.typeck_body(body_id);
+            if let Some(fn_def_id) = closure_typeck.type_dependent_def_id(clone.hir_id);
+            if match_def_path(self.cx, fn_def_id, &paths::CLONE_TRAIT_METHOD);
+            if let ExprKind::Path(ref qpath) = clone_args[0].kind;
+            if let Res::Local(local_id) = qpath_res(self.cx, qpath, clone_args[0].hir_id);
+            // `x.clone()` with `x: &T` can't be replaced by `x`
+            if TyS::same_type(closure_typeck.expr_ty(clone), closure_typeck.expr_ty(&clone_args[0]));
+            then {
+                self.calls.push((expr, clone, local_id));
             }
+        }
+        walk_expr(self, expr);
+    }
 
-            let (fn_def_id, arg, arg_ty, clone_ret) =
-                unwrap_or_continue!(is_call_with_ref_arg(cx, mir, &terminator.kind));
-
-            let from_borrow = match_def_path(cx, fn_def_id, &paths::CLONE_TRAIT_METHOD)
-                || match_def_path(cx, fn_def_id, &paths::TO_OWNED_METHOD)
-                || (match_def_path(cx, fn_def_id, &paths::TO_STRING_METHOD)
-                    && is_type_diagnostic_item(cx, arg_ty, sym!(string_type)));
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
 
-            let from_deref = !from_borrow
-                && (match_def_path(cx, fn_def_id, &paths::PATH_TO_PATH_BUF)
-                    || match_def_path(cx, fn_def_id, &paths::OS_STR_TO_OS_STRING));
+/// Collects the `collect()` calls of `v.iter().map(|x| x.clone()).collect()` into the type of
+/// `v` or of the type `v` refers to, with the expression of `v`.
+struct ClonedCollectionVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    collects: Vec<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)>,
+}
 
-            if !from_borrow && !from_deref {
-                continue;
+impl<'a, 'tcx> Visitor<'tcx> for ClonedCollectionVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if_chain! {
+            if let ExprKind::MethodCall(ref path, _, ref args, _) = expr.kind;
+            if path.ident.name == sym!(collect) && !expr.span.from_expansion();
+            if match_trait_method(self.cx, expr, &paths::ITERATOR);
+            if let ExprKind::MethodCall(ref map_path, _, ref map_args, _) = args[0].kind;
+            if map_path.ident.name == sym!(map) && match_trait_method(self.cx, &args[0], &paths::ITERATOR);
+            if let ExprKind::MethodCall(ref iter_path, _, ref iter_args, _) = map_args[0].kind;
+            if iter_path.ident.name == sym!(iter) && !iter_args[0].span.from_expansion();
+            if is_element_clone(self.cx, &map_args[1]);
+            let typeck = self.cx.typeck_results();
+            let ty = typeck.expr_ty(expr);
+            if TyS::same_type(ty, walk_ptrs_ty_depth(typeck.expr_ty(&iter_args[0])).0);
+            if let Some(clone_trait) = get_trait_def_id(self.cx, &paths::CLONE_TRAIT);
+            if implements_trait(self.cx, ty, clone_trait, &[]);
+            then {
+                self.collects.push((expr, &iter_args[0]));
             }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
 
-            if let ty::Adt(ref def, _) = arg_ty.kind() {
-                if match_def_path(cx, def.did, &paths::MEM_MANUALLY_DROP) {
-                    continue;
+/// Returns `true` if the argument of `map` clones the element, like `|x| x.clone()` or
+/// `Clone::clone`.
+fn is_element_clone(cx: &LateContext<'_>, arg: &Expr<'_>) -> bool {
+    match arg.kind {
+        ExprKind::Closure(_, _, body_id, _, _) => {
+            let closure_body = cx.tcx.hir().body(body_id);
+            if_chain! {
+                if let [param] = closure_body.params;
+                if let PatKind::Binding(_, param_id, _, None) = param.pat.kind;
+                if let Some(clone) = closure_value(&closure_body.value);
+                if let ExprKind::MethodCall(_, _, ref clone_args, _) = clone.kind;
+                if let Some(fn_def_id) = cx.tcx.typeck_body(body_id).type_dependent_def_id(clone.hir_id);
+                if match_def_path(cx, fn_def_id, &paths::CLONE_TRAIT_METHOD);
+                if let ExprKind::Path(ref qpath) = clone_args[0].kind;
+                if let Res::Local(local_id) = qpath_res(cx, qpath, clone_args[0].hir_id);
+                then {
+                    local_id == param_id
+                } else {
+                    false
                 }
             }
+        },
+        ExprKind::Path(ref qpath) => qpath_res(cx, qpath, arg.hir_id)
+            .opt_def_id()
+            .map_or(false, |def_id| match_def_path(cx, def_id, &paths::CLONE_TRAIT_METHOD)),
+        _ => false,
+    }
+}
 
-            // `{ cloned = &arg; clone(move cloned); }` or `{ cloned = &arg; to_path_buf(cloned); }`
-            let (cloned, cannot_move_out) = unwrap_or_continue!(find_stmt_assigns_to(cx, mir, arg, from_borrow, bb));
+/// Returns the value of a closure body consisting of a single expression, possibly in a block.
+fn closure_value<'tcx>(mut expr: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    while let ExprKind::Block(ref block, _) = expr.kind {
+        match (block.stmts, block.expr) {
+            ([], Some(inner)) => expr = inner,
+            _ => return None,
+        }
+    }
+    Some(expr)
+}
 
-            let loc = mir::Location {
-                block: bb,
-                statement_index: bbdata.statements.len(),
-            };
+/// Returns the receiver of a method call.
+fn clone_receiver<'tcx>(clone: &'tcx Expr<'tcx>) -> &'tcx Expr<'tcx> {
+    match clone.kind {
+        ExprKind::MethodCall(_, _, ref args, _) => &args[0],
+        _ => clone,
+    }
+}
 
-            // `Local` to be cloned, and a local of `clone` call's destination
-            let (local, ret_local) = if from_borrow {
-                // `res = clone(arg)` can be turned into `res = move arg;`
-                // if `arg` is the only borrow of `cloned` at this point.
+/// Returns `true` if `expr` is in the body of a loop.
+fn is_in_loop(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    cx.tcx
+        .hir()
+        .parent_iter(expr.hir_id)
+        .any(|(_, node)| matches!(node, Node::Expr(parent) if matches!(parent.kind, ExprKind::Loop(..))))
+}
 
-                if cannot_move_out || !possible_borrower.only_borrowers(&[arg], cloned, loc) {
-                    continue;
-                }
+/// Returns `true` if the local `local_id` is used after the expression `expr` in `body`.
+fn is_local_used_after<'tcx>(cx: &LateContext<'tcx>, body: &'tcx Body<'_>, expr: &Expr<'_>, local_id: HirId) -> bool {
+    let mut visitor = UsedAfterVisitor {
+        cx,
+        local_id,
+        expr_id: expr.hir_id,
+        past_expr: false,
+        used: false,
+    };
+    visitor.visit_expr(&body.value);
+    visitor.used
+}
 
-                (cloned, clone_ret)
-            } else {
-                // `arg` is a reference as it is `.deref()`ed in the previous block.
-                // Look into the predecessor block and find out the source of deref.
+struct UsedAfterVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    local_id: HirId,
+    expr_id: HirId,
+    past_expr: bool,
+    used: bool,
+}
 
-                let ps = &mir.predecessors()[bb];
-                if ps.len() != 1 {
-                    continue;
+impl<'a, 'tcx> Visitor<'tcx> for UsedAfterVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if self.used {
+            return;
+        }
+        if expr.hir_id == self.expr_id {
+            self.past_expr = true;
+            return;
+        }
+        if self.past_expr {
+            if let ExprKind::Path(ref qpath) = expr.kind {
+                if qpath_res(self.cx, qpath, expr.hir_id) == Res::Local(self.local_id) {
+                    self.used = true;
+                    return;
                 }
-                let pred_terminator = mir[ps[0]].terminator();
-
-                // receiver of the `deref()` call
-                let (pred_arg, deref_clone_ret) = if_chain! {
-                    if let Some((pred_fn_def_id, pred_arg, pred_arg_ty, res)) =
-                        is_call_with_ref_arg(cx, mir, &pred_terminator.kind);
-                    if res == cloned;
-                    if match_def_path(cx, pred_fn_def_id, &paths::DEREF_TRAIT_METHOD);
-                    if match_type(cx, pred_arg_ty, &paths::PATH_BUF)
-                        || match_type(cx, pred_arg_ty, &paths::OS_STRING);
-                    then {
-                        (pred_arg, res)
-                    } else {
-                        continue;
-                    }
-                };
+            }
+        }
+        walk_expr(self, expr);
+    }
 
-                let (local, cannot_move_out) =
-                    unwrap_or_continue!(find_stmt_assigns_to(cx, mir, pred_arg, true, ps[0]));
-                let loc = mir::Location {
-                    block: bb,
-                    statement_index: mir.basic_blocks()[bb].statements.len(),
-                };
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
 
-                // This can be turned into `res = move local` if `arg` and `cloned` are not borrowed
-                // at the last statement:
-                //
-                // ```
-                // pred_arg = &local;
-                // cloned = deref(pred_arg);
-                // arg = &cloned;
-                // StorageDead(pred_arg);
-                // res = to_path_buf(cloned);
-                // ```
-                if cannot_move_out || !possible_borrower.only_borrowers(&[arg, cloned], local, loc) {
-                    continue;
-                }
+/// Returns `true` for locals and fields, dereferences and borrows of them.
+fn is_side_effect_free_place(expr: &Expr<'_>) -> bool {
+    match expr.kind {
+        ExprKind::Path(QPath::Resolved(None, _)) => true,
+        ExprKind::Field(ref inner, _)
+        | ExprKind::AddrOf(_, _, ref inner)
+        | ExprKind::Unary(UnOp::UnDeref, ref inner) => is_side_effect_free_place(inner),
+        _ => false,
+    }
+}
 
-                (local, deref_clone_ret)
-            };
+/// Returns the suggestion to borrow the original value in `&*x.clone()`, which is either `&*x`
+/// or `&**x` if `x` is a reference, or `None` if neither has the type of the reborrow.
+fn reborrow_sugg<'tcx>(
+    cx: &LateContext<'tcx>,
+    clone: &'tcx Expr<'_>,
+    recv: &Expr<'_>,
+    app: &mut Applicability,
+) -> Option<(Span, &'static str, String)> {
+    let map = cx.tcx.hir();
+    let borrow = get_parent_expr(cx, get_parent_expr(cx, clone)?)?;
+    // With `let r = &*x.clone();` the borrow lives until the end of the block
+    if let Some(Node::Local(_)) = map.find(map.get_parent_node(borrow.hir_id)) {
+        *app = Applicability::MaybeIncorrect;
+    }
 
-            let is_temp = mir.local_kind(ret_local) == mir::LocalKind::Temp;
-
-            // 1. `local` can be moved out if it is not used later.
-            // 2. If `ret_local` is a temporary and is neither consumed nor mutated, we can remove this `clone`
-            // call anyway.
-            let (used, consumed_or_mutated) = traversal::ReversePostorder::new(&mir, bb).skip(1).fold(
-                (false, !is_temp),
-                |(used, consumed), (tbb, tdata)| {
-                    // Short-circuit
-                    if (used && consumed) ||
-                        // Give up on loops
-                        tdata.terminator().successors().any(|s| *s == bb)
-                    {
-                        return (true, true);
-                    }
+    let typeck = cx.typeck_results();
+    let (clone_ty, recv_ty) = (typeck.expr_ty(clone), typeck.expr_ty(recv));
+    let target_ty = match typeck.expr_ty(borrow).kind() {
+        ty::Ref(_, target_ty, _) => target_ty,
+        _ => return None,
+    };
+    match recv_ty.kind() {
+        // `&*x.to_owned()` with `x: &str`
+        ty::Ref(_, recv_ty, _) if recv_ty == target_ty => {
+            Some((clone.span.with_lo(recv.span.hi()), "remove this", String::new()))
+        },
+        // `&*x.clone()` with `x: &String`
+        ty::Ref(_, recv_ty, _) if *recv_ty == clone_ty => {
+            let sugg = Sugg::hir_with_applicability(cx, recv, "..", app).deref();
+            Some((clone.span, "dereference the original instead", sugg.to_string()))
+        },
+        ty::Ref(..) => None,
+        _ if recv_ty == clone_ty => Some((clone.span.with_lo(recv.span.hi()), "remove this", String::new())),
+        _ => None,
+    }
+}
 
-                    let mut vis = LocalUseVisitor {
-                        used: (local, false),
-                        consumed_or_mutated: (ret_local, false),
-                    };
-                    vis.visit_basic_block_data(tbb, tdata);
-                    (used || vis.used.1, consumed || vis.consumed_or_mutated.1)
-                },
-            );
+/// Returns `true` if `recv` is a local which is also used outside of `clone` in the statement
+/// containing `clone`.
+fn is_used_elsewhere_in_stmt<'tcx>(cx: &LateContext<'tcx>, clone: &'tcx Expr<'_>, recv: &Expr<'_>) -> bool {
+    let local_id = match recv.kind {
+        ExprKind::Path(ref qpath) => match qpath_res(cx, qpath, recv.hir_id) {
+            Res::Local(id) => id,
+            _ => return false,
+        },
+        _ => return false,
+    };
+    let mut stmt_expr = clone;
+    while let Some(parent) = get_parent_expr(cx, stmt_expr) {
+        stmt_expr = parent;
+    }
+    let mut visitor = LocalUsesVisitor { cx, local_id, uses: 0 };
+    visitor.visit_expr(stmt_expr);
+    visitor.uses > 1
+}
 
-            if !used || !consumed_or_mutated {
-                let span = terminator.source_info.span;
-                let scope = terminator.source_info.scope;
-                let node = mir.source_scopes[scope]
-                    .local_data
-                    .as_ref()
-                    .assert_crate_local()
-                    .lint_root;
-
-                if_chain! {
-                    if let Some(snip) = snippet_opt(cx, span);
-                    if let Some(dot) = snip.rfind('.');
-                    then {
-                        let sugg_span = span.with_lo(
-                            span.lo() + BytePos(u32::try_from(dot).unwrap())
-                        );
-                        let mut app = Applicability::MaybeIncorrect;
-
-                        let mut call_snip = &snip[dot + 1..];
-                        // Machine applicable when `call_snip` looks like `foobar()`
-                        if call_snip.ends_with("()") {
-                            call_snip = call_snip[..call_snip.len()-2].trim();
-                            if call_snip.as_bytes().iter().all(|b| b.is_ascii_alphabetic() || *b == b'_') {
-                                app = Applicability::MachineApplicable;
-                            }
-                        }
+/// Counts the uses of a local in an expression, including the bodies of closures.
+struct LocalUsesVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    local_id: HirId,
+    uses: usize,
+}
 
-                        span_lint_hir_and_then(cx, REDUNDANT_CLONE, node, sugg_span, "redundant clone", |diag| {
-                            diag.span_suggestion(
-                                sugg_span,
-                                "remove this",
-                                String::new(),
-                                app,
-                            );
-                            if used {
-                                diag.span_note(
-                                    span,
-                                    "cloned value is neither consumed nor mutated",
-                                );
-                            } else {
-                                diag.span_note(
-                                    span.with_hi(span.lo() + BytePos(u32::try_from(dot).unwrap())),
-                                    "this value is dropped without further use",
-                                );
-                            }
-                        });
-                    } else {
-                        span_lint_hir(cx, REDUNDANT_CLONE, node, span, "redundant clone");
-                    }
-                }
+impl<'a, 'tcx> Visitor<'tcx> for LocalUsesVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Path(ref qpath) = expr.kind {
+            if qpath_res(self.cx, qpath, expr.hir_id) == Res::Local(self.local_id) {
+                self.uses += 1;
             }
         }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
     }
 }
 
+/// Returns `true` if `pat` moves a non-`Copy` value out of the matched place.
+fn binds_by_move(cx: &LateContext<'_>, pat: &Pat<'_>) -> bool {
+    let mut moves = false;
+    pat.each_binding(|annotation, hir_id, _, _| {
+        if matches!(annotation, BindingAnnotation::Unannotated | BindingAnnotation::Mutable)
+            && !is_copy(cx, cx.typeck_results().node_type(hir_id))
+        {
+            moves = true;
+        }
+    });
+    moves
+}
+
 /// If `kind` is `y = func(x: &T)` where `T: !Copy`, returns `(DefId of func, x, T, y)`.
+///
+/// The caller location passed to `#[track_caller]` functions, like a `clone` implementation
+/// with the attribute, is only added during codegen, so such calls still have a single argument.
 fn is_call_with_ref_arg<'tcx>(
     cx: &LateContext<'tcx>,
     mir: &'tcx mir::Body<'tcx>,
@@ -288,6 +2043,31 @@ fn is_call_with_ref_arg<'tcx>(
         if let mir::Operand::Move(mir::Place { local, .. }) = &args[0];
         if let ty::FnDef(def_id, _) = *func.ty(&*mir, cx.tcx).kind();
         if let (inner_ty, 1) = walk_ptrs_ty_depth(args[0].ty(&*mir, cx.tcx));
+        // Associated types may not be normalized yet, e.g. `&<I as Iterator>::Item`
+        let inner_ty = cx.tcx.normalize_erasing_regions(cx.param_env, inner_ty);
+        if !is_copy(cx, inner_ty);
+        then {
+            Some((def_id, *local, inner_ty, destination.as_ref().map(|(dest, _)| dest)?.as_local()?))
+        } else {
+            None
+        }
+    }
+}
+
+/// If `kind` is a call of `Option::cloned` on an `Option` of a reference to a non-`Copy` type,
+/// like `opt.as_ref().cloned()`, returns the same as `is_call_with_ref_arg`.
+fn is_option_cloned<'tcx>(
+    cx: &LateContext<'tcx>,
+    mir: &'tcx mir::Body<'tcx>,
+    kind: &'tcx mir::TerminatorKind<'tcx>,
+) -> Option<(def_id::DefId, mir::Local, Ty<'tcx>, mir::Local)> {
+    if_chain! {
+        if let mir::TerminatorKind::Call { func, args, destination, .. } = kind;
+        if let [mir::Operand::Move(mir::Place { local, .. })] = &**args;
+        if let ty::FnDef(def_id, _) = *func.ty(&*mir, cx.tcx).kind();
+        if match_def_path(cx, def_id, &paths::OPTION_CLONED);
+        if let ty::Adt(_, substs) = args[0].ty(&*mir, cx.tcx).kind();
+        if let ty::Ref(_, inner_ty, _) = substs.type_at(0).kind();
         if !is_copy(cx, inner_ty);
         then {
             Some((def_id, *local, inner_ty, destination.as_ref().map(|(dest, _)| dest)?.as_local()?))
@@ -297,26 +2077,348 @@ fn is_call_with_ref_arg<'tcx>(
     }
 }
 
+/// If the result of the call `kind`, `ret_local`, is moved into a field of an enum variant in the
+/// block the call returns to, returns the `DefId` of the variant and the name of the field.
+/// `Option` and `Result` are left to `REDUNDANT_CLONE`.
+///
+/// Enum aggregates are usually already split into field assignments and a `SetDiscriminant`,
+/// i.e. `(_1 as V).1 = move ret_local`, so both forms are handled.
+fn moved_into_enum_variant<'tcx>(
+    cx: &LateContext<'tcx>,
+    mir: &'tcx mir::Body<'tcx>,
+    kind: &'tcx mir::TerminatorKind<'tcx>,
+    ret_local: mir::Local,
+) -> Option<(def_id::DefId, Symbol)> {
+    let target = match kind {
+        mir::TerminatorKind::Call {
+            destination: Some((_, target)),
+            ..
+        } => *target,
+        _ => return None,
+    };
+    let is_ret_local =
+        |op: &mir::Operand<'_>| matches!(op, mir::Operand::Move(place) if place.as_local() == Some(ret_local));
+
+    let (adt, variant_idx, field_idx) = mir.basic_blocks()[target].statements.iter().find_map(|stmt| {
+        use rustc_middle::mir::ProjectionElem::{Downcast, Field};
+
+        if let mir::StatementKind::Assign(box (place, rvalue)) = &stmt.kind {
+            match (&place.projection[..], rvalue) {
+                // `(_1 as V).1 = move ret_local`
+                ([base @ .., Downcast(_, variant_idx), Field(field, _)], mir::Rvalue::Use(op)) if is_ret_local(op) => {
+                    if let ty::Adt(adt, _) = mir::Place::ty_from(place.local, base, &mir.local_decls, cx.tcx)
+                        .ty
+                        .kind()
+                    {
+                        return Some((*adt, *variant_idx, field.index()));
+                    }
+                },
+                // `_1 = V(_2, move ret_local)`
+                (_, mir::Rvalue::Aggregate(box mir::AggregateKind::Adt(adt, variant_idx, _, _, None), ops)) => {
+                    if let Some(field_idx) = ops.iter().position(|op| is_ret_local(op)) {
+                        return Some((*adt, *variant_idx, field_idx));
+                    }
+                },
+                _ => {},
+            }
+        }
+        None
+    })?;
+
+    if !adt.is_enum()
+        || cx.tcx.is_diagnostic_item(sym!(option_type), adt.did)
+        || cx.tcx.is_diagnostic_item(sym!(result_type), adt.did)
+    {
+        return None;
+    }
+
+    // Fields of the aggregate are in declaration order, which is not necessarily the order in
+    // which they are written in a struct-like variant expression
+    let variant = &adt.variants[variant_idx];
+    let field = variant.fields.get(field_idx)?;
+    Some((variant.def_id, field.ident.name))
+}
+
+/// Checks whether the result of the call `kind`, `ret_local`, is moved into a call of one of the
+/// functions `fn_paths` which ends the block the call returns to, like in `tx.send(x.clone())`.
+fn is_moved_into_call<'tcx>(
+    cx: &LateContext<'tcx>,
+    mir: &'tcx mir::Body<'tcx>,
+    kind: &'tcx mir::TerminatorKind<'tcx>,
+    ret_local: mir::Local,
+    fn_paths: &[&[&str]],
+) -> bool {
+    moved_into_callee(cx, mir, kind, ret_local).map_or(false, |def_id| {
+        fn_paths.iter().any(|path| match_def_path(cx, def_id, path))
+    })
+}
+
+/// If the result of the call `kind`, `ret_local`, is moved into an argument of the call which
+/// ends the block the call returns to, returns the `DefId` of the called function.
+fn moved_into_callee<'tcx>(
+    cx: &LateContext<'tcx>,
+    mir: &'tcx mir::Body<'tcx>,
+    kind: &'tcx mir::TerminatorKind<'tcx>,
+    ret_local: mir::Local,
+) -> Option<def_id::DefId> {
+    let target = match kind {
+        mir::TerminatorKind::Call {
+            destination: Some((_, target)),
+            ..
+        } => *target,
+        _ => return None,
+    };
+    if_chain! {
+        if let mir::TerminatorKind::Call { func, args, .. } = &mir.basic_blocks()[target].terminator().kind;
+        if let ty::FnDef(def_id, _) = *func.ty(&*mir, cx.tcx).kind();
+        if args
+            .iter()
+            .any(|arg| matches!(arg, mir::Operand::Move(place) if place.as_local() == Some(ret_local)));
+        then {
+            Some(def_id)
+        } else {
+            None
+        }
+    }
+}
+
+/// Checks whether the result of the call `kind`, `ret_local`, is moved into a parameter of the
+/// call which ends the block the call returns to, whose type is a generic parameter bounded by
+/// `Into`, like `x` in `fn f(x: impl Into<String>)`.
+fn is_moved_into_into_param<'tcx>(
+    cx: &LateContext<'tcx>,
+    mir: &'tcx mir::Body<'tcx>,
+    kind: &'tcx mir::TerminatorKind<'tcx>,
+    ret_local: mir::Local,
+) -> bool {
+    let target = match kind {
+        mir::TerminatorKind::Call {
+            destination: Some((_, target)),
+            ..
+        } => *target,
+        _ => return false,
+    };
+    if_chain! {
+        if let mir::TerminatorKind::Call { func, args, .. } = &mir.basic_blocks()[target].terminator().kind;
+        if let ty::FnDef(def_id, _) = *func.ty(&*mir, cx.tcx).kind();
+        if let Some(idx) = args
+            .iter()
+            .position(|arg| matches!(arg, mir::Operand::Move(place) if place.as_local() == Some(ret_local)));
+        if let Some(&param_ty) = cx.tcx.fn_sig(def_id).skip_binder().inputs().get(idx);
+        if let ty::Param(_) = param_ty.kind();
+        if let Some(into_trait) = get_trait_def_id(cx, &paths::INTO);
+        then {
+            cx.tcx.predicates_of(def_id).predicates.iter().any(|(pred, _)| {
+                matches!(
+                    pred.skip_binders(),
+                    ty::PredicateAtom::Trait(trait_pred, _)
+                        if trait_pred.def_id() == into_trait && trait_pred.self_ty() == param_ty
+                )
+            })
+        } else {
+            false
+        }
+    }
+}
+
+/// If the result of the call `kind`, `ret_local`, is moved into a call of a method named `with_*`
+/// or `set_*` which ends the block the call returns to, like `builder.with_name(name.clone())`,
+/// returns the name of the method.
+fn moved_into_builder_method<'tcx>(
+    cx: &LateContext<'tcx>,
+    mir: &'tcx mir::Body<'tcx>,
+    kind: &'tcx mir::TerminatorKind<'tcx>,
+    ret_local: mir::Local,
+) -> Option<Symbol> {
+    let def_id = moved_into_callee(cx, mir, kind, ret_local)?;
+    let method = cx.tcx.opt_associated_item(def_id)?;
+    let name = method.ident.as_str();
+    if method.fn_has_self_parameter && (name.starts_with("with_") || name.starts_with("set_")) {
+        Some(method.ident.name)
+    } else {
+        None
+    }
+}
+
+/// Checks whether the result of the call `kind`, `ret_local`, is coerced to a trait object,
+/// either directly, like a `Box<T>` coerced to a `Box<dyn Trait>`, or after being moved into
+/// the call which ends the block the call returns to, like `Box::new`, whose result is coerced.
+fn is_coerced_to_trait_object<'tcx>(
+    mir: &'tcx mir::Body<'tcx>,
+    kind: &'tcx mir::TerminatorKind<'tcx>,
+    ret_local: mir::Local,
+) -> bool {
+    fn is_moved(op: &mir::Operand<'_>, local: mir::Local) -> bool {
+        matches!(op, mir::Operand::Move(place) if place.as_local() == Some(local))
+    }
+
+    let target = match kind {
+        mir::TerminatorKind::Call {
+            destination: Some((_, target)),
+            ..
+        } => *target,
+        _ => return false,
+    };
+    let is_coerced_in = |block: mir::BasicBlock, local: mir::Local| {
+        mir.basic_blocks()[block].statements.iter().any(|stmt| {
+            matches!(
+                &stmt.kind,
+                mir::StatementKind::Assign(box (
+                    _,
+                    mir::Rvalue::Cast(mir::CastKind::Pointer(PointerCast::Unsize), op, ty),
+                )) if is_moved(op, local) && points_to_trait_object(ty)
+            )
+        })
+    };
+
+    if is_coerced_in(target, ret_local) {
+        return true;
+    }
+    if_chain! {
+        if let mir::TerminatorKind::Call {
+            args,
+            destination: Some((dest, next)),
+            ..
+        } = &mir.basic_blocks()[target].terminator().kind;
+        if args.iter().any(|arg| is_moved(arg, ret_local));
+        if let Some(dest) = dest.as_local();
+        then {
+            is_coerced_in(*next, dest)
+        } else {
+            false
+        }
+    }
+}
+
+/// Checks whether `ty` is a pointer to a trait object, like `&dyn Trait`, `Box<dyn Trait>` or
+/// `Rc<dyn Trait>`.
+fn points_to_trait_object(ty: Ty<'_>) -> bool {
+    match ty.kind() {
+        ty::Ref(_, pointee, _) | ty::RawPtr(ty::TypeAndMut { ty: pointee, .. }) => {
+            matches!(pointee.kind(), ty::Dynamic(..))
+        },
+        ty::Adt(_, substs) => substs.types().any(|ty| matches!(ty.kind(), ty::Dynamic(..))),
+        _ => false,
+    }
+}
+
+/// Checks whether the result of the call `kind`, `ret_local`, is moved into the left operand of
+/// `+`. The right operand may be evaluated by further calls in between, like the `deref` of `&y`
+/// in `x.clone() + &y`.
+fn is_concat_lhs<'tcx>(
+    cx: &LateContext<'tcx>,
+    mir: &'tcx mir::Body<'tcx>,
+    kind: &'tcx mir::TerminatorKind<'tcx>,
+    ret_local: mir::Local,
+) -> bool {
+    let mut kind = kind;
+    // Bounded, a chain of calls can be a loop
+    for _ in 0..mir.basic_blocks().len() {
+        let target = match kind {
+            mir::TerminatorKind::Call {
+                destination: Some((_, target)),
+                ..
+            } => *target,
+            _ => return false,
+        };
+        kind = &mir.basic_blocks()[target].terminator().kind;
+        let (func, args) = match kind {
+            mir::TerminatorKind::Call { func, args, .. } => (func, args),
+            _ => return false,
+        };
+        if let ty::FnDef(def_id, _) = *func.ty(&*mir, cx.tcx).kind() {
+            if cx.tcx.trait_of_item(def_id) == cx.tcx.lang_items().add_trait() {
+                return matches!(args.first(), Some(mir::Operand::Move(place)) if place.as_local() == Some(ret_local));
+            }
+        }
+        let uses_ret = args.iter().any(|arg| match arg {
+            mir::Operand::Copy(place) | mir::Operand::Move(place) => place.local == ret_local,
+            mir::Operand::Constant(_) => false,
+        });
+        if uses_ret {
+            return false;
+        }
+    }
+    false
+}
+
+/// Checks whether fields are moved or copied out of the result of the call `kind`, `ret_local`,
+/// in the block the call returns to, like the bindings of `let Foo(a, b) = x.clone();` do.
+fn is_destructured(mir: &mir::Body<'_>, kind: &mir::TerminatorKind<'_>, ret_local: mir::Local) -> bool {
+    let target = match kind {
+        mir::TerminatorKind::Call {
+            destination: Some((_, target)),
+            ..
+        } => *target,
+        _ => return false,
+    };
+
+    mir.basic_blocks()[target].statements.iter().any(|stmt| {
+        if let mir::StatementKind::Assign(box (
+            _,
+            mir::Rvalue::Use(mir::Operand::Move(place) | mir::Operand::Copy(place)),
+        )) = &stmt.kind
+        {
+            place.local == ret_local && matches!(place.projection.first(), Some(mir::ProjectionElem::Field(..)))
+        } else {
+            false
+        }
+    })
+}
+
 type CannotMoveOut = bool;
 
+/// The field of a closure environment that holds an upvar captured by value.
+type Upvar = Option<mir::Field>;
+
+/// The temporaries a borrow was moved or copied through before reaching the `clone` call.
+type Temporaries = Vec<mir::Local>;
+
+/// The maximum number of trivial assignments between temporaries followed by
+/// `find_stmt_assigns_to`.
+const MAX_TEMPORARY_HOPS: usize = 8;
+
 /// Finds the first `to = (&)from`, and returns
-/// ``Some((from, whether `from` cannot be moved out))``.
+/// ``Some((from, the upvar `from` refers to, whether `from` cannot be moved out))``.
 fn find_stmt_assigns_to<'tcx>(
     cx: &LateContext<'tcx>,
     mir: &mir::Body<'tcx>,
     to_local: mir::Local,
     by_ref: bool,
     bb: mir::BasicBlock,
-) -> Option<(mir::Local, CannotMoveOut)> {
-    let rvalue = mir.basic_blocks()[bb].statements.iter().rev().find_map(|stmt| {
-        if let mir::StatementKind::Assign(box (mir::Place { local, .. }, v)) = &stmt.kind {
-            return if *local == to_local { Some(v) } else { None };
-        }
+) -> Option<(mir::Local, Upvar, CannotMoveOut, Temporaries)> {
+    let statements = &mir.basic_blocks()[bb].statements;
+    let find_assign = |to_local: mir::Local, end: usize| {
+        statements[..end].iter().enumerate().rev().find_map(|(index, stmt)| {
+            if let mir::StatementKind::Assign(box (mir::Place { local, .. }, v)) = &stmt.kind {
+                return if *local == to_local { Some((index, v)) } else { None };
+            }
 
-        None
-    })?;
+            None
+        })
+    };
+    let (mut index, mut rvalue) = find_assign(to_local, statements.len())?;
+
+    // Follow trivial moves and copies between temporaries, like `_4 = &_1; _3 = move _4; _2 = move _3;`
+    let mut temporaries = Vec::new();
+    while temporaries.len() < MAX_TEMPORARY_HOPS {
+        match rvalue {
+            mir::Rvalue::Use(mir::Operand::Copy(place) | mir::Operand::Move(place))
+                if place.projection.is_empty() && mir.local_kind(place.local) == mir::LocalKind::Temp =>
+            {
+                if let Some((prev_index, prev_rvalue)) = find_assign(place.local, index) {
+                    temporaries.push(place.local);
+                    index = prev_index;
+                    rvalue = prev_rvalue;
+                } else {
+                    break;
+                }
+            },
+            _ => break,
+        }
+    }
 
-    match (by_ref, &*rvalue) {
+    let (local, upvar, cannot_move_out) = match (by_ref, rvalue) {
         (true, mir::Rvalue::Ref(_, _, place)) | (false, mir::Rvalue::Use(mir::Operand::Copy(place))) => {
             base_local_and_movability(cx, mir, *place)
         },
@@ -328,18 +2430,22 @@ fn find_stmt_assigns_to<'tcx>(
             }
         },
         _ => None,
-    }
+    }?;
+    Some((local, upvar, cannot_move_out, temporaries))
 }
 
 /// Extracts and returns the undermost base `Local` of given `place`. Returns `place` itself
 /// if it is already a `Local`.
 ///
+/// If `place` is an upvar captured by value in the environment of a closure or generator,
+/// also returns the field of the environment it is stored in.
+///
 /// Also reports whether given `place` cannot be moved out.
 fn base_local_and_movability<'tcx>(
     cx: &LateContext<'tcx>,
     mir: &mir::Body<'tcx>,
     place: mir::Place<'tcx>,
-) -> Option<(mir::Local, CannotMoveOut)> {
+) -> Option<(mir::Local, Upvar, CannotMoveOut)> {
     use rustc_middle::mir::PlaceRef;
 
     // Dereference. You cannot move things out from a borrowed value.
@@ -351,6 +2457,18 @@ fn base_local_and_movability<'tcx>(
     let mut slice = false;
 
     let PlaceRef { local, mut projection } = place.as_ref();
+
+    // Only a closure's own body can project into its environment. Upvars captured by value are
+    // fields of it, upvars captured by reference are behind a `Deref` and can't be moved out anyway.
+    let upvar = match projection {
+        [mir::ProjectionElem::Field(field, _), ..]
+            if matches!(mir.local_decls[local].ty.kind(), ty::Closure(..) | ty::Generator(..)) =>
+        {
+            Some(*field)
+        },
+        _ => None,
+    };
+
     while let [base @ .., elem] = projection {
         projection = base;
         deref |= matches!(elem, mir::ProjectionElem::Deref);
@@ -360,12 +2478,17 @@ fn base_local_and_movability<'tcx>(
             && !is_copy(cx, mir::Place::ty_from(local, projection, &mir.local_decls, cx.tcx).ty);
     }
 
-    Some((local, deref || field || slice))
+    Some((local, upvar, deref || field || slice))
 }
 
 struct LocalUseVisitor {
     used: (mir::Local, bool),
+    /// If `used` is a closure environment, only the uses of this upvar are relevant.
+    upvar: Upvar,
     consumed_or_mutated: (mir::Local, bool),
+    /// Whether `used` was overwritten as a whole in the visited block, later statements of the
+    /// block see the new value.
+    overwritten: bool,
 }
 
 impl<'tcx> mir::visit::Visitor<'tcx> for LocalUseVisitor {
@@ -384,11 +2507,29 @@ impl<'tcx> mir::visit::Visitor<'tcx> for LocalUseVisitor {
         );
     }
 
+    fn visit_assign(&mut self, place: &mir::Place<'tcx>, rvalue: &mir::Rvalue<'tcx>, location: mir::Location) {
+        // The value is read before the place is written to, e.g. `_1 = move (_1.0)`
+        self.visit_rvalue(rvalue, location);
+        self.visit_place(place, PlaceContext::MutatingUse(MutatingUseContext::Store), location);
+    }
+
     fn visit_place(&mut self, place: &mir::Place<'tcx>, ctx: PlaceContext, _: mir::Location) {
         let local = place.local;
 
+        if local == self.used.0 && !self.overwritten {
+            // Overwriting the whole value, like `x = Vec::new()`, does not need the old one
+            if ctx == PlaceContext::MutatingUse(MutatingUseContext::Store) && place.projection.is_empty() {
+                self.overwritten = true;
+            }
+        }
+
         if local == self.used.0
+            && !self.overwritten
             && !matches!(ctx, PlaceContext::MutatingUse(MutatingUseContext::Drop) | PlaceContext::NonUse(_))
+            && self.upvar.map_or(true, |upvar| match place.projection.first() {
+                Some(mir::ProjectionElem::Field(field, _)) => *field == upvar,
+                _ => true,
+            })
         {
             self.used.1 = true;
         }
@@ -477,7 +2618,8 @@ impl<'a, 'tcx> PossibleBorrowerVisitor<'a, 'tcx> {
     fn into_map(
         self,
         cx: &LateContext<'tcx>,
-        maybe_live: ResultsCursor<'tcx, 'tcx, MaybeStorageLive>,
+        maybe_storage_live: ResultsCursor<'tcx, 'tcx, MaybeStorageLive>,
+        maybe_live: ResultsCursor<'tcx, 'tcx, MaybeLiveLocals>,
     ) -> PossibleBorrowerMap<'a, 'tcx> {
         let mut map = FxHashMap::default();
         for row in (1..self.body.local_decls.len()).map(mir::Local::from_usize) {
@@ -503,6 +2645,7 @@ impl<'a, 'tcx> PossibleBorrowerVisitor<'a, 'tcx> {
         let bs = BitSet::new_empty(self.body.local_decls.len());
         PossibleBorrowerMap {
             map,
+            maybe_storage_live,
             maybe_live,
             bitset: (bs.clone(), bs),
         }
@@ -513,11 +2656,11 @@ impl<'a, 'tcx> mir::visit::Visitor<'tcx> for PossibleBorrowerVisitor<'a, 'tcx> {
     fn visit_assign(&mut self, place: &mir::Place<'tcx>, rvalue: &mir::Rvalue<'_>, _location: mir::Location) {
         let lhs = place.local;
         match rvalue {
-            mir::Rvalue::Ref(_, _, borrowed) => {
+            mir::Rvalue::Ref(_, _, borrowed) | mir::Rvalue::AddressOf(_, borrowed) => {
                 self.possible_borrower.add(borrowed.local, lhs);
             },
             other => {
-                if !ContainsRegion.visit_ty(place.ty(&self.body.local_decls, self.cx.tcx).ty) {
+                if !ContainsBorrow.visit_ty(place.ty(&self.body.local_decls, self.cx.tcx).ty) {
                     return;
                 }
                 rvalue_locals(other, |rhs| {
@@ -536,10 +2679,10 @@ impl<'a, 'tcx> mir::visit::Visitor<'tcx> for PossibleBorrowerVisitor<'a, 'tcx> {
             ..
         } = &terminator.kind
         {
-            // If the call returns something with lifetimes,
+            // If the call returns something with lifetimes or raw pointers,
             // let's conservatively assume the returned value contains lifetime of all the arguments.
             // For example, given `let y: Foo<'a> = foo(x)`, `y` is considered to be a possible borrower of `x`.
-            if !ContainsRegion.visit_ty(&self.body.local_decls[*dest].ty) {
+            if !ContainsBorrow.visit_ty(&self.body.local_decls[*dest].ty) {
                 return;
             }
 
@@ -555,9 +2698,15 @@ impl<'a, 'tcx> mir::visit::Visitor<'tcx> for PossibleBorrowerVisitor<'a, 'tcx> {
     }
 }
 
-struct ContainsRegion;
+/// Finds types which can hold a borrow: anything with a region, and raw pointers, which are
+/// often derived from a `&mut` borrow that is then no longer visible in the type.
+struct ContainsBorrow;
+
+impl<'tcx> TypeVisitor<'tcx> for ContainsBorrow {
+    fn visit_ty(&mut self, ty: Ty<'tcx>) -> bool {
+        matches!(ty.kind(), ty::RawPtr(..)) || ty.super_visit_with(self)
+    }
 
-impl TypeVisitor<'_> for ContainsRegion {
     fn visit_region(&mut self, _: ty::Region<'_>) -> bool {
         true
     }
@@ -586,20 +2735,28 @@ fn rvalue_locals(rvalue: &mir::Rvalue<'_>, mut visit: impl FnMut(mir::Local)) {
 struct PossibleBorrowerMap<'a, 'tcx> {
     /// Mapping `Local -> its possible borrowers`
     map: FxHashMap<mir::Local, HybridBitSet<mir::Local>>,
-    maybe_live: ResultsCursor<'a, 'tcx, MaybeStorageLive>,
+    maybe_storage_live: ResultsCursor<'a, 'tcx, MaybeStorageLive>,
+    /// A borrower which is used later keeps its borrow active, even if its storage is not
+    /// tracked by `StorageLive`/`StorageDead` statements.
+    maybe_live: ResultsCursor<'a, 'tcx, MaybeLiveLocals>,
     // Caches to avoid allocation of `BitSet` on every query
     bitset: (BitSet<mir::Local>, BitSet<mir::Local>),
 }
 
 impl PossibleBorrowerMap<'_, '_> {
-    /// Returns true if the set of borrowers of `borrowed` living at `at` matches with `borrowers`.
+    /// Returns true if the set of borrowers of `borrowed` active at `at` matches with `borrowers`.
+    /// A borrower is active if its storage may be live, or if it may be used at `at` or later.
     fn only_borrowers(&mut self, borrowers: &[mir::Local], borrowed: mir::Local, at: mir::Location) -> bool {
+        self.maybe_storage_live.seek_after_primary_effect(at);
         self.maybe_live.seek_after_primary_effect(at);
 
         self.bitset.0.clear();
-        let maybe_live = &mut self.maybe_live;
+        let (maybe_storage_live, maybe_live) = (&self.maybe_storage_live, &self.maybe_live);
         if let Some(bitset) = self.map.get(&borrowed) {
-            for b in bitset.iter().filter(move |b| maybe_live.contains(*b)) {
+            for b in bitset
+                .iter()
+                .filter(|b| maybe_storage_live.contains(*b) || maybe_live.contains(*b))
+            {
                 self.bitset.0.insert(b);
             }
         } else {
@@ -614,3 +2771,27 @@ impl PossibleBorrowerMap<'_, '_> {
         self.bitset.0 == self.bitset.1
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::scope_lint_root;
+    use rustc_hir::def_id::{LocalDefId, CRATE_DEF_INDEX};
+    use rustc_hir::{HirId, ItemLocalId, CRATE_HIR_ID};
+    use rustc_middle::mir::{ClearCrossCrate, Safety, SourceScopeLocalData};
+
+    #[test]
+    fn test_scope_lint_root() {
+        let lint_root = HirId {
+            owner: LocalDefId {
+                local_def_index: CRATE_DEF_INDEX,
+            },
+            local_id: ItemLocalId::from_u32(1),
+        };
+        let set = ClearCrossCrate::Set(SourceScopeLocalData {
+            lint_root,
+            safety: Safety::Safe,
+        });
+        assert_eq!(scope_lint_root(&set, CRATE_HIR_ID), lint_root);
+        assert_eq!(scope_lint_root(&ClearCrossCrate::Clear, CRATE_HIR_ID), CRATE_HIR_ID);
+    }
+}
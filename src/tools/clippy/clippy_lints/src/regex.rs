@@ -1,9 +1,11 @@
 use crate::consts::{constant, Constant};
-use crate::utils::{match_def_path, paths, span_lint, span_lint_and_help};
+use crate::utils::{higher, match_def_path, paths, qpath_res, snippet_opt, span_lint, span_lint_and_help};
 use if_chain::if_chain;
 use rustc_ast::ast::{LitKind, StrStyle};
 use rustc_data_structures::fx::FxHashSet;
-use rustc_hir::{BorrowKind, Expr, ExprKind, HirId};
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{BorrowKind, Expr, ExprKind, HirId, ItemKind, Node};
+use rustc_lexer::unescape::{self, Mode};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::source_map::{BytePos, Span};
@@ -11,8 +13,9 @@ use std::convert::TryFrom;
 
 declare_clippy_lint! {
     /// **What it does:** Checks [regex](https://crates.io/crates/regex) creation
-    /// (with `Regex::new`,`RegexBuilder::new` or `RegexSet::new`) for correct
-    /// regex syntax.
+    /// (with `Regex::new`,`RegexBuilder::new` or `RegexSet::new`, or their
+    /// `regex::bytes` counterparts) from string literals, constants and statics
+    /// for correct regex syntax.
     ///
     /// **Why is this bad?** This will lead to a runtime panic.
     ///
@@ -78,21 +81,63 @@ impl<'tcx> LateLintPass<'tcx> for Regex {
     }
 }
 
+/// Returns the span of the part `c` of the regex in the string literal at `base`. The offsets of
+/// `c` are into the unescaped string, so escape sequences in the source of the literal have to be
+/// accounted for. Falls back to the span of the whole literal if its source is not available.
 #[allow(clippy::cast_possible_truncation)] // truncation very unlikely here
 #[must_use]
-fn str_span(base: Span, c: regex_syntax::ast::Span, offset: u16) -> Span {
-    let offset = u32::from(offset);
-    let end = base.lo() + BytePos(u32::try_from(c.end.offset).expect("offset too large") + offset);
-    let start = base.lo() + BytePos(u32::try_from(c.start.offset).expect("offset too large") + offset);
+fn str_span(cx: &LateContext<'_>, base: Span, c: regex_syntax::ast::Span, style: StrStyle) -> Span {
+    let (open, close, mode) = match style {
+        StrStyle::Cooked => (1, 1, Mode::Str),
+        StrStyle::Raw(n) => (2 + usize::from(n), 1 + usize::from(n), Mode::RawStr),
+    };
+    let source = match snippet_opt(cx, base) {
+        Some(source) if !base.from_expansion() && source.len() >= open + close => source,
+        _ => return base,
+    };
+    let contents = &source[open..source.len() - close];
+
+    // the offsets of the chars in the unescaped string, and in `contents`
+    let mut offsets = Vec::with_capacity(contents.len());
+    let mut unescaped_len = 0;
+    unescape::unescape_literal(contents, mode, &mut |range, c| {
+        if let Ok(c) = c {
+            offsets.push((unescaped_len, range.start));
+            unescaped_len += c.len_utf8();
+        }
+    });
+    let source_offset = |offset: usize| {
+        let offset = offsets
+            .iter()
+            .find(|&&(unescaped, _)| unescaped == offset)
+            .map_or(contents.len(), |&(_, source)| source);
+        BytePos(u32::try_from(open + offset).expect("offset too large"))
+    };
+
+    let start = base.lo() + source_offset(c.start.offset);
+    let end = base.lo() + source_offset(c.end.offset);
     assert!(start <= end);
     Span::new(start, end, base.ctxt())
 }
 
+/// Returns the value of a constant or of a local `static` string.
 fn const_str<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) -> Option<String> {
-    constant(cx, cx.typeck_results(), e).and_then(|(c, _)| match c {
-        Constant::Str(s) => Some(s),
-        _ => None,
-    })
+    if let Some((Constant::Str(s), _)) = constant(cx, cx.typeck_results(), e) {
+        return Some(s);
+    }
+    if_chain! {
+        if let ExprKind::Path(ref qpath) = e.kind;
+        if let Res::Def(DefKind::Static, def_id) = qpath_res(cx, qpath, e.hir_id);
+        if let Some(Node::Item(item)) = cx.tcx.hir().get_if_local(def_id);
+        if let ItemKind::Static(_, _, body_id) = item.kind;
+        if let ExprKind::Lit(ref lit) = cx.tcx.hir().body(body_id).value.kind;
+        if let LitKind::Str(ref s, _) = lit.node;
+        then {
+            Some(s.to_string())
+        } else {
+            None
+        }
+    }
 }
 
 fn is_trivial_regex(s: &regex_syntax::hir::Hir) -> Option<&'static str> {
@@ -129,15 +174,21 @@ fn is_trivial_regex(s: &regex_syntax::hir::Hir) -> Option<&'static str> {
     }
 }
 
+/// Checks the elements of the array or `vec!` passed to `RegexSet::new`, optionally borrowed.
 fn check_set<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, utf8: bool) {
-    if_chain! {
-        if let ExprKind::AddrOf(BorrowKind::Ref, _, ref expr) = expr.kind;
-        if let ExprKind::Array(exprs) = expr.kind;
-        then {
-            for expr in exprs {
-                check_regex(cx, expr, utf8);
-            }
-        }
+    let expr = match expr.kind {
+        ExprKind::AddrOf(BorrowKind::Ref, _, expr) => expr,
+        _ => expr,
+    };
+    let exprs = if let ExprKind::Array(exprs) = expr.kind {
+        exprs
+    } else if let Some(higher::VecArgs::Vec(exprs)) = higher::vec_macro(cx, expr) {
+        exprs
+    } else {
+        return;
+    };
+    for expr in exprs {
+        check_regex(cx, expr, utf8);
     }
 }
 
@@ -150,7 +201,6 @@ fn check_regex<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, utf8: bool) {
     if let ExprKind::Lit(ref lit) = expr.kind {
         if let LitKind::Str(ref r, style) = lit.node {
             let r = &r.as_str();
-            let offset = if let StrStyle::Raw(n) = style { 2 + n } else { 1 };
             match parser.parse(r) {
                 Ok(r) => {
                     if let Some(repl) = is_trivial_regex(&r) {
@@ -161,7 +211,7 @@ fn check_regex<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, utf8: bool) {
                     span_lint(
                         cx,
                         INVALID_REGEX,
-                        str_span(expr.span, *e.span(), offset),
+                        str_span(cx, expr.span, *e.span(), style),
                         &format!("regex syntax error: {}", e.kind()),
                     );
                 },
@@ -169,7 +219,7 @@ fn check_regex<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, utf8: bool) {
                     span_lint(
                         cx,
                         INVALID_REGEX,
-                        str_span(expr.span, *e.span(), offset),
+                        str_span(cx, expr.span, *e.span(), style),
                         &format!("regex syntax error: {}", e.kind()),
                     );
                 },
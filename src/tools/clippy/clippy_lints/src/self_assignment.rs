@@ -1,7 +1,8 @@
-use crate::utils::{eq_expr_value, snippet, span_lint};
-use rustc_hir::{Expr, ExprKind};
+use crate::utils::{eq_expr_value, in_macro, snippet, span_lint_and_then};
+use rustc_hir::{Expr, ExprKind, Node, StmtKind};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::Symbol;
 
 declare_clippy_lint! {
     /// **What it does:** Checks for explicit self-assignments.
@@ -10,7 +11,8 @@ declare_clippy_lint! {
     /// intentional.
     ///
     /// **Known problems:** If expression contains any deref coercions or
-    /// indexing operations they are assumed not to have any side effects.
+    /// indexing operations of arrays, slices or `Vec`s they are assumed not to
+    /// have any side effects.
     ///
     /// **Example:**
     ///
@@ -36,16 +38,48 @@ declare_lint_pass!(SelfAssignment => [SELF_ASSIGNMENT]);
 impl<'tcx> LateLintPass<'tcx> for SelfAssignment {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if let ExprKind::Assign(lhs, rhs, _) = &expr.kind {
+            if in_macro(expr.span) {
+                return;
+            }
             if eq_expr_value(cx, lhs, rhs) {
                 let lhs = snippet(cx, lhs.span, "<lhs>");
-                let rhs = snippet(cx, rhs.span, "<rhs>");
-                span_lint(
+                let rhs_snip = snippet(cx, rhs.span, "<rhs>");
+                span_lint_and_then(
                     cx,
                     SELF_ASSIGNMENT,
                     expr.span,
-                    &format!("self-assignment of `{}` to `{}`", rhs, lhs),
+                    &format!("self-assignment of `{}` to `{}`", rhs_snip, lhs),
+                    |diag| {
+                        // `self.x = self.x` is often a typo for `self.x = x`
+                        if let ExprKind::Field(_, ident) = rhs.kind {
+                            if has_local_named(cx, expr, ident.name) {
+                                diag.help(&format!("did you mean `{} = {}`?", lhs, ident));
+                            }
+                        }
+                    },
                 );
             }
         }
     }
 }
+
+/// Returns `true` if a parameter of the enclosing body or a local declared before `expr` is
+/// named `name`.
+fn has_local_named(cx: &LateContext<'_>, expr: &Expr<'_>, name: Symbol) -> bool {
+    let mut found = false;
+    for (_, node) in cx.tcx.hir().parent_iter(expr.hir_id) {
+        if let Node::Block(block) = node {
+            for stmt in block.stmts.iter().take_while(|stmt| stmt.span.hi() <= expr.span.lo()) {
+                if let StmtKind::Local(local) = stmt.kind {
+                    local.pat.each_binding(|_, _, _, ident| found |= ident.name == name);
+                }
+            }
+        }
+    }
+    if let Some(body_id) = cx.enclosing_body {
+        for param in cx.tcx.hir().body(body_id).params {
+            param.pat.each_binding(|_, _, _, ident| found |= ident.name == name);
+        }
+    }
+    found
+}
@@ -0,0 +1,153 @@
+use crate::consts::{constant_simple, Constant};
+use crate::utils::{in_constant, in_macro, is_guarded_by, sext, snippet, span_lint, span_lint_and_help, SpanlessEq};
+use if_chain::if_chain;
+use rustc_hir::def::Res;
+use rustc_hir::{BinOpKind, Expr, ExprKind, Node, QPath};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_target::abi::LayoutOf;
+use std::convert::TryFrom;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for shifts of integers by a constant amount which is not
+    /// smaller than the bit width of the shifted integer, or which is negative.
+    ///
+    /// **Why is this bad?** Such a shift panics in debug mode, and only the lowest bits of the
+    /// amount are used in release mode, which is almost certainly not what was intended.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// let x: u8 = 1;
+    /// let y = x << 8;
+    /// ```
+    pub SHIFT_OVERFLOW,
+    correctness,
+    "shifting an integer by at least its bit width"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for shifts of integers by a function parameter, where nothing
+    /// makes sure that the amount is smaller than the bit width of the shifted integer.
+    ///
+    /// **Why is this bad?** The amount is under the control of the caller, and a shift by the
+    /// bit width or more panics in debug mode and silently uses only the lowest bits of the
+    /// amount in release mode.
+    ///
+    /// **Known problems:** Only an enclosing `if` which compares the amount with a constant is
+    /// recognized as a check, other ways to establish the invariant, like an early return or an
+    /// assertion, are not. Generic integers are not linted. Shifts by a constant amount are left
+    /// to `shift_overflow`.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn mask(bits: u32) -> u32 {
+    ///     (1 << bits) - 1
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn mask(bits: u32) -> u32 {
+    ///     1u32.checked_shl(bits).map_or(u32::MAX, |m| m - 1)
+    /// }
+    /// ```
+    pub UNCHECKED_SHIFT,
+    pedantic,
+    "shifting an integer by a function parameter without checking it against the bit width"
+}
+
+declare_lint_pass!(ShiftOverflow => [SHIFT_OVERFLOW, UNCHECKED_SHIFT]);
+
+impl<'tcx> LateLintPass<'tcx> for ShiftOverflow {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if let ExprKind::Binary(op, ref lhs, ref rhs) | ExprKind::AssignOp(op, ref lhs, ref rhs) = expr.kind;
+            if let BinOpKind::Shl | BinOpKind::Shr = op.node;
+            if !in_macro(expr.span);
+            // overflows in constants are compile errors anyway
+            if !in_constant(cx, expr.hir_id);
+            let lhs_ty = cx.typeck_results().expr_ty(lhs);
+            if let ty::Int(_) | ty::Uint(_) = lhs_ty.kind();
+            if let Ok(layout) = cx.layout_of(lhs_ty);
+            then {
+                let bits = u128::from(layout.size.bits());
+                match constant_simple(cx, cx.typeck_results(), rhs) {
+                    Some(Constant::Int(amount)) => {
+                        let (amount, overflows) = match *cx.typeck_results().expr_ty(rhs).kind() {
+                            ty::Int(ity) => {
+                                let amount = sext(cx.tcx, amount, ity);
+                                (amount.to_string(), u128::try_from(amount).map_or(true, |amount| amount >= bits))
+                            },
+                            _ => (amount.to_string(), amount >= bits),
+                        };
+                        if overflows {
+                            span_lint(
+                                cx,
+                                SHIFT_OVERFLOW,
+                                expr.span,
+                                &format!("this shift by {} overflows `{}`, which has {} bits", amount, lhs_ty, bits),
+                            );
+                        }
+                    },
+                    Some(_) => {},
+                    None => {
+                        if is_param(cx, rhs) && !is_guarded_by(cx, expr, |cond| guards(cx, cond, rhs, bits)) {
+                            let method = if op.node == BinOpKind::Shl { "shl" } else { "shr" };
+                            let (lhs, rhs) = (snippet(cx, lhs.span, ".."), snippet(cx, rhs.span, ".."));
+                            span_lint_and_help(
+                                cx,
+                                UNCHECKED_SHIFT,
+                                expr.span,
+                                &format!("this shift amount may not be smaller than the bit width of `{}`", lhs_ty),
+                                None,
+                                &format!(
+                                    "consider using `{0}.checked_{2}({1})` or `{0}.wrapping_{2}({1})`, \
+                                     or masking the amount with `{1} & {3}`",
+                                    lhs,
+                                    rhs,
+                                    method,
+                                    bits - 1
+                                ),
+                            );
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+/// Checks whether `expr` is a path to a parameter of the enclosing function.
+fn is_param(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    if_chain! {
+        if let ExprKind::Path(QPath::Resolved(None, ref path)) = expr.kind;
+        if let Res::Local(id) = path.res;
+        then {
+            matches!(cx.tcx.hir().find(cx.tcx.hir().get_parent_node(id)), Some(Node::Param(_)))
+        } else {
+            false
+        }
+    }
+}
+
+/// Checks whether `cond` makes sure that `amount < bits`.
+fn guards(cx: &LateContext<'_>, cond: &Expr<'_>, amount: &Expr<'_>, bits: u128) -> bool {
+    if let ExprKind::Binary(ref op, ref left, ref right) = cond.kind {
+        let (small, big, strict) = match op.node {
+            BinOpKind::Lt => (left, right, true),
+            BinOpKind::Le => (left, right, false),
+            BinOpKind::Gt => (right, left, true),
+            BinOpKind::Ge => (right, left, false),
+            _ => return false,
+        };
+        SpanlessEq::new(cx).eq_expr(small, amount)
+            && match constant_simple(cx, cx.typeck_results(), big) {
+                Some(Constant::Int(limit)) => limit < bits + u128::from(strict),
+                _ => false,
+            }
+    } else {
+        false
+    }
+}
@@ -0,0 +1,203 @@
+use crate::utils::{is_copy, match_type, paths, qpath_res, span_lint_and_then};
+use if_chain::if_chain;
+use rustc_ast::ast::{IntTy, UintTy};
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{Expr, ExprKind, Item, ItemKind, Mutability};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, Ty};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for declarations of `static mut` items and for every use of
+    /// them.
+    ///
+    /// **Why is this bad?** Each access to a `static mut` is `unsafe`, as nothing prevents data
+    /// races between threads, or aliasing `&mut` references within a single thread. Atomics,
+    /// a `Mutex` or a `RwLock` make the same shared state safe to use, and `thread_local!`
+    /// gives each thread its own value.
+    ///
+    /// **Known problems:** Whether the value is shared between threads at all can't be known,
+    /// so `thread_local!` is only offered as an alternative.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// static mut COUNTER: u32 = 0;
+    ///
+    /// fn next_id() -> u32 {
+    ///     unsafe {
+    ///         COUNTER += 1;
+    ///         COUNTER
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use std::sync::atomic::{AtomicU32, Ordering};
+    ///
+    /// static COUNTER: AtomicU32 = AtomicU32::new(0);
+    ///
+    /// fn next_id() -> u32 {
+    ///     COUNTER.fetch_add(1, Ordering::SeqCst) + 1
+    /// }
+    /// ```
+    pub STATIC_MUT,
+    restriction,
+    "declarations and uses of `static mut` items"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for statics whose type has a `Cell` or `RefCell` field, and is
+    /// made `Sync` by an `unsafe impl Sync` in the same crate.
+    ///
+    /// **Why is this bad?** `Cell` and `RefCell` are not `Sync` because they are not safe to use
+    /// from several threads at once. Asserting `Sync` for a type containing them allows exactly
+    /// that through the static, which is a data race.
+    ///
+    /// **Known problems:** Only fields of the type itself are checked, not fields of fields.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use std::cell::Cell;
+    ///
+    /// struct Stats {
+    ///     hits: Cell<u64>,
+    /// }
+    ///
+    /// unsafe impl Sync for Stats {}
+    ///
+    /// static STATS: Stats = Stats { hits: Cell::new(0) };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use std::sync::atomic::AtomicU64;
+    ///
+    /// struct Stats {
+    ///     hits: AtomicU64,
+    /// }
+    ///
+    /// static STATS: Stats = Stats { hits: AtomicU64::new(0) };
+    /// ```
+    pub UNSAFE_SYNC_STATIC,
+    pedantic,
+    "statics of types with `Cell` or `RefCell` fields which implement `Sync` through an `unsafe impl`"
+}
+
+declare_lint_pass!(StaticMut => [STATIC_MUT, UNSAFE_SYNC_STATIC]);
+
+impl<'tcx> LateLintPass<'tcx> for StaticMut {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        if in_external_macro(cx.sess(), item.span) {
+            return;
+        }
+        if let ItemKind::Static(_, mutability, _) = item.kind {
+            let ty = cx.tcx.type_of(cx.tcx.hir().local_def_id(item.hir_id));
+            match mutability {
+                Mutability::Mut => lint_static_mut_decl(cx, item, ty),
+                Mutability::Not => check_unsafe_sync(cx, item, ty),
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if !in_external_macro(cx.sess(), expr.span);
+            if let ExprKind::Path(ref qpath) = expr.kind;
+            if let Res::Def(DefKind::Static, def_id) = qpath_res(cx, qpath, expr.hir_id);
+            if cx.tcx.is_mutable_static(def_id) && !cx.tcx.is_foreign_item(def_id);
+            then {
+                span_lint_and_then(
+                    cx,
+                    STATIC_MUT,
+                    expr.span,
+                    &format!("use of `static mut {}`", cx.tcx.item_name(def_id)),
+                    |diag| {
+                        if let Some(decl_span) = cx.tcx.hir().span_if_local(def_id) {
+                            diag.span_note(decl_span, "declared here");
+                        }
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Returns the atomic type with the same representation as `ty`, e.g. `AtomicU32` for `u32`.
+fn atomic_for<'tcx>(ty: Ty<'tcx>) -> Option<String> {
+    match ty.kind() {
+        ty::Bool => Some("AtomicBool".to_string()),
+        // `AtomicI128` and `AtomicU128` are unstable
+        ty::Int(int_ty) if *int_ty != IntTy::I128 => Some(format!("AtomicI{}", &int_ty.name_str()[1..])),
+        ty::Uint(uint_ty) if *uint_ty != UintTy::U128 => Some(format!("AtomicU{}", &uint_ty.name_str()[1..])),
+        ty::RawPtr(ty::TypeAndMut {
+            ty: pointee,
+            mutbl: Mutability::Mut,
+        }) => Some(format!("AtomicPtr<{}>", pointee)),
+        _ => None,
+    }
+}
+
+fn lint_static_mut_decl<'tcx>(cx: &LateContext<'tcx>, item: &Item<'_>, ty: Ty<'tcx>) {
+    span_lint_and_then(
+        cx,
+        STATIC_MUT,
+        item.span,
+        &format!("declaration of `static mut {}`", item.ident),
+        |diag| {
+            if let Some(atomic) = atomic_for(ty) {
+                diag.help(&format!(
+                    "use `static {}: {}` instead, which is accessed with `load`, `store` and the like",
+                    item.ident, atomic
+                ));
+                diag.note("each atomic operation takes an `Ordering`, `Ordering::SeqCst` is the safe choice");
+            } else {
+                if is_copy(cx, ty) {
+                    diag.help(&format!(
+                        "use a `Mutex<{0}>` or `RwLock<{0}>` instead, and copy the value out while holding the lock",
+                        ty
+                    ));
+                } else {
+                    diag.help(&format!("use a `Mutex<{0}>` or `RwLock<{0}>` instead", ty));
+                }
+                diag.note("`Mutex::new` is not a `const fn`, so the static has to be initialized lazily");
+            }
+            diag.help("if each thread should have its own value, use `thread_local!` instead");
+        },
+    );
+}
+
+/// Implementation of the `UNSAFE_SYNC_STATIC` lint.
+fn check_unsafe_sync<'tcx>(cx: &LateContext<'tcx>, item: &Item<'_>, ty: Ty<'tcx>) {
+    if_chain! {
+        if let ty::Adt(def, substs) = ty.kind();
+        if let Some(cell_ty) = def
+            .all_fields()
+            .map(|field| field.ty(cx.tcx, substs))
+            .find(|field_ty| match_type(cx, field_ty, &paths::CELL) || match_type(cx, field_ty, &paths::REFCELL));
+        if let Some(sync_trait) = cx.tcx.lang_items().sync_trait();
+        then {
+            let mut sync_impl = None;
+            cx.tcx.for_each_relevant_impl(sync_trait, ty, |impl_id| {
+                if let Some(span) = cx.tcx.hir().span_if_local(impl_id) {
+                    sync_impl = Some(span);
+                }
+            });
+            if let Some(sync_impl) = sync_impl {
+                span_lint_and_then(
+                    cx,
+                    UNSAFE_SYNC_STATIC,
+                    item.span,
+                    &format!(
+                        "static `{}` is shared between threads, but its type has a `{}` field",
+                        item.ident, cell_ty
+                    ),
+                    |diag| {
+                        diag.span_note(sync_impl, &format!("`{}` is made `Sync` here", ty));
+                        diag.help("use atomics, a `Mutex` or a `RwLock` instead of the `Cell` or `RefCell`");
+                        diag.help("if each thread should have its own value, use `thread_local!` instead");
+                    },
+                );
+            }
+        }
+    }
+}
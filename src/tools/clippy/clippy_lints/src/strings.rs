@@ -1,14 +1,22 @@
+use rustc_ast::LitKind;
 use rustc_errors::Applicability;
-use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_hir::def::DefKind;
+use rustc_hir::{BinOpKind, BorrowKind, Expr, ExprKind, Mutability, Node, TyKind, UnOp};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, Ty};
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 use rustc_span::source_map::Spanned;
+use rustc_span::symbol::Symbol;
 
 use if_chain::if_chain;
 
+use crate::utils::sugg::Sugg;
 use crate::utils::SpanlessEq;
-use crate::utils::{get_parent_expr, is_allowed, is_type_diagnostic_item, span_lint, span_lint_and_sugg, walk_ptrs_ty};
+use crate::utils::{
+    get_parent_expr, higher, in_macro, is_allowed, is_type_diagnostic_item, snippet_with_applicability, span_lint,
+    span_lint_and_note, span_lint_and_sugg, walk_ptrs_ty,
+};
 
 declare_clippy_lint! {
     /// **What it does:** Checks for string appends of the form `x = x + y` (without
@@ -84,6 +92,92 @@ declare_clippy_lint! {
     "calling `as_bytes` on a string literal instead of using a byte string literal"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for comparisons of strings converted to the same case with
+    /// `to_lowercase()`, `to_uppercase()` or their ASCII variants, like
+    /// `a.to_lowercase() == b.to_lowercase()` or `name.to_uppercase() == "FOO"`.
+    ///
+    /// **Why is this bad?** Every conversion allocates a new `String` which is only used for the
+    /// comparison. `eq_ignore_ascii_case()` compares the strings without allocating.
+    ///
+    /// **Known problems:** `eq_ignore_ascii_case()` only ignores the case of ASCII letters, so it
+    /// is only suggested if the comparison is known to be about ASCII strings, i.e. if the ASCII
+    /// variants are used on both sides, or if the other side is an ASCII literal. Strictly
+    /// speaking, some non-ASCII characters are converted to ASCII letters by `to_lowercase()`,
+    /// e.g. the Kelvin sign, which `eq_ignore_ascii_case()` does not match.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let name = "Foo";
+    /// if name.to_ascii_lowercase() == "foo" {}
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let name = "Foo";
+    /// if name.eq_ignore_ascii_case("foo") {}
+    /// ```
+    pub MANUAL_IGNORE_CASE_CMP,
+    perf,
+    "comparing strings converted to the same case instead of using `eq_ignore_ascii_case()`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for comparisons of a string converted to lower (upper) case with
+    /// a string literal which contains ASCII upper (lower) case letters.
+    ///
+    /// **Why is this bad?** The converted string can never contain these letters, so the
+    /// comparison is always `false` (or always `true` for `!=`). Most likely, the literal was
+    /// meant to be converted as well.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let name = "Foo";
+    /// if name.to_lowercase() == "Foo" {}
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let name = "Foo";
+    /// if name.eq_ignore_ascii_case("foo") {}
+    /// ```
+    pub MISMATCHED_CASE_CMP,
+    correctness,
+    "comparing a string converted to lower or upper case with a literal in another case"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for slicing the whole range of a `String`, `Vec`, array, boxed
+    /// slice or slice, like `&s[..]`, and for reborrows like `&*s` or `&**s` of a `s: &String`
+    /// which is passed where a `&str` is expected.
+    ///
+    /// **Why is this bad?** The slicing or reborrowing does nothing, the value can be used
+    /// directly where deref coercion applies, or converted with `as_str()` or `as_slice()`
+    /// otherwise.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn takes_str(_: &str) {}
+    ///
+    /// let s = String::from("foo");
+    /// takes_str(&s[..]);
+    /// let t = &s[..];
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn takes_str(_: &str) {}
+    ///
+    /// let s = String::from("foo");
+    /// takes_str(&s);
+    /// let t = s.as_str();
+    /// ```
+    pub REDUNDANT_SLICING,
+    complexity,
+    "slicing the whole range of a string or slice, or reborrowing a `&String` where a `&str` is expected"
+}
+
 declare_lint_pass!(StringAdd => [STRING_ADD, STRING_ADD_ASSIGN]);
 
 impl<'tcx> LateLintPass<'tcx> for StringAdd {
@@ -160,8 +254,7 @@ declare_lint_pass!(StringLitAsBytes => [STRING_LIT_AS_BYTES]);
 
 impl<'tcx> LateLintPass<'tcx> for StringLitAsBytes {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
-        use crate::utils::{snippet, snippet_with_applicability};
-        use rustc_ast::LitKind;
+        use crate::utils::snippet;
 
         if_chain! {
             if let ExprKind::MethodCall(path, _, args, _) = &e.kind;
@@ -206,3 +299,337 @@ impl<'tcx> LateLintPass<'tcx> for StringLitAsBytes {
         }
     }
 }
+
+declare_lint_pass!(CaseConversionCmp => [MANUAL_IGNORE_CASE_CMP, MISMATCHED_CASE_CMP]);
+
+impl<'tcx> LateLintPass<'tcx> for CaseConversionCmp {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
+        if_chain! {
+            if let ExprKind::Binary(op, ref left, ref right) = e.kind;
+            if let BinOpKind::Eq | BinOpKind::Ne = op.node;
+            if !in_macro(e.span);
+            then {
+                let eq = op.node == BinOpKind::Eq;
+                match (case_conversion(cx, left), case_conversion(cx, right)) {
+                    (Some(left), Some(right)) => check_conversions(cx, e, eq, &left, &right),
+                    (Some(conversion), None) => {
+                        if let Some(lit) = str_lit(right) {
+                            check_conversion_with_lit(cx, e, eq, &conversion, right, lit);
+                        }
+                    },
+                    (None, Some(conversion)) => {
+                        if let Some(lit) = str_lit(left) {
+                            check_conversion_with_lit(cx, e, eq, &conversion, left, lit);
+                        }
+                    },
+                    (None, None) => {},
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Case {
+    Lower,
+    Upper,
+}
+
+/// A string converted to lower or upper case, like `recv.to_lowercase()`.
+struct CaseConversion<'a> {
+    recv: &'a Expr<'a>,
+    method: Symbol,
+    case: Case,
+    /// Whether only ASCII letters are converted
+    ascii: bool,
+}
+
+fn case_conversion<'a>(cx: &LateContext<'_>, expr: &'a Expr<'a>) -> Option<CaseConversion<'a>> {
+    if let ExprKind::MethodCall(ref path, _, ref args, _) = expr.kind {
+        let (case, ascii) = match &*path.ident.as_str() {
+            "to_lowercase" => (Case::Lower, false),
+            "to_uppercase" => (Case::Upper, false),
+            "to_ascii_lowercase" => (Case::Lower, true),
+            "to_ascii_uppercase" => (Case::Upper, true),
+            _ => return None,
+        };
+        let ty = walk_ptrs_ty(cx.typeck_results().expr_ty(&args[0]));
+        if args.len() == 1 && (*ty.kind() == ty::Str || is_type_diagnostic_item(cx, ty, sym!(string_type))) {
+            return Some(CaseConversion {
+                recv: &args[0],
+                method: path.ident.name,
+                case,
+                ascii,
+            });
+        }
+    }
+    None
+}
+
+fn str_lit(expr: &Expr<'_>) -> Option<Symbol> {
+    match expr.kind {
+        ExprKind::Lit(ref lit) => match lit.node {
+            LitKind::Str(s, _) => Some(s),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Lints `a.to_lowercase() == b.to_lowercase()`.
+fn check_conversions(
+    cx: &LateContext<'_>,
+    e: &Expr<'_>,
+    eq: bool,
+    left: &CaseConversion<'_>,
+    right: &CaseConversion<'_>,
+) {
+    if left.case != right.case {
+        return;
+    }
+    if left.ascii && right.ascii {
+        let mut app = Applicability::MachineApplicable;
+        let arg = Sugg::hir_with_applicability(cx, right.recv, "..", &mut app);
+        let arg = if let ty::Ref(..) = cx.typeck_results().expr_ty(right.recv).kind() {
+            arg
+        } else {
+            arg.addr()
+        };
+        suggest_eq_ignore_ascii_case(cx, e, eq, left, &arg.to_string(), app);
+    } else {
+        span_lint_and_note(
+            cx,
+            MANUAL_IGNORE_CASE_CMP,
+            e.span,
+            "manual case-insensitive comparison",
+            None,
+            "both sides of the comparison allocate a new `String`, consider using `eq_ignore_ascii_case()` \
+             if the strings are known to be ASCII",
+        );
+    }
+}
+
+/// Lints `a.to_lowercase() == "foo"`.
+fn check_conversion_with_lit(
+    cx: &LateContext<'_>,
+    e: &Expr<'_>,
+    eq: bool,
+    conversion: &CaseConversion<'_>,
+    lit_expr: &Expr<'_>,
+    lit: Symbol,
+) {
+    let lit = lit.as_str();
+    let (mismatched, case) = match conversion.case {
+        Case::Lower => (lit.chars().any(|c| c.is_ascii_uppercase()), "uppercase"),
+        Case::Upper => (lit.chars().any(|c| c.is_ascii_lowercase()), "lowercase"),
+    };
+    if mismatched {
+        span_lint_and_note(
+            cx,
+            MISMATCHED_CASE_CMP,
+            e.span,
+            &format!("this comparison is always `{}`", !eq),
+            None,
+            &format!(
+                "`{}()` never returns ASCII {} letters, but the literal contains some",
+                conversion.method, case
+            ),
+        );
+    } else if lit.is_ascii() {
+        let mut app = Applicability::MachineApplicable;
+        let arg = snippet_with_applicability(cx, lit_expr.span, "..", &mut app);
+        suggest_eq_ignore_ascii_case(cx, e, eq, conversion, &arg, app);
+    } else {
+        span_lint_and_note(
+            cx,
+            MANUAL_IGNORE_CASE_CMP,
+            e.span,
+            "manual case-insensitive comparison",
+            None,
+            "the converted string is allocated only for the comparison",
+        );
+    }
+}
+
+fn suggest_eq_ignore_ascii_case(
+    cx: &LateContext<'_>,
+    e: &Expr<'_>,
+    eq: bool,
+    conversion: &CaseConversion<'_>,
+    arg: &str,
+    mut app: Applicability,
+) {
+    let recv = Sugg::hir_with_applicability(cx, conversion.recv, "..", &mut app).maybe_par();
+    span_lint_and_sugg(
+        cx,
+        MANUAL_IGNORE_CASE_CMP,
+        e.span,
+        "manual case-insensitive ASCII comparison",
+        "consider using `eq_ignore_ascii_case()`",
+        format!("{}{}.eq_ignore_ascii_case({})", if eq { "" } else { "!" }, recv, arg),
+        app,
+    );
+}
+
+declare_lint_pass!(RedundantSlicing => [REDUNDANT_SLICING]);
+
+impl<'tcx> LateLintPass<'tcx> for RedundantSlicing {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
+        if in_macro(e.span) {
+            return;
+        }
+        if let ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, ref inner) = e.kind {
+            match inner.kind {
+                ExprKind::Index(ref base, ref index) => {
+                    if let Some(higher::Range {
+                        start: None, end: None, ..
+                    }) = higher::range(index)
+                    {
+                        check_full_slice(cx, e, base);
+                    }
+                },
+                ExprKind::Unary(UnOp::UnDeref, ref inner) => check_reborrow(cx, e, inner),
+                _ => {},
+            }
+        }
+    }
+}
+
+/// Lints `e`, which is `&base[..]`.
+fn check_full_slice<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>, base: &Expr<'_>) {
+    let base_ty = cx.typeck_results().expr_ty(base);
+    let mut app = Applicability::MachineApplicable;
+    let base_sugg = Sugg::hir_with_applicability(cx, base, "..", &mut app);
+    let sugg = match base_ty.kind() {
+        // `&s[..]` of a `s: &str` is `s` itself
+        ty::Ref(_, inner, Mutability::Not) if matches!(inner.kind(), ty::Str | ty::Slice(_)) => base_sugg.to_string(),
+        _ => {
+            let target = walk_ptrs_ty(base_ty);
+            let derefs_to_slice = match target.kind() {
+                ty::Str | ty::Slice(_) | ty::Array(..) => true,
+                ty::Adt(def, _) if def.is_box() => matches!(target.boxed_ty().kind(), ty::Str | ty::Slice(_)),
+                _ => {
+                    is_type_diagnostic_item(cx, target, sym!(string_type))
+                        || is_type_diagnostic_item(cx, target, sym!(vec_type))
+                },
+            };
+            if !derefs_to_slice {
+                return;
+            }
+            if coerces_to_ref_to(cx, e, walk_ptrs_ty(cx.typeck_results().expr_ty(e))) {
+                // deref coercion takes care of the rest
+                if base_ty.is_ref() {
+                    base_sugg.to_string()
+                } else {
+                    base_sugg.addr().to_string()
+                }
+            } else if !matches!(base.kind, ExprKind::Path(_) | ExprKind::Field(..)) && !base_ty.is_ref() {
+                // `temporary().as_str()` does not live as long as `&temporary()[..]` in a `let`
+                return;
+            } else if is_type_diagnostic_item(cx, target, sym!(string_type)) {
+                format!("{}.as_str()", base_sugg.maybe_par())
+            } else if is_type_diagnostic_item(cx, target, sym!(vec_type)) {
+                format!("{}.as_slice()", base_sugg.maybe_par())
+            } else {
+                return;
+            }
+        },
+    };
+    span_lint_and_sugg(
+        cx,
+        REDUNDANT_SLICING,
+        e.span,
+        "redundant slicing of the whole range",
+        "try",
+        sugg,
+        app,
+    );
+}
+
+/// Lints `e`, which is `&*inner`, if it reborrows a `&String` as a `&String` or a `&str`.
+fn check_reborrow<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>, inner: &Expr<'_>) {
+    let (s, twice) = match inner.kind {
+        ExprKind::Unary(UnOp::UnDeref, ref s) => (&**s, true),
+        _ => (inner, false),
+    };
+    if_chain! {
+        if let ty::Ref(_, string, Mutability::Not) = cx.typeck_results().expr_ty(s).kind();
+        if is_type_diagnostic_item(cx, string, sym!(string_type));
+        then {
+            let mut app = Applicability::MachineApplicable;
+            let s_sugg = Sugg::hir_with_applicability(cx, s, "..", &mut app);
+            let sugg = if coerces_to_ref_to(cx, e, cx.tcx.types.str_) {
+                s_sugg.to_string()
+            } else if twice {
+                format!("{}.as_str()", s_sugg.maybe_par())
+            } else {
+                return;
+            };
+            span_lint_and_sugg(cx, REDUNDANT_SLICING, e.span, "redundant reborrow", "try", sugg, app);
+        }
+    }
+}
+
+/// Checks whether `e` is at a coercion site where a reference to `slice` (a `str` or `[T]`) is
+/// expected, so that deref coercion applies to any reference which derefs to it.
+fn coerces_to_ref_to<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>, slice: Ty<'tcx>) -> bool {
+    if_chain! {
+        if let Some(expected) = coercion_target(cx, e);
+        if let ty::Ref(_, expected, _) = expected.kind();
+        then {
+            matches!((slice.kind(), expected.kind()), (ty::Str, ty::Str) | (ty::Slice(_), ty::Slice(_)))
+        } else {
+            false
+        }
+    }
+}
+
+/// Returns the declared type `e` is coerced to, if `e` is an argument of a function call, the
+/// initializer of a `let` with a type annotation, or the returned value of a function.
+fn coercion_target<'tcx>(cx: &LateContext<'tcx>, e: &Expr<'_>) -> Option<Ty<'tcx>> {
+    let map = cx.tcx.hir();
+    match map.find(map.get_parent_node(e.hir_id))? {
+        Node::Expr(parent) => match parent.kind {
+            ExprKind::Call(ref func, ref args) => {
+                let index = args.iter().position(|arg| arg.hir_id == e.hir_id)?;
+                match cx.typeck_results().expr_ty(func).kind() {
+                    ty::FnDef(def_id, _) => cx.tcx.fn_sig(*def_id).skip_binder().inputs().get(index).copied(),
+                    _ => None,
+                }
+            },
+            // the receiver is auto-referenced, not coerced
+            ExprKind::MethodCall(_, _, ref args, _) => {
+                let index = args.iter().skip(1).position(|arg| arg.hir_id == e.hir_id)? + 1;
+                let def_id = cx.typeck_results().type_dependent_def_id(parent.hir_id)?;
+                cx.tcx.fn_sig(def_id).skip_binder().inputs().get(index).copied()
+            },
+            ExprKind::Ret(Some(_)) => fn_output(cx, e),
+            _ => None,
+        },
+        Node::Local(local) => match local.ty?.kind {
+            TyKind::Rptr(_, ref mut_ty) if !matches!(mut_ty.ty.kind, TyKind::Infer) => {
+                Some(cx.typeck_results().pat_ty(local.pat))
+            },
+            _ => None,
+        },
+        Node::Block(block) if block.expr.map_or(false, |tail| tail.hir_id == e.hir_id) => {
+            let body = map.body(map.body_owned_by(map.enclosing_body_owner(e.hir_id)));
+            if map.get_parent_node(block.hir_id) == body.value.hir_id {
+                fn_output(cx, e)
+            } else {
+                None
+            }
+        },
+        _ => None,
+    }
+}
+
+/// Returns the declared return type of the function `e` is in, if it is not in a closure.
+fn fn_output<'tcx>(cx: &LateContext<'tcx>, e: &Expr<'_>) -> Option<Ty<'tcx>> {
+    let owner = cx.tcx.hir().enclosing_body_owner(e.hir_id);
+    let def_id = cx.tcx.hir().local_def_id(owner).to_def_id();
+    match cx.tcx.def_kind(def_id) {
+        DefKind::Fn | DefKind::AssocFn => Some(cx.tcx.fn_sig(def_id).skip_binder().output()),
+        _ => None,
+    }
+}
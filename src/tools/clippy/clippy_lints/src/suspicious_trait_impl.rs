@@ -1,18 +1,29 @@
-use crate::utils::{get_trait_def_id, span_lint, trait_ref_of_method};
+use crate::utils::{get_trait_def_id, in_macro, qpath_res, span_lint, span_lint_and_then, trait_ref_of_method};
 use if_chain::if_chain;
 use rustc_hir as hir;
+use rustc_hir::def::Res;
+use rustc_hir::def_id::DefId;
 use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
-use rustc_lint::{LateContext, LateLintPass};
+use rustc_lint::{LateContext, LateLintPass, Lint};
 use rustc_middle::hir::map::Map;
 use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::source_map::Span;
+use rustc_span::symbol::Symbol;
 
 declare_clippy_lint! {
     /// **What it does:** Lints for suspicious operations in impls of arithmetic operators, e.g.
     /// subtracting elements in an Add impl.
     ///
+    /// Implementations combining the fields of `self` with the corresponding fields of the
+    /// right-hand side, like `self.x + rhs.x`, are checked field by field. The
+    /// `wrapping_*`, `saturating_*`, `checked_*` and `overflowing_*` methods count as the
+    /// operation they perform, and `Neg` and `Not` implementations are checked as well.
+    ///
     /// **Why this is bad?** This is probably a typo or copy-and-paste error and not intended.
     ///
-    /// **Known problems:** None.
+    /// **Known problems:** Implementations which also use operators in any other way, e.g.
+    /// `self.num * rhs.den + rhs.num * self.den`, are only checked if they contain a single
+    /// operation, as mixed operations are usually intended.
     ///
     /// **Example:**
     /// ```ignore
@@ -23,6 +34,17 @@ declare_clippy_lint! {
     ///         Foo(self.0 - other.0)
     ///     }
     /// }
+    ///
+    /// impl Sub for Vec2 {
+    ///     type Output = Vec2;
+    ///
+    ///     fn sub(self, rhs: Vec2) -> Vec2 {
+    ///         Vec2 {
+    ///             x: self.x - rhs.x,
+    ///             y: self.y + rhs.y,
+    ///         }
+    ///     }
+    /// }
     /// ```
     pub SUSPICIOUS_ARITHMETIC_IMPL,
     correctness,
@@ -148,6 +170,70 @@ impl<'tcx> LateLintPass<'tcx> for SuspiciousImpl {
             }
         }
     }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, impl_item: &'tcx hir::ImplItem<'_>) {
+        if_chain! {
+            if !in_macro(impl_item.span);
+            if let hir::ImplItemKind::Fn(_, body_id) = impl_item.kind;
+            if let Some(trait_ref) = trait_ref_of_method(cx, impl_item.hir_id);
+            if let Some(trait_id) = trait_ref.trait_def_id();
+            if let Some((impl_trait, expected, lint)) = op_of_trait(cx, trait_id);
+            let body = cx.tcx.hir().body(body_id);
+            if let Some(self_id) = param_binding(body, 0);
+            then {
+                let rhs_id = param_binding(body, 1);
+                if matches!(expected, Op::Bin(_)) && rhs_id.is_none() {
+                    return;
+                }
+                let mut visitor = FieldOpVisitor {
+                    cx,
+                    self_id,
+                    rhs_id,
+                    ops: Vec::new(),
+                    mixed: false,
+                };
+                visitor.visit_expr(&body.value);
+                if visitor.mixed {
+                    return;
+                }
+
+                let (expected_ops, wrong_ops): (Vec<_>, Vec<_>) =
+                    visitor.ops.iter().partition(|field_op| field_op.op == expected);
+                // Several different operators are most likely intended
+                if wrong_ops.iter().any(|field_op| field_op.op != wrong_ops[0].op) {
+                    return;
+                }
+
+                // A single binary operator is linted by `check_expr`
+                let mut counter = BinaryExprVisitor { nb_binops: 0 };
+                walk_expr(&mut counter, &body.value);
+                let single_binop = counter.nb_binops == 1;
+
+                for field_op in wrong_ops {
+                    let what = match (field_op.method, field_op.op) {
+                        (Some(method), _) => format!("`{}`", method),
+                        (None, Op::Bin(_)) if single_binop => continue,
+                        (None, Op::Bin(_)) => "binary operator".to_string(),
+                        (None, Op::Un(_)) => "unary operator".to_string(),
+                    };
+                    span_lint_and_then(
+                        cx,
+                        lint,
+                        field_op.op_span,
+                        &format!("suspicious use of {} in `{}` impl", what, impl_trait),
+                        |diag| {
+                            if let Some(expected_op) = expected_ops.first() {
+                                diag.span_note(
+                                    expected_op.expr_span,
+                                    &format!("the field `{}` uses `{}`", expected_op.field, expected.as_str()),
+                                );
+                            }
+                        },
+                    );
+                }
+            }
+        }
+    }
 }
 
 fn check_binop(
@@ -206,3 +292,192 @@ impl<'tcx> Visitor<'tcx> for BinaryExprVisitor {
         NestedVisitorMap::None
     }
 }
+
+#[derive(Clone, Copy, PartialEq)]
+enum Op {
+    Bin(hir::BinOpKind),
+    Un(hir::UnOp),
+}
+
+impl Op {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Bin(op) => op.as_str(),
+            Self::Un(op) => op.as_str(),
+        }
+    }
+}
+
+/// The operator traits checked field by field, with the operation their implementations are
+/// expected to perform. The `*Assign` variants of the binary operators are checked as well.
+const OP_TRAITS: [(&str, Op); 12] = [
+    ("Add", Op::Bin(hir::BinOpKind::Add)),
+    ("Sub", Op::Bin(hir::BinOpKind::Sub)),
+    ("Mul", Op::Bin(hir::BinOpKind::Mul)),
+    ("Div", Op::Bin(hir::BinOpKind::Div)),
+    ("Rem", Op::Bin(hir::BinOpKind::Rem)),
+    ("BitAnd", Op::Bin(hir::BinOpKind::BitAnd)),
+    ("BitOr", Op::Bin(hir::BinOpKind::BitOr)),
+    ("BitXor", Op::Bin(hir::BinOpKind::BitXor)),
+    ("Shl", Op::Bin(hir::BinOpKind::Shl)),
+    ("Shr", Op::Bin(hir::BinOpKind::Shr)),
+    ("Neg", Op::Un(hir::UnOp::UnNeg)),
+    ("Not", Op::Un(hir::UnOp::UnNot)),
+];
+
+/// Returns the name of the operator trait `trait_id`, the operation it is expected to perform
+/// and the lint to emit for other operations.
+fn op_of_trait(cx: &LateContext<'_>, trait_id: DefId) -> Option<(String, Op, &'static Lint)> {
+    let [krate, module] = crate::utils::paths::OPS_MODULE;
+    OP_TRAITS.iter().find_map(|&(name, op)| {
+        if get_trait_def_id(cx, &[krate, module, name]) == Some(trait_id) {
+            return Some((name.to_string(), op, SUSPICIOUS_ARITHMETIC_IMPL));
+        }
+        let assign_name = format!("{}Assign", name);
+        if matches!(op, Op::Bin(_)) && get_trait_def_id(cx, &[krate, module, &assign_name]) == Some(trait_id) {
+            return Some((assign_name, op, SUSPICIOUS_OP_ASSIGN_IMPL));
+        }
+        None
+    })
+}
+
+/// Returns the operation performed by a method like `wrapping_add` or `checked_neg`.
+fn method_op(name: &str) -> Option<Op> {
+    let op = ["wrapping_", "saturating_", "checked_", "overflowing_"]
+        .iter()
+        .find_map(|prefix| name.strip_prefix(prefix))
+        .unwrap_or(name);
+    Some(match op {
+        "add" => Op::Bin(hir::BinOpKind::Add),
+        "sub" => Op::Bin(hir::BinOpKind::Sub),
+        "mul" => Op::Bin(hir::BinOpKind::Mul),
+        "div" => Op::Bin(hir::BinOpKind::Div),
+        "rem" => Op::Bin(hir::BinOpKind::Rem),
+        "shl" => Op::Bin(hir::BinOpKind::Shl),
+        "shr" => Op::Bin(hir::BinOpKind::Shr),
+        "neg" => Op::Un(hir::UnOp::UnNeg),
+        _ => return None,
+    })
+}
+
+fn param_binding(body: &hir::Body<'_>, idx: usize) -> Option<hir::HirId> {
+    match body.params.get(idx)?.pat.kind {
+        hir::PatKind::Binding(_, id, ..) => Some(id),
+        _ => None,
+    }
+}
+
+/// An operation on a field of `self` and the same field of the right-hand side, like
+/// `self.x + rhs.x`, or on a field of `self` alone for unary operators.
+struct FieldOp {
+    op: Op,
+    /// The span of the operator or the method name
+    op_span: Span,
+    expr_span: Span,
+    field: String,
+    method: Option<Symbol>,
+}
+
+struct FieldOpVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    self_id: hir::HirId,
+    rhs_id: Option<hir::HirId>,
+    ops: Vec<FieldOp>,
+    /// Operators are used on anything else than corresponding fields
+    mixed: bool,
+}
+
+impl<'a, 'tcx> FieldOpVisitor<'a, 'tcx> {
+    /// Returns the path of the field `expr` of the parameter `param_id`, e.g. `pos.x`.
+    fn field_of(&self, mut expr: &hir::Expr<'_>, param_id: hir::HirId) -> Option<String> {
+        let mut path = Vec::new();
+        while let hir::ExprKind::Field(ref base, ident) = expr.kind {
+            path.push(ident.as_str().to_string());
+            expr = base;
+        }
+        while let hir::ExprKind::Unary(hir::UnOp::UnDeref, ref inner) = expr.kind {
+            expr = inner;
+        }
+        match expr.kind {
+            hir::ExprKind::Path(ref qpath)
+                if !path.is_empty() && qpath_res(self.cx, qpath, expr.hir_id) == Res::Local(param_id) =>
+            {
+                path.reverse();
+                Some(path.join("."))
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the path of the field if `lhs` and `rhs` are the same field of `self` and of
+    /// the right-hand side, in any order.
+    fn field_pair(&self, lhs: &hir::Expr<'_>, rhs: &hir::Expr<'_>) -> Option<String> {
+        let rhs_id = self.rhs_id?;
+        let field = self.field_of(lhs, self.self_id)?;
+        if self.field_of(rhs, rhs_id).as_ref() == Some(&field) {
+            return Some(field);
+        }
+        let field = self.field_of(lhs, rhs_id)?;
+        if self.field_of(rhs, self.self_id).as_ref() == Some(&field) {
+            return Some(field);
+        }
+        None
+    }
+
+    /// Returns the path of the field if `operand` is a field of `self` in the implementation of
+    /// a unary operator. In other implementations, unary operators are not expected.
+    fn unary_field(&self, operand: &hir::Expr<'_>) -> Option<String> {
+        if self.rhs_id.is_some() {
+            return None;
+        }
+        self.field_of(operand, self.self_id)
+    }
+
+    fn push(&mut self, op: Op, op_span: Span, expr: &hir::Expr<'_>, field: Option<String>, method: Option<Symbol>) {
+        match field {
+            Some(field) => self.ops.push(FieldOp {
+                op,
+                op_span,
+                expr_span: expr.span,
+                field,
+                method,
+            }),
+            None => self.mixed = true,
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for FieldOpVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr<'_>) {
+        match expr.kind {
+            hir::ExprKind::Binary(op, ref lhs, ref rhs) | hir::ExprKind::AssignOp(op, ref lhs, ref rhs)
+                if !op.node.is_comparison() && !op.node.is_lazy() =>
+            {
+                let field = self.field_pair(lhs, rhs);
+                self.push(Op::Bin(op.node), op.span, expr, field, None);
+            },
+            hir::ExprKind::Unary(op @ (hir::UnOp::UnNeg | hir::UnOp::UnNot), ref operand) => {
+                let field = self.unary_field(operand);
+                self.push(Op::Un(op), expr.span, expr, field, None);
+            },
+            hir::ExprKind::MethodCall(ref path, _, ref args, _) => {
+                if let Some(op) = method_op(&path.ident.as_str()) {
+                    let field = match (op, &**args) {
+                        (Op::Bin(_), [lhs, rhs]) => self.field_pair(lhs, rhs),
+                        (Op::Un(_), [operand]) => self.unary_field(operand),
+                        _ => None,
+                    };
+                    self.push(op, path.ident.span, expr, field, Some(path.ident.name));
+                }
+            },
+            _ => {},
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
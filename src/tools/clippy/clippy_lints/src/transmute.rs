@@ -1,5 +1,5 @@
 use crate::utils::{
-    in_constant, is_normalizable, last_path_segment, match_def_path, paths, snippet, span_lint, span_lint_and_sugg,
+    in_constant, is_normalizable, last_path_segment, match_def_path, paths, snippet, span_lint, span_lint_and_help,
     span_lint_and_then, sugg,
 };
 use if_chain::if_chain;
@@ -148,15 +148,15 @@ declare_clippy_lint! {
 declare_clippy_lint! {
     /// **What it does:** Checks for transmutes from a `&[u8]` to a `&str`.
     ///
-    /// **Why is this bad?** Not every byte slice is a valid UTF-8 string.
+    /// **Why is this bad?** Not every byte slice is a valid UTF-8 string, and
+    /// [`from_utf8_unchecked`] states the requirement on the bytes in its name while
+    /// being as fast as `transmute`.
     ///
     /// **Known problems:**
-    /// - [`from_utf8`] which this lint suggests using is slower than `transmute`
-    /// as it needs to validate the input.
-    /// If you are certain that the input is always a valid UTF-8,
-    /// use [`from_utf8_unchecked`] which is as fast as `transmute`
-    /// but has a semantically meaningful name.
-    /// - You might want to handle errors returned from [`from_utf8`] instead of calling `unwrap`.
+    /// - If the bytes are not known to be valid UTF-8, use [`from_utf8`] instead, which
+    /// validates the input and returns an error otherwise.
+    /// - [`from_utf8_unchecked`] is not a `const fn` yet, so transmutes in constant
+    /// contexts are linted without a suggestion.
     ///
     /// [`from_utf8`]: https://doc.rust-lang.org/std/str/fn.from_utf8.html
     /// [`from_utf8_unchecked`]: https://doc.rust-lang.org/std/str/fn.from_utf8_unchecked.html
@@ -169,7 +169,9 @@ declare_clippy_lint! {
     /// }
     ///
     /// // should be:
-    /// let _ = std::str::from_utf8(b).unwrap();
+    /// let _ = unsafe { std::str::from_utf8_unchecked(b) };
+    /// // or, if `b` may not be valid UTF-8:
+    /// let _ = std::str::from_utf8(b);
     /// ```
     pub TRANSMUTE_BYTES_TO_STR,
     complexity,
@@ -242,6 +244,31 @@ declare_clippy_lint! {
     "transmutes from a float to an integer"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for transmutes from a byte array to a number.
+    ///
+    /// **Why is this bad?** Transmutes are dangerous and error-prone, whereas `from_ne_bytes` is
+    /// safe and makes it easy to switch to a fixed byte order with `from_le_bytes` or
+    /// `from_be_bytes`.
+    ///
+    /// **Known problems:** `from_ne_bytes` of the floating point types is not a `const fn` yet,
+    /// so these transmutes are linted without a suggestion in constant contexts.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let bytes = [1_u8, 0, 0, 0];
+    /// unsafe {
+    ///     let _: u32 = std::mem::transmute(bytes);
+    /// }
+    ///
+    /// // should be:
+    /// let _ = u32::from_ne_bytes(bytes);
+    /// ```
+    pub TRANSMUTE_BYTES_TO_NUM,
+    complexity,
+    "transmutes from a byte array to a number"
+}
+
 declare_clippy_lint! {
     /// **What it does:** Checks for transmutes from a pointer to a pointer, or
     /// from a reference to a reference.
@@ -308,6 +335,7 @@ declare_lint_pass!(Transmute => [
     TRANSMUTE_INT_TO_BOOL,
     TRANSMUTE_INT_TO_FLOAT,
     TRANSMUTE_FLOAT_TO_INT,
+    TRANSMUTE_BYTES_TO_NUM,
     UNSOUND_COLLECTION_TRANSMUTE,
     TRANSMUTES_EXPRESSIBLE_AS_PTR_CASTS,
 ]);
@@ -340,6 +368,16 @@ impl<'tcx> LateLintPass<'tcx> for Transmute {
                 let to_ty = cx.typeck_results().expr_ty(e);
 
                 match (&from_ty.kind(), &to_ty.kind()) {
+                    // Regions are erased at this point, so the lifetimes of the references may differ
+                    (ty::Ref(..), _) if from_ty == to_ty => span_lint_and_help(
+                        cx,
+                        USELESS_TRANSMUTE,
+                        e.span,
+                        &format!("transmute from a reference (`{}`) to a reference of the same type", from_ty),
+                        None,
+                        "if this changes the lifetime of the reference, make sure the referenced value lives \
+                         long enough and document why, otherwise remove the transmute",
+                    ),
                     _ if from_ty == to_ty => span_lint(
                         cx,
                         USELESS_TRANSMUTE,
@@ -473,18 +511,37 @@ impl<'tcx> LateLintPass<'tcx> for Transmute {
                                     ""
                                 };
 
-                                span_lint_and_sugg(
+                                span_lint_and_then(
                                     cx,
                                     TRANSMUTE_BYTES_TO_STR,
                                     e.span,
                                     &format!("transmute from a `{}` to a `{}`", from_ty, to_ty),
-                                    "consider using",
-                                    format!(
-                                        "std::str::from_utf8{}({}).unwrap()",
-                                        postfix,
-                                        snippet(cx, args[0].span, ".."),
-                                    ),
-                                    Applicability::Unspecified,
+                                    |diag| {
+                                        // `from_utf8_unchecked` is not a `const fn` yet
+                                        if const_context {
+                                            diag.note(&format!(
+                                                "`std::str::from_utf8_unchecked{}` cannot be used in a constant \
+                                                 context yet",
+                                                postfix,
+                                            ));
+                                            return;
+                                        }
+                                        diag.span_suggestion(
+                                            e.span,
+                                            "consider using",
+                                            format!(
+                                                "std::str::from_utf8_unchecked{}({})",
+                                                postfix,
+                                                snippet(cx, args[0].span, ".."),
+                                            ),
+                                            Applicability::Unspecified,
+                                        );
+                                        diag.help(&format!(
+                                            "if the bytes may not be valid UTF-8, use `std::str::from_utf8{}` \
+                                             and handle the error",
+                                            postfix,
+                                        ));
+                                    },
                                 );
                             } else {
                                 if (cx.tcx.erase_regions(&from_ty) != cx.tcx.erase_regions(&to_ty))
@@ -617,6 +674,34 @@ impl<'tcx> LateLintPass<'tcx> for Transmute {
                             );
                         },
                     ),
+                    (ty::Array(elem_ty, _), ty::Int(_) | ty::Uint(_) | ty::Float(_))
+                        if *elem_ty.kind() == ty::Uint(ast::UintTy::U8) => span_lint_and_then(
+                        cx,
+                        TRANSMUTE_BYTES_TO_NUM,
+                        e.span,
+                        &format!("transmute from a `{}` to a `{}`", from_ty, to_ty),
+                        |diag| {
+                            // `from_ne_bytes` of the floats is not a `const fn` yet
+                            if const_context && to_ty.is_floating_point() {
+                                diag.note(&format!(
+                                    "`{}::from_ne_bytes` cannot be used in a constant context yet",
+                                    to_ty
+                                ));
+                                return;
+                            }
+                            let arg = sugg::Sugg::hir(cx, &args[0], "..");
+                            diag.span_suggestion(
+                                e.span,
+                                "consider using",
+                                format!("{}::from_ne_bytes({})", to_ty, arg.to_string()),
+                                Applicability::Unspecified,
+                            );
+                            diag.note(
+                                "`from_ne_bytes` uses the byte order of the target, use `from_le_bytes` or \
+                                 `from_be_bytes` if the bytes have a fixed order",
+                            );
+                        },
+                    ),
                     (ty::Adt(from_adt, from_substs), ty::Adt(to_adt, to_substs)) => {
                         if from_adt.did != to_adt.did ||
                                 !COLLECTIONS.iter().any(|path| match_def_path(cx, to_adt.did, path)) {
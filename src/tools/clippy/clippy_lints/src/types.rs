@@ -6,18 +6,22 @@ use std::collections::BTreeMap;
 
 use if_chain::if_chain;
 use rustc_ast::{FloatTy, IntTy, LitFloatType, LitIntType, LitKind, UintTy};
+use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::{Applicability, DiagnosticBuilder};
 use rustc_hir as hir;
+use rustc_hir::def::{DefKind, Res};
 use rustc_hir::intravisit::{walk_body, walk_expr, walk_ty, FnKind, NestedVisitorMap, Visitor};
 use rustc_hir::{
-    BinOpKind, Block, Body, Expr, ExprKind, FnDecl, FnRetTy, FnSig, GenericArg, GenericParamKind, HirId, ImplItem,
-    ImplItemKind, Item, ItemKind, Lifetime, Local, MatchSource, MutTy, Mutability, Node, QPath, Stmt, StmtKind,
-    TraitFn, TraitItem, TraitItemKind, TyKind, UnOp,
+    BinOpKind, Block, Body, Crate, Expr, ExprKind, FnDecl, FnRetTy, FnSig, GenericArg, GenericParamKind, HirId,
+    ImplItem, ImplItemKind, Item, ItemKind, Lifetime, Local, MatchSource, MutTy, Mutability, Node, QPath, Stmt,
+    StmtKind, TraitFn, TraitItem, TraitItemKind, TyKind, UnOp, UseKind,
 };
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::hir::map::Map;
 use rustc_middle::lint::in_external_macro;
-use rustc_middle::ty::{self, InferTy, Ty, TyCtxt, TyS, TypeckResults};
+use rustc_middle::ty::subst::GenericArgKind;
+use rustc_middle::ty::util::Discr;
+use rustc_middle::ty::{self, InferTy, Ty, TyCtxt, TyS, TypeAndMut, TypeckResults};
 use rustc_session::{declare_lint_pass, declare_tool_lint, impl_lint_pass};
 use rustc_span::hygiene::{ExpnKind, MacroKind};
 use rustc_span::source_map::Span;
@@ -27,12 +31,15 @@ use rustc_target::spec::abi::Abi;
 use rustc_typeck::hir_ty_to_ty;
 
 use crate::consts::{constant, Constant};
+use crate::utils::msrvs::{self, meets_msrv, update_msrv_from_attrs, RustcVersion};
 use crate::utils::paths;
+use crate::utils::sugg::Sugg;
 use crate::utils::{
-    clip, comparisons, differing_macro_contexts, higher, in_constant, indent_of, int_bits, is_type_diagnostic_item,
+    clip, comparisons, differing_macro_contexts, get_parent_expr, get_trait_def_id, higher, in_constant, indent_of,
+    int_bits, is_entrypoint_fn, is_guarded_by, is_test_function, is_trait_impl_item, is_type_diagnostic_item,
     last_path_segment, match_def_path, match_path, method_chain_args, multispan_sugg, numeric_literal::NumericLiteral,
     qpath_res, reindent_multiline, sext, snippet, snippet_opt, snippet_with_applicability, snippet_with_macro_callsite,
-    span_lint, span_lint_and_help, span_lint_and_sugg, span_lint_and_then, unsext,
+    span_lint, span_lint_and_help, span_lint_and_note, span_lint_and_sugg, span_lint_and_then, unsext, SpanlessEq,
 };
 
 declare_clippy_lint! {
@@ -72,7 +79,7 @@ declare_clippy_lint! {
     /// the heap. So if you `Box` its contents, you just add another level of indirection.
     ///
     /// **Known problems:** Vec<Box<T: Sized>> makes sense if T is a large type (see #3530,
-    /// 1st comment).
+    /// 1st comment), or if the elements need a stable address.
     ///
     /// **Example:**
     /// ```rust
@@ -89,13 +96,13 @@ declare_clippy_lint! {
     /// }
     /// ```
     pub VEC_BOX,
-    complexity,
+    pedantic,
     "usage of `Vec<Box<T>>` where T: Sized, vector elements are already on the heap"
 }
 
 declare_clippy_lint! {
-    /// **What it does:** Checks for use of `Option<Option<_>>` in function signatures and type
-    /// definitions
+    /// **What it does:** Checks for use of `Option<Option<_>>` in function signatures, type
+    /// definitions and type aliases, also when it is spelled through type aliases
     ///
     /// **Why is this bad?** `Option<_>` represents an optional value. `Option<Option<_>>`
     /// represents an optional optional value which is logically the same thing as an optional
@@ -131,6 +138,40 @@ declare_clippy_lint! {
     "usage of `Option<Option<T>>`"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for use of `Option<Result<_, _>>` in function signatures, type
+    /// definitions and type aliases, also when it is spelled through type aliases like
+    /// `type Result<T> = std::result::Result<T, Error>`.
+    ///
+    /// **Why is this bad?** `Result<Option<_>, _>` is the more common way to represent a
+    /// fallible optional value, e.g. it works with the `?` operator in functions returning a
+    /// `Result`. Mixing both forms is confusing. `Option::transpose` and `Result::transpose`
+    /// convert between them.
+    ///
+    /// **Known problems:** `Option<Result<_, _>>` is the natural type of some APIs, like the
+    /// items of an iterator over fallible values.
+    ///
+    /// **Example**
+    /// ```rust
+    /// # use std::num::ParseIntError;
+    /// fn parse(s: Option<&str>) -> Option<Result<u32, ParseIntError>> {
+    ///     s.map(str::parse)
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    ///
+    /// ```rust
+    /// # use std::num::ParseIntError;
+    /// fn parse(s: Option<&str>) -> Result<Option<u32>, ParseIntError> {
+    ///     s.map(str::parse).transpose()
+    /// }
+    /// ```
+    pub OPTION_OF_RESULT,
+    pedantic,
+    "usage of `Option<Result<T, E>>`"
+}
+
 declare_clippy_lint! {
     /// **What it does:** Checks for usage of any `LinkedList`, suggesting to use a
     /// `Vec` or a `VecDeque` (formerly called `RingBuf`).
@@ -157,6 +198,9 @@ declare_clippy_lint! {
     /// **Known problems:** False positives – the instances where using a
     /// `LinkedList` makes sense are few and far between, but they can still happen.
     ///
+    /// Besides type annotations, this also lints `use` items importing `LinkedList` and
+    /// calls like `LinkedList::new()`.
+    ///
     /// **Example:**
     /// ```rust
     /// # use std::collections::LinkedList;
@@ -219,7 +263,15 @@ pub struct Types {
     vec_box_size_threshold: u64,
 }
 
-impl_lint_pass!(Types => [BOX_VEC, VEC_BOX, OPTION_OPTION, LINKEDLIST, BORROWED_BOX, REDUNDANT_ALLOCATION]);
+impl_lint_pass!(Types => [
+    BOX_VEC,
+    VEC_BOX,
+    OPTION_OPTION,
+    OPTION_OF_RESULT,
+    LINKEDLIST,
+    BORROWED_BOX,
+    REDUNDANT_ALLOCATION,
+]);
 
 impl<'tcx> LateLintPass<'tcx> for Types {
     fn check_fn(&mut self, cx: &LateContext<'_>, _: FnKind<'_>, decl: &FnDecl<'_>, _: &Body<'_>, _: Span, id: HirId) {
@@ -234,6 +286,10 @@ impl<'tcx> LateLintPass<'tcx> for Types {
     }
 
     fn check_struct_field(&mut self, cx: &LateContext<'_>, field: &hir::StructField<'_>) {
+        // Derives may copy the type of a field into the structs they generate
+        if field.span.from_expansion() {
+            return;
+        }
         self.check_ty(cx, &field.ty, false);
     }
 
@@ -250,6 +306,157 @@ impl<'tcx> LateLintPass<'tcx> for Types {
             self.check_ty(cx, ty, true);
         }
     }
+
+    fn check_item(&mut self, cx: &LateContext<'_>, item: &Item<'_>) {
+        if let ItemKind::TyAlias(ref ty, _) = item.kind {
+            if !item.span.from_expansion() {
+                NestedOptionVisitor { cx }.visit_ty(ty);
+            }
+        }
+
+        if_chain! {
+            if let ItemKind::Use(ref path, UseKind::Single) = item.kind;
+            if !item.span.from_expansion();
+            if let Res::Def(_, def_id) = path.res;
+            if match_def_path(cx, def_id, &paths::LINKED_LIST);
+            then {
+                lint_linked_list(cx, path.span);
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'_>, expr: &Expr<'_>) {
+        if_chain! {
+            if let ExprKind::Call(ref func, _) = expr.kind;
+            if let ExprKind::Path(QPath::TypeRelative(ref ty, _)) = func.kind;
+            if !expr.span.from_expansion();
+            if let TyKind::Path(ref qpath) = ty.kind;
+            if let Some(def_id) = qpath_res(cx, qpath, ty.hir_id).opt_def_id();
+            if match_def_path(cx, def_id, &paths::LINKED_LIST);
+            then {
+                lint_linked_list(cx, expr.span);
+            }
+        }
+    }
+}
+
+fn lint_linked_list(cx: &LateContext<'_>, span: Span) {
+    span_lint_and_help(
+        cx,
+        LINKEDLIST,
+        span,
+        "I see you're using a LinkedList! Perhaps you meant some other data structure?",
+        None,
+        "a `VecDeque` might work",
+    );
+}
+
+/// Returns the type wrapped by `ty` if it is an `Option`.
+fn option_inner<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<Ty<'tcx>> {
+    match ty.kind() {
+        ty::Adt(def, substs) if cx.tcx.is_diagnostic_item(sym!(option_type), def.did) => Some(substs.type_at(0)),
+        _ => None,
+    }
+}
+
+/// Returns `true` if `ty` is an `Option<Option<_>>` or an `Option<Result<_, _>>`.
+fn is_nested_option<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+    option_inner(cx, ty).map_or(false, |inner| {
+        is_type_diagnostic_item(cx, inner, sym!(option_type)) || is_type_diagnostic_item(cx, inner, sym!(result_type))
+    })
+}
+
+/// Checks for `OPTION_OPTION` and `OPTION_OF_RESULT` in `hir_ty`, a path resolving to `res`,
+/// seeing through type aliases. Returns `true` if a lint was emitted.
+fn check_nested_option(cx: &LateContext<'_>, hir_ty: &hir::Ty<'_>, res: Res) -> bool {
+    let ty = hir_ty_to_ty(cx.tcx, hir_ty);
+    let inner = match option_inner(cx, ty) {
+        Some(inner) => inner,
+        None => return false,
+    };
+    // An alias which is nested by itself is linted where it is defined
+    if let Res::Def(DefKind::TyAlias, def_id) = res {
+        if is_nested_option(cx, cx.tcx.type_of(def_id)) {
+            return false;
+        }
+    }
+    if is_type_diagnostic_item(cx, inner, sym!(option_type)) {
+        span_lint(
+            cx,
+            OPTION_OPTION,
+            hir_ty.span,
+            "consider using `Option<T>` instead of `Option<Option<T>>` or a custom \
+             enum if you need to distinguish all 3 cases",
+        );
+        true
+    } else if is_type_diagnostic_item(cx, inner, sym!(result_type)) {
+        span_lint_and_note(
+            cx,
+            OPTION_OF_RESULT,
+            hir_ty.span,
+            "consider using `Result<Option<T>, E>` instead of `Option<Result<T, E>>`",
+            None,
+            "`Option::transpose` converts an `Option<Result<T, E>>` into a `Result<Option<T>, E>`, \
+             `Result::transpose` converts it back",
+        );
+        true
+    } else {
+        false
+    }
+}
+
+/// Lints nested options in the definition of a type alias.
+struct NestedOptionVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for NestedOptionVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_ty(&mut self, ty: &'tcx hir::Ty<'tcx>) {
+        if ty.span.from_expansion() {
+            return;
+        }
+        if let TyKind::Path(ref qpath) = ty.kind {
+            if check_nested_option(self.cx, ty, qpath_res(self.cx, qpath, ty.hir_id)) {
+                return;
+            }
+        }
+        walk_ty(self, ty);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
+
+/// Returns `true` if `ty` is an ADT containing itself, like `Node` in
+/// `struct Node { children: Vec<Box<Node>> }`, directly or through the fields of other local ADTs.
+fn is_recursive_adt<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+    fn contains<'tcx>(
+        cx: &LateContext<'tcx>,
+        adt: Ty<'tcx>,
+        target: Ty<'tcx>,
+        visited: &mut FxHashSet<Ty<'tcx>>,
+    ) -> bool {
+        if let ty::Adt(def, substs) = adt.kind() {
+            def.all_fields().any(|field| {
+                field.ty(cx.tcx, substs).walk().any(|arg| match arg.unpack() {
+                    GenericArgKind::Type(inner) => {
+                        inner == target
+                            || (matches!(inner.kind(), ty::Adt(inner_def, _) if inner_def.did.is_local())
+                                && visited.insert(inner)
+                                && contains(cx, inner, target, visited))
+                    },
+                    _ => false,
+                })
+            })
+        } else {
+            false
+        }
+    }
+
+    contains(cx, ty, ty, &mut FxHashSet::default())
 }
 
 /// Checks if `qpath` has last segment with type parameter matching `path`
@@ -304,6 +511,43 @@ impl Types {
         }
     }
 
+    /// Checks for `VEC_BOX` in `hir_ty`, which is either a `Vec` or a type alias that may resolve
+    /// to one. Returns `true` if the lint was emitted.
+    fn check_vec_box(&self, cx: &LateContext<'_>, hir_ty: &hir::Ty<'_>, through_alias: bool) -> bool {
+        let ty = hir_ty_to_ty(cx.tcx, hir_ty);
+        if_chain! {
+            if let ty::Adt(def, substs) = ty.kind();
+            if cx.tcx.is_diagnostic_item(sym!(vec_type), def.did);
+            let elem_ty = substs.type_at(0);
+            if elem_ty.is_box();
+            // At this point, we know the element is `Box<T>`, now get `T`
+            let boxed_ty = elem_ty.boxed_ty();
+            if boxed_ty.is_sized(cx.tcx.at(hir_ty.span), cx.param_env);
+            // The box is what makes a recursive type representable
+            if !is_recursive_adt(cx, boxed_ty);
+            if let Ok(boxed_ty_size) = cx.layout_of(boxed_ty).map(|l| l.size.bytes());
+            if boxed_ty_size <= self.vec_box_size_threshold;
+            then {
+                span_lint_and_sugg(
+                    cx,
+                    VEC_BOX,
+                    hir_ty.span,
+                    "`Vec<T>` is already on the heap, the boxing is unnecessary.",
+                    "try",
+                    format!("Vec<{}>", boxed_ty),
+                    if through_alias {
+                        Applicability::MaybeIncorrect
+                    } else {
+                        Applicability::MachineApplicable
+                    },
+                );
+                true
+            } else {
+                false
+            }
+        }
+    }
+
     /// Recursively check for `TypePass` lints in the given type. Stop at the first
     /// lint found.
     ///
@@ -392,62 +636,20 @@ impl Types {
                             return; // don't recurse into the type
                         }
                     } else if cx.tcx.is_diagnostic_item(sym!(vec_type), def_id) {
-                        if_chain! {
-                            // Get the _ part of Vec<_>
-                            if let Some(ref last) = last_path_segment(qpath).args;
-                            if let Some(ty) = last.args.iter().find_map(|arg| match arg {
-                                GenericArg::Type(ty) => Some(ty),
-                                _ => None,
-                            });
-                            // ty is now _ at this point
-                            if let TyKind::Path(ref ty_qpath) = ty.kind;
-                            let res = qpath_res(cx, ty_qpath, ty.hir_id);
-                            if let Some(def_id) = res.opt_def_id();
-                            if Some(def_id) == cx.tcx.lang_items().owned_box();
-                            // At this point, we know ty is Box<T>, now get T
-                            if let Some(ref last) = last_path_segment(ty_qpath).args;
-                            if let Some(boxed_ty) = last.args.iter().find_map(|arg| match arg {
-                                GenericArg::Type(ty) => Some(ty),
-                                _ => None,
-                            });
-                            let ty_ty = hir_ty_to_ty(cx.tcx, boxed_ty);
-                            if ty_ty.is_sized(cx.tcx.at(ty.span), cx.param_env);
-                            if let Ok(ty_ty_size) = cx.layout_of(ty_ty).map(|l| l.size.bytes());
-                            if ty_ty_size <= self.vec_box_size_threshold;
-                            then {
-                                span_lint_and_sugg(
-                                    cx,
-                                    VEC_BOX,
-                                    hir_ty.span,
-                                    "`Vec<T>` is already on the heap, the boxing is unnecessary.",
-                                    "try",
-                                    format!("Vec<{}>", ty_ty),
-                                    Applicability::MachineApplicable,
-                                );
-                                return; // don't recurse into the type
-                            }
+                        if self.check_vec_box(cx, hir_ty, false) {
+                            return; // don't recurse into the type
                         }
                     } else if cx.tcx.is_diagnostic_item(sym!(option_type), def_id) {
-                        if match_type_parameter(cx, qpath, &paths::OPTION).is_some() {
-                            span_lint(
-                                cx,
-                                OPTION_OPTION,
-                                hir_ty.span,
-                                "consider using `Option<T>` instead of `Option<Option<T>>` or a custom \
-                                 enum if you need to distinguish all 3 cases",
-                            );
+                        if check_nested_option(cx, hir_ty, res) {
                             return; // don't recurse into the type
                         }
                     } else if match_def_path(cx, def_id, &paths::LINKED_LIST) {
-                        span_lint_and_help(
-                            cx,
-                            LINKEDLIST,
-                            hir_ty.span,
-                            "I see you're using a LinkedList! Perhaps you meant some other data structure?",
-                            None,
-                            "a `VecDeque` might work",
-                        );
+                        lint_linked_list(cx, hir_ty.span);
                         return; // don't recurse into the type
+                    } else if let Res::Def(DefKind::TyAlias, _) = res {
+                        if self.check_vec_box(cx, hir_ty, true) || check_nested_option(cx, hir_ty, res) {
+                            return; // don't recurse into the type
+                        }
                     }
                 }
                 match *qpath {
@@ -827,7 +1029,7 @@ fn fmt_stmts_and_call(
         .map(|v| reindent_multiline(v.into(), true, Some(call_expr_indent)).into_owned())
         .collect();
 
-    let mut stmts_and_call_snippet = stmts_and_call.join(&format!("{}{}", ";\n", " ".repeat(call_expr_indent)));
+    let mut stmts_and_call_snippet = stmts_and_call.join(&format!(";\n{}", " ".repeat(call_expr_indent)));
     // expr is not in a block statement or result expression position, wrap in a block
     let parent_node = cx.tcx.hir().find(cx.tcx.hir().get_parent_node(call_expr.hir_id));
     if !matches!(parent_node, Some(Node::Block(_))) && !matches!(parent_node, Some(Node::Stmt(_))) {
@@ -1028,6 +1230,13 @@ declare_clippy_lint! {
     ///     x as u8
     /// }
     /// ```
+    /// If the value may really be out of range, use `TryFrom` and handle the error:
+    /// ```rust
+    /// # use std::convert::TryFrom;
+    /// fn as_u8(x: u64) -> Option<u8> {
+    ///     u8::try_from(x).ok()
+    /// }
+    /// ```
     pub CAST_POSSIBLE_TRUNCATION,
     pedantic,
     "casts that may cause truncation of the value, e.g., `x as u8` where `x: u32`, or `x as i32` where `x: f32`"
@@ -1182,6 +1391,101 @@ declare_clippy_lint! {
     "casting a function pointer to a numeric type not wide enough to store the address"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for casts of an enum to an integer type that cannot
+    /// hold the discriminant of all of its variants.
+    ///
+    /// **Why is this bad?** The discriminant is silently truncated, so different variants
+    /// may end up as the same integer.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// enum Status {
+    ///     Ok = 0,
+    ///     Unknown = 256,
+    /// }
+    ///
+    /// let code = Status::Unknown as u8; // `0`, same as `Status::Ok`
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # enum Status {
+    /// #     Ok = 0,
+    /// #     Unknown = 256,
+    /// # }
+    /// fn code(status: &Status) -> u16 {
+    ///     match status {
+    ///         Status::Ok => 0,
+    ///         Status::Unknown => 256,
+    ///     }
+    /// }
+    /// ```
+    pub CAST_ENUM_TRUNCATION,
+    pedantic,
+    "casts of an enum to an integer type too small for some of its discriminants"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for casts of a shared reference or a `*const T` pointer to a
+    /// `*mut T` pointer, also through several chained casts like `&x as *const T as *mut T`.
+    ///
+    /// **Why is this bad?** Writing through the resulting pointer is undefined behavior
+    /// unless the memory behind it is actually mutable, which the original type does not
+    /// promise.
+    ///
+    /// **Known problems:** Shared references to types with interior mutability (e.g.
+    /// `UnsafeCell`) are not linted.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let x = 42;
+    /// let p = &x as *const i32 as *mut i32;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let mut x = 42;
+    /// let p = &mut x as *mut i32;
+    /// ```
+    pub CAST_AWAY_CONSTNESS,
+    restriction,
+    "casts of shared references or `*const T` pointers to `*mut T` pointers"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for casts of a `char` to an integer type too small to hold
+    /// every Unicode scalar value (e.g. `c as u8`), and for integers squeezed through `u8` to
+    /// become a `char` (e.g. `n as u8 as char`). Casts of constants whose value fits are
+    /// not linted.
+    ///
+    /// **Why is this bad?** Both casts silently drop the upper bits: `'€' as u8` is `0xAC`,
+    /// and `0x20AC as u8 as char` is `'¬'`. Only `u8` can be cast to `char` directly, so the
+    /// second form is usually a workaround that hides the truncation.
+    ///
+    /// **Known problems:** Casts inside `if c.is_ascii() { .. }` are not linted, but other
+    /// ways to check the range of the character first, like `match`ing on it or returning
+    /// early, are not recognized.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let c = 'a';
+    /// # let n = 0x61_u32;
+    /// let byte = c as u8;
+    /// let ch = n as u8 as char;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let c = 'a';
+    /// # let n = 0x61_u32;
+    /// let code = u32::from(c);
+    /// let ch = std::char::from_u32(n).unwrap_or(std::char::REPLACEMENT_CHARACTER);
+    /// ```
+    pub CAST_CHAR_TRUNCATION,
+    pedantic,
+    "casts truncating a `char`, or an integer cast through `u8` to `char`"
+}
+
 /// Returns the size in bits of an integral type.
 /// Will return 0 if the type is not an int or uint variant
 fn int_ty_to_nbits(typ: Ty<'_>, tcx: TyCtxt<'_>) -> u64 {
@@ -1246,28 +1550,32 @@ fn should_strip_parens(op: &Expr<'_>, snip: &str) -> bool {
     false
 }
 
-fn span_lossless_lint(cx: &LateContext<'_>, expr: &Expr<'_>, op: &Expr<'_>, cast_from: Ty<'_>, cast_to: Ty<'_>) {
-    // Do not suggest using From in consts/statics until it is valid to do so (see #2267).
-    if in_constant(cx, expr.hir_id) {
-        return;
-    }
+/// Returns the snippet of the operand of a cast, to be used as the argument of a function call.
+fn cast_operand_snippet(cx: &LateContext<'_>, op: &Expr<'_>, applicability: &mut Applicability) -> String {
     // The suggestion is to use a function call, so if the original expression
     // has parens on the outside, they are no longer needed.
-    let mut applicability = Applicability::MachineApplicable;
-    let opt = snippet_opt(cx, op.span);
-    let sugg = opt.as_ref().map_or_else(
+    snippet_opt(cx, op.span).map_or_else(
         || {
-            applicability = Applicability::HasPlaceholders;
-            ".."
+            *applicability = Applicability::HasPlaceholders;
+            "..".to_string()
         },
         |snip| {
-            if should_strip_parens(op, snip) {
-                &snip[1..snip.len() - 1]
+            if should_strip_parens(op, &snip) {
+                snip[1..snip.len() - 1].to_string()
             } else {
-                snip.as_str()
+                snip
             }
         },
-    );
+    )
+}
+
+fn span_lossless_lint(cx: &LateContext<'_>, expr: &Expr<'_>, op: &Expr<'_>, cast_from: Ty<'_>, cast_to: Ty<'_>) {
+    // Do not suggest using From in consts/statics until it is valid to do so (see #2267).
+    if in_constant(cx, expr.hir_id) {
+        return;
+    }
+    let mut applicability = Applicability::MachineApplicable;
+    let sugg = cast_operand_snippet(cx, op, &mut applicability);
 
     span_lint_and_sugg(
         cx,
@@ -1283,13 +1591,88 @@ fn span_lossless_lint(cx: &LateContext<'_>, expr: &Expr<'_>, op: &Expr<'_>, cast
     );
 }
 
+/// Suggests replacing an integer cast that may lose information with a call to `try_from`.
+/// Nothing is suggested in constant contexts, where `try_from` cannot be called, or if
+/// `TryFrom` is not available.
+fn suggest_try_from(
+    cx: &LateContext<'_>,
+    diag: &mut DiagnosticBuilder<'_>,
+    expr: &Expr<'_>,
+    op: &Expr<'_>,
+    cast_to: Ty<'_>,
+    msrv: Option<RustcVersion>,
+) {
+    if in_constant(cx, expr.hir_id)
+        || !meets_msrv(msrv, msrvs::TRY_FROM)
+        || get_trait_def_id(cx, &paths::TRY_FROM_TRAIT).is_none()
+    {
+        return;
+    }
+    // The error has to be handled by hand, so this is never machine applicable
+    let mut applicability = Applicability::MaybeIncorrect;
+    let sugg = cast_operand_snippet(cx, op, &mut applicability);
+    diag.span_suggestion(
+        expr.span,
+        "use `try_from` and handle the error",
+        format!("{}::try_from({})", cast_to, sugg),
+        applicability,
+    );
+    diag.note("`TryFrom` is not in the prelude, it may have to be imported with `use std::convert::TryFrom;`");
+}
+
+/// Suggests making the rounding of a float to integer cast explicit. Nothing is suggested in
+/// constant contexts, where the rounding methods cannot be called, for unsuffixed literals, or if
+/// the operand is rounded already. The rounded cast still saturates, so it is linted as well.
+fn suggest_explicit_rounding(cx: &LateContext<'_>, diag: &mut DiagnosticBuilder<'_>, expr: &Expr<'_>, op: &Expr<'_>) {
+    if in_constant(cx, expr.hir_id) {
+        return;
+    }
+    let rounding_methods = ["round", "trunc", "floor", "ceil"];
+    match op.kind {
+        ExprKind::MethodCall(ref path, _, _, _) if rounding_methods.contains(&&*path.ident.as_str()) => return,
+        // methods cannot be called on unsuffixed float literals
+        ExprKind::Lit(ref lit) if matches!(lit.node, LitKind::Float(_, LitFloatType::Unsuffixed)) => return,
+        _ => {},
+    }
+    let ty = match expr.kind {
+        ExprKind::Cast(_, ref ty) => ty,
+        _ => return,
+    };
+    let mut applicability = Applicability::MaybeIncorrect;
+    let recv = Sugg::hir_with_applicability(cx, op, "..", &mut applicability).maybe_par();
+    let ty = snippet_with_applicability(cx, ty.span, "..", &mut applicability);
+    diag.span_suggestion(
+        expr.span,
+        "round to the nearest integer",
+        format!("{}.round() as {}", recv, ty),
+        applicability,
+    );
+    diag.span_suggestion(
+        expr.span,
+        "or make the truncation explicit",
+        format!("{}.trunc() as {}", recv, ty),
+        applicability,
+    );
+    diag.note(&format!(
+        "the rounded value still saturates at the bounds of `{}`, so the cast is still linted",
+        ty
+    ));
+}
+
 enum ArchSuffix {
     _32,
     _64,
     None,
 }
 
-fn check_loss_of_sign(cx: &LateContext<'_>, expr: &Expr<'_>, op: &Expr<'_>, cast_from: Ty<'_>, cast_to: Ty<'_>) {
+fn check_loss_of_sign(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    op: &Expr<'_>,
+    cast_from: Ty<'_>,
+    cast_to: Ty<'_>,
+    msrv: Option<RustcVersion>,
+) {
     if !cast_from.is_signed() || cast_to.is_signed() {
         return;
     }
@@ -1325,7 +1708,7 @@ fn check_loss_of_sign(cx: &LateContext<'_>, expr: &Expr<'_>, op: &Expr<'_>, cast
         }
     }
 
-    span_lint(
+    span_lint_and_then(
         cx,
         CAST_SIGN_LOSS,
         expr.span,
@@ -1333,10 +1716,18 @@ fn check_loss_of_sign(cx: &LateContext<'_>, expr: &Expr<'_>, op: &Expr<'_>, cast
             "casting `{}` to `{}` may lose the sign of the value",
             cast_from, cast_to
         ),
+        |diag| suggest_try_from(cx, diag, expr, op, cast_to, msrv),
     );
 }
 
-fn check_truncation_and_wrapping(cx: &LateContext<'_>, expr: &Expr<'_>, cast_from: Ty<'_>, cast_to: Ty<'_>) {
+fn check_truncation_and_wrapping(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    op: &Expr<'_>,
+    cast_from: Ty<'_>,
+    cast_to: Ty<'_>,
+    msrv: Option<RustcVersion>,
+) {
     let arch_64_suffix = " on targets with 64-bit wide pointers";
     let arch_32_suffix = " on targets with 32-bit wide pointers";
     let cast_unsigned_to_signed = !cast_from.is_signed() && cast_to.is_signed();
@@ -1372,7 +1763,7 @@ fn check_truncation_and_wrapping(cx: &LateContext<'_>, expr: &Expr<'_>, cast_fro
             ),
         };
     if span_truncation {
-        span_lint(
+        span_lint_and_then(
             cx,
             CAST_POSSIBLE_TRUNCATION,
             expr.span,
@@ -1386,10 +1777,11 @@ fn check_truncation_and_wrapping(cx: &LateContext<'_>, expr: &Expr<'_>, cast_fro
                     ArchSuffix::None => "",
                 }
             ),
+            |diag| suggest_try_from(cx, diag, expr, op, cast_to, msrv),
         );
     }
     if span_wrap {
-        span_lint(
+        span_lint_and_then(
             cx,
             CAST_POSSIBLE_WRAP,
             expr.span,
@@ -1403,6 +1795,7 @@ fn check_truncation_and_wrapping(cx: &LateContext<'_>, expr: &Expr<'_>, cast_fro
                     ArchSuffix::None => "",
                 }
             ),
+            |diag| suggest_try_from(cx, diag, expr, op, cast_to, msrv),
         );
     }
 }
@@ -1417,7 +1810,18 @@ fn check_lossless(cx: &LateContext<'_>, expr: &Expr<'_>, op: &Expr<'_>, cast_fro
     }
 }
 
-declare_lint_pass!(Casts => [
+pub struct Casts {
+    msrv: Option<RustcVersion>,
+}
+
+impl Casts {
+    #[must_use]
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(Casts => [
     CAST_PRECISION_LOSS,
     CAST_SIGN_LOSS,
     CAST_POSSIBLE_TRUNCATION,
@@ -1427,6 +1831,9 @@ declare_lint_pass!(Casts => [
     CAST_PTR_ALIGNMENT,
     FN_TO_NUMERIC_CAST,
     FN_TO_NUMERIC_CAST_WITH_TRUNCATION,
+    CAST_ENUM_TRUNCATION,
+    CAST_AWAY_CONSTNESS,
+    CAST_CHAR_TRUNCATION,
 ]);
 
 // Check if the given type is either `core::ffi::c_void` or
@@ -1456,6 +1863,10 @@ fn fp_ty_mantissa_nbits(typ: Ty<'_>) -> u32 {
 }
 
 impl<'tcx> LateLintPass<'tcx> for Casts {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>, krate: &'tcx Crate<'_>) {
+        update_msrv_from_attrs(&mut self.msrv, cx.sess(), &krate.item.attrs);
+    }
+
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if expr.span.from_expansion() {
             return;
@@ -1503,10 +1914,13 @@ impl<'tcx> LateLintPass<'tcx> for Casts {
                 }
             }
             if cast_from.is_numeric() && cast_to.is_numeric() && !in_external_macro(cx.sess(), expr.span) {
-                lint_numeric_casts(cx, expr, ex, cast_from, cast_to);
+                lint_numeric_casts(cx, expr, ex, cast_from, cast_to, self.msrv);
             }
 
             lint_cast_ptr_alignment(cx, expr, cast_from, cast_to);
+            lint_enum_cast_truncation(cx, expr, cast_from, cast_to);
+            lint_cast_away_constness(cx, expr, ex, cast_to);
+            lint_cast_char_truncation(cx, expr, ex, cast_from, cast_to);
         }
     }
 }
@@ -1517,6 +1931,7 @@ fn lint_numeric_casts<'tcx>(
     cast_expr: &Expr<'_>,
     cast_from: Ty<'tcx>,
     cast_to: Ty<'tcx>,
+    msrv: Option<RustcVersion>,
 ) {
     match (cast_from.is_integral(), cast_to.is_integral()) {
         (true, false) => {
@@ -1534,11 +1949,18 @@ fn lint_numeric_casts<'tcx>(
             }
         },
         (false, true) => {
-            span_lint(
+            span_lint_and_then(
                 cx,
                 CAST_POSSIBLE_TRUNCATION,
                 expr.span,
                 &format!("casting `{}` to `{}` may truncate the value", cast_from, cast_to),
+                |diag| {
+                    diag.note(&format!(
+                        "`as` rounds towards zero and saturates at the bounds of `{}`, check the range first",
+                        cast_to
+                    ));
+                    suggest_explicit_rounding(cx, diag, expr, cast_expr);
+                },
             );
             if !cast_to.is_signed() {
                 span_lint(
@@ -1553,8 +1975,8 @@ fn lint_numeric_casts<'tcx>(
             }
         },
         (true, true) => {
-            check_loss_of_sign(cx, expr, cast_expr, cast_from, cast_to);
-            check_truncation_and_wrapping(cx, expr, cast_from, cast_to);
+            check_loss_of_sign(cx, expr, cast_expr, cast_from, cast_to, msrv);
+            check_truncation_and_wrapping(cx, expr, cast_expr, cast_from, cast_to, msrv);
             check_lossless(cx, expr, cast_expr, cast_from, cast_to);
         },
         (false, false) => {
@@ -1577,6 +1999,10 @@ fn lint_cast_ptr_alignment<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, cast_f
     if_chain! {
         if let ty::RawPtr(from_ptr_ty) = &cast_from.kind();
         if let ty::RawPtr(to_ptr_ty) = &cast_to.kind();
+        // the alignment of unsized pointees depends on their metadata
+        if from_ptr_ty.ty.is_sized(cx.tcx.at(expr.span), cx.param_env);
+        if to_ptr_ty.ty.is_sized(cx.tcx.at(expr.span), cx.param_env);
+        // bails out on generic pointees
         if let Ok(from_layout) = cx.layout_of(from_ptr_ty.ty);
         if let Ok(to_layout) = cx.layout_of(to_ptr_ty.ty);
         if from_layout.align.abi < to_layout.align.abi;
@@ -1601,6 +2027,185 @@ fn lint_cast_ptr_alignment<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, cast_f
     }
 }
 
+fn is_mut_ptr(ty: Ty<'_>) -> bool {
+    matches!(ty.kind(), ty::RawPtr(TypeAndMut { mutbl: Mutability::Mut, .. }))
+}
+
+fn lint_cast_away_constness<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, cast_expr: &Expr<'_>, cast_to: Ty<'tcx>) {
+    if !is_mut_ptr(cast_to) {
+        return;
+    }
+    // In a chain of casts, only lint the outermost one
+    if let Some(parent) = get_parent_expr(cx, expr) {
+        if let ExprKind::Cast(..) = parent.kind {
+            if is_mut_ptr(cx.typeck_results().expr_ty(parent)) {
+                return;
+            }
+        }
+    }
+    // Look through the chain of casts for the original pointer
+    let mut origin = cast_expr;
+    while let ExprKind::Cast(ref inner, _) = origin.kind {
+        origin = inner;
+    }
+    let origin_ty = cx.typeck_results().expr_ty(origin);
+    let pointee = match origin_ty.kind() {
+        ty::Ref(_, pointee, Mutability::Not)
+        | ty::RawPtr(TypeAndMut {
+            ty: pointee,
+            mutbl: Mutability::Not,
+        }) => pointee,
+        _ => return,
+    };
+    // Mutating through a shared reference is fine with interior mutability
+    if !pointee.is_freeze(cx.tcx.at(expr.span), cx.param_env) {
+        return;
+    }
+    span_lint_and_help(
+        cx,
+        CAST_AWAY_CONSTNESS,
+        expr.span,
+        &format!("casting away the constness of `{}`", origin_ty),
+        None,
+        "writing through the resulting pointer is undefined behavior, derive it from a mutable reference instead",
+    );
+}
+
+fn lint_cast_char_truncation<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &Expr<'_>,
+    cast_expr: &Expr<'_>,
+    cast_from: Ty<'tcx>,
+    cast_to: Ty<'tcx>,
+) {
+    if *cast_from.kind() == ty::Char && cast_to.is_integral() {
+        let to_nbits = int_ty_to_nbits(cast_to, cx.tcx);
+        // A `char` is at most `0x10FFFF`, so only 8 and 16 bit integers can truncate it
+        if to_nbits > 16 {
+            return;
+        }
+        let max = if cast_to.is_signed() {
+            (1_u32 << (to_nbits - 1)) - 1
+        } else {
+            (1_u32 << to_nbits) - 1
+        };
+        // Casts of literals to `u8` are handled by `char_lit_as_u8`
+        if let ExprKind::Lit(_) = cast_expr.kind {
+            if *cast_to.kind() == ty::Uint(UintTy::U8) {
+                return;
+            }
+        }
+        if let Some((Constant::Char(c), _)) = constant(cx, cx.typeck_results(), cast_expr) {
+            if u32::from(c) <= max {
+                return;
+            }
+        }
+        if is_guarded_by(cx, expr, |cond| is_ascii_check(cx, cond, cast_expr)) {
+            return;
+        }
+        span_lint_and_then(
+            cx,
+            CAST_CHAR_TRUNCATION,
+            expr.span,
+            &format!("casting `char` to `{}` may truncate the character", cast_to),
+            |diag| {
+                diag.note(&format!(
+                    "characters above `U+{:04X}` do not fit into `{}` and are silently truncated",
+                    max, cast_to
+                ));
+                diag.help("use `u32::from` to get the full scalar value, or check the range of the character first");
+            },
+        );
+    } else if *cast_to.kind() == ty::Char {
+        let inner = match cast_expr.kind {
+            ExprKind::Cast(ref inner, _) => inner,
+            _ => return,
+        };
+        let inner_ty = cx.typeck_results().expr_ty(inner);
+        if !inner_ty.is_integral() || *inner_ty.kind() == ty::Uint(UintTy::U8) {
+            return;
+        }
+        if let Some((Constant::Int(n), _)) = constant(cx, cx.typeck_results(), inner) {
+            if n <= 0xFF {
+                return;
+            }
+        }
+        let msg = format!("casting `{}` to `char` through `u8` truncates the value", inner_ty);
+        let arg = match inner_ty.kind() {
+            ty::Uint(UintTy::U32) => snippet(cx, inner.span, "..").to_string(),
+            ty::Uint(UintTy::U16) => format!("u32::from({})", snippet(cx, inner.span, "..")),
+            _ => {
+                span_lint_and_help(
+                    cx,
+                    CAST_CHAR_TRUNCATION,
+                    expr.span,
+                    &msg,
+                    None,
+                    "convert the value with `u32::try_from` first, then use `std::char::from_u32` and handle `None`",
+                );
+                return;
+            },
+        };
+        span_lint_and_then(cx, CAST_CHAR_TRUNCATION, expr.span, &msg, |diag| {
+            // Invalid scalar values have to be handled by hand
+            diag.span_suggestion(
+                expr.span,
+                "use `std::char::from_u32` and handle `None`",
+                format!("std::char::from_u32({})", arg),
+                Applicability::MaybeIncorrect,
+            );
+        });
+    }
+}
+
+/// Checks whether `cond` is `c.is_ascii()` or another `is_ascii_*` method called on `c`.
+fn is_ascii_check(cx: &LateContext<'_>, cond: &Expr<'_>, c: &Expr<'_>) -> bool {
+    if let ExprKind::MethodCall(ref path, _, ref args, _) = cond.kind {
+        path.ident.as_str().starts_with("is_ascii") && SpanlessEq::new(cx).eq_expr(&args[0], c)
+    } else {
+        false
+    }
+}
+
+fn lint_enum_cast_truncation(cx: &LateContext<'_>, expr: &Expr<'_>, cast_from: Ty<'_>, cast_to: Ty<'_>) {
+    if_chain! {
+        if let ty::Adt(adt, _) = cast_from.kind();
+        if adt.is_enum();
+        if cast_to.is_integral();
+        if !in_external_macro(cx.sess(), expr.span);
+        if let Some((idx, _)) = adt.discriminants(cx.tcx).find(|(_, discr)| !discr_fits_in(cx, discr, cast_to));
+        then {
+            span_lint_and_help(
+                cx,
+                CAST_ENUM_TRUNCATION,
+                expr.span,
+                &format!(
+                    "casting `{}` to `{}` truncates the discriminant of `{}::{}`",
+                    cast_from, cast_to, cast_from, adt.variants[idx].ident
+                ),
+                None,
+                "read the discriminant explicitly with a `match` on the variants, or cast to a wider type",
+            );
+        }
+    }
+}
+
+/// Returns `true` if the value of the enum discriminant `discr` fits in the integer type `ty`.
+fn discr_fits_in(cx: &LateContext<'_>, discr: &Discr<'_>, ty: Ty<'_>) -> bool {
+    let nbits = int_ty_to_nbits(ty, cx.tcx);
+    if let ty::Int(ity) = *discr.ty.kind() {
+        let val = sext(cx.tcx, discr.val, ity);
+        if ty.is_signed() {
+            nbits >= 128 || (-(1_i128 << (nbits - 1)) <= val && val < 1_i128 << (nbits - 1))
+        } else {
+            val >= 0 && (nbits >= 128 || val < 1_i128 << nbits)
+        }
+    } else {
+        let value_bits = if ty.is_signed() { nbits - 1 } else { nbits };
+        value_bits >= 128 || discr.val < 1_u128 << value_bits
+    }
+}
+
 fn lint_fn_to_numeric_cast(
     cx: &LateContext<'_>,
     expr: &Expr<'_>,
@@ -2665,3 +3270,150 @@ impl<'tcx> LateLintPass<'tcx> for RefToMut {
         }
     }
 }
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for functions that return `Result<_, ()>`, also when the
+    /// `Result` is hidden behind a type alias.
+    ///
+    /// **Why is this bad?** A `()` error carries no information about what went wrong.
+    /// Either return a dedicated error type, or an `Option` if there is only one way to fail.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn parse_digit(c: char) -> Result<u32, ()> {
+    ///     c.to_digit(10).ok_or(())
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn parse_digit(c: char) -> Option<u32> {
+    ///     c.to_digit(10)
+    /// }
+    /// ```
+    pub RESULT_UNIT_ERR,
+    pedantic,
+    "functions returning `Result<_, ()>`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for public functions of library crates that return
+    /// `Result<_, Box<dyn std::error::Error>>` or `Result<_, String>`.
+    ///
+    /// **Why is this bad?** Callers cannot match on such an error to handle the different
+    /// ways the function can fail, and `Box<dyn Error>` is neither `Send` nor `Sync`. This
+    /// is fine for applications, but makes for an unfriendly library API.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// pub fn read_config(path: &str) -> Result<String, Box<dyn std::error::Error>> {
+    ///     Ok(std::fs::read_to_string(path)?)
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// pub fn read_config(path: &str) -> Result<String, std::io::Error> {
+    ///     std::fs::read_to_string(path)
+    /// }
+    /// ```
+    pub RESULT_OPAQUE_ERR,
+    restriction,
+    "public library functions returning `Result<_, Box<dyn Error>>` or `Result<_, String>`"
+}
+
+declare_lint_pass!(ResultErrType => [RESULT_UNIT_ERR, RESULT_OPAQUE_ERR]);
+
+impl<'tcx> LateLintPass<'tcx> for ResultErrType {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        if let ItemKind::Fn(ref sig, ..) = item.kind {
+            let def_id = cx.tcx.hir().local_def_id(item.hir_id).to_def_id();
+            if !is_entrypoint_fn(cx, def_id) && !is_test_function(cx, item.hir_id, item.ident.name) {
+                check_result_err_type(cx, item.hir_id, sig.decl);
+            }
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ImplItem<'_>) {
+        // The signature of a trait method is dictated by the trait.
+        if let ImplItemKind::Fn(ref sig, _) = item.kind {
+            if !is_trait_impl_item(cx, item.hir_id) {
+                check_result_err_type(cx, item.hir_id, sig.decl);
+            }
+        }
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx TraitItem<'_>) {
+        if let TraitItemKind::Fn(ref sig, _) = item.kind {
+            check_result_err_type(cx, item.hir_id, sig.decl);
+        }
+    }
+}
+
+fn check_result_err_type(cx: &LateContext<'_>, hir_id: HirId, decl: &FnDecl<'_>) {
+    if_chain! {
+        if let FnRetTy::Return(ref ty) = decl.output;
+        if !in_external_macro(cx.sess(), ty.span);
+        // Look at the return type of the resolved signature rather than at the written one,
+        // so that type aliases are seen through.
+        let ret_ty = cx.tcx.fn_sig(cx.tcx.hir().local_def_id(hir_id)).output().skip_binder();
+        if is_type_diagnostic_item(cx, ret_ty, sym!(result_type));
+        if let ty::Adt(_, substs) = ret_ty.kind();
+        then {
+            let err_ty = substs.type_at(1);
+            if err_ty.is_unit() {
+                span_lint_and_help(
+                    cx,
+                    RESULT_UNIT_ERR,
+                    ty.span,
+                    "this returns a `Result<_, ()>`",
+                    None,
+                    "use a custom error type describing the failure, or return an `Option` instead",
+                );
+            } else if cx.access_levels.is_exported(hir_id) && !is_executable(cx) {
+                let err_desc = if is_type_diagnostic_item(cx, err_ty, sym!(string_type)) {
+                    "String"
+                } else if is_boxed_dyn_error(cx, err_ty) {
+                    "Box<dyn Error>"
+                } else {
+                    return;
+                };
+                span_lint_and_note(
+                    cx,
+                    RESULT_OPAQUE_ERR,
+                    ty.span,
+                    &format!("this public function returns a `Result<_, {}>`", err_desc),
+                    None,
+                    "callers of a library cannot match on this error to handle the different causes of failure, \
+                    consider returning a dedicated error type",
+                );
+            }
+        }
+    }
+}
+
+/// Returns `true` if `ty` is `Box<dyn std::error::Error>`, possibly with auto traits.
+fn is_boxed_dyn_error(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+    if !ty.is_box() {
+        return false;
+    }
+    if let ty::Dynamic(preds, _) = ty.boxed_ty().kind() {
+        preds
+            .principal_def_id()
+            .map_or(false, |def_id| match_def_path(cx, def_id, &paths::STD_ERROR_TRAIT))
+    } else {
+        false
+    }
+}
+
+fn is_executable(cx: &LateContext<'_>) -> bool {
+    use rustc_session::config::CrateType;
+
+    cx.tcx
+        .sess
+        .crate_types()
+        .iter()
+        .any(|t: &CrateType| matches!(t, CrateType::Executable))
+}
@@ -1,5 +1,8 @@
-use crate::utils::{match_def_path, paths, span_lint, span_lint_and_help};
+use crate::utils::{
+    in_macro, match_def_path, paths, snippet_with_applicability, span_lint, span_lint_and_help, span_lint_and_then,
+};
 use if_chain::if_chain;
+use rustc_errors::Applicability;
 use rustc_hir::{BinOpKind, Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty;
@@ -53,7 +56,48 @@ declare_clippy_lint! {
     "comparison with an address of a trait vtable"
 }
 
-declare_lint_pass!(UnnamedAddress => [FN_ADDRESS_COMPARISONS, VTABLE_ADDRESS_COMPARISONS]);
+declare_clippy_lint! {
+    /// **What it does:** Checks for comparisons of addresses which are likely not what was
+    /// intended: references to two values cast to raw pointers in the same expression, like
+    /// `&a as *const _ == &b as *const _`, two function pointers compared with `==`, and
+    /// `std::ptr::eq` on slices or `str`.
+    ///
+    /// **Why is this bad?** Casting references to raw pointers only to compare them is an
+    /// obscure identity check, often written when the values were meant to be compared;
+    /// `std::ptr::eq` says what it does. Function pointers have the same problem as
+    /// `fn_address_comparisons`: the address of a function is not unique. `std::ptr::eq` on a
+    /// slice compares its length too, so slices starting at the same address are not equal if
+    /// their lengths differ.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// let a = 1;
+    /// let b = 1;
+    /// if &a as *const _ == &b as *const _ {
+    ///     // ...
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let a = 1;
+    /// let b = 1;
+    /// if std::ptr::eq(&a, &b) {
+    ///     // ...
+    /// }
+    /// ```
+    pub SUSPICIOUS_ADDRESS_COMPARISONS,
+    style,
+    "comparison of references cast to raw pointers, of function pointers, or of slice addresses"
+}
+
+declare_lint_pass!(UnnamedAddress => [
+    FN_ADDRESS_COMPARISONS,
+    VTABLE_ADDRESS_COMPARISONS,
+    SUSPICIOUS_ADDRESS_COMPARISONS,
+]);
 
 impl LateLintPass<'_> for UnnamedAddress {
     fn check_expr(&mut self, cx: &LateContext<'_>, expr: &Expr<'_>) {
@@ -127,5 +171,93 @@ impl LateLintPass<'_> for UnnamedAddress {
                 );
             }
         }
+
+        if in_macro(expr.span) {
+            return;
+        }
+
+        if_chain! {
+            if let ExprKind::Binary(binop, ref left, ref right) = expr.kind;
+            if binop.node == BinOpKind::Eq || binop.node == BinOpKind::Ne;
+            if cx.typeck_results().expr_ty(left).is_fn_ptr() && cx.typeck_results().expr_ty(right).is_fn_ptr();
+            then {
+                span_lint_and_help(
+                    cx,
+                    SUSPICIOUS_ADDRESS_COMPARISONS,
+                    expr.span,
+                    "comparing function pointers compares non-unique function addresses",
+                    None,
+                    "the same function can have different addresses, and different functions the same address",
+                );
+            }
+        }
+
+        if_chain! {
+            if let ExprKind::Binary(binop, ref left, ref right) = expr.kind;
+            if binop.node == BinOpKind::Eq || binop.node == BinOpKind::Ne;
+            if let Some(left_ref) = ref_cast_to_ptr(cx, left);
+            if let Some(right_ref) = ref_cast_to_ptr(cx, right);
+            then {
+                span_lint_and_then(
+                    cx,
+                    SUSPICIOUS_ADDRESS_COMPARISONS,
+                    expr.span,
+                    "comparing the addresses of references cast to raw pointers",
+                    |diag| {
+                        let mut applicability = Applicability::MaybeIncorrect;
+                        let sugg = format!(
+                            "{}std::ptr::eq({}, {})",
+                            if binop.node == BinOpKind::Ne { "!" } else { "" },
+                            snippet_with_applicability(cx, left_ref.span, "..", &mut applicability),
+                            snippet_with_applicability(cx, right_ref.span, "..", &mut applicability),
+                        );
+                        diag.span_suggestion(
+                            expr.span,
+                            "if the addresses are meant to be compared, use",
+                            sugg,
+                            applicability,
+                        );
+                        diag.note("to compare the values, compare the references instead");
+                    },
+                );
+            }
+        }
+
+        if_chain! {
+            if let ExprKind::Call(ref func, [_, _]) = expr.kind;
+            if let ExprKind::Path(ref func_qpath) = func.kind;
+            if let Some(def_id) = cx.qpath_res(func_qpath, func.hir_id).opt_def_id();
+            if match_def_path(cx, def_id, &paths::PTR_EQ);
+            let ty_param = cx.typeck_results().node_substs(func.hir_id).type_at(0);
+            if matches!(ty_param.kind(), ty::Slice(_) | ty::Str);
+            then {
+                span_lint_and_help(
+                    cx,
+                    SUSPICIOUS_ADDRESS_COMPARISONS,
+                    expr.span,
+                    "comparing slice pointers compares their lengths too",
+                    None,
+                    "slices starting at the same address are not equal if their lengths differ, \
+                     cast them to thin pointers like `*const u8` to compare the addresses only",
+                );
+            }
+        }
+    }
+}
+
+/// If `expr` is a reference cast to a raw pointer to the referenced type, like `&a as *const _`,
+/// returns the reference.
+fn ref_cast_to_ptr<'a>(cx: &LateContext<'_>, expr: &Expr<'a>) -> Option<&'a Expr<'a>> {
+    if_chain! {
+        if let ExprKind::Cast(inner, _) = expr.kind;
+        if let ExprKind::AddrOf(..) = inner.kind;
+        if let ty::Ref(_, referent, _) = cx.typeck_results().expr_ty(inner).kind();
+        if let ty::RawPtr(ty::TypeAndMut { ty: pointee, .. }) = cx.typeck_results().expr_ty(expr).kind();
+        if referent == pointee;
+        then {
+            Some(inner)
+        } else {
+            None
+        }
     }
 }
@@ -0,0 +1,232 @@
+use crate::utils::{
+    get_parent_expr, in_macro, multispan_sugg_with_applicability, qpath_res, snippet_opt, span_lint_and_help,
+    span_lint_and_then, type_is_unsafe_function,
+};
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{Block, BlockCheckMode, Expr, ExprKind, StmtKind, UnOp, UnsafeSource, Unsafety};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::source_map::{BytePos, Span};
+use std::convert::TryFrom;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `unsafe` blocks that do not contain any operation
+    /// requiring `unsafe`: no dereference of a raw pointer, no call to an `unsafe` function
+    /// or method, no access to a mutable or extern static, no read of a union field and no
+    /// inline assembly.
+    ///
+    /// **Why is this bad?** An `unsafe` block is a promise to the reader that something
+    /// needing extra care is going on. Putting safe code in one dilutes that promise.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// let x = 42;
+    /// let y = unsafe { x + 1 };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let x = 42;
+    /// let y = x + 1;
+    /// ```
+    pub UNNECESSARY_UNSAFE,
+    complexity,
+    "`unsafe` blocks that contain no `unsafe` operations"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `unsafe` blocks containing several statements where
+    /// only a single statement actually needs `unsafe`.
+    ///
+    /// **Why is this bad?** The larger an `unsafe` block is, the harder it is to see which
+    /// operation the safety comment is about, and the easier it is to add another `unsafe`
+    /// operation to it by accident.
+    ///
+    /// **Known problems:** The suggestion keeps the remaining statements in a plain block
+    /// so that scoping does not change; that block may often be removed by hand.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// # let p = &0 as *const i32;
+    /// unsafe {
+    ///     let x = *p;
+    ///     println!("{}", x);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let p = &0 as *const i32;
+    /// {
+    ///     let x = unsafe { *p };
+    ///     println!("{}", x);
+    /// }
+    /// ```
+    pub OVERSIZED_UNSAFE_BLOCK,
+    pedantic,
+    "`unsafe` blocks where only a single statement needs `unsafe`"
+}
+
+declare_lint_pass!(UnnecessaryUnsafe => [UNNECESSARY_UNSAFE, OVERSIZED_UNSAFE_BLOCK]);
+
+impl<'tcx> LateLintPass<'tcx> for UnnecessaryUnsafe {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        if block.rules != BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided)
+            || in_macro(block.span)
+            || in_unsafe_fn(cx, block)
+        {
+            return;
+        }
+
+        // The expression of every statement (and of the trailing expression), together with
+        // the `unsafe` operations found in it.
+        let mut units = Vec::new();
+        for stmt in block.stmts {
+            let expr = match stmt.kind {
+                StmtKind::Local(local) => local.init,
+                StmtKind::Expr(e) | StmtKind::Semi(e) => Some(e),
+                StmtKind::Item(_) => None,
+            };
+            units.push(expr.map(|e| (e, unsafe_operations(cx, e))));
+        }
+        if let Some(e) = block.expr {
+            units.push(Some((e, unsafe_operations(cx, e))));
+        }
+
+        let mut unsafe_units = units.iter().flatten().filter(|(_, ops)| !ops.is_empty());
+        match (unsafe_units.next(), unsafe_units.next()) {
+            (None, _) => {
+                span_lint_and_help(
+                    cx,
+                    UNNECESSARY_UNSAFE,
+                    block.span,
+                    "unnecessary `unsafe` block",
+                    None,
+                    "this block contains no operations requiring `unsafe`, remove the `unsafe` keyword",
+                );
+            },
+            (Some((unit, ops)), None) if units.len() > 1 => {
+                let target = match ops.as_slice() {
+                    [op] if !is_place_context(cx, op) => op,
+                    _ => unit,
+                };
+                if in_macro(target.span) {
+                    return;
+                }
+                if let Some(unsafe_kw) = unsafe_keyword_span(cx, block.span) {
+                    span_lint_and_then(
+                        cx,
+                        OVERSIZED_UNSAFE_BLOCK,
+                        block.span,
+                        "this `unsafe` block is larger than necessary",
+                        |diag| {
+                            multispan_sugg_with_applicability(
+                                diag,
+                                "only wrap the operation requiring `unsafe`",
+                                Applicability::MaybeIncorrect,
+                                vec![
+                                    (unsafe_kw, String::new()),
+                                    (target.span.shrink_to_lo(), "unsafe { ".to_string()),
+                                    (target.span.shrink_to_hi(), " }".to_string()),
+                                ],
+                            );
+                        },
+                    );
+                }
+            },
+            _ => {},
+        }
+    }
+}
+
+/// Returns `true` if `block` is (transitively) part of the body of an `unsafe fn`.
+fn in_unsafe_fn(cx: &LateContext<'_>, block: &Block<'_>) -> bool {
+    let item = cx.tcx.hir().get_parent_item(block.hir_id);
+    cx.tcx
+        .hir()
+        .fn_sig_by_hir_id(item)
+        .map_or(false, |sig| sig.header.unsafety == Unsafety::Unsafe)
+}
+
+/// Returns the span of the `unsafe ` prefix of a block, including the whitespace up to the
+/// opening brace.
+fn unsafe_keyword_span(cx: &LateContext<'_>, span: Span) -> Option<Span> {
+    let snip = snippet_opt(cx, span)?;
+    if !snip.starts_with("unsafe") {
+        return None;
+    }
+    let brace = snip.find('{')?;
+    Some(span.with_hi(span.lo() + BytePos(u32::try_from(brace).ok()?)))
+}
+
+/// Returns `true` if `expr` is used as a place (assigned to, borrowed, indexed, or used to access a
+/// field), where wrapping it into a block would change its meaning.
+fn is_place_context(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    get_parent_expr(cx, expr).map_or(false, |parent| match parent.kind {
+        ExprKind::Assign(ref lhs, ..) | ExprKind::AssignOp(_, ref lhs, _) | ExprKind::Index(ref lhs, _) => {
+            lhs.hir_id == expr.hir_id
+        },
+        ExprKind::MethodCall(_, _, ref args, _) => args[0].hir_id == expr.hir_id,
+        ExprKind::AddrOf(..) | ExprKind::Field(..) => true,
+        _ => false,
+    })
+}
+
+/// Collects the operations in `expr` which need an `unsafe` context. Nested `unsafe` blocks are
+/// not looked into.
+fn unsafe_operations<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Vec<&'tcx Expr<'tcx>> {
+    let mut visitor = UnsafeOpVisitor { cx, ops: Vec::new() };
+    visitor.visit_expr(expr);
+    visitor.ops
+}
+
+struct UnsafeOpVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    ops: Vec<&'tcx Expr<'tcx>>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for UnsafeOpVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Block(ref block, _) = expr.kind {
+            if let BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided) = block.rules {
+                return;
+            }
+        }
+        if is_unsafe_operation(self.cx, expr) {
+            self.ops.push(expr);
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
+
+fn is_unsafe_operation(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    let typeck = cx.typeck_results();
+    match expr.kind {
+        ExprKind::Unary(UnOp::UnDeref, ref inner) => matches!(typeck.expr_ty_adjusted(inner).kind(), ty::RawPtr(..)),
+        ExprKind::Call(ref callee, _) => type_is_unsafe_function(cx, typeck.expr_ty(callee)),
+        ExprKind::MethodCall(..) => typeck
+            .type_dependent_def_id(expr.hir_id)
+            .map_or(false, |id| cx.tcx.fn_sig(id).unsafety() == Unsafety::Unsafe),
+        ExprKind::Path(ref qpath) => match qpath_res(cx, qpath, expr.hir_id) {
+            Res::Def(DefKind::Static, def_id) => cx.tcx.is_mutable_static(def_id) || cx.tcx.is_foreign_item(def_id),
+            _ => false,
+        },
+        ExprKind::Field(ref base, _) => {
+            matches!(typeck.expr_ty_adjusted(base).kind(), ty::Adt(def, _) if def.is_union())
+        },
+        ExprKind::InlineAsm(..) | ExprKind::LlvmInlineAsm(..) => true,
+        _ => false,
+    }
+}
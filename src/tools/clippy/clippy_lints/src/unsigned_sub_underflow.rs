@@ -0,0 +1,161 @@
+use crate::consts::{constant_simple, Constant};
+use crate::utils::{
+    get_parent_expr, higher, in_constant, in_macro, is_guarded_by, snippet, span_lint_and_help, SpanlessEq,
+};
+use if_chain::if_chain;
+use rustc_hir::{BinOpKind, Expr, ExprKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for subtractions of unsigned integers which are used as an
+    /// index, as a range bound or in a comparison with a `len()`, where nothing makes sure
+    /// that the left operand is not smaller than the right one.
+    ///
+    /// **Why is this bad?** The subtraction panics on underflow in debug mode, but wraps
+    /// around silently in release mode, which usually yields a huge index or a comparison
+    /// with a surprising result.
+    ///
+    /// **Known problems:** Only an enclosing `if` which compares both operands (or checks
+    /// `!is_empty()` before `len() - 1`) is recognized as a check, other ways to establish
+    /// the invariant, like an early return, are not.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let v = vec![1, 2, 3];
+    /// let last = v[v.len() - 1];
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let v = vec![1, 2, 3];
+    /// if !v.is_empty() {
+    ///     let last = v[v.len() - 1];
+    /// }
+    /// ```
+    pub UNSIGNED_SUB_UNDERFLOW,
+    pedantic,
+    "unchecked subtraction of unsigned integers used for indexing or length computations"
+}
+
+declare_lint_pass!(UnsignedSubUnderflow => [UNSIGNED_SUB_UNDERFLOW]);
+
+impl<'tcx> LateLintPass<'tcx> for UnsignedSubUnderflow {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if let ExprKind::Binary(ref op, ref lhs, ref rhs) = expr.kind;
+            if op.node == BinOpKind::Sub;
+            if !in_macro(expr.span);
+            if let ty::Uint(_) = cx.typeck_results().expr_ty(expr).kind();
+            // overflows in constants are compile errors anyway
+            if !in_constant(cx, expr.hir_id);
+            if !is_provably_non_negative(cx, lhs, rhs);
+            if let Some(parent) = get_parent_expr(cx, expr);
+            if let Some(in_comparison) = use_kind(expr, lhs, parent);
+            if !is_guarded_by(cx, expr, |cond| guards(cx, cond, lhs, rhs));
+            then {
+                let (lhs, rhs) = (snippet(cx, lhs.span, ".."), snippet(cx, rhs.span, ".."));
+                let help = if in_comparison {
+                    format!("consider adding `{}` to the other side of the comparison instead", rhs)
+                } else {
+                    format!(
+                        "consider using `{0}.checked_sub({1})` or `{0}.saturating_sub({1})`, \
+                         or checking that `{0} >= {1}` first",
+                        lhs, rhs
+                    )
+                };
+                span_lint_and_help(
+                    cx,
+                    UNSIGNED_SUB_UNDERFLOW,
+                    expr.span,
+                    "this subtraction of unsigned integers may underflow",
+                    None,
+                    &help,
+                );
+            }
+        }
+    }
+}
+
+fn const_int(cx: &LateContext<'_>, e: &Expr<'_>) -> Option<u128> {
+    match constant_simple(cx, cx.typeck_results(), e) {
+        Some(Constant::Int(n)) => Some(n),
+        _ => None,
+    }
+}
+
+fn is_provably_non_negative(cx: &LateContext<'_>, lhs: &Expr<'_>, rhs: &Expr<'_>) -> bool {
+    match (const_int(cx, lhs), const_int(cx, rhs)) {
+        (_, Some(0)) => true,
+        (Some(l), Some(r)) => l >= r,
+        _ => false,
+    }
+}
+
+fn is_len_call(e: &Expr<'_>) -> bool {
+    matches!(e.kind, ExprKind::MethodCall(ref path, _, ref args, _) if path.ident.name == sym!(len) && args.len() == 1)
+}
+
+/// Returns `Some(true)` if `expr` is compared with a `len()`, `Some(false)` if it is used as an
+/// index or range bound, and `None` otherwise.
+fn use_kind(expr: &Expr<'_>, lhs: &Expr<'_>, parent: &Expr<'_>) -> Option<bool> {
+    match parent.kind {
+        ExprKind::Index(_, ref index) if index.hir_id == expr.hir_id => Some(false),
+        ExprKind::Binary(ref op, ref left, ref right) if op.node.is_comparison() => {
+            let other = if left.hir_id == expr.hir_id { right } else { left };
+            if is_len_call(lhs) || is_len_call(other) {
+                Some(true)
+            } else {
+                None
+            }
+        },
+        _ => {
+            let range = higher::range(parent)?;
+            let is_bound = |bound: Option<&Expr<'_>>| bound.map_or(false, |bound| bound.hir_id == expr.hir_id);
+            if is_bound(range.start) || is_bound(range.end) {
+                Some(false)
+            } else {
+                None
+            }
+        },
+    }
+}
+
+/// Checks whether `cond` makes sure that `lhs >= rhs`.
+fn guards(cx: &LateContext<'_>, cond: &Expr<'_>, lhs: &Expr<'_>, rhs: &Expr<'_>) -> bool {
+    match cond.kind {
+        ExprKind::Binary(ref op, ref left, ref right) => {
+            let (big, small, strict) = match op.node {
+                BinOpKind::Ge => (left, right, false),
+                BinOpKind::Gt => (left, right, true),
+                BinOpKind::Le => (right, left, false),
+                BinOpKind::Lt => (right, left, true),
+                // `x != 0` is `x > 0` for unsigned integers
+                BinOpKind::Ne if const_int(cx, right) == Some(0) => (left, right, true),
+                BinOpKind::Ne if const_int(cx, left) == Some(0) => (right, left, true),
+                _ => return false,
+            };
+            SpanlessEq::new(cx).eq_expr(big, lhs)
+                && (SpanlessEq::new(cx).eq_expr(small, rhs)
+                    || match (const_int(cx, small), const_int(cx, rhs)) {
+                        (Some(small), Some(rhs)) => small.saturating_add(u128::from(strict)) >= rhs,
+                        _ => false,
+                    })
+        },
+        // `!v.is_empty()` guards `v.len() - 1`
+        ExprKind::Unary(UnOp::UnNot, ref inner) => {
+            if_chain! {
+                if let ExprKind::MethodCall(ref path, _, ref args, _) = inner.kind;
+                if path.ident.name == sym!(is_empty) && args.len() == 1;
+                if let ExprKind::MethodCall(_, _, ref len_args, _) = lhs.kind;
+                if is_len_call(lhs);
+                then {
+                    SpanlessEq::new(cx).eq_expr(&args[0], &len_args[0]) && const_int(cx, rhs) == Some(1)
+                } else {
+                    false
+                }
+            }
+        },
+        _ => false,
+    }
+}
@@ -0,0 +1,166 @@
+use crate::utils::{
+    get_trait_def_id, implements_trait, in_macro, is_direct_expn_of, is_type_diagnostic_item, match_trait_method,
+    paths, span_lint_and_help, walk_ptrs_ty,
+};
+use rustc_hir::{Expr, ExprKind, Stmt, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for statements which only create a value that does nothing
+    /// by itself: lazy iterator adapters like `iter.map(f);` or `v.iter().cloned();`, and
+    /// `format!(..);`.
+    ///
+    /// **Why is this bad?** Iterator adapters are lazy, the closure passed to them is never
+    /// called unless the iterator is consumed. A `format!` statement allocates a `String`
+    /// and drops it right away. Either way the statement has no effect, which is almost
+    /// certainly a bug.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # #![allow(unused_must_use)]
+    /// let v = vec![1, 2, 3];
+    /// v.iter().map(|x| println!("{}", x));
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let v = vec![1, 2, 3];
+    /// v.iter().for_each(|x| println!("{}", x));
+    /// ```
+    pub UNUSED_LAZY_VALUE,
+    correctness,
+    "a lazy iterator adapter or `format!` used as a statement, which does nothing"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `Option` and `Result` combinators like `map`,
+    /// `and_then` or `ok` used as a statement.
+    ///
+    /// **Why is this bad?** The combinator only transforms the value, which is then
+    /// dropped. The statement is only useful for the side effects of the closure, which
+    /// are clearer with `if let`, or it is meant to ignore a `Result`, which is clearer
+    /// with `let _ =`.
+    ///
+    /// **Known problems:** `map` with a closure returning `()` is left to the
+    /// `option_map_unit_fn` and `result_map_unit_fn` lints.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # fn send(x: i32) -> Result<(), ()> { Ok(()) }
+    /// let x = Some(1);
+    /// x.map(send);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # fn send(x: i32) -> Result<(), ()> { Ok(()) }
+    /// let x = Some(1);
+    /// if let Some(x) = x {
+    ///     let _ = send(x);
+    /// }
+    /// ```
+    pub UNUSED_COMBINATOR_RESULT,
+    pedantic,
+    "the result of an `Option` or `Result` combinator used as a statement is ignored"
+}
+
+declare_lint_pass!(UnusedCombinator => [UNUSED_LAZY_VALUE, UNUSED_COMBINATOR_RESULT]);
+
+/// Methods of `Option` and `Result` whose only effect, besides calling their argument,
+/// is returning a new value.
+const COMBINATORS: [&str; 12] = [
+    "and",
+    "and_then",
+    "err",
+    "filter",
+    "map",
+    "map_err",
+    "map_or",
+    "map_or_else",
+    "ok",
+    "or",
+    "or_else",
+    "xor",
+];
+
+/// Methods creating an iterator without consuming anything.
+const ITER_METHODS: [&str; 2] = ["iter", "iter_mut"];
+
+impl<'tcx> LateLintPass<'tcx> for UnusedCombinator {
+    fn check_stmt(&mut self, cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'_>) {
+        let expr = match stmt.kind {
+            StmtKind::Semi(ref expr) => expr,
+            _ => return,
+        };
+        if in_macro(stmt.span) {
+            return;
+        }
+
+        if expr.span.from_expansion() {
+            if is_direct_expn_of(expr.span, "format").is_some() {
+                span_lint_and_help(
+                    cx,
+                    UNUSED_LAZY_VALUE,
+                    stmt.span,
+                    "this `format!` call only creates a `String` which is dropped immediately",
+                    None,
+                    "use `print!` or `write!` to output the text, or remove the statement",
+                );
+            }
+            return;
+        }
+
+        if let ExprKind::MethodCall(ref path, _, ref args, _) = expr.kind {
+            let name = &*path.ident.as_str();
+            if is_lazy_iterator(cx, expr, name) {
+                span_lint_and_help(
+                    cx,
+                    UNUSED_LAZY_VALUE,
+                    stmt.span,
+                    &format!("this call to `{}` creates an iterator which is never consumed", name),
+                    None,
+                    "iterators are lazy and do nothing unless consumed, use `for_each` or a `for` loop to run it",
+                );
+            } else if COMBINATORS.contains(&name) && !(name == "map" && maps_to_unit(cx, expr)) {
+                let recv_ty = walk_ptrs_ty(cx.typeck_results().expr_ty(&args[0]));
+                let recv = if is_type_diagnostic_item(cx, recv_ty, sym!(option_type)) {
+                    "Option"
+                } else if is_type_diagnostic_item(cx, recv_ty, sym!(result_type)) {
+                    "Result"
+                } else {
+                    return;
+                };
+                span_lint_and_help(
+                    cx,
+                    UNUSED_COMBINATOR_RESULT,
+                    stmt.span,
+                    &format!("the result of `{}::{}` is ignored", recv, name),
+                    None,
+                    "use `if let` to run code for the side effects only, or `let _ =` to ignore the result explicitly",
+                );
+            }
+        }
+    }
+}
+
+/// Returns `true` if `expr` is an `Iterator` adapter or `iter()` call returning an iterator.
+fn is_lazy_iterator<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, name: &str) -> bool {
+    // `fold` is the only consuming method of `Iterator` which may return an iterator
+    if name == "fold" || (!ITER_METHODS.contains(&name) && !match_trait_method(cx, expr, &paths::ITERATOR)) {
+        return false;
+    }
+    let ty = cx.typeck_results().expr_ty(expr);
+    // `by_ref` returns a `&mut` reference to the iterator itself
+    !matches!(ty.kind(), ty::Ref(..))
+        && get_trait_def_id(cx, &paths::ITERATOR).map_or(false, |id| implements_trait(cx, ty, id, &[]))
+}
+
+/// Returns `true` if `expr` is an `Option<()>` or a `Result<(), _>`, which `map_unit_fn` handles.
+fn maps_to_unit<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) -> bool {
+    match cx.typeck_results().expr_ty(expr).kind() {
+        ty::Adt(_, substs) => substs.types().next().map_or(false, |ty| ty.is_unit()),
+        _ => false,
+    }
+}
@@ -1,6 +1,14 @@
-use crate::utils::{is_try, match_trait_method, paths, span_lint};
+use crate::utils::{
+    in_macro, is_integer_literal, is_try, match_trait_method, paths, snippet_with_applicability, span_lint,
+    span_lint_and_help, span_lint_and_note, span_lint_and_sugg,
+};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
 use rustc_hir as hir;
+use rustc_hir::def::Res;
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 
 declare_clippy_lint! {
@@ -14,6 +22,10 @@ declare_clippy_lint! {
     /// partial-write/read, use
     /// `write_all`/`read_exact` instead.
     ///
+    /// An amount read in a loop, like `let n = r.read(&mut buf)?;`, which is never compared
+    /// against `0` is linted as well, as `read` returns `Ok(0)` once the end of the stream is
+    /// reached and the loop may never stop.
+    ///
     /// **Known problems:** Detects only common patterns.
     ///
     /// **Example:**
@@ -33,60 +45,197 @@ declare_clippy_lint! {
 declare_lint_pass!(UnusedIoAmount => [UNUSED_IO_AMOUNT]);
 
 impl<'tcx> LateLintPass<'tcx> for UnusedIoAmount {
-    fn check_stmt(&mut self, cx: &LateContext<'_>, s: &hir::Stmt<'_>) {
+    fn check_stmt(&mut self, cx: &LateContext<'tcx>, s: &'tcx hir::Stmt<'_>) {
         let expr = match s.kind {
             hir::StmtKind::Semi(ref expr) | hir::StmtKind::Expr(ref expr) => &**expr,
+            hir::StmtKind::Local(ref local) => {
+                check_read_in_loop(cx, local);
+                return;
+            },
             _ => return,
         };
 
-        match expr.kind {
-            hir::ExprKind::Match(ref res, _, _) if is_try(expr).is_some() => {
-                if let hir::ExprKind::Call(ref func, ref args) = res.kind {
-                    if matches!(
-                        func.kind,
-                        hir::ExprKind::Path(hir::QPath::LangItem(hir::LangItem::TryIntoResult, _))
-                    ) {
-                        check_method_call(cx, &args[0], expr);
-                    }
+        if let Some((call, replaceable)) = unwrapped_call(expr) {
+            check_method_call(cx, call, expr, replaceable);
+        }
+    }
+}
+
+/// Returns the call whose result is unwrapped by `expr`, with `?`, `try!` or one of the `unwrap`
+/// methods, and whether the call can be replaced by one returning `io::Result<()>`.
+fn unwrapped_call<'a>(expr: &'a hir::Expr<'a>) -> Option<(&'a hir::Expr<'a>, bool)> {
+    match expr.kind {
+        hir::ExprKind::Match(ref res, _, _) if is_try(expr).is_some() => {
+            if let hir::ExprKind::Call(ref func, ref args) = res.kind {
+                if matches!(
+                    func.kind,
+                    hir::ExprKind::Path(hir::QPath::LangItem(hir::LangItem::TryIntoResult, _))
+                ) {
+                    Some((&args[0], true))
                 } else {
-                    check_method_call(cx, res, expr);
+                    None
                 }
-            },
+            } else {
+                Some((res, true))
+            }
+        },
 
-            hir::ExprKind::MethodCall(ref path, _, ref args, _) => match &*path.ident.as_str() {
-                "expect" | "unwrap" | "unwrap_or" | "unwrap_or_else" => {
-                    check_method_call(cx, &args[0], expr);
-                },
-                _ => (),
-            },
+        hir::ExprKind::MethodCall(ref path, _, ref args, _) => match &*path.ident.as_str() {
+            "expect" | "unwrap" => Some((&args[0], true)),
+            "unwrap_or" | "unwrap_or_else" => Some((&args[0], false)),
+            _ => None,
+        },
 
-            _ => (),
-        }
+        _ => None,
     }
 }
 
-fn check_method_call(cx: &LateContext<'_>, call: &hir::Expr<'_>, expr: &hir::Expr<'_>) {
+fn check_method_call(cx: &LateContext<'_>, call: &hir::Expr<'_>, expr: &hir::Expr<'_>, replaceable: bool) {
     if let hir::ExprKind::MethodCall(ref path, _, _, _) = call.kind {
         let symbol = &*path.ident.as_str();
         let read_trait = match_trait_method(cx, call, &paths::IO_READ);
         let write_trait = match_trait_method(cx, call, &paths::IO_WRITE);
 
-        match (read_trait, write_trait, symbol) {
-            (true, _, "read") => span_lint(
-                cx,
-                UNUSED_IO_AMOUNT,
-                expr.span,
-                "read amount is not handled. Use `Read::read_exact` instead",
-            ),
-            (true, _, "read_vectored") => span_lint(cx, UNUSED_IO_AMOUNT, expr.span, "read amount is not handled"),
-            (_, true, "write") => span_lint(
+        let (msg, replacement) = match (read_trait, write_trait, symbol) {
+            (true, _, "read") => ("read amount is not handled", Some(("Read", "read_exact"))),
+            (true, _, "read_vectored") => ("read amount is not handled", None),
+            (_, true, "write") => ("written amount is not handled", Some(("Write", "write_all"))),
+            (_, true, "write_vectored") => ("written amount is not handled", None),
+            _ => return,
+        };
+
+        match replacement {
+            Some((trait_name, method)) if replaceable && !in_macro(expr.span) => {
+                let mut app = Applicability::MaybeIncorrect;
+                let sugg = format!(
+                    "{}{}{}",
+                    snippet_with_applicability(cx, expr.span.until(path.ident.span), "..", &mut app),
+                    method,
+                    snippet_with_applicability(cx, expr.span.with_lo(path.ident.span.hi()), "..", &mut app),
+                );
+                span_lint_and_sugg(
+                    cx,
+                    UNUSED_IO_AMOUNT,
+                    expr.span,
+                    msg,
+                    &format!("use `{}::{}` instead", trait_name, method),
+                    sugg,
+                    app,
+                );
+            },
+            Some((trait_name, method)) => span_lint_and_help(
                 cx,
                 UNUSED_IO_AMOUNT,
                 expr.span,
-                "written amount is not handled. Use `Write::write_all` instead",
+                msg,
+                None,
+                &format!("use `{}::{}` instead", trait_name, method),
             ),
-            (_, true, "write_vectored") => span_lint(cx, UNUSED_IO_AMOUNT, expr.span, "written amount is not handled"),
-            _ => (),
+            None => span_lint(cx, UNUSED_IO_AMOUNT, expr.span, msg),
         }
     }
 }
+
+/// Checks for `let n = r.read(&mut buf)?;` in a loop, where `n` is never compared against `0`.
+fn check_read_in_loop<'tcx>(cx: &LateContext<'tcx>, local: &'tcx hir::Local<'tcx>) {
+    if_chain! {
+        if let hir::PatKind::Binding(_, binding_id, ..) = local.pat.kind;
+        if let Some(init) = local.init;
+        if !in_macro(init.span);
+        if let Some((call, _)) = unwrapped_call(init);
+        if let hir::ExprKind::MethodCall(ref path, _, _, _) = call.kind;
+        if matches!(&*path.ident.as_str(), "read" | "read_vectored");
+        if match_trait_method(cx, call, &paths::IO_READ);
+        if let Some(loop_expr) = enclosing_loop(cx, local.hir_id);
+        then {
+            let mut visitor = ZeroComparisonVisitor {
+                binding_id,
+                compared: false,
+            };
+            visitor.visit_expr(loop_expr);
+            if !visitor.compared {
+                span_lint_and_note(
+                    cx,
+                    UNUSED_IO_AMOUNT,
+                    init.span,
+                    "read amount is not checked for the end of the stream",
+                    None,
+                    &format!(
+                        "`Read::{}` returns `Ok(0)` once the end of the stream is reached, \
+                        which this loop never handles",
+                        path.ident
+                    ),
+                );
+            }
+        }
+    }
+}
+
+/// Returns the innermost loop the node `hir_id` is in, in the same closure or function.
+fn enclosing_loop<'tcx>(cx: &LateContext<'tcx>, hir_id: hir::HirId) -> Option<&'tcx hir::Expr<'tcx>> {
+    for (_, node) in cx.tcx.hir().parent_iter(hir_id) {
+        match node {
+            hir::Node::Expr(expr) => match expr.kind {
+                hir::ExprKind::Loop(..) => return Some(expr),
+                hir::ExprKind::Closure(..) => return None,
+                _ => {},
+            },
+            hir::Node::Item(_) | hir::Node::ImplItem(_) | hir::Node::TraitItem(_) => return None,
+            _ => {},
+        }
+    }
+    None
+}
+
+/// Checks whether a local is compared against `0`, with a comparison operator or a pattern.
+/// Closures are not visited.
+struct ZeroComparisonVisitor {
+    binding_id: hir::HirId,
+    compared: bool,
+}
+
+impl ZeroComparisonVisitor {
+    fn is_binding(&self, expr: &hir::Expr<'_>) -> bool {
+        matches!(
+            expr.kind,
+            hir::ExprKind::Path(hir::QPath::Resolved(None, ref path)) if path.res == Res::Local(self.binding_id)
+        )
+    }
+}
+
+impl<'tcx> Visitor<'tcx> for ZeroComparisonVisitor {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr<'tcx>) {
+        match expr.kind {
+            hir::ExprKind::Binary(op, ref left, ref right) if op.node.is_comparison() => {
+                if (self.is_binding(left) && is_integer_literal(right, 0))
+                    || (is_integer_literal(left, 0) && self.is_binding(right))
+                {
+                    self.compared = true;
+                }
+            },
+            hir::ExprKind::Match(ref scrutinee, ref arms, _) => {
+                if self.is_binding(scrutinee) && arms.iter().any(|arm| matches_zero(&arm.pat)) {
+                    self.compared = true;
+                }
+            },
+            _ => {},
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
+
+/// Returns `true` if `pat` matches `0`, with a literal or a range starting at `0`.
+fn matches_zero(pat: &hir::Pat<'_>) -> bool {
+    match pat.kind {
+        hir::PatKind::Lit(ref lit) | hir::PatKind::Range(Some(ref lit), _, _) => is_integer_literal(lit, 0),
+        hir::PatKind::Or(ref pats) => pats.iter().any(|pat| matches_zero(pat)),
+        hir::PatKind::Binding(_, _, _, Some(ref pat)) => matches_zero(pat),
+        _ => false,
+    }
+}
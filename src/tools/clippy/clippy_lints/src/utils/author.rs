@@ -3,11 +3,12 @@
 
 use crate::utils::{get_attr, higher};
 use rustc_ast::ast::{Attribute, LitFloatType, LitKind};
-use rustc_ast::walk_list;
 use rustc_data_structures::fx::FxHashMap;
 use rustc_hir as hir;
 use rustc_hir::intravisit::{NestedVisitorMap, Visitor};
-use rustc_hir::{BindingAnnotation, Block, Expr, ExprKind, Pat, PatKind, QPath, Stmt, StmtKind, TyKind};
+use rustc_hir::{
+    BindingAnnotation, Block, Body, Expr, ExprKind, Label, Movability, Pat, PatKind, QPath, Stmt, StmtKind, TyKind,
+};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::hir::map::Map;
 use rustc_session::Session;
@@ -36,16 +37,21 @@ declare_clippy_lint! {
     /// ```rust,ignore
     /// // ./tests/ui/new_lint.stdout
     /// if_chain! {
-    ///     if let ExprKind::If(ref cond, ref then, None) = item.kind,
-    ///     if let ExprKind::Binary(BinOp::Eq, ref left, ref right) = cond.kind,
-    ///     if let ExprKind::Path(ref path) = left.kind,
-    ///     if let ExprKind::Lit(ref lit) = right.kind,
-    ///     if let LitKind::Int(42, _) = lit.node,
+    ///     if let Some((ref cond, ref then, None)) = higher::if_block(&expr);
+    ///     if let ExprKind::Binary(ref op, ref left, ref right) = cond.kind;
+    ///     if BinOpKind::Eq == op.node;
+    ///     if let ExprKind::Path(ref path) = left.kind;
+    ///     if match_qpath(path, &["x"]);
+    ///     if let ExprKind::Lit(ref lit) = right.kind;
+    ///     if let LitKind::Int(42, _) = lit.node;
     ///     then {
     ///         // report your lint here
     ///     }
     /// }
     /// ```
+    ///
+    /// The generated code uses the helpers of `clippy_lints::utils`, like `match_qpath` and
+    /// `higher::range`, and compiles in a `LateLintPass` with `cx` and the checked node in scope.
     pub LINT_AUTHOR,
     internal_warn,
     "helper for writing lints"
@@ -70,7 +76,7 @@ impl<'tcx> LateLintPass<'tcx> for Author {
             return;
         }
         prelude();
-        PrintVisitor::new("item").visit_item(item);
+        PrintVisitor::new(cx, "item").visit_item(item);
         done();
     }
 
@@ -79,7 +85,7 @@ impl<'tcx> LateLintPass<'tcx> for Author {
             return;
         }
         prelude();
-        PrintVisitor::new("item").visit_impl_item(item);
+        PrintVisitor::new(cx, "item").visit_impl_item(item);
         done();
     }
 
@@ -88,7 +94,7 @@ impl<'tcx> LateLintPass<'tcx> for Author {
             return;
         }
         prelude();
-        PrintVisitor::new("item").visit_trait_item(item);
+        PrintVisitor::new(cx, "item").visit_trait_item(item);
         done();
     }
 
@@ -98,7 +104,7 @@ impl<'tcx> LateLintPass<'tcx> for Author {
         }
         prelude();
         let parent_hir_id = cx.tcx.hir().get_parent_node(var.id);
-        PrintVisitor::new("var").visit_variant(var, &hir::Generics::empty(), parent_hir_id);
+        PrintVisitor::new(cx, "var").visit_variant(var, &hir::Generics::empty(), parent_hir_id);
         done();
     }
 
@@ -107,7 +113,7 @@ impl<'tcx> LateLintPass<'tcx> for Author {
             return;
         }
         prelude();
-        PrintVisitor::new("field").visit_struct_field(field);
+        PrintVisitor::new(cx, "field").visit_struct_field(field);
         done();
     }
 
@@ -116,7 +122,7 @@ impl<'tcx> LateLintPass<'tcx> for Author {
             return;
         }
         prelude();
-        PrintVisitor::new("expr").visit_expr(expr);
+        PrintVisitor::new(cx, "expr").visit_expr(expr);
         done();
     }
 
@@ -125,7 +131,7 @@ impl<'tcx> LateLintPass<'tcx> for Author {
             return;
         }
         prelude();
-        PrintVisitor::new("arm").visit_arm(arm);
+        PrintVisitor::new(cx, "arm").visit_arm(arm);
         done();
     }
 
@@ -134,7 +140,7 @@ impl<'tcx> LateLintPass<'tcx> for Author {
             return;
         }
         prelude();
-        PrintVisitor::new("stmt").visit_stmt(stmt);
+        PrintVisitor::new(cx, "stmt").visit_stmt(stmt);
         done();
     }
 
@@ -143,15 +149,16 @@ impl<'tcx> LateLintPass<'tcx> for Author {
             return;
         }
         prelude();
-        PrintVisitor::new("item").visit_foreign_item(item);
+        PrintVisitor::new(cx, "item").visit_foreign_item(item);
         done();
     }
 }
 
-impl PrintVisitor {
+impl<'a, 'tcx> PrintVisitor<'a, 'tcx> {
     #[must_use]
-    fn new(s: &'static str) -> Self {
+    fn new(cx: &'a LateContext<'tcx>, s: &'static str) -> Self {
         Self {
+            cx,
             ids: FxHashMap::default(),
             current: s.to_owned(),
         }
@@ -186,9 +193,91 @@ impl PrintVisitor {
             println!("]);");
         }
     }
+
+    fn print_ty(&mut self, ty: &hir::Ty<'_>) {
+        match ty.kind {
+            TyKind::Path(ref qpath) => {
+                let qp_label = self.next("qp");
+                println!("    if let TyKind::Path(ref {}) = {}.kind;", qp_label, self.current);
+                self.current = qp_label;
+                self.print_qpath(qpath);
+            },
+            TyKind::Rptr(_, ref mut_ty) => {
+                let inner_pat = self.next("inner_ty");
+                println!(
+                    "    if let TyKind::Rptr(_, MutTy {{ ty: ref {}, mutbl: Mutability::{:?} }}) = {}.kind;",
+                    inner_pat, mut_ty.mutbl, self.current
+                );
+                self.current = inner_pat;
+                self.print_ty(mut_ty.ty);
+            },
+            TyKind::Ptr(ref mut_ty) => {
+                let inner_pat = self.next("inner_ty");
+                println!(
+                    "    if let TyKind::Ptr(MutTy {{ ty: ref {}, mutbl: Mutability::{:?} }}) = {}.kind;",
+                    inner_pat, mut_ty.mutbl, self.current
+                );
+                self.current = inner_pat;
+                self.print_ty(mut_ty.ty);
+            },
+            TyKind::Slice(ref inner) => {
+                let inner_pat = self.next("inner_ty");
+                println!("    if let TyKind::Slice(ref {}) = {}.kind;", inner_pat, self.current);
+                self.current = inner_pat;
+                self.print_ty(inner);
+            },
+            TyKind::Tup(ref elements) => {
+                let elements_pat = self.next("elements");
+                println!("    if let TyKind::Tup(ref {}) = {}.kind;", elements_pat, self.current);
+                self.print_len(&elements_pat, elements.len());
+                for (i, element) in elements.iter().enumerate() {
+                    self.current = format!("{}[{}]", elements_pat, i);
+                    self.print_ty(element);
+                }
+            },
+            TyKind::Infer => println!("    if let TyKind::Infer = {}.kind;", self.current),
+            TyKind::Never => println!("    if let TyKind::Never = {}.kind;", self.current),
+            _ => println!(
+                "    // unimplemented: `{}` is not further destructured at the moment",
+                self.current
+            ),
+        }
+    }
+
+    fn print_len(&self, slice: &str, len: usize) {
+        if len == 0 {
+            println!("    if {}.is_empty();", slice);
+        } else {
+            println!("    if {}.len() == {};", slice, len);
+        }
+    }
+
+    fn print_label(&mut self, label: Option<Label>) {
+        if let Some(label) = label {
+            let label_pat = self.next("label");
+            println!("    if let Some(ref {}) = {};", label_pat, self.current);
+            println!("    if {}.ident.as_str() == {:?};", label_pat, &*label.ident.as_str());
+        } else {
+            println!("    if {}.is_none();", self.current);
+        }
+    }
+
+    /// Prints the checks of a body fetched from its `BodyId` in `body_id_pat`.
+    fn print_body(&mut self, body_id_pat: &str, body: &Body<'_>) {
+        let body_pat = self.next("body");
+        println!("    let {} = cx.tcx.hir().body({});", body_pat, body_id_pat);
+        self.print_len(&format!("{}.params", body_pat), body.params.len());
+        for (i, param) in body.params.iter().enumerate() {
+            self.current = format!("{}.params[{}].pat", body_pat, i);
+            self.visit_pat(&param.pat);
+        }
+        self.current = format!("{}.value", body_pat);
+        self.visit_expr(&body.value);
+    }
 }
 
-struct PrintVisitor {
+struct PrintVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
     /// Fields are the current index that needs to be appended to pattern
     /// binding names
     ids: FxHashMap<&'static str, usize>,
@@ -196,7 +285,7 @@ struct PrintVisitor {
     current: String,
 }
 
-impl<'tcx> Visitor<'tcx> for PrintVisitor {
+impl<'a, 'tcx> Visitor<'tcx> for PrintVisitor<'a, 'tcx> {
     type Map = Map<'tcx>;
 
     #[allow(clippy::too_many_lines)]
@@ -226,6 +315,22 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
             self.visit_expr(then);
             return;
         }
+        if let Some(higher::Range { start, end, limits }) = higher::range(expr) {
+            let start_pat = start.map(|_| self.next("start"));
+            let end_pat = end.map(|_| self.next("end"));
+            println!(
+                "    if let Some(higher::Range {{ start: {}, end: {}, limits: RangeLimits::{:?} }}) = higher::range(&{});",
+                option_pat(start_pat.as_deref()),
+                option_pat(end_pat.as_deref()),
+                limits,
+                self.current
+            );
+            for (pat, bound) in start_pat.into_iter().zip(start).chain(end_pat.into_iter().zip(end)) {
+                self.current = pat;
+                self.visit_expr(bound);
+            }
+            return;
+        }
 
         print!("    if let ExprKind::");
         let current = format!("{}.kind", self.current);
@@ -239,7 +344,7 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
             ExprKind::Array(ref elements) => {
                 let elements_pat = self.next("elements");
                 println!("Array(ref {}) = {};", elements_pat, current);
-                println!("    if {}.len() == {};", elements_pat, elements.len());
+                self.print_len(&elements_pat, elements.len());
                 for (i, element) in elements.iter().enumerate() {
                     self.current = format!("{}[{}]", elements_pat, i);
                     self.visit_expr(element);
@@ -251,23 +356,35 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
                 println!("Call(ref {}, ref {}) = {};", func_pat, args_pat, current);
                 self.current = func_pat;
                 self.visit_expr(func);
-                println!("    if {}.len() == {};", args_pat, args.len());
+                self.print_len(&args_pat, args.len());
                 for (i, arg) in args.iter().enumerate() {
                     self.current = format!("{}[{}]", args_pat, i);
                     self.visit_expr(arg);
                 }
             },
-            ExprKind::MethodCall(ref _method_name, ref _generics, ref _args, ref _fn_span) => {
+            ExprKind::MethodCall(ref method_name, _, ref args, _) => {
+                let method_name_pat = self.next("method_name");
+                let args_pat = self.next("args");
                 println!(
-                    "MethodCall(ref method_name, ref generics, ref args, ref fn_span) = {};",
-                    current
+                    "MethodCall(ref {}, _, ref {}, _) = {};",
+                    method_name_pat, args_pat, current
                 );
-                println!("    // unimplemented: `ExprKind::MethodCall` is not further destructured at the moment");
+                println!(
+                    "    if {}.ident.as_str() == {:?};",
+                    method_name_pat,
+                    &*method_name.ident.as_str()
+                );
+                // `args[0]` is the receiver, so method chains are destructured from the last call
+                self.print_len(&args_pat, args.len());
+                for (i, arg) in args.iter().enumerate() {
+                    self.current = format!("{}[{}]", args_pat, i);
+                    self.visit_expr(arg);
+                }
             },
             ExprKind::Tup(ref elements) => {
                 let elements_pat = self.next("elements");
                 println!("Tup(ref {}) = {};", elements_pat, current);
-                println!("    if {}.len() == {};", elements_pat, elements.len());
+                self.print_len(&elements_pat, elements.len());
                 for (i, element) in elements.iter().enumerate() {
                     self.current = format!("{}[{}]", elements_pat, i);
                     self.visit_expr(element);
@@ -319,35 +436,35 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
                     LitKind::Str(ref text, _) => {
                         let str_pat = self.next("s");
                         println!("    if let LitKind::Str(ref {}, _) = {}.node;", str_pat, lit_pat);
-                        println!("    if {}.as_str() == {:?}", str_pat, &*text.as_str())
+                        println!("    if {}.as_str() == {:?};", str_pat, &*text.as_str())
                     },
                 }
             },
             ExprKind::Cast(ref expr, ref ty) => {
                 let cast_pat = self.next("expr");
                 let cast_ty = self.next("cast_ty");
-                let qp_label = self.next("qp");
-
                 println!("Cast(ref {}, ref {}) = {};", cast_pat, cast_ty, current);
-                if let TyKind::Path(ref qp) = ty.kind {
-                    println!("    if let TyKind::Path(ref {}) = {}.kind;", qp_label, cast_ty);
-                    self.current = qp_label;
-                    self.print_qpath(qp);
-                }
+                self.current = cast_ty;
+                self.print_ty(ty);
                 self.current = cast_pat;
                 self.visit_expr(expr);
             },
-            ExprKind::Type(ref expr, ref _ty) => {
+            ExprKind::Type(ref expr, ref ty) => {
                 let cast_pat = self.next("expr");
-                println!("Type(ref {}, _) = {};", cast_pat, current);
+                let cast_ty = self.next("cast_ty");
+                println!("Type(ref {}, ref {}) = {};", cast_pat, cast_ty, current);
+                self.current = cast_ty;
+                self.print_ty(ty);
                 self.current = cast_pat;
                 self.visit_expr(expr);
             },
-            ExprKind::Loop(ref body, _, desugaring) => {
+            ExprKind::Loop(ref body, label, desugaring) => {
                 let body_pat = self.next("body");
                 let des = loop_desugaring_name(desugaring);
                 let label_pat = self.next("label");
                 println!("Loop(ref {}, ref {}, {}) = {};", body_pat, label_pat, des, current);
+                self.current = label_pat;
+                self.print_label(label);
                 self.current = body_pat;
                 self.visit_block(body);
             },
@@ -358,49 +475,45 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
                 println!("Match(ref {}, ref {}, {}) = {};", expr_pat, arms_pat, des, current);
                 self.current = expr_pat;
                 self.visit_expr(expr);
-                println!("    if {}.len() == {};", arms_pat, arms.len());
+                self.print_len(&arms_pat, arms.len());
                 for (i, arm) in arms.iter().enumerate() {
-                    self.current = format!("{}[{}].body", arms_pat, i);
-                    self.visit_expr(&arm.body);
-                    if let Some(ref guard) = arm.guard {
-                        let guard_pat = self.next("guard");
-                        println!("    if let Some(ref {}) = {}[{}].guard;", guard_pat, arms_pat, i);
-                        match guard {
-                            hir::Guard::If(ref if_expr) => {
-                                let if_expr_pat = self.next("expr");
-                                println!("    if let Guard::If(ref {}) = {};", if_expr_pat, guard_pat);
-                                self.current = if_expr_pat;
-                                self.visit_expr(if_expr);
-                            },
-                        }
-                    }
-                    self.current = format!("{}[{}].pat", arms_pat, i);
-                    self.visit_pat(&arm.pat);
+                    self.current = format!("{}[{}]", arms_pat, i);
+                    self.visit_arm(arm);
                 }
             },
-            ExprKind::Closure(ref _capture_clause, ref _func, _, _, _) => {
-                println!("Closure(ref capture_clause, ref func, _, _, _) = {};", current);
-                println!("    // unimplemented: `ExprKind::Closure` is not further destructured at the moment");
+            ExprKind::Closure(capture_clause, _, body_id, _, movability) => {
+                let body_id_pat = self.next("body_id");
+                let movability = match movability {
+                    Some(Movability::Static) => "Some(Movability::Static)",
+                    Some(Movability::Movable) => "Some(Movability::Movable)",
+                    None => "None",
+                };
+                println!(
+                    "Closure(CaptureBy::{:?}, _, {}, _, {}) = {};",
+                    capture_clause, body_id_pat, movability, current
+                );
+                let body = self.cx.tcx.hir().body(body_id);
+                self.print_body(&body_id_pat, body);
             },
             ExprKind::Yield(ref sub, _) => {
                 let sub_pat = self.next("sub");
-                println!("Yield(ref sub) = {};", current);
+                println!("Yield(ref {}, _) = {};", sub_pat, current);
                 self.current = sub_pat;
                 self.visit_expr(sub);
             },
-            ExprKind::Block(ref block, _) => {
+            ExprKind::Block(ref block, label) => {
                 let block_pat = self.next("block");
-                println!("Block(ref {}) = {};", block_pat, current);
+                let label_pat = self.next("label");
+                println!("Block(ref {}, ref {}) = {};", block_pat, label_pat, current);
+                self.current = label_pat;
+                self.print_label(label);
                 self.current = block_pat;
                 self.visit_block(block);
             },
             ExprKind::Assign(ref target, ref value, _) => {
                 let target_pat = self.next("target");
                 let value_pat = self.next("value");
-                println!(
-                    "Assign(ref {}, ref {}, ref _span) = {};",
-                    target_pat, value_pat, current
-                );
+                println!("Assign(ref {}, ref {}, _) = {};", target_pat, value_pat, current);
                 self.current = target_pat;
                 self.visit_expr(target);
                 self.current = value_pat;
@@ -424,7 +537,7 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
                 let obj_pat = self.next("object");
                 let field_name_pat = self.next("field_name");
                 println!("Field(ref {}, ref {}) = {};", obj_pat, field_name_pat, current);
-                println!("    if {}.as_str() == {:?}", field_name_pat, field_ident.as_str());
+                println!("    if {}.as_str() == {:?};", field_name_pat, &*field_ident.as_str());
                 self.current = obj_pat;
                 self.visit_expr(object);
             },
@@ -452,22 +565,26 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
                 self.current = inner_pat;
                 self.visit_expr(inner);
             },
-            ExprKind::Break(ref _destination, ref opt_value) => {
+            ExprKind::Break(ref destination, ref opt_value) => {
                 let destination_pat = self.next("destination");
                 if let Some(ref value) = *opt_value {
                     let value_pat = self.next("value");
                     println!("Break(ref {}, Some(ref {})) = {};", destination_pat, value_pat, current);
+                    self.current = format!("{}.label", destination_pat);
+                    self.print_label(destination.label);
                     self.current = value_pat;
                     self.visit_expr(value);
                 } else {
                     println!("Break(ref {}, None) = {};", destination_pat, current);
+                    self.current = format!("{}.label", destination_pat);
+                    self.print_label(destination.label);
                 }
-                // FIXME: implement label printing
             },
-            ExprKind::Continue(ref _destination) => {
+            ExprKind::Continue(ref destination) => {
                 let destination_pat = self.next("destination");
-                println!("Again(ref {}) = {};", destination_pat, current);
-                // FIXME: implement label printing
+                println!("Continue(ref {}) = {};", destination_pat, current);
+                self.current = format!("{}.label", destination_pat);
+                self.print_label(destination.label);
             },
             ExprKind::Ret(ref opt_value) => {
                 if let Some(ref value) = *opt_value {
@@ -503,19 +620,29 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
                 }
                 self.current = path_pat;
                 self.print_qpath(path);
-                println!("    if {}.len() == {};", fields_pat, fields.len());
-                println!("    // unimplemented: field checks");
+                self.print_len(&fields_pat, fields.len());
+                for (i, field) in fields.iter().enumerate() {
+                    println!(
+                        "    if {}[{}].ident.as_str() == {:?};",
+                        fields_pat,
+                        i,
+                        &*field.ident.as_str()
+                    );
+                    self.current = format!("{}[{}].expr", fields_pat, i);
+                    self.visit_expr(&field.expr);
+                }
             },
-            // FIXME: compute length (needs type info)
-            ExprKind::Repeat(ref value, _) => {
+            ExprKind::Repeat(ref value, ref length) => {
                 let value_pat = self.next("value");
-                println!("Repeat(ref {}, _) = {};", value_pat, current);
-                println!("// unimplemented: repeat count check");
+                let length_pat = self.next("length");
+                println!("Repeat(ref {}, ref {}) = {};", value_pat, length_pat, current);
                 self.current = value_pat;
                 self.visit_expr(value);
+                let length_body = self.cx.tcx.hir().body(length.body);
+                self.print_body(&format!("{}.body", length_pat), length_body);
             },
             ExprKind::Err => {
-                println!("Err = {}", current);
+                println!("Err = {};", current);
             },
             ExprKind::DropTemps(ref expr) => {
                 let expr_pat = self.next("expr");
@@ -526,15 +653,41 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
         }
     }
 
+    fn visit_arm(&mut self, arm: &hir::Arm<'_>) {
+        let current = self.current.clone();
+        self.current = format!("{}.body", current);
+        self.visit_expr(&arm.body);
+        if let Some(ref guard) = arm.guard {
+            let guard_pat = self.next("guard");
+            println!("    if let Some(ref {}) = {}.guard;", guard_pat, current);
+            match guard {
+                hir::Guard::If(ref if_expr) => {
+                    let if_expr_pat = self.next("expr");
+                    println!("    if let Guard::If(ref {}) = *{};", if_expr_pat, guard_pat);
+                    self.current = if_expr_pat;
+                    self.visit_expr(if_expr);
+                },
+            }
+        }
+        self.current = format!("{}.pat", current);
+        self.visit_pat(&arm.pat);
+    }
+
     fn visit_block(&mut self, block: &Block<'_>) {
-        let trailing_pat = self.next("trailing_expr");
-        println!("    if let Some({}) = &{}.expr;", trailing_pat, self.current);
-        println!("    if {}.stmts.len() == {};", self.current, block.stmts.len());
         let current = self.current.clone();
+        self.print_len(&format!("{}.stmts", current), block.stmts.len());
         for (i, stmt) in block.stmts.iter().enumerate() {
             self.current = format!("{}.stmts[{}]", current, i);
             self.visit_stmt(stmt);
         }
+        if let Some(ref trailing_expr) = block.expr {
+            let trailing_pat = self.next("trailing_expr");
+            println!("    if let Some(ref {}) = {}.expr;", trailing_pat, current);
+            self.current = trailing_pat;
+            self.visit_expr(trailing_expr);
+        } else {
+            println!("    if {}.expr.is_none();", current);
+        }
     }
 
     #[allow(clippy::too_many_lines)]
@@ -562,7 +715,7 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
                 } else {
                     println!("Binding({}, _, {}, None) = {};", anno_pat, name_pat, current);
                 }
-                println!("    if {}.as_str() == \"{}\";", name_pat, ident.as_str());
+                println!("    if {}.as_str() == {:?};", name_pat, &*ident.as_str());
             },
             PatKind::Struct(ref path, ref fields, ignore) => {
                 let path_pat = self.next("path");
@@ -573,14 +726,26 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
                 );
                 self.current = path_pat;
                 self.print_qpath(path);
-                println!("    if {}.len() == {};", fields_pat, fields.len());
-                println!("    // unimplemented: field checks");
+                self.print_len(&fields_pat, fields.len());
+                for (i, field) in fields.iter().enumerate() {
+                    println!(
+                        "    if {}[{}].ident.as_str() == {:?};",
+                        fields_pat,
+                        i,
+                        &*field.ident.as_str()
+                    );
+                    self.current = format!("{}[{}].pat", fields_pat, i);
+                    self.visit_pat(&field.pat);
+                }
             },
             PatKind::Or(ref fields) => {
                 let fields_pat = self.next("fields");
                 println!("Or(ref {}) = {};", fields_pat, current);
-                println!("    if {}.len() == {};", fields_pat, fields.len());
-                println!("    // unimplemented: field checks");
+                self.print_len(&fields_pat, fields.len());
+                for (i, field) in fields.iter().enumerate() {
+                    self.current = format!("{}[{}]", fields_pat, i);
+                    self.visit_pat(field);
+                }
             },
             PatKind::TupleStruct(ref path, ref fields, skip_pos) => {
                 let path_pat = self.next("path");
@@ -591,8 +756,11 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
                 );
                 self.current = path_pat;
                 self.print_qpath(path);
-                println!("    if {}.len() == {};", fields_pat, fields.len());
-                println!("    // unimplemented: field checks");
+                self.print_len(&fields_pat, fields.len());
+                for (i, field) in fields.iter().enumerate() {
+                    self.current = format!("{}[{}]", fields_pat, i);
+                    self.visit_pat(field);
+                }
             },
             PatKind::Path(ref path) => {
                 let path_pat = self.next("path");
@@ -603,8 +771,11 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
             PatKind::Tuple(ref fields, skip_pos) => {
                 let fields_pat = self.next("fields");
                 println!("Tuple(ref {}, {:?}) = {};", fields_pat, skip_pos, current);
-                println!("    if {}.len() == {};", fields_pat, fields.len());
-                println!("    // unimplemented: field checks");
+                self.print_len(&fields_pat, fields.len());
+                for (i, field) in fields.iter().enumerate() {
+                    self.current = format!("{}[{}]", fields_pat, i);
+                    self.visit_pat(field);
+                }
             },
             PatKind::Box(ref pat) => {
                 let pat_pat = self.next("pat");
@@ -620,21 +791,24 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
             },
             PatKind::Lit(ref lit_expr) => {
                 let lit_expr_pat = self.next("lit_expr");
-                println!("Lit(ref {}) = {}", lit_expr_pat, current);
+                println!("Lit(ref {}) = {};", lit_expr_pat, current);
                 self.current = lit_expr_pat;
                 self.visit_expr(lit_expr);
             },
             PatKind::Range(ref start, ref end, end_kind) => {
-                let start_pat = self.next("start");
-                let end_pat = self.next("end");
+                let start_pat = start.map(|_| self.next("start"));
+                let end_pat = end.map(|_| self.next("end"));
                 println!(
-                    "Range(ref {}, ref {}, RangeEnd::{:?}) = {};",
-                    start_pat, end_pat, end_kind, current
+                    "Range({}, {}, RangeEnd::{:?}) = {};",
+                    option_pat(start_pat.as_deref()),
+                    option_pat(end_pat.as_deref()),
+                    end_kind,
+                    current
                 );
-                self.current = start_pat;
-                walk_list!(self, visit_expr, start);
-                self.current = end_pat;
-                walk_list!(self, visit_expr, end);
+                for (pat, bound) in start_pat.into_iter().zip(*start).chain(end_pat.into_iter().zip(*end)) {
+                    self.current = pat;
+                    self.visit_expr(bound);
+                }
             },
             PatKind::Slice(ref start, ref middle, ref end) => {
                 let start_pat = self.next("start");
@@ -650,12 +824,12 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
                 } else {
                     println!("Slice(ref {}, None, ref {}) = {};", start_pat, end_pat, current);
                 }
-                println!("    if {}.len() == {};", start_pat, start.len());
+                self.print_len(&start_pat, start.len());
                 for (i, pat) in start.iter().enumerate() {
                     self.current = format!("{}[{}]", start_pat, i);
                     self.visit_pat(pat);
                 }
-                println!("    if {}.len() == {};", end_pat, end.len());
+                self.print_len(&end_pat, end.len());
                 for (i, pat) in end.iter().enumerate() {
                     self.current = format!("{}[{}]", end_pat, i);
                     self.visit_pat(pat);
@@ -678,18 +852,24 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
                     self.current = init_pat;
                     self.visit_expr(init);
                 }
+                if let Some(ref ty) = local.ty {
+                    let ty_pat = self.next("ty");
+                    println!("    if let Some(ref {}) = {}.ty;", ty_pat, local_pat);
+                    self.current = ty_pat;
+                    self.print_ty(ty);
+                }
                 self.current = format!("{}.pat", local_pat);
                 self.visit_pat(&local.pat);
             },
             // An item binding:
             StmtKind::Item(_) => {
-                println!("Item(item_id) = {};", current);
+                println!("Item(_) = {};", current);
             },
 
             // Expr without trailing semi-colon (must have unit type):
             StmtKind::Expr(ref e) => {
                 let e_pat = self.next("e");
-                println!("Expr(ref {}, _) = {}", e_pat, current);
+                println!("Expr(ref {}) = {};", e_pat, current);
                 self.current = e_pat;
                 self.visit_expr(e);
             },
@@ -697,18 +877,37 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
             // Expr with trailing semi-colon (may have any type):
             StmtKind::Semi(ref e) => {
                 let e_pat = self.next("e");
-                println!("Semi(ref {}, _) = {}", e_pat, current);
+                println!("Semi(ref {}) = {};", e_pat, current);
                 self.current = e_pat;
                 self.visit_expr(e);
             },
         }
     }
 
+    fn visit_item(&mut self, item: &hir::Item<'_>) {
+        if let hir::ItemKind::Fn(_, _, body_id) = item.kind {
+            let body_id_pat = self.next("body_id");
+            println!(
+                "    if let ItemKind::Fn(_, _, {}) = {}.kind;",
+                body_id_pat, self.current
+            );
+            let body = self.cx.tcx.hir().body(body_id);
+            self.print_body(&body_id_pat, body);
+        } else {
+            println!("    // unimplemented: only functions are destructured at the moment");
+        }
+    }
+
     fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
         NestedVisitorMap::None
     }
 }
 
+/// Returns `Some(ref name)` or `None`, the pattern of an optional node bound to `name`.
+fn option_pat(name: Option<&str>) -> String {
+    name.map_or_else(|| "None".to_string(), |name| format!("Some(ref {})", name))
+}
+
 fn has_attr(sess: &Session, attrs: &[Attribute]) -> bool {
     get_attr(sess, attrs, "author").count() > 0
 }
@@ -770,3 +969,51 @@ fn print_path(path: &QPath<'_>, first: &mut bool) {
         QPath::LangItem(..) => panic!("print_path: called for lang item qpath"),
     }
 }
+
+/// Compiles the code generated for the `tests/ui/author` tests, so that a change which makes the
+/// output invalid Rust fails the build instead of only the `.stdout` comparison. Files with more
+/// than one `if_chain!`, like `blocks.stdout`, can't be included as a single expression.
+#[cfg(test)]
+#[allow(unused_variables, unused_imports, dead_code, clippy::all, clippy::pedantic)]
+mod generated {
+    use crate::utils::{higher, match_qpath};
+    use if_chain::if_chain;
+    use rustc_ast::ast::{LitFloatType, LitKind, RangeLimits};
+    use rustc_hir::{
+        BinOpKind, BindingAnnotation, BorrowKind, CaptureBy, Expr, ExprKind, Guard, Item, ItemKind, LangItem,
+        LoopSource, MatchSource, Movability, MutTy, Mutability, PatKind, QPath, RangeEnd, Stmt, StmtKind, TyKind, UnOp,
+    };
+    use rustc_lint::LateContext;
+
+    fn author(cx: &LateContext<'_>, stmt: &Stmt<'_>) {
+        include!("../../../tests/ui/author.stdout")
+    }
+
+    fn call(cx: &LateContext<'_>, stmt: &Stmt<'_>) {
+        include!("../../../tests/ui/author/call.stdout")
+    }
+
+    fn for_loop(cx: &LateContext<'_>, expr: &Expr<'_>) {
+        include!("../../../tests/ui/author/for_loop.stdout")
+    }
+
+    fn if_(cx: &LateContext<'_>, stmt: &Stmt<'_>) {
+        include!("../../../tests/ui/author/if.stdout")
+    }
+
+    fn issue_3849(cx: &LateContext<'_>, stmt: &Stmt<'_>) {
+        include!("../../../tests/ui/author/issue_3849.stdout")
+    }
+
+    fn matches(cx: &LateContext<'_>, stmt: &Stmt<'_>) {
+        include!("../../../tests/ui/author/matches.stdout")
+    }
+
+    fn method_chain(cx: &LateContext<'_>, stmt: &Stmt<'_>) {
+        include!("../../../tests/ui/author/method_chain.stdout")
+    }
+
+    fn patterns(cx: &LateContext<'_>, item: &Item<'_>) {
+        include!("../../../tests/ui/author/patterns.stdout")
+    }
+}
@@ -4,8 +4,10 @@
 
 use lazy_static::lazy_static;
 use rustc_ast::ast::{LitKind, MetaItemKind, NestedMetaItem};
+use rustc_session::Session;
 use rustc_span::source_map;
-use source_map::Span;
+use serde::Deserialize;
+use source_map::{BytePos, Span};
 use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 use std::{env, fmt, fs, io};
@@ -37,6 +39,19 @@ pub enum Error {
     Io(io::Error),
     /// Not valid toml or doesn't fit the expected config format
     Toml(String),
+    /// A threshold set to zero, which would lint every item. Contains the key of the threshold.
+    ZeroThreshold(&'static str),
+}
+
+impl Error {
+    /// The key of the configuration entry the error is about, if it is known.
+    #[must_use]
+    pub fn key(&self) -> Option<&'static str> {
+        match self {
+            Self::ZeroThreshold(key) => Some(key),
+            Self::Io(_) | Self::Toml(_) => None,
+        }
+    }
 }
 
 impl fmt::Display for Error {
@@ -44,6 +59,7 @@ impl fmt::Display for Error {
         match self {
             Self::Io(err) => err.fmt(f),
             Self::Toml(err) => err.fmt(f),
+            Self::ZeroThreshold(key) => write!(f, "`{}` must be greater than zero", key),
         }
     }
 }
@@ -54,6 +70,41 @@ impl From<io::Error> for Error {
     }
 }
 
+/// An entry of `disallowed-methods` or `disallowed-types`, either a plain path like
+/// `"std::env::var"` or a table like `{ path = "std::env::var", reason = "..." }`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum DisallowedPath {
+    /// A path without a reason.
+    Simple(String),
+    /// A path with an optional reason, shown in the diagnostic.
+    WithReason {
+        /// The fully qualified path.
+        path: String,
+        /// Why the path is disallowed.
+        reason: Option<String>,
+    },
+}
+
+impl DisallowedPath {
+    /// The fully qualified path, like `std::env::var`.
+    #[must_use]
+    pub fn path(&self) -> &str {
+        match self {
+            Self::Simple(path) | Self::WithReason { path, .. } => path,
+        }
+    }
+
+    /// The reason configured for the path, if any.
+    #[must_use]
+    pub fn reason(&self) -> Option<&str> {
+        match self {
+            Self::Simple(_) => None,
+            Self::WithReason { reason, .. } => reason.as_deref(),
+        }
+    }
+}
+
 lazy_static! {
     static ref ERRORS: Mutex<Vec<Error>> = Mutex::new(Vec::new());
 }
@@ -105,6 +156,20 @@ macro_rules! define_Conf {
 }
 
 pub use self::helpers::Conf;
+
+impl Conf {
+    /// The thresholds of the size and complexity lints, with their keys.
+    fn thresholds(&self) -> [(&'static str, u64); 5] {
+        [
+            ("cognitive-complexity-threshold", self.cognitive_complexity_threshold),
+            ("too-many-arguments-threshold", self.too_many_arguments_threshold),
+            ("type-complexity-threshold", self.type_complexity_threshold),
+            ("enum-variant-size-threshold", self.enum_variant_size_threshold),
+            ("too-many-lines-threshold", self.too_many_lines_threshold),
+        ]
+    }
+}
+
 define_Conf! {
     /// Lint: BLACKLISTED_NAME. The list of blacklisted names to lint about. NB: `bar` is not here since it has legitimate uses
     (blacklisted_names, "blacklisted_names": Vec<String>, ["foo", "baz", "quux"].iter().map(ToString::to_string).collect()),
@@ -148,6 +213,14 @@ define_Conf! {
     (verbose_bit_mask_threshold, "verbose_bit_mask_threshold": u64, 1),
     /// Lint: DECIMAL_LITERAL_REPRESENTATION. The lower bound for linting decimal literals
     (literal_representation_threshold, "literal_representation_threshold": u64, 16384),
+    /// Lint: UNREADABLE_LITERAL, INCONSISTENT_DIGIT_GROUPING, LARGE_DIGIT_GROUPS. The size of the digit groups of binary literals, usually 4 or 8
+    (binary_digit_group_size, "binary_digit_group_size": u64, 4),
+    /// Lint: UNREADABLE_LITERAL, INCONSISTENT_DIGIT_GROUPING, LARGE_DIGIT_GROUPS. The size of the digit groups of octal literals
+    (octal_digit_group_size, "octal_digit_group_size": u64, 3),
+    /// Lint: UNREADABLE_LITERAL, INCONSISTENT_DIGIT_GROUPING, LARGE_DIGIT_GROUPS. The size of the digit groups of decimal literals
+    (decimal_digit_group_size, "decimal_digit_group_size": u64, 3),
+    /// Lint: UNREADABLE_LITERAL, INCONSISTENT_DIGIT_GROUPING, LARGE_DIGIT_GROUPS. The size of the digit groups of hexadecimal literals
+    (hexadecimal_digit_group_size, "hexadecimal_digit_group_size": u64, 4),
     /// Lint: TRIVIALLY_COPY_PASS_BY_REF. The maximum size (in bytes) to consider a `Copy` type for passing by value instead of by reference.
     (trivial_copy_size_limit, "trivial_copy_size_limit": Option<u64>, None),
     /// Lint: TOO_MANY_LINES. The maximum number of lines a function or method can have
@@ -164,6 +237,26 @@ define_Conf! {
     (max_fn_params_bools, "max_fn_params_bools": u64, 3),
     /// Lint: WILDCARD_IMPORTS. Whether to allow certain wildcard imports (prelude, super in tests).
     (warn_on_all_wildcard_imports, "warn_on_all_wildcard_imports": bool, false),
+    /// Lint: REDUNDANT_CLONE. The list of paths of types whose `Clone` implementation has side effects, for which clones are never considered redundant
+    (redundant_clone_side_effect_types, "redundant_clone_side_effect_types": Vec<String>, Vec::new()),
+    /// Lint: REDUNDANT_CLONE. Whether to skip `#[test]` and `#[bench]` functions and `#[cfg(test)]` items
+    (redundant_clone_skip_tests, "redundant_clone_skip_tests": bool, false),
+    /// Lint: MUTABLE_KEY_TYPE. The list of paths of types with interior mutability whose `Hash` and `Ord` implementations do not depend on it, which are allowed in keys
+    (ignore_interior_mutability, "ignore_interior_mutability": Vec<String>, ["bytes::Bytes"].iter().map(ToString::to_string).collect()),
+    /// Lint: PANIC_IN_RESULT_FN. Whether to also check the bodies of closures in functions returning `Result`
+    (panic_in_result_fn_check_closures, "panic_in_result_fn_check_closures": bool, false),
+    /// Lint: CLONE_WITHOUT_COPY. The maximum size (in bytes) of a type deriving `Clone` to suggest deriving `Copy` as well
+    (clone_without_copy_size_limit, "clone_without_copy_size_limit": u64, 16),
+    /// Lint: CAST_POSSIBLE_TRUNCATION, CAST_POSSIBLE_WRAP, CAST_SIGN_LOSS, CHECKED_CONVERSIONS, MAP_CLONE, OPTION_AS_REF_DEREF, RANGE_PLUS_ONE. The minimum Rust version the project supports, like `"1.30"`. Suggestions needing a newer version are not made
+    (msrv, "msrv": Option<String>, None),
+    /// Lint: DISALLOWED_METHODS. The list of disallowed functions and methods, written as their fully qualified paths like `"std::env::var"` or as tables like `{ path = "std::env::var", reason = "use the `Config` instead" }`
+    (disallowed_methods, "disallowed_methods": Vec<crate::utils::conf::DisallowedPath>, Vec::new()),
+    /// Lint: DISALLOWED_TYPES. The list of disallowed types, written as their fully qualified paths like `"std::collections::HashMap"` or as tables like `{ path = "std::collections::HashMap", reason = "use `FxHashMap` instead" }`
+    (disallowed_types, "disallowed_types": Vec<crate::utils::conf::DisallowedPath>, Vec::new()),
+    /// Lint: BLACKLISTED_NAME, SIMILAR_NAMES, MANY_SINGLE_CHAR_NAMES, JUST_UNDERSCORES_AND_DIGITS. The names exempted from these lints, either exact names or prefixes followed by `*`, like `tmp*`
+    (allowed_names, "allowed_names": Vec<String>, Vec::new()),
+    /// Lint: WILDCARD_IMPORTS. The paths of the modules whose wildcard imports are allowed, like `crate::ast`, or prefixes of them followed by `*`
+    (allowed_wildcard_imports, "allowed_wildcard_imports": Vec<String>, Vec::new()),
 }
 
 impl Default for Conf {
@@ -234,6 +327,14 @@ pub fn read(path: &Path) -> (Conf, Vec<Error>) {
                 errors.push(Error::Toml(cyc_err));
             }
 
+            errors.extend(
+                toml_ref
+                    .thresholds()
+                    .iter()
+                    .filter(|&&(_, threshold)| threshold == 0)
+                    .map(|&(key, _)| Error::ZeroThreshold(key)),
+            );
+
             (toml, errors)
         },
         Err(e) => {
@@ -244,3 +345,11 @@ pub fn read(path: &Path) -> (Conf, Vec<Error>) {
         },
     }
 }
+
+/// Returns the span of the first occurrence of `text` in the configuration file at `path`.
+pub fn conf_file_span(sess: &Session, path: &Path, text: &str) -> Option<Span> {
+    let file = sess.source_map().load_file(path).ok()?;
+    let lo = file.src.as_ref()?.find(text)?;
+    let lo = file.start_pos + BytePos(lo as u32);
+    Some(Span::with_root_ctxt(lo, lo + BytePos(text.len() as u32)))
+}
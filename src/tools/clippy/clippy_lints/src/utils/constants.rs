@@ -11,3 +11,31 @@ pub const BUILTIN_TYPES: &[&str] = &[
     "i8", "u8", "i16", "u16", "i32", "u32", "i64", "u64", "i128", "u128", "isize", "usize", "f32", "f64", "bool",
     "str", "char",
 ];
+
+/// List of the `str` methods taking a `Pattern`, with the position of the pattern in the
+/// arguments of the method call (the receiver being at position 0).
+#[rustfmt::skip]
+pub const STR_PATTERN_METHODS: &[(&str, usize)] = &[
+    ("contains", 1),
+    ("starts_with", 1),
+    ("ends_with", 1),
+    ("find", 1),
+    ("rfind", 1),
+    ("split", 1),
+    ("rsplit", 1),
+    ("split_terminator", 1),
+    ("rsplit_terminator", 1),
+    ("splitn", 2),
+    ("rsplitn", 2),
+    ("matches", 1),
+    ("rmatches", 1),
+    ("match_indices", 1),
+    ("rmatch_indices", 1),
+    ("trim_matches", 1),
+    ("trim_start_matches", 1),
+    ("trim_end_matches", 1),
+    ("strip_prefix", 1),
+    ("strip_suffix", 1),
+    ("replace", 1),
+    ("replacen", 1),
+];
@@ -0,0 +1,44 @@
+//! A tiny matcher for the name and path patterns of `clippy.toml`, like `tmp*`.
+
+/// Returns `true` if `name` matches `pattern`. A pattern ending with `*` matches the names
+/// starting with the text before the `*`, any other pattern only matches itself.
+#[must_use]
+pub fn matches(pattern: &str, name: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => name.starts_with(prefix),
+        None => pattern == name,
+    }
+}
+
+/// Returns `true` if `name` matches any of `patterns`.
+#[must_use]
+pub fn matches_any(patterns: &[String], name: &str) -> bool {
+    patterns.iter().any(|pattern| matches(pattern, name))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{matches, matches_any};
+
+    #[test]
+    fn test_matches() {
+        assert!(matches("foo", "foo"));
+        assert!(!matches("foo", "foobar"));
+        assert!(!matches("foo", "fo"));
+        assert!(matches("foo*", "foo"));
+        assert!(matches("foo*", "foobar"));
+        assert!(!matches("foo*", "barfoo"));
+        assert!(matches("*", "anything"));
+        assert!(matches("crate::prelude*", "crate::prelude::v1"));
+        assert!(!matches("fo*o", "foo"));
+    }
+
+    #[test]
+    fn test_matches_any() {
+        let patterns = vec!["tmp*".to_string(), "bar".to_string()];
+        assert!(matches_any(&patterns, "tmp_value"));
+        assert!(matches_any(&patterns, "bar"));
+        assert!(!matches_any(&patterns, "baz"));
+        assert!(!matches_any(&[], "bar"));
+    }
+}
@@ -1,15 +1,15 @@
 use crate::consts::{constant_context, constant_simple};
-use crate::utils::differing_macro_contexts;
+use crate::utils::{differing_macro_contexts, is_type_diagnostic_item, walk_ptrs_ty};
 use rustc_ast::ast::InlineAsmTemplatePiece;
 use rustc_data_structures::stable_hasher::{HashStable, StableHasher};
 use rustc_hir::{
     BinOpKind, Block, BlockCheckMode, BodyId, BorrowKind, CaptureBy, Expr, ExprKind, Field, FieldPat, FnRetTy,
     GenericArg, GenericArgs, Guard, InlineAsmOperand, Lifetime, LifetimeName, ParamName, Pat, PatKind, Path,
-    PathSegment, QPath, Stmt, StmtKind, Ty, TyKind, TypeBinding,
+    PathSegment, QPath, Stmt, StmtKind, Ty, TyKind, TypeBinding, UnOp,
 };
 use rustc_lint::LateContext;
 use rustc_middle::ich::StableHashingContextProvider;
-use rustc_middle::ty::TypeckResults;
+use rustc_middle::ty::{self, TypeckResults};
 use rustc_span::Symbol;
 use std::hash::Hash;
 
@@ -117,7 +117,7 @@ impl<'a, 'tcx> SpanlessEq<'a, 'tcx> {
                 l_f_ident.name == r_f_ident.name && self.eq_expr(l_f_exp, r_f_exp)
             },
             (&ExprKind::Index(ref la, ref li), &ExprKind::Index(ref ra, ref ri)) => {
-                self.eq_expr(la, ra) && self.eq_expr(li, ri)
+                (self.allow_side_effects || self.is_builtin_index(la)) && self.eq_expr(la, ra) && self.eq_expr(li, ri)
             },
             (&ExprKind::Lit(ref l), &ExprKind::Lit(ref r)) => l.node == r.node,
             (&ExprKind::Loop(ref lb, ref ll, ref lls), &ExprKind::Loop(ref rb, ref rl, ref rls)) => {
@@ -151,13 +151,31 @@ impl<'a, 'tcx> SpanlessEq<'a, 'tcx> {
                     && over(lf, rf, |l, r| self.eq_field(l, r))
             },
             (&ExprKind::Tup(l_tup), &ExprKind::Tup(r_tup)) => self.eq_exprs(l_tup, r_tup),
-            (&ExprKind::Unary(l_op, ref le), &ExprKind::Unary(r_op, ref re)) => l_op == r_op && self.eq_expr(le, re),
+            (&ExprKind::Unary(l_op, ref le), &ExprKind::Unary(r_op, ref re)) => {
+                l_op == r_op && (self.allow_side_effects || !self.is_raw_ptr_deref(l_op, le)) && self.eq_expr(le, re)
+            },
             (&ExprKind::Array(l), &ExprKind::Array(r)) => self.eq_exprs(l, r),
             (&ExprKind::DropTemps(ref le), &ExprKind::DropTemps(ref re)) => self.eq_expr(le, re),
             _ => false,
         }
     }
 
+    /// Whether indexing `base` cannot run user code, i.e. it is an array, a slice or a `Vec`.
+    fn is_builtin_index(&self, base: &Expr<'_>) -> bool {
+        self.maybe_typeck_results.map_or(false, |typeck_results| {
+            let ty = walk_ptrs_ty(typeck_results.expr_ty(base));
+            matches!(ty.kind(), ty::Array(..) | ty::Slice(_)) || is_type_diagnostic_item(self.cx, ty, sym!(vec_type))
+        })
+    }
+
+    /// Whether `op` dereferences a raw pointer, which may point to memory with volatile semantics.
+    fn is_raw_ptr_deref(&self, op: UnOp, expr: &Expr<'_>) -> bool {
+        op == UnOp::UnDeref
+            && self
+                .maybe_typeck_results
+                .map_or(true, |typeck_results| typeck_results.expr_ty(expr).is_unsafe_ptr())
+    }
+
     fn eq_exprs(&mut self, left: &[Expr<'_>], right: &[Expr<'_>]) -> bool {
         over(left, right, |l, r| self.eq_expr(l, r))
     }
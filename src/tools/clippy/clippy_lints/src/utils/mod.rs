@@ -10,10 +10,12 @@ pub mod comparisons;
 pub mod conf;
 pub mod constants;
 mod diagnostics;
+pub mod glob;
 pub mod higher;
 mod hir_utils;
 pub mod inspector;
 pub mod internal_lints;
+pub mod msrvs;
 pub mod numeric_literal;
 pub mod paths;
 pub mod ptr;
@@ -25,6 +27,7 @@ pub use self::diagnostics::*;
 pub use self::hir_utils::{both, eq_expr_value, over, SpanlessEq, SpanlessHash};
 
 use std::borrow::Cow;
+use std::iter;
 use std::mem;
 
 use if_chain::if_chain;
@@ -433,6 +436,45 @@ pub fn is_entrypoint_fn(cx: &LateContext<'_>, def_id: DefId) -> bool {
         .map_or(false, |(entry_fn_def_id, _)| def_id == entry_fn_def_id.to_def_id())
 }
 
+/// Returns `true` if the function with the given `HirId` and name is a `#[test]` function, i.e. if
+/// its module contains the test descriptor the test harness generates for it.
+pub fn is_test_function(cx: &LateContext<'_>, hir_id: HirId, name: Symbol) -> bool {
+    let module = cx.tcx.parent_module(hir_id);
+    cx.tcx.hir().get_module(module).0.item_ids.iter().any(|id| {
+        let item = cx.tcx.hir().item(id.id);
+        item.ident.name == name && item.attrs.iter().any(|attr| attr.has_name(symbol::sym::rustc_test_marker))
+    })
+}
+
+/// Returns `true` if the node with the given `HirId` is test code, i.e. if it is or is within a
+/// `#[test]` or `#[bench]` function or an item annotated with `#[cfg(test)]`.
+pub fn is_in_test_code(cx: &LateContext<'_>, hir_id: HirId) -> bool {
+    let map = cx.tcx.hir();
+    iter::once((hir_id, map.get(hir_id)))
+        .chain(map.parent_iter(hir_id))
+        .any(|(id, node)| match node {
+            Node::Item(item) => {
+                (matches!(item.kind, ItemKind::Fn(..)) && is_test_function(cx, id, item.ident.name))
+                    || item.attrs.iter().any(is_cfg_test)
+            },
+            _ => false,
+        })
+}
+
+/// Returns `true` if the item is a test module, i.e. a module annotated with `#[cfg(test)]` or
+/// with "test" in its name.
+pub fn is_test_module(item: &Item<'_>) -> bool {
+    matches!(item.kind, ItemKind::Mod(..))
+        && (item.attrs.iter().any(is_cfg_test) || item.ident.name.as_str().contains("test"))
+}
+
+fn is_cfg_test(attr: &Attribute) -> bool {
+    attr.has_name(symbol::sym::cfg)
+        && attr
+            .meta_item_list()
+            .map_or(false, |items| items.iter().any(|item| item.has_name(symbol::sym::test)))
+}
+
 /// Gets the name of the item the expression is in, if available.
 pub fn get_item_name(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<Symbol> {
     let parent_id = cx.tcx.hir().get_parent_item(expr.hir_id);
@@ -718,6 +760,36 @@ pub fn get_parent_expr<'c>(cx: &'c LateContext<'_>, e: &Expr<'_>) -> Option<&'c
     })
 }
 
+/// Checks whether `expr` is in the `then` branch of an `if` whose condition, or one of the
+/// operands of a `&&` chain in it, satisfies `guards`.
+pub fn is_guarded_by(cx: &LateContext<'_>, expr: &Expr<'_>, mut guards: impl FnMut(&Expr<'_>) -> bool) -> bool {
+    fn check(cond: &Expr<'_>, guards: &mut impl FnMut(&Expr<'_>) -> bool) -> bool {
+        match cond.kind {
+            ExprKind::Binary(op, ref left, ref right) if op.node == hir::BinOpKind::And => {
+                check(left, guards) || check(right, guards)
+            },
+            _ => guards(cond),
+        }
+    }
+
+    let mut child = expr.hir_id;
+    for (id, node) in cx.tcx.hir().parent_iter(expr.hir_id) {
+        match node {
+            Node::Expr(parent) => {
+                if let Some((cond, then, _)) = higher::if_block(parent) {
+                    if then.hir_id == child && check(cond, &mut guards) {
+                        return true;
+                    }
+                }
+                child = id;
+            },
+            Node::Block(_) | Node::Stmt(_) | Node::Local(_) | Node::Arm(_) => {},
+            _ => return false,
+        }
+    }
+    false
+}
+
 pub fn get_enclosing_block<'tcx>(cx: &LateContext<'tcx>, hir_id: HirId) -> Option<&'tcx Block<'tcx>> {
     let map = &cx.tcx.hir();
     let enclosing_node = map
@@ -831,6 +903,49 @@ pub fn is_expn_of(mut span: Span, name: &str) -> Option<Span> {
     }
 }
 
+/// Returns the pre-expansion span if the span comes from an expansion of the macro with the def
+/// path `path`. Unlike `is_expn_of`, the macro is resolved, so a macro of the same name defined
+/// elsewhere does not match.
+pub fn is_expn_of_def_path(cx: &LateContext<'_>, mut span: Span, path: &[&str]) -> Option<Span> {
+    loop {
+        if span.from_expansion() {
+            let data = span.ctxt().outer_expn_data();
+            let new_span = data.call_site;
+
+            if_chain! {
+                if let ExpnKind::Macro(MacroKind::Bang, _) = data.kind;
+                if let Some(def_id) = data.macro_def_id;
+                if match_def_path(cx, def_id, path);
+                then {
+                    return Some(new_span);
+                }
+            }
+
+            span = new_span;
+        } else {
+            return None;
+        }
+    }
+}
+
+/// Returns `true` if the span comes from an expansion of the `panic!` macro of `core` or `std`.
+pub fn is_panic_expn(cx: &LateContext<'_>, span: Span) -> bool {
+    is_expn_of_def_path(cx, span, &paths::STD_PANIC_MACRO).is_some()
+        || is_expn_of_def_path(cx, span, &paths::PANIC_MACRO).is_some()
+}
+
+/// Returns `true` if the span comes from an expansion of `debug_assert!`, `debug_assert_eq!` or
+/// `debug_assert_ne!`, which are only checked in debug builds.
+pub fn is_in_debug_assertion(cx: &LateContext<'_>, span: Span) -> bool {
+    [
+        &paths::DEBUG_ASSERT_MACRO,
+        &paths::DEBUG_ASSERT_EQ_MACRO,
+        &paths::DEBUG_ASSERT_NE_MACRO,
+    ]
+    .iter()
+    .any(|path| is_expn_of_def_path(cx, span, *path).is_some())
+}
+
 /// Returns the pre-expansion span if the span directly comes from an expansion
 /// of the macro `name`.
 /// The difference with `is_expn_of` is that in
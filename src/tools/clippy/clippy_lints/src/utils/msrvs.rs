@@ -0,0 +1,100 @@
+//! Minimum supported Rust versions (MSRV) of the suggestions made by Clippy lints, configured
+//! with the `msrv` key of `clippy.toml` or the `#![clippy::msrv = "1.30"]` crate attribute.
+
+use crate::utils::get_attr;
+use rustc_ast::ast;
+use rustc_session::Session;
+use std::fmt;
+
+/// A Rust version, like `1.40.0`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RustcVersion {
+    major: u32,
+    minor: u32,
+    patch: u32,
+}
+
+impl RustcVersion {
+    #[must_use]
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self { major, minor, patch }
+    }
+
+    /// Parses versions like `1`, `1.40` or `1.40.0`. Missing components default to `0`.
+    #[must_use]
+    pub fn parse(version: &str) -> Option<Self> {
+        let mut components = version.trim().split('.');
+        let mut next = |required: bool| match components.next() {
+            Some(component) if !component.is_empty() && component.bytes().all(|b| b.is_ascii_digit()) => {
+                component.parse().ok()
+            },
+            None if !required => Some(0),
+            _ => None,
+        };
+        let version = Self::new(next(true)?, next(false)?, next(false)?);
+        if components.next().is_none() {
+            Some(version)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for RustcVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Returns `true` if a project whose MSRV is `msrv` can use a feature stabilized in `required`.
+/// No configured MSRV means the latest version.
+#[must_use]
+pub fn meets_msrv(msrv: Option<RustcVersion>, required: RustcVersion) -> bool {
+    msrv.map_or(true, |msrv| msrv >= required)
+}
+
+/// Updates `msrv` with the `#![clippy::msrv = "x.y.z"]` crate attribute, if any.
+pub fn update_msrv_from_attrs(msrv: &mut Option<RustcVersion>, sess: &Session, attrs: &[ast::Attribute]) {
+    for attr in get_attr(sess, attrs, "msrv") {
+        match attr.value_str().map(|value| RustcVersion::parse(&value.as_str())) {
+            Some(Some(version)) => *msrv = Some(version),
+            Some(None) => sess.span_err(attr.span, "`msrv` is not a valid Rust version"),
+            None => sess.span_err(attr.span, "bad clippy attribute"),
+        }
+    }
+}
+
+/// The versions in which the features suggested by Clippy lints were stabilized.
+pub const TRY_FROM: RustcVersion = RustcVersion::new(1, 34, 0);
+pub const OPTION_COPIED: RustcVersion = RustcVersion::new(1, 35, 0);
+pub const ITERATOR_COPIED: RustcVersion = RustcVersion::new(1, 36, 0);
+pub const OPTION_AS_DEREF: RustcVersion = RustcVersion::new(1, 40, 0);
+pub const RANGE_INCLUSIVE: RustcVersion = RustcVersion::new(1, 26, 0);
+
+#[cfg(test)]
+mod test {
+    use super::{meets_msrv, RustcVersion};
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(RustcVersion::parse("1.40.1"), Some(RustcVersion::new(1, 40, 1)));
+        assert_eq!(RustcVersion::parse("1.40"), Some(RustcVersion::new(1, 40, 0)));
+        assert_eq!(RustcVersion::parse("2"), Some(RustcVersion::new(2, 0, 0)));
+        assert_eq!(RustcVersion::parse(" 1.30 "), Some(RustcVersion::new(1, 30, 0)));
+        assert_eq!(RustcVersion::parse(""), None);
+        assert_eq!(RustcVersion::parse("1."), None);
+        assert_eq!(RustcVersion::parse("1.40.0.1"), None);
+        assert_eq!(RustcVersion::parse("1.x"), None);
+        assert_eq!(RustcVersion::parse("-1.40"), None);
+    }
+
+    #[test]
+    fn test_meets_msrv() {
+        let required = RustcVersion::new(1, 36, 0);
+        assert!(meets_msrv(None, required));
+        assert!(meets_msrv(RustcVersion::parse("1.36"), required));
+        assert!(meets_msrv(RustcVersion::parse("1.40"), required));
+        assert!(!meets_msrv(RustcVersion::parse("1.35.9"), required));
+        assert!(RustcVersion::new(1, 9, 0) < RustcVersion::new(1, 10, 0));
+    }
+}
@@ -1,4 +1,4 @@
-use rustc_ast::ast::{Lit, LitFloatType, LitIntType, LitKind};
+use rustc_ast::ast::{Lit, LitKind};
 
 #[derive(Debug, PartialEq)]
 pub enum Radix {
@@ -21,8 +21,8 @@ impl Radix {
 
 /// A helper method to format numeric literals with digit grouping.
 /// `lit` must be a valid numeric literal without suffix.
-pub fn format(lit: &str, type_suffix: Option<&str>, float: bool) -> String {
-    NumericLiteral::new(lit, type_suffix, float).format()
+pub fn format(lit: &str, type_suffix: Option<&str>) -> String {
+    NumericLiteral::new(lit, type_suffix).format()
 }
 
 #[derive(Debug)]
@@ -36,10 +36,11 @@ pub struct NumericLiteral<'a> {
     pub integer: &'a str,
     /// The fraction part of the number.
     pub fraction: Option<&'a str>,
-    /// The character used as exponent separator (b'e' or b'E') and the exponent part.
+    /// The character used as exponent separator (b'e' or b'E') and the exponent part, including
+    /// its sign.
     pub exponent: Option<(char, &'a str)>,
 
-    /// The type suffix, including preceding underscore if present.
+    /// The type suffix, without preceding underscore.
     pub suffix: Option<&'a str>,
 }
 
@@ -50,16 +51,16 @@ impl<'a> NumericLiteral<'a> {
 
     pub fn from_lit_kind(src: &'a str, lit_kind: &LitKind) -> Option<NumericLiteral<'a>> {
         if lit_kind.is_numeric() && src.chars().next().map_or(false, |c| c.is_digit(10)) {
-            let (unsuffixed, suffix) = split_suffix(&src, lit_kind);
-            let float = matches!(lit_kind, LitKind::Float(..));
-            Some(NumericLiteral::new(unsuffixed, suffix, float))
+            Some(NumericLiteral::new(src, None))
         } else {
             None
         }
     }
 
+    /// Splits the literal `lit` into its parts. A type suffix is recognized in `lit` itself,
+    /// unless `suffix` is given.
     #[must_use]
-    pub fn new(lit: &'a str, suffix: Option<&'a str>, float: bool) -> Self {
+    pub fn new(lit: &'a str, suffix: Option<&'a str>) -> Self {
         // Determine delimiter for radix prefix, if present, and radix.
         let radix = if lit.starts_with("0x") {
             Radix::Hexadecimal
@@ -72,27 +73,30 @@ impl<'a> NumericLiteral<'a> {
         };
 
         // Grab part of the literal after prefix, if present.
-        let (prefix, mut sans_prefix) = if let Radix::Decimal = radix {
+        let (prefix, sans_prefix) = if let Radix::Decimal = radix {
             (None, lit)
         } else {
             let (p, s) = lit.split_at(2);
             (Some(p), s)
         };
 
-        if suffix.is_some() && sans_prefix.ends_with('_') {
-            // The '_' before the suffix isn't part of the digits
-            sans_prefix = &sans_prefix[..sans_prefix.len() - 1];
-        }
-
-        let (integer, fraction, exponent) = Self::split_digit_parts(sans_prefix, float);
+        let mut tokenizer = Tokenizer { src: sans_prefix };
+        let integer = tokenizer.digits(&radix);
+        // Only decimal literals can be floats, `0x1e2` is an integer and `0x1f32` has no suffix
+        let (fraction, exponent) = if radix == Radix::Decimal {
+            (tokenizer.fraction(), tokenizer.exponent())
+        } else {
+            (None, None)
+        };
+        let rest = tokenizer.src.trim_start_matches('_');
 
         Self {
             radix,
             prefix,
-            integer,
+            integer: integer.trim_end_matches('_'),
             fraction,
             exponent,
-            suffix,
+            suffix: suffix.or_else(|| if rest.is_empty() { None } else { Some(rest) }),
         }
     }
 
@@ -100,45 +104,20 @@ impl<'a> NumericLiteral<'a> {
         self.radix == Radix::Decimal
     }
 
-    pub fn split_digit_parts(digits: &str, float: bool) -> (&str, Option<&str>, Option<(char, &str)>) {
-        let mut integer = digits;
-        let mut fraction = None;
-        let mut exponent = None;
-
-        if float {
-            for (i, c) in digits.char_indices() {
-                match c {
-                    '.' => {
-                        integer = &digits[..i];
-                        fraction = Some(&digits[i + 1..]);
-                    },
-                    'e' | 'E' => {
-                        if integer.len() > i {
-                            integer = &digits[..i];
-                        } else {
-                            fraction = Some(&digits[integer.len() + 1..i]);
-                        };
-                        exponent = Some((c, &digits[i + 1..]));
-                        break;
-                    },
-                    _ => {},
-                }
-            }
-        }
-
-        (integer, fraction, exponent)
-    }
-
     /// Returns literal formatted in a sensible way.
     pub fn format(&self) -> String {
+        self.format_grouped(self.radix.suggest_grouping())
+    }
+
+    /// Returns the literal with the digits of its integer and fraction part grouped in groups of
+    /// `group_size`. The exponent is kept as it is.
+    pub fn format_grouped(&self, group_size: usize) -> String {
         let mut output = String::new();
 
         if let Some(prefix) = self.prefix {
             output.push_str(prefix);
         }
 
-        let group_size = self.radix.suggest_grouping();
-
         Self::group_digits(
             &mut output,
             self.integer,
@@ -154,7 +133,7 @@ impl<'a> NumericLiteral<'a> {
 
         if let Some((separator, exponent)) = self.exponent {
             output.push(separator);
-            Self::group_digits(&mut output, exponent, group_size, true, false);
+            output.push_str(exponent);
         }
 
         if let Some(suffix) = self.suffix {
@@ -198,28 +177,47 @@ impl<'a> NumericLiteral<'a> {
     }
 }
 
-fn split_suffix<'a>(src: &'a str, lit_kind: &LitKind) -> (&'a str, Option<&'a str>) {
-    debug_assert!(lit_kind.is_numeric());
-    lit_suffix_length(lit_kind).map_or((src, None), |suffix_length| {
-        let (unsuffixed, suffix) = src.split_at(src.len() - suffix_length);
-        (unsuffixed, Some(suffix))
-    })
+/// Splits the digits of a numeric literal into its parts, front to back.
+struct Tokenizer<'a> {
+    src: &'a str,
 }
 
-fn lit_suffix_length(lit_kind: &LitKind) -> Option<usize> {
-    debug_assert!(lit_kind.is_numeric());
-    let suffix = match lit_kind {
-        LitKind::Int(_, int_lit_kind) => match int_lit_kind {
-            LitIntType::Signed(int_ty) => Some(int_ty.name_str()),
-            LitIntType::Unsigned(uint_ty) => Some(uint_ty.name_str()),
-            LitIntType::Unsuffixed => None,
-        },
-        LitKind::Float(_, float_lit_kind) => match float_lit_kind {
-            LitFloatType::Suffixed(float_ty) => Some(float_ty.name_str()),
-            LitFloatType::Unsuffixed => None,
-        },
-        _ => None,
-    };
-
-    suffix.map(str::len)
+impl<'a> Tokenizer<'a> {
+    fn take_while(&mut self, pred: impl Fn(char) -> bool) -> &'a str {
+        let end = self.src.find(|c| !pred(c)).unwrap_or_else(|| self.src.len());
+        let (taken, rest) = self.src.split_at(end);
+        self.src = rest;
+        taken
+    }
+
+    /// Takes the digits of the integer part, including underscores.
+    fn digits(&mut self, radix: &Radix) -> &'a str {
+        let base = match radix {
+            Radix::Binary => 2,
+            Radix::Octal => 8,
+            Radix::Decimal => 10,
+            Radix::Hexadecimal => 16,
+        };
+        self.take_while(|c| c == '_' || c.is_digit(base))
+    }
+
+    /// Takes a `.` and the digits after it. The fraction may be empty, as in `1.`.
+    fn fraction(&mut self) -> Option<&'a str> {
+        if self.src.starts_with('.') {
+            self.src = &self.src[1..];
+            Some(self.take_while(|c| c == '_' || c.is_digit(10)).trim_end_matches('_'))
+        } else {
+            None
+        }
+    }
+
+    /// Takes an `e` or `E`, and the signed exponent after it.
+    fn exponent(&mut self) -> Option<(char, &'a str)> {
+        let separator = self.src.chars().next().filter(|&c| c == 'e' || c == 'E')?;
+        let signed = &self.src[1..];
+        self.src = signed.strip_prefix(|c| c == '+' || c == '-').unwrap_or(signed);
+        self.take_while(|c| c == '_' || c.is_digit(10));
+        let exponent = &signed[..signed.len() - self.src.len()];
+        Some((separator, exponent.trim_end_matches('_')))
+    }
 }
@@ -2,6 +2,7 @@
 #![deny(clippy::missing_docs_in_private_items)]
 
 use crate::utils::{higher, snippet, snippet_opt, snippet_with_macro_callsite};
+use if_chain::if_chain;
 use rustc_ast::util::parser::AssocOp;
 use rustc_ast::{ast, token};
 use rustc_ast_pretty::pprust::token_kind_to_string;
@@ -558,6 +559,66 @@ pub trait DiagnosticBuilderExt<T: LintContext> {
     /// diag.suggest_remove_item(cx, item, "remove this")
     /// ```
     fn suggest_remove_item(&mut self, cx: &T, item: Span, msg: &str, applicability: Applicability);
+
+    /// Suggest to add an item after another, separated by a blank line.
+    ///
+    /// The item should not be indented (except for inner indentation), it gets the indentation
+    /// of `item`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// diag.suggest_append_item(cx, item.span, "add this",
+    /// "impl Default for Foo {
+    ///     fn default() -> Self {
+    ///         Self::new()
+    ///     }
+    /// }", app);
+    /// ```
+    fn suggest_append_item(&mut self, cx: &T, item: Span, msg: &str, new_item: &str, applicability: Applicability);
+
+    /// Suggest to insert a statement on its own line before another statement.
+    ///
+    /// The statement should not be indented (except for inner indentation), it gets the
+    /// indentation of `stmt`. Nothing is suggested if `stmt` does not start its line.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// diag.suggest_insert_stmt_before(cx, stmt.span, "declare it first", "let mut v = Vec::new();", app);
+    /// ```
+    fn suggest_insert_stmt_before(
+        &mut self,
+        cx: &T,
+        stmt: Span,
+        msg: &str,
+        new_stmt: &str,
+        applicability: Applicability,
+    );
+
+    /// Suggest to insert a statement on its own line after another statement.
+    ///
+    /// The statement is inserted after the semicolon of `stmt`, if any. Nothing is suggested if
+    /// `stmt` does not start its line.
+    fn suggest_insert_stmt_after(
+        &mut self,
+        cx: &T,
+        stmt: Span,
+        msg: &str,
+        new_stmt: &str,
+        applicability: Applicability,
+    );
+
+    /// Suggest to replace a statement, including its semicolon, with another one.
+    ///
+    /// Lines of `new_stmt` after the first get the indentation of `stmt`.
+    fn suggest_replace_stmt(&mut self, cx: &T, stmt: Span, msg: &str, new_stmt: &str, applicability: Applicability);
+
+    /// Suggest to completely remove a statement.
+    ///
+    /// This removes the statement with its semicolon. If the statement is alone on its lines, the
+    /// indentation before it and the newline after it are removed as well.
+    fn suggest_remove_stmt(&mut self, cx: &T, stmt: Span, msg: &str, applicability: Applicability);
 }
 
 impl<T: LintContext> DiagnosticBuilderExt<T> for rustc_errors::DiagnosticBuilder<'_> {
@@ -613,11 +674,146 @@ impl<T: LintContext> DiagnosticBuilderExt<T> for rustc_errors::DiagnosticBuilder
 
         self.span_suggestion(remove_span, msg, String::new(), applicability);
     }
+
+    fn suggest_append_item(&mut self, cx: &T, item: Span, msg: &str, new_item: &str, applicability: Applicability) {
+        if let Some(indent) = indentation(cx, item) {
+            self.span_suggestion(
+                item.shrink_to_hi(),
+                msg,
+                format!("\n\n{}{}", indent, indent_lines(new_item, &indent)),
+                applicability,
+            );
+        }
+    }
+
+    fn suggest_insert_stmt_before(
+        &mut self,
+        cx: &T,
+        stmt: Span,
+        msg: &str,
+        new_stmt: &str,
+        applicability: Applicability,
+    ) {
+        if let Some(indent) = indentation(cx, stmt) {
+            // Insert whole lines at the start of the line, so that a removal of `stmt` with its
+            // indentation does not overlap
+            let line_start = stmt.lo() - BytePos(indent.len().try_into().expect("indentation too large"));
+            self.span_suggestion(
+                stmt.with_lo(line_start).shrink_to_lo(),
+                msg,
+                stmt_lines(new_stmt, &indent),
+                applicability,
+            );
+        }
+    }
+
+    fn suggest_insert_stmt_after(
+        &mut self,
+        cx: &T,
+        stmt: Span,
+        msg: &str,
+        new_stmt: &str,
+        applicability: Applicability,
+    ) {
+        if let Some(indent) = indentation(cx, stmt) {
+            self.span_suggestion(
+                with_trailing_semicolon(cx, stmt).shrink_to_hi(),
+                msg,
+                format!("\n{}{}", indent, indent_lines(new_stmt, &indent)),
+                applicability,
+            );
+        }
+    }
+
+    fn suggest_replace_stmt(&mut self, cx: &T, stmt: Span, msg: &str, new_stmt: &str, applicability: Applicability) {
+        let indent = indentation(cx, stmt).unwrap_or_default();
+        self.span_suggestion(
+            with_trailing_semicolon(cx, stmt),
+            msg,
+            indent_lines(new_stmt, &indent),
+            applicability,
+        );
+    }
+
+    fn suggest_remove_stmt(&mut self, cx: &T, stmt: Span, msg: &str, applicability: Applicability) {
+        let mut remove_span = with_trailing_semicolon(cx, stmt);
+        if_chain! {
+            if let Some(indent) = indentation(cx, remove_span);
+            if let Ok(rest) = cx.sess().source_map().span_to_next_source(remove_span);
+            if let Some(line_end) = rest_of_line_len(&rest);
+            then {
+                let lo = remove_span.lo() - BytePos(indent.len().try_into().expect("indentation too large"));
+                let hi = remove_span.hi() + BytePos(line_end.try_into().expect("offset too large"));
+                remove_span = remove_span.with_lo(lo).with_hi(hi);
+            }
+        }
+
+        self.span_suggestion(remove_span, msg, String::new(), applicability);
+    }
+}
+
+/// Returns `stmt` extended to the semicolon following it, if there is one and it is not part of
+/// the span already.
+fn with_trailing_semicolon<T: LintContext>(cx: &T, stmt: Span) -> Span {
+    if let Ok(rest) = cx.sess().source_map().span_to_next_source(stmt) {
+        let trimmed = rest.trim_start_matches(|c| c == ' ' || c == '\t');
+        if trimmed.starts_with(';') {
+            let len = rest.len() - trimmed.len() + 1;
+            return stmt.with_hi(stmt.hi() + BytePos(len.try_into().expect("offset too large")));
+        }
+    }
+    stmt
+}
+
+/// Returns the length of the rest of the line in `rest`, the source following a statement,
+/// including the newline, if there is nothing but whitespace before the newline.
+fn rest_of_line_len(rest: &str) -> Option<usize> {
+    let newline = rest.find('\n')?;
+    if rest[..newline].trim().is_empty() {
+        Some(newline + 1)
+    } else {
+        None
+    }
+}
+
+/// Formats `stmt` as whole lines indented with `indent`, to be inserted at the start of a line.
+fn stmt_lines(stmt: &str, indent: &str) -> String {
+    format!("{}{}\n", indent, indent_lines(stmt, indent))
+}
+
+/// Indents the lines of `text` after the first one with `indent`, leaving empty lines empty.
+///
+/// Suggestions are written with four spaces per level. If `indent` contains tabs, the leading
+/// spaces of each line are converted to tabs, so that the result matches the surrounding code.
+fn indent_lines(text: &str, indent: &str) -> String {
+    let use_tabs = indent.contains('\t');
+    text.lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let line = if use_tabs {
+                let spaces = line.len() - line.trim_start_matches(' ').len();
+                format!(
+                    "{}{}{}",
+                    "\t".repeat(spaces / 4),
+                    " ".repeat(spaces % 4),
+                    &line[spaces..]
+                )
+            } else {
+                line.to_string()
+            };
+            if i == 0 || line.is_empty() {
+                line
+            } else {
+                format!("{}{}", indent, line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
 #[cfg(test)]
 mod test {
-    use super::Sugg;
+    use super::{indent_lines, rest_of_line_len, stmt_lines, Sugg};
     use std::borrow::Cow;
 
     const SUGGESTION: Sugg<'static> = Sugg::NonParen(Cow::Borrowed("function_call()"));
@@ -631,4 +827,45 @@ mod test {
     fn blockify_transforms_sugg_into_a_block() {
         assert_eq!("{ function_call() }", SUGGESTION.blockify().to_string());
     }
+
+    #[test]
+    fn indent_lines_keeps_the_first_line_and_empty_lines() {
+        assert_eq!(
+            "impl Default for Foo {\n    fn default() -> Self {\n\n    }\n    }",
+            indent_lines("impl Default for Foo {\nfn default() -> Self {\n\n}\n}", "    ")
+        );
+    }
+
+    #[test]
+    fn indent_lines_converts_spaces_to_tabs() {
+        assert_eq!(
+            "impl Default for Foo {\n\t\tfn default() -> Self {\n\t\t\tSelf::new()\n\t\t}\n\t}",
+            indent_lines(
+                "impl Default for Foo {\n    fn default() -> Self {\n        Self::new()\n    }\n}",
+                "\t"
+            )
+        );
+    }
+
+    #[test]
+    fn stmt_lines_indents_every_line() {
+        assert_eq!(
+            "    let v = vec![\n        1,\n    ];\n",
+            stmt_lines("let v = vec![\n    1,\n];", "    ")
+        );
+        assert_eq!(
+            "\t\tlet v = vec![\n\t\t\t1,\n\t\t];\n",
+            stmt_lines("let v = vec![\n    1,\n];", "\t\t")
+        );
+    }
+
+    #[test]
+    fn rest_of_line_len_only_takes_whitespace() {
+        assert_eq!(Some(1), rest_of_line_len("\n    foo();"));
+        assert_eq!(Some(3), rest_of_line_len(" \t\n\tfoo();"));
+        assert_eq!(Some(2), rest_of_line_len("\r\n"));
+        assert_eq!(None, rest_of_line_len(" // comment\n"));
+        assert_eq!(None, rest_of_line_len(" foo();\n"));
+        assert_eq!(None, rest_of_line_len(""));
+    }
 }
@@ -1,8 +1,8 @@
-use crate::utils::match_var;
+use crate::utils::{match_var, qpath_res};
 use rustc_data_structures::fx::FxHashSet;
 use rustc_hir::def::Res;
-use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
-use rustc_hir::{Expr, HirId, Path};
+use rustc_hir::intravisit::{walk_expr, walk_stmt, NestedVisitorMap, Visitor};
+use rustc_hir::{Expr, ExprKind, HirId, Path, Stmt};
 use rustc_infer::infer::TyCtxtInferExt;
 use rustc_lint::LateContext;
 use rustc_middle::hir::map::Map;
@@ -108,3 +108,61 @@ pub fn is_unused<'tcx>(ident: &'tcx Ident, body: &'tcx Expr<'_>) -> bool {
     walk_expr(&mut visitor, body);
     !visitor.used
 }
+
+/// Checks whether the local binding `local_id` is referenced anywhere in `expr`, including the
+/// bodies of closures defined in `expr`.
+pub fn is_local_used<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, local_id: HirId) -> bool {
+    let mut visitor = LocalUsedVisitor {
+        cx,
+        local_id,
+        used: false,
+    };
+    visitor.visit_expr(expr);
+    visitor.used
+}
+
+/// Same as `is_local_used`, but for a statement.
+pub fn is_local_used_in_stmt<'tcx>(cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'_>, local_id: HirId) -> bool {
+    let mut visitor = LocalUsedVisitor {
+        cx,
+        local_id,
+        used: false,
+    };
+    visitor.visit_stmt(stmt);
+    visitor.used
+}
+
+struct LocalUsedVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    local_id: HirId,
+    used: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for LocalUsedVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
+        if self.used {
+            return;
+        }
+        if let ExprKind::Path(ref qpath) = expr.kind {
+            if let Res::Local(id) = qpath_res(self.cx, qpath, expr.hir_id) {
+                if id == self.local_id {
+                    self.used = true;
+                    return;
+                }
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn visit_stmt(&mut self, stmt: &'tcx Stmt<'_>) {
+        if !self.used {
+            walk_stmt(self, stmt);
+        }
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
@@ -1,4 +1,4 @@
-use crate::utils::{in_macro, snippet, snippet_with_applicability, span_lint_and_sugg};
+use crate::utils::{glob, in_macro, is_test_module, snippet, snippet_with_applicability, span_lint_and_sugg};
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir::{
@@ -7,7 +7,7 @@ use rustc_hir::{
 };
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
-use rustc_span::BytePos;
+use rustc_span::{symbol::kw, BytePos};
 
 declare_clippy_lint! {
     /// **What it does:** Checks for `use Enum::*`.
@@ -54,10 +54,14 @@ declare_clippy_lint! {
     /// Wildcard imports are allowed from modules named `prelude`. Many crates (including the standard library)
     /// provide modules named "prelude" specifically designed for wildcard import.
     ///
-    /// `use super::*` is allowed in test modules. This is defined as any module with "test" in the name.
+    /// `use super::*` is allowed in test modules. This is defined as any module with "test" in the name
+    /// or annotated with `#[cfg(test)]`.
     ///
     /// These exceptions can be disabled using the `warn-on-all-wildcard-imports` configuration flag.
     ///
+    /// Wildcard imports from the modules listed in the `allowed-wildcard-imports` configuration, like
+    /// `crate::ast` or `crate::ast*`, are always allowed.
+    ///
     /// **Known problems:** If macros are imported through the wildcard, this macro is not included
     /// by the suggestion and has to be added by hand.
     ///
@@ -87,13 +91,15 @@ declare_clippy_lint! {
 #[derive(Default)]
 pub struct WildcardImports {
     warn_on_all: bool,
+    allowed: Vec<String>,
     test_modules_deep: u32,
 }
 
 impl WildcardImports {
-    pub fn new(warn_on_all: bool) -> Self {
+    pub fn new(warn_on_all: bool, allowed: Vec<String>) -> Self {
         Self {
             warn_on_all,
+            allowed,
             test_modules_deep: 0,
         }
     }
@@ -103,7 +109,7 @@ impl_lint_pass!(WildcardImports => [ENUM_GLOB_USE, WILDCARD_IMPORTS]);
 
 impl LateLintPass<'_> for WildcardImports {
     fn check_item(&mut self, cx: &LateContext<'_>, item: &Item<'_>) {
-        if is_test_module_or_function(item) {
+        if is_test_module(item) {
             self.test_modules_deep = self.test_modules_deep.saturating_add(1);
         }
         if item.vis.node.is_pub() || item.vis.node.is_pub_restricted() {
@@ -112,6 +118,7 @@ impl LateLintPass<'_> for WildcardImports {
         if_chain! {
             if let ItemKind::Use(use_path, UseKind::Glob) = &item.kind;
             if self.warn_on_all || !self.check_exceptions(item, use_path.segments);
+            if !self.is_allowed(use_path.segments);
             let used_imports = cx.tcx.names_imported_by_glob_use(item.hir_id.owner);
             if !used_imports.is_empty(); // Already handled by `unused_imports`
             then {
@@ -181,7 +188,7 @@ impl LateLintPass<'_> for WildcardImports {
     }
 
     fn check_item_post(&mut self, _: &LateContext<'_>, item: &Item<'_>) {
-        if is_test_module_or_function(item) {
+        if is_test_module(item) {
             self.test_modules_deep = self.test_modules_deep.saturating_sub(1);
         }
     }
@@ -193,6 +200,20 @@ impl WildcardImports {
             || is_prelude_import(segments)
             || (is_super_only_import(segments) && self.test_modules_deep > 0)
     }
+
+    /// Checks the path of the imported module against the `allowed-wildcard-imports` configuration.
+    fn is_allowed(&self, segments: &[PathSegment<'_>]) -> bool {
+        if self.allowed.is_empty() {
+            return false;
+        }
+        let path = segments
+            .iter()
+            .filter(|ps| ps.ident.name != kw::PathRoot)
+            .map(|ps| ps.ident.as_str().to_string())
+            .collect::<Vec<_>>()
+            .join("::");
+        glob::matches_any(&self.allowed, &path)
+    }
 }
 
 // Allow "...prelude::..::*" imports.
@@ -205,7 +226,3 @@ fn is_prelude_import(segments: &[PathSegment<'_>]) -> bool {
 fn is_super_only_import(segments: &[PathSegment<'_>]) -> bool {
     segments.len() == 1 && segments[0].ident.as_str() == "super"
 }
-
-fn is_test_module_or_function(item: &Item<'_>) -> bool {
-    matches!(item.kind, ItemKind::Mod(..)) && item.ident.name.as_str().contains("test")
-}
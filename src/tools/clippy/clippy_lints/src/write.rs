@@ -1,21 +1,31 @@
 use std::borrow::Cow;
 use std::ops::Range;
 
+use crate::utils::ast_utils::eq_expr;
 use crate::utils::{snippet_with_applicability, span_lint, span_lint_and_sugg, span_lint_and_then};
-use rustc_ast::ast::{Expr, ExprKind, Item, ItemKind, MacCall, StrLit, StrStyle};
+use if_chain::if_chain;
+use rustc_ast::ast::{
+    Block, Expr, ExprKind, Item, ItemKind, Lit, LitKind, MacCall, MacStmtStyle, StmtKind, StrLit, StrStyle,
+};
+use rustc_ast::ptr::P;
 use rustc_ast::token;
 use rustc_ast::tokenstream::TokenStream;
+use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::Applicability;
 use rustc_lexer::unescape::{self, EscapeError};
 use rustc_lint::{EarlyContext, EarlyLintPass};
 use rustc_parse::parser;
+use rustc_parse_format::{
+    AlignUnknown, Argument, ArgumentImplicitlyIs, ArgumentIs, ArgumentNamed, CountImplied, CountIsName, CountIsParam,
+    FormatSpec, Position,
+};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::symbol::Symbol;
 use rustc_span::{BytePos, Span};
 
 declare_clippy_lint! {
-    /// **What it does:** This lint warns when you use `println!("")` to
-    /// print a newline.
+    /// **What it does:** This lint warns when you use `println!("")` or `eprintln!("")`
+    /// to print a newline.
     ///
     /// **Why is this bad?** You should use `println!()`, which is simpler.
     ///
@@ -35,8 +45,8 @@ declare_clippy_lint! {
 }
 
 declare_clippy_lint! {
-    /// **What it does:** This lint warns when you use `print!()` with a format
-    /// string that ends in a newline.
+    /// **What it does:** This lint warns when you use `print!()` or `eprint!()` with a
+    /// format string that ends in a newline.
     ///
     /// **Why is this bad?** You should use `println!()` instead, which appends the
     /// newline.
@@ -193,9 +203,61 @@ declare_clippy_lint! {
     "writing a literal with a format string"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** This lint warns about the use of literals as `format!` args.
+    ///
+    /// **Why is this bad?** The literal can be put in the format string directly, which is
+    /// both shorter and faster.
+    ///
+    /// **Known problems:** Only literals are inlined, constants are not. Will also warn with
+    /// macro calls as arguments that expand to literals -- e.g., `format!("{}", env!("FOO"))`.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let name = "World";
+    /// let greeting = format!("{}, {}!", "Hello", name);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let name = "World";
+    /// let greeting = format!("Hello, {}!", name);
+    /// ```
+    pub FORMAT_LITERAL,
+    style,
+    "formatting a literal with a format string"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** This lint warns when a `print!()`, `eprint!()` or `write!()` is
+    /// immediately followed by another call of the same macro which only prints a newline.
+    ///
+    /// **Why is this bad?** The `println!()` family appends the newline itself, there is no
+    /// need for a second call.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let name = "World";
+    /// print!("Hello {}!", name);
+    /// println!();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let name = "World";
+    /// println!("Hello {}!", name);
+    /// ```
+    pub PRINT_FOLLOWED_BY_NEWLINE,
+    style,
+    "using `print!()` followed by a separate call printing only a newline"
+}
+
 #[derive(Default)]
 pub struct Write {
     in_debug_impl: bool,
+    /// The spans of macro calls which only print a newline after a `print!()`-like call, these
+    /// are linted by `PRINT_FOLLOWED_BY_NEWLINE` already.
+    separate_newlines: FxHashSet<Span>,
 }
 
 impl_lint_pass!(Write => [
@@ -206,9 +268,22 @@ impl_lint_pass!(Write => [
     PRINT_LITERAL,
     WRITE_WITH_NEWLINE,
     WRITELN_EMPTY_STRING,
-    WRITE_LITERAL
+    WRITE_LITERAL,
+    PRINT_FOLLOWED_BY_NEWLINE,
+    FORMAT_LITERAL
 ]);
 
+/// The kinds of macros taking a format string.
+#[derive(Clone, Copy, PartialEq)]
+enum FormatMacro {
+    /// `print[ln]!` and `eprint[ln]!`
+    Print,
+    /// `write[ln]!`
+    Write,
+    /// `format!`
+    Format,
+}
+
 impl EarlyLintPass for Write {
     fn check_item(&mut self, _: &EarlyContext<'_>, item: &Item) {
         if let ItemKind::Impl {
@@ -234,37 +309,29 @@ impl EarlyLintPass for Write {
         self.in_debug_impl = false;
     }
 
-    fn check_mac(&mut self, cx: &EarlyContext<'_>, mac: &MacCall) {
-        if mac.path == sym!(println) {
-            span_lint(cx, PRINT_STDOUT, mac.span(), "use of `println!`");
-            if let (Some(fmt_str), _) = self.check_tts(cx, mac.args.inner_tokens(), false) {
-                if fmt_str.symbol == Symbol::intern("") {
-                    span_lint_and_sugg(
-                        cx,
-                        PRINTLN_EMPTY_STRING,
-                        mac.span(),
-                        "using `println!(\"\")`",
-                        "replace it with",
-                        "println!()".to_string(),
-                        Applicability::MachineApplicable,
-                    );
-                }
-            }
-        } else if mac.path == sym!(print) {
-            span_lint(cx, PRINT_STDOUT, mac.span(), "use of `print!`");
-            if let (Some(fmt_str), _) = self.check_tts(cx, mac.args.inner_tokens(), false) {
-                if check_newlines(&fmt_str) {
+    fn check_block(&mut self, cx: &EarlyContext<'_>, block: &Block) {
+        for w in block.stmts.windows(2) {
+            if_chain! {
+                if let StmtKind::MacCall(ref first) = w[0].kind;
+                if let StmtKind::MacCall(ref second) = w[1].kind;
+                if first.style == MacStmtStyle::Semicolon && second.attrs.is_empty();
+                if let Some(&name) = ["print", "eprint", "write"]
+                    .iter()
+                    .find(|&&name| first.mac.path == Symbol::intern(name));
+                if is_separate_newline(cx, &first.mac, &second.mac, name);
+                then {
+                    self.separate_newlines.insert(second.mac.span());
                     span_lint_and_then(
                         cx,
-                        PRINT_WITH_NEWLINE,
-                        mac.span(),
-                        "using `print!()` with a format string that ends in a single newline",
+                        PRINT_FOLLOWED_BY_NEWLINE,
+                        w[0].span.to(w[1].span),
+                        &format!("using `{}!()` followed by a separate newline", name),
                         |err| {
                             err.multipart_suggestion(
-                                "use `println!` instead",
+                                &format!("use `{}ln!` instead", name),
                                 vec![
-                                    (mac.path.span, String::from("println")),
-                                    (newline_span(&fmt_str), String::new()),
+                                    (first.mac.path.span, format!("{}ln", name)),
+                                    (w[0].span.shrink_to_hi().to(w[1].span), String::new()),
                                 ],
                                 Applicability::MachineApplicable,
                             );
@@ -272,9 +339,23 @@ impl EarlyLintPass for Write {
                     );
                 }
             }
+        }
+    }
+
+    fn check_mac(&mut self, cx: &EarlyContext<'_>, mac: &MacCall) {
+        if mac.path == sym!(println) {
+            span_lint(cx, PRINT_STDOUT, mac.span(), "use of `println!`");
+            self.lint_println_empty_string(cx, mac, "println");
+        } else if mac.path == sym!(eprintln) {
+            self.lint_println_empty_string(cx, mac, "eprintln");
+        } else if mac.path == sym!(print) {
+            span_lint(cx, PRINT_STDOUT, mac.span(), "use of `print!`");
+            self.lint_print_with_newline(cx, mac, "print");
+        } else if mac.path == sym!(eprint) {
+            self.lint_print_with_newline(cx, mac, "eprint");
         } else if mac.path == sym!(write) {
-            if let (Some(fmt_str), _) = self.check_tts(cx, mac.args.inner_tokens(), true) {
-                if check_newlines(&fmt_str) {
+            if let (Some(fmt_str), _) = self.check_tts(cx, mac.args.inner_tokens(), FormatMacro::Write) {
+                if check_newlines(&fmt_str) && !self.separate_newlines.contains(&mac.span()) {
                     span_lint_and_then(
                         cx,
                         WRITE_WITH_NEWLINE,
@@ -294,8 +375,8 @@ impl EarlyLintPass for Write {
                 }
             }
         } else if mac.path == sym!(writeln) {
-            if let (Some(fmt_str), expr) = self.check_tts(cx, mac.args.inner_tokens(), true) {
-                if fmt_str.symbol == Symbol::intern("") {
+            if let (Some(fmt_str), expr) = self.check_tts(cx, mac.args.inner_tokens(), FormatMacro::Write) {
+                if fmt_str.symbol == Symbol::intern("") && !self.separate_newlines.contains(&mac.span()) {
                     let mut applicability = Applicability::MachineApplicable;
                     // FIXME: remove this `#[allow(...)]` once the issue #5822 gets fixed
                     #[allow(clippy::option_if_let_else)]
@@ -317,6 +398,8 @@ impl EarlyLintPass for Write {
                     );
                 }
             }
+        } else if mac.path == sym!(format) {
+            self.check_tts(cx, mac.args.inner_tokens(), FormatMacro::Format);
         }
     }
 }
@@ -345,11 +428,52 @@ fn newline_span(fmtstr: &StrLit) -> Span {
 }
 
 impl Write {
-    /// Checks the arguments of `print[ln]!` and `write[ln]!` calls. It will return a tuple of two
-    /// `Option`s. The first `Option` of the tuple is the macro's format string. It includes
-    /// the contents of the string, whether it's a raw string, and the span of the literal in the
-    /// source. The second `Option` in the tuple is, in the `write[ln]!` case, the expression the
-    /// `format_str` should be written to.
+    /// Lints `println!("")` and `eprintln!("")`, `name` being the name of the macro.
+    fn lint_println_empty_string(&self, cx: &EarlyContext<'_>, mac: &MacCall, name: &str) {
+        if let (Some(fmt_str), _) = self.check_tts(cx, mac.args.inner_tokens(), FormatMacro::Print) {
+            if fmt_str.symbol == Symbol::intern("") && !self.separate_newlines.contains(&mac.span()) {
+                span_lint_and_sugg(
+                    cx,
+                    PRINTLN_EMPTY_STRING,
+                    mac.span(),
+                    &format!("using `{}!(\"\")`", name),
+                    "replace it with",
+                    format!("{}!()", name),
+                    Applicability::MachineApplicable,
+                );
+            }
+        }
+    }
+
+    /// Lints `print!("..\n")` and `eprint!("..\n")`, `name` being the name of the macro.
+    fn lint_print_with_newline(&self, cx: &EarlyContext<'_>, mac: &MacCall, name: &str) {
+        if let (Some(fmt_str), _) = self.check_tts(cx, mac.args.inner_tokens(), FormatMacro::Print) {
+            if check_newlines(&fmt_str) && !self.separate_newlines.contains(&mac.span()) {
+                span_lint_and_then(
+                    cx,
+                    PRINT_WITH_NEWLINE,
+                    mac.span(),
+                    &format!("using `{}!()` with a format string that ends in a single newline", name),
+                    |err| {
+                        err.multipart_suggestion(
+                            &format!("use `{}ln!` instead", name),
+                            vec![
+                                (mac.path.span, format!("{}ln", name)),
+                                (newline_span(&fmt_str), String::new()),
+                            ],
+                            Applicability::MachineApplicable,
+                        );
+                    },
+                );
+            }
+        }
+    }
+
+    /// Checks the arguments of `print[ln]!`, `write[ln]!` and `format!` calls. It will return a
+    /// tuple of two `Option`s. The first `Option` of the tuple is the macro's format string. It
+    /// includes the contents of the string, whether it's a raw string, and the span of the
+    /// literal in the source. The second `Option` in the tuple is, in the `write[ln]!` case, the
+    /// expression the `format_str` should be written to.
     ///
     /// Example:
     ///
@@ -364,16 +488,17 @@ impl Write {
     /// ```rust,ignore
     /// (Some("string to write: {}"), Some(buf))
     /// ```
-    #[allow(clippy::too_many_lines)]
-    fn check_tts<'a>(&self, cx: &EarlyContext<'a>, tts: TokenStream, is_write: bool) -> (Option<StrLit>, Option<Expr>) {
-        use rustc_parse_format::{
-            AlignUnknown, ArgumentImplicitlyIs, ArgumentIs, ArgumentNamed, CountImplied, FormatSpec, ParseMode, Parser,
-            Piece,
-        };
+    fn check_tts<'a>(
+        &self,
+        cx: &EarlyContext<'a>,
+        tts: TokenStream,
+        kind: FormatMacro,
+    ) -> (Option<StrLit>, Option<Expr>) {
+        use rustc_parse_format::{ParseMode, Parser, Piece};
 
         let mut parser = parser::Parser::new(&cx.sess.parse_sess, tts, false, None);
         let mut expr: Option<Expr> = None;
-        if is_write {
+        if kind == FormatMacro::Write {
             expr = match parser.parse_expr().map_err(|mut err| err.cancel()) {
                 Ok(p) => Some(p.into_inner()),
                 Err(_) => return (None, None),
@@ -396,83 +521,231 @@ impl Write {
                 return (None, expr);
             }
             if let Piece::NextArgument(arg) = piece {
-                if !self.in_debug_impl && arg.format.ty == "?" {
+                if !self.in_debug_impl && kind != FormatMacro::Format && arg.format.ty == "?" {
                     // FIXME: modify rustc's fmt string parser to give us the current span
                     span_lint(cx, USE_DEBUG, parser.prev_token.span, "use of `Debug`-based formatting");
                 }
                 args.push(arg);
             }
         }
-        let lint = if is_write { WRITE_LITERAL } else { PRINT_LITERAL };
-        let mut idx = 0;
-        loop {
-            const SIMPLE: FormatSpec<'_> = FormatSpec {
-                fill: None,
-                align: AlignUnknown,
-                flags: 0,
-                precision: CountImplied,
-                precision_span: None,
-                width: CountImplied,
-                width_span: None,
-                ty: "",
-                ty_span: None,
-            };
-            if !parser.eat(&token::Comma) {
-                return (Some(fmtstr), expr);
+
+        // the arguments of the call, with the span of the comma before each of them
+        let mut call_args = vec![];
+        while parser.eat(&token::Comma) {
+            let comma = parser.prev_token.span;
+            if let Ok(token_expr) = parser.parse_expr().map_err(|mut err| err.cancel()) {
+                call_args.push((comma, token_expr));
+            } else {
+                expr = None;
+                break;
             }
-            let token_expr = if let Ok(expr) = parser.parse_expr().map_err(|mut err| err.cancel()) {
-                expr
+        }
+
+        check_literal_args(cx, kind, &fmtstr, &args, &call_args);
+
+        (Some(fmtstr), expr)
+    }
+}
+
+/// Lints the literal arguments of a call of a `kind` macro, whose format string `fmtstr` has the
+/// placeholders `args`.
+fn check_literal_args(
+    cx: &EarlyContext<'_>,
+    kind: FormatMacro,
+    fmtstr: &StrLit,
+    args: &[Argument<'_>],
+    call_args: &[(Span, P<Expr>)],
+) {
+    let lint = match kind {
+        FormatMacro::Print => PRINT_LITERAL,
+        FormatMacro::Write => WRITE_LITERAL,
+        FormatMacro::Format => FORMAT_LITERAL,
+    };
+    let source = fmtstr.symbol.as_str();
+    let fmt = FormatString::new(fmtstr, &source, args, call_args);
+    let mut idx = 0;
+    for (comma, token_expr) in call_args {
+        let (target, lit) = match &token_expr.kind {
+            ExprKind::Lit(lit) => {
+                idx += 1;
+                (ArgumentIs(idx - 1), lit)
+            },
+            ExprKind::Assign(lhs, rhs, _) => match (&lhs.kind, &rhs.kind) {
+                (ExprKind::Path(_, p), ExprKind::Lit(lit)) if p.segments.len() == 1 => {
+                    (ArgumentNamed(p.segments[0].ident.name), lit)
+                },
+                _ => continue,
+            },
+            _ => {
+                idx += 1;
+                continue;
+            },
+        };
+        let mut uses = args.iter().filter(|arg| refers_to(arg.position, target)).peekable();
+        if uses.peek().is_some() && uses.all(|arg| arg.format == SIMPLE) {
+            let span = if let ArgumentNamed(_) = target {
+                lit.span
             } else {
-                return (Some(fmtstr), None);
+                token_expr.span
             };
-            match &token_expr.kind {
-                ExprKind::Lit(_) => {
-                    let mut all_simple = true;
-                    let mut seen = false;
-                    for arg in &args {
-                        match arg.position {
-                            ArgumentImplicitlyIs(n) | ArgumentIs(n) => {
-                                if n == idx {
-                                    all_simple &= arg.format == SIMPLE;
-                                    seen = true;
-                                }
-                            },
-                            ArgumentNamed(_) => {},
-                        }
-                    }
-                    if all_simple && seen {
-                        span_lint(cx, lint, token_expr.span, "literal with an empty format string");
-                    }
-                    idx += 1;
-                },
-                ExprKind::Assign(lhs, rhs, _) => {
-                    if let ExprKind::Lit(_) = rhs.kind {
-                        if let ExprKind::Path(_, p) = &lhs.kind {
-                            let mut all_simple = true;
-                            let mut seen = false;
-                            for arg in &args {
-                                match arg.position {
-                                    ArgumentImplicitlyIs(_) | ArgumentIs(_) => {},
-                                    ArgumentNamed(name) => {
-                                        if *p == name {
-                                            seen = true;
-                                            all_simple &= arg.format == SIMPLE;
-                                        }
-                                    },
-                                }
-                            }
-                            if all_simple && seen {
-                                span_lint(cx, lint, rhs.span, "literal with an empty format string");
-                            }
-                        }
+            match fmt.inline(target, lit) {
+                Some(fmt_sugg) => span_lint_and_then(cx, lint, span, "literal with an empty format string", |err| {
+                    err.multipart_suggestion(
+                        "try this",
+                        vec![(fmtstr.span, fmt_sugg), (comma.to(token_expr.span), String::new())],
+                        Applicability::MachineApplicable,
+                    );
+                }),
+                None => span_lint(cx, lint, span, "literal with an empty format string"),
+            }
+        }
+    }
+}
+
+/// The format spec of a plain `{}`.
+const SIMPLE: FormatSpec<'static> = FormatSpec {
+    fill: None,
+    align: AlignUnknown,
+    flags: 0,
+    precision: CountImplied,
+    precision_span: None,
+    width: CountImplied,
+    width_span: None,
+    ty: "",
+    ty_span: None,
+};
+
+/// Checks whether an argument at `position` in a format string refers to the argument `target`,
+/// which is either `ArgumentIs` or `ArgumentNamed`.
+fn refers_to(position: Position, target: Position) -> bool {
+    match (position, target) {
+        (ArgumentImplicitlyIs(n) | ArgumentIs(n), ArgumentIs(m)) => n == m,
+        (ArgumentNamed(n), ArgumentNamed(m)) => n == m,
+        _ => false,
+    }
+}
+
+/// A format string, prepared for inlining the literal arguments of the call.
+struct FormatString<'a> {
+    style: StrStyle,
+    source: &'a str,
+    args: &'a [Argument<'a>],
+    /// The ranges of the placeholders in `source`, in the order of `args`.
+    placeholders: Vec<Range<usize>>,
+    /// Whether renumbering the remaining arguments is possible.
+    inlinable: bool,
+}
+
+impl<'a> FormatString<'a> {
+    fn new(fmtstr: &StrLit, source: &'a str, args: &'a [Argument<'a>], call_args: &[(Span, P<Expr>)]) -> Self {
+        let placeholders = placeholder_ranges(source);
+        let positional = call_args
+            .iter()
+            .filter(|(_, arg)| !matches!(arg.kind, ExprKind::Assign(..)))
+            .count();
+        // widths and precisions taken from the arguments, and named arguments referred to by
+        // their index, would need to be renumbered as well
+        let inlinable = placeholders.len() == args.len()
+            && args.iter().all(|arg| {
+                !matches!(arg.format.width, CountIsParam(_) | CountIsName(_))
+                    && !matches!(arg.format.precision, CountIsParam(_) | CountIsName(_))
+                    && match arg.position {
+                        ArgumentImplicitlyIs(n) | ArgumentIs(n) => n < positional,
+                        ArgumentNamed(_) => true,
                     }
+            });
+        Self {
+            style: fmtstr.style,
+            source,
+            args,
+            placeholders,
+            inlinable,
+        }
+    }
+
+    /// Returns the format string literal with the argument `target` inlined as `lit`, and the
+    /// indices of the following positional arguments adjusted to its removal.
+    fn inline(&self, target: Position, lit: &Lit) -> Option<String> {
+        if !self.inlinable {
+            return None;
+        }
+        let text = escape_literal(lit, self.style)?;
+        let mut new = String::with_capacity(self.source.len() + text.len());
+        let mut last = 0;
+        for (arg, range) in self.args.iter().zip(&self.placeholders) {
+            new.push_str(&self.source[last..range.start]);
+            last = range.end;
+            let placeholder = &self.source[range.clone()];
+            match (arg.position, target) {
+                _ if refers_to(arg.position, target) => new.push_str(&text),
+                // implicit positions are shifted by removing the implicit use of `target`
+                (ArgumentIs(n), ArgumentIs(removed)) if n > removed => {
+                    new.push('{');
+                    new.push_str(&(n - 1).to_string());
+                    new.push_str(placeholder[1..].trim_start_matches(|c: char| c.is_ascii_digit()));
                 },
-                _ => idx += 1,
+                _ => new.push_str(placeholder),
             }
         }
+        new.push_str(&self.source[last..]);
+        Some(match self.style {
+            StrStyle::Cooked => format!("\"{}\"", new),
+            StrStyle::Raw(hashes) => format!("r{0}\"{1}\"{0}", "#".repeat(hashes.into()), new),
+        })
     }
 }
 
+/// Returns the ranges of the placeholders in the format string `source`, i.e. of the `{..}`
+/// which are not escaped as `{{`.
+fn placeholder_ranges(source: &str) -> Vec<Range<usize>> {
+    let mut ranges = vec![];
+    let mut chars = source.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        match c {
+            '{' if chars.peek().map(|&(_, c)| c) == Some('{') => {
+                chars.next();
+            },
+            '{' => {
+                if let Some((end, _)) = chars.find(|&(_, c)| c == '}') {
+                    ranges.push(start..end + 1);
+                }
+            },
+            '}' => {
+                chars.next();
+            },
+            _ => {},
+        }
+    }
+    ranges
+}
+
+/// Returns the value of `lit` as it has to be written in a format string of the given style, or
+/// `None` if it can't be written there.
+fn escape_literal(lit: &Lit, style: StrStyle) -> Option<String> {
+    let value = match lit.kind {
+        LitKind::Str(s, _) => s.to_string(),
+        LitKind::Char(c) => c.to_string(),
+        LitKind::Int(i, _) => i.to_string(),
+        _ => return None,
+    };
+    let mut text = String::with_capacity(value.len());
+    for c in value.chars() {
+        match (c, style) {
+            ('{', _) => text.push_str("{{"),
+            ('}', _) => text.push_str("}}"),
+            ('"' | '\r', StrStyle::Raw(_)) => return None,
+            (_, StrStyle::Raw(_)) => text.push(c),
+            ('"' | '\\', StrStyle::Cooked) => {
+                text.push('\\');
+                text.push(c);
+            },
+            (_, StrStyle::Cooked) if c.is_control() => text.extend(c.escape_default()),
+            (_, StrStyle::Cooked) => text.push(c),
+        }
+    }
+    Some(text)
+}
+
 /// Checks if the format string contains a single newline that terminates it.
 ///
 /// Literal and escaped newlines are both checked (only literal for raw strings).
@@ -503,3 +776,77 @@ fn check_newlines(fmtstr: &StrLit) -> bool {
 
     should_lint
 }
+
+/// Checks whether the macro call `second` only prints a newline to the same destination as the
+/// call `first` of the `name` macro, e.g. `println!()` after `print!(..)`.
+fn is_separate_newline(cx: &EarlyContext<'_>, first: &MacCall, second: &MacCall, name: &str) -> bool {
+    let is_ln = if second.path == Symbol::intern(name) {
+        false
+    } else if second.path == Symbol::intern(&format!("{}ln", name)) {
+        true
+    } else {
+        return false;
+    };
+    let is_write = name == "write";
+    match newline_only(cx, second.args.inner_tokens(), is_write, is_ln) {
+        Some(Some(second_dest)) => {
+            let mut parser = parser::Parser::new(&cx.sess.parse_sess, first.args.inner_tokens(), false, None);
+            parser
+                .parse_expr()
+                .map_err(|mut err| err.cancel())
+                .map_or(false, |first_dest| eq_expr(&first_dest, &second_dest))
+        },
+        Some(None) => !is_write,
+        None => false,
+    }
+}
+
+/// Checks whether the arguments `tts` of a `print!()`-like macro call only print a newline,
+/// i.e. whether the format string is a single newline, or, if `is_ln`, whether it is empty or
+/// missing. Returns the destination of `write!()`-like calls.
+fn newline_only(cx: &EarlyContext<'_>, tts: TokenStream, is_write: bool, is_ln: bool) -> Option<Option<Expr>> {
+    let mut parser = parser::Parser::new(&cx.sess.parse_sess, tts, false, None);
+    let dest = if is_write {
+        let dest = parser.parse_expr().map_err(|mut err| err.cancel()).ok()?;
+        if !parser.eat(&token::Comma) {
+            // might be `writeln!(foo)`
+            return if is_ln && parser.token.kind == token::Eof {
+                Some(Some(dest.into_inner()))
+            } else {
+                None
+            };
+        }
+        Some(dest.into_inner())
+    } else {
+        None
+    };
+    if parser.token.kind == token::Eof {
+        return if is_ln { Some(dest) } else { None };
+    }
+    let fmtstr = parser.parse_str_lit().ok()?;
+    parser.eat(&token::Comma);
+    let expected = if is_ln { "" } else { "\n" };
+    if parser.token.kind == token::Eof && unescaped(&fmtstr) == expected {
+        Some(dest)
+    } else {
+        None
+    }
+}
+
+/// Returns the contents of the string literal, with escapes resolved for non-raw strings.
+fn unescaped(fmtstr: &StrLit) -> String {
+    let contents = &fmtstr.symbol.as_str();
+    let mut unescaped = String::with_capacity(contents.len());
+    let mut cb = |_: Range<usize>, c: Result<char, EscapeError>| {
+        if let Ok(c) = c {
+            unescaped.push(c);
+        }
+    };
+
+    match fmtstr.style {
+        StrStyle::Cooked => unescape::unescape_literal(contents, unescape::Mode::Str, &mut cb),
+        StrStyle::Raw(_) => unescape::unescape_literal(contents, unescape::Mode::RawStr, &mut cb),
+    }
+
+    unescaped
+}
@@ -146,7 +146,7 @@ Available lint options:
 
     let print_lints = |lints: &[&Lint]| {
         for lint in lints {
-            let name = lint.name.replace("_", "-");
+            let name = lint.name.replace('_', "-");
             println!(
                 "    {}  {:7.7}  {}",
                 padded(&scoped(&name)),
@@ -182,12 +182,12 @@ Available lint options:
 
     let print_lint_groups = || {
         for group in lint_groups {
-            let name = group.to_lowercase().replace("_", "-");
+            let name = group.to_lowercase().replace('_', "-");
             let desc = lints
                 .iter()
                 .filter(|&lint| lint.group == group)
                 .map(|lint| lint.name)
-                .map(|name| name.replace("_", "-"))
+                .map(|name| name.replace('_', "-"))
                 .collect::<Vec<String>>()
                 .join(", ");
             println!("    {}  {}", padded_group(&scoped(&name)), desc);
@@ -150,6 +150,27 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "cargo_common_metadata",
     },
+    Lint {
+        name: "cast_away_constness",
+        group: "restriction",
+        desc: "casts of shared references or `*const T` pointers to `*mut T` pointers",
+        deprecation: None,
+        module: "types",
+    },
+    Lint {
+        name: "cast_char_truncation",
+        group: "pedantic",
+        desc: "casts truncating a `char`, or an integer cast through `u8` to `char`",
+        deprecation: None,
+        module: "types",
+    },
+    Lint {
+        name: "cast_enum_truncation",
+        group: "pedantic",
+        desc: "casts of an enum to an integer type too small for some of its discriminants",
+        deprecation: None,
+        module: "types",
+    },
     Lint {
         name: "cast_lossless",
         group: "pedantic",
@@ -227,6 +248,20 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "checked_conversions",
     },
+    Lint {
+        name: "clone_before_drop_explicit",
+        group: "perf",
+        desc: "cloning a value into a binding which is explicitly dropped without being used",
+        deprecation: None,
+        module: "clone_before_drop_explicit",
+    },
+    Lint {
+        name: "clone_before_serialize",
+        group: "pedantic",
+        desc: "passing a reference to a fresh clone, like `to_string(&x.clone())`, instead of borrowing the original",
+        deprecation: None,
+        module: "clone_before_serialize",
+    },
     Lint {
         name: "clone_double_ref",
         group: "correctness",
@@ -234,6 +269,27 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "methods",
     },
+    Lint {
+        name: "clone_in_debug_assert",
+        group: "pedantic",
+        desc: "cloning an argument of `debug_assert_eq!`, `debug_assert_ne!` or of a comparison in `debug_assert!`",
+        deprecation: None,
+        module: "clone_in_debug_assert",
+    },
+    Lint {
+        name: "clone_inside_unwrap_or_else_closure",
+        group: "perf",
+        desc: "`unwrap_or_else` with a closure cloning a local which is dropped afterwards",
+        deprecation: None,
+        module: "redundant_clone",
+    },
+    Lint {
+        name: "clone_on_borrowed_in_closure_capture_by_value",
+        group: "nursery",
+        desc: "cloning a value only to capture it by value in a closure which only reads it",
+        deprecation: None,
+        module: "clone_on_borrowed_in_closure_capture_by_value",
+    },
     Lint {
         name: "clone_on_copy",
         group: "complexity",
@@ -241,6 +297,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "methods",
     },
+    Lint {
+        name: "clone_on_iterator_adapter",
+        group: "pedantic",
+        desc: "using `clone` on a lazy iterator adapter",
+        deprecation: None,
+        module: "methods",
+    },
     Lint {
         name: "clone_on_ref_ptr",
         group: "restriction",
@@ -248,6 +311,34 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "methods",
     },
+    Lint {
+        name: "clone_on_static_ref",
+        group: "pedantic",
+        desc: "calling `to_string()` or `to_owned()` on `&\'static` data which is only borrowed afterwards",
+        deprecation: None,
+        module: "clone_on_static_ref",
+    },
+    Lint {
+        name: "clone_then_drop_same_statement",
+        group: "complexity",
+        desc: "`clone()` whose result is dropped at the end of the same statement",
+        deprecation: None,
+        module: "redundant_clone",
+    },
+    Lint {
+        name: "clone_to_satisfy_fnmut_reuse",
+        group: "nursery",
+        desc: "cloning a captured value on every call of a closure passed to an iterator method",
+        deprecation: None,
+        module: "clone_to_satisfy_fnmut_reuse",
+    },
+    Lint {
+        name: "clone_without_copy",
+        group: "pedantic",
+        desc: "small structs deriving `Clone` but not `Copy`, although all their fields are `Copy`",
+        deprecation: None,
+        module: "derive",
+    },
     Lint {
         name: "cmp_nan",
         group: "correctness",
@@ -367,6 +458,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "reference",
     },
+    Lint {
+        name: "derivable_clone_impl",
+        group: "pedantic",
+        desc: "explicit `Clone` implementations equivalent to `#[derive(Clone)]`",
+        deprecation: None,
+        module: "derive",
+    },
     Lint {
         name: "derive_hash_xor_eq",
         group: "correctness",
@@ -381,6 +479,20 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "derive",
     },
+    Lint {
+        name: "disallowed_methods",
+        group: "nursery",
+        desc: "use of a function or method disallowed in `clippy.toml`",
+        deprecation: None,
+        module: "disallowed_paths",
+    },
+    Lint {
+        name: "disallowed_types",
+        group: "nursery",
+        desc: "use of a type disallowed in `clippy.toml`",
+        deprecation: None,
+        module: "disallowed_paths",
+    },
     Lint {
         name: "diverging_sub_expression",
         group: "complexity",
@@ -608,7 +720,7 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
     Lint {
         name: "fallible_impl_from",
         group: "nursery",
-        desc: "Warn on impls of `From<..>` that contain `panic!()` or `unwrap()`",
+        desc: "Warn on impls of `From<..>` that can panic, e.g. with `panic!()` or `unwrap()`",
         deprecation: None,
         module: "fallible_impl_from",
     },
@@ -661,6 +773,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "arithmetic",
     },
+    Lint {
+        name: "float_bits_hash_xor_eq",
+        group: "pedantic",
+        desc: "`Hash` implementations hashing the bit pattern of a float field which `PartialEq` compares with `==`",
+        deprecation: None,
+        module: "derive",
+    },
     Lint {
         name: "float_cmp",
         group: "correctness",
@@ -682,6 +801,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "float_equality_without_abs",
     },
+    Lint {
+        name: "float_partial_eq_hash_key",
+        group: "pedantic",
+        desc: "types deriving `PartialEq` with float fields used as `HashMap` or `HashSet` keys",
+        deprecation: None,
+        module: "derive",
+    },
     Lint {
         name: "fn_address_comparisons",
         group: "correctness",
@@ -738,6 +864,20 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "drop_forget_ref",
     },
+    Lint {
+        name: "forget_self_in_drop",
+        group: "correctness",
+        desc: "forgetting `self` inside `Drop::drop`, which does not prevent the drop",
+        deprecation: None,
+        module: "drop_impl",
+    },
+    Lint {
+        name: "format_literal",
+        group: "style",
+        desc: "formatting a literal with a format string",
+        deprecation: None,
+        module: "write",
+    },
     Lint {
         name: "future_not_send",
         group: "nursery",
@@ -836,6 +976,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "literal_representation",
     },
+    Lint {
+        name: "inconsistent_size_hint",
+        group: "correctness",
+        desc: "`size_hint` implementations returning a lower bound greater than the upper bound",
+        deprecation: None,
+        module: "iterator_size_hint",
+    },
     Lint {
         name: "indexing_slicing",
         group: "restriction",
@@ -1088,6 +1235,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "types",
     },
+    Lint {
+        name: "lock_held_in_scrutinee",
+        group: "correctness",
+        desc: "locking or borrowing a cell again while the guard from the `match` scrutinee is still held",
+        deprecation: None,
+        module: "lock_held_in_scrutinee",
+    },
     Lint {
         name: "logic_bug",
         group: "correctness",
@@ -1123,6 +1277,20 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "manual_async_fn",
     },
+    Lint {
+        name: "manual_empty_new",
+        group: "style",
+        desc: "creating an empty `String` or `Vec` without using `new()`",
+        deprecation: None,
+        module: "manual_empty_new",
+    },
+    Lint {
+        name: "manual_ignore_case_cmp",
+        group: "perf",
+        desc: "comparing strings converted to the same case instead of using `eq_ignore_ascii_case()`",
+        deprecation: None,
+        module: "strings",
+    },
     Lint {
         name: "manual_memcpy",
         group: "perf",
@@ -1301,10 +1469,24 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
     Lint {
         name: "mem_replace_with_uninit",
         group: "correctness",
-        desc: "`mem::replace(&mut _, mem::uninitialized())` or `mem::replace(&mut _, mem::zeroed())`",
+        desc: "`mem::replace(&mut _, mem::uninitialized())` or `mem::replace(&mut _, MaybeUninit::uninit().assume_init())`",
         deprecation: None,
         module: "mem_replace",
     },
+    Lint {
+        name: "mem_replace_with_zeroed",
+        group: "correctness",
+        desc: "`mem::replace(&mut _, mem::zeroed())` on a type which has no valid all-zero value",
+        deprecation: None,
+        module: "mem_replace",
+    },
+    Lint {
+        name: "mem_uninitialized",
+        group: "correctness",
+        desc: "use of `mem::uninitialized()`, which is undefined behavior for almost all types",
+        deprecation: None,
+        module: "mem_uninitialized",
+    },
     Lint {
         name: "min_max",
         group: "correctness",
@@ -1312,6 +1494,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "minmax",
     },
+    Lint {
+        name: "mismatched_case_cmp",
+        group: "correctness",
+        desc: "comparing a string converted to lower or upper case with a literal in another case",
+        deprecation: None,
+        module: "strings",
+    },
     Lint {
         name: "mismatched_target_os",
         group: "correctness",
@@ -1354,10 +1543,17 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "missing_inline",
     },
+    Lint {
+        name: "missing_iterator_size_hint",
+        group: "nursery",
+        desc: "`Iterator` implementations delegating `next` to a field without forwarding `size_hint`",
+        deprecation: None,
+        module: "iterator_size_hint",
+    },
     Lint {
         name: "missing_safety_doc",
         group: "style",
-        desc: "`pub unsafe fn` without `# Safety` docs",
+        desc: "`pub unsafe fn` or `pub unsafe trait` without `# Safety` docs",
         deprecation: None,
         module: "doc",
     },
@@ -1508,6 +1704,20 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "needless_borrowed_ref",
     },
+    Lint {
+        name: "needless_clone_for_into_param",
+        group: "perf",
+        desc: "`clone()` of an owned value passed to an `Into`-bounded parameter while the original is dropped",
+        deprecation: None,
+        module: "redundant_clone",
+    },
+    Lint {
+        name: "needless_clone_in_trait_default_method",
+        group: "pedantic",
+        desc: "default trait methods returning a clone of something borrowed from `self`",
+        deprecation: None,
+        module: "needless_clone_in_trait_default_method",
+    },
     Lint {
         name: "needless_collect",
         group: "perf",
@@ -1585,6 +1795,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "loops",
     },
+    Lint {
+        name: "new_differs_from_default",
+        group: "pedantic",
+        desc: "`fn new() -> Self` and `Default::default()` setting a field to different values",
+        deprecation: None,
+        module: "new_without_default",
+    },
     Lint {
         name: "new_ret_no_self",
         group: "style",
@@ -1613,6 +1830,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "unicode",
     },
+    Lint {
+        name: "non_canonical_partial_ord",
+        group: "style",
+        desc: "manual `PartialOrd` implementations on `Ord` types not delegating to `Ord::cmp`",
+        deprecation: None,
+        module: "derive",
+    },
     Lint {
         name: "nonminimal_bool",
         group: "complexity",
@@ -1683,6 +1907,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "map_unit_fn",
     },
+    Lint {
+        name: "option_of_result",
+        group: "pedantic",
+        desc: "usage of `Option<Result<T, E>>`",
+        deprecation: None,
+        module: "types",
+    },
     Lint {
         name: "option_option",
         group: "pedantic",
@@ -1711,6 +1942,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "overflow_check_conditional",
     },
+    Lint {
+        name: "oversized_unsafe_block",
+        group: "pedantic",
+        desc: "`unsafe` blocks where only a single statement needs `unsafe`",
+        deprecation: None,
+        module: "unnecessary_unsafe",
+    },
     Lint {
         name: "panic",
         group: "restriction",
@@ -1718,6 +1956,20 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "panic_unimplemented",
     },
+    Lint {
+        name: "panic_in_drop",
+        group: "pedantic",
+        desc: "code that can panic inside `Drop::drop`",
+        deprecation: None,
+        module: "drop_impl",
+    },
+    Lint {
+        name: "panic_in_result_fn",
+        group: "restriction",
+        desc: "functions returning `Result` which contain `panic!()`, `todo!()`, `unwrap()` or assertions",
+        deprecation: None,
+        module: "panic_in_result_fn",
+    },
     Lint {
         name: "panic_params",
         group: "style",
@@ -1732,6 +1984,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "unwrap",
     },
+    Lint {
+        name: "partial_eq_ignores_hashed_fields",
+        group: "correctness",
+        desc: "manual `PartialEq` implementations not comparing all fields hashed by a derived `Hash`",
+        deprecation: None,
+        module: "derive",
+    },
     Lint {
         name: "partialeq_ne_impl",
         group: "complexity",
@@ -1767,6 +2026,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "precedence",
     },
+    Lint {
+        name: "print_followed_by_newline",
+        group: "style",
+        desc: "using `print!()` followed by a separate call printing only a newline",
+        deprecation: None,
+        module: "write",
+    },
     Lint {
         name: "print_literal",
         group: "style",
@@ -1798,7 +2064,7 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
     Lint {
         name: "ptr_arg",
         group: "style",
-        desc: "fn arguments of the type `&Vec<...>` or `&String`, suggesting to use `&[...]` or `&str` instead, respectively",
+        desc: "fn arguments of the type `&Vec<...>`, `&String`, `&PathBuf`, `&OsString` or `&Cow<T>`, suggesting to use `&[...]`, `&str`, `&Path`, `&OsStr` or `&T` instead, respectively",
         deprecation: None,
         module: "ptr",
     },
@@ -1844,6 +2110,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "ranges",
     },
+    Lint {
+        name: "recursive_trait_impl",
+        group: "correctness",
+        desc: "trait implementations which call themselves again, like `Display` formatting `self` with `{}`",
+        deprecation: None,
+        module: "recursive_trait_impl",
+    },
     Lint {
         name: "redundant_allocation",
         group: "perf",
@@ -1858,6 +2131,69 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "redundant_clone",
     },
+    Lint {
+        name: "redundant_clone_across_channel_send",
+        group: "perf",
+        desc: "`clone()` of an owned value sent through a channel while the original is dropped",
+        deprecation: None,
+        module: "redundant_clone",
+    },
+    Lint {
+        name: "redundant_clone_for_trait_object_coercion",
+        group: "perf",
+        desc: "`clone()` of an owned value coerced to a trait object while the original is dropped",
+        deprecation: None,
+        module: "redundant_clone",
+    },
+    Lint {
+        name: "redundant_clone_in_builder_with_method",
+        group: "perf",
+        desc: "`clone()` of an owned value passed to a `with_*` or `set_*` builder method while the original is dropped",
+        deprecation: None,
+        module: "redundant_clone",
+    },
+    Lint {
+        name: "redundant_clone_in_enum_variant_payload",
+        group: "perf",
+        desc: "`clone()` of an owned value moved into an enum variant while the original is dropped",
+        deprecation: None,
+        module: "redundant_clone",
+    },
+    Lint {
+        name: "redundant_clone_in_map_entry",
+        group: "perf",
+        desc: "`clone()` of an owned value passed to `Entry::or_insert` while the original is dropped",
+        deprecation: None,
+        module: "redundant_clone",
+    },
+    Lint {
+        name: "redundant_clone_in_recursive_call",
+        group: "perf",
+        desc: "`clone()` of an owned value passed to a recursive call while the original is dropped",
+        deprecation: None,
+        module: "redundant_clone",
+    },
+    Lint {
+        name: "redundant_clone_in_sort_dedup",
+        group: "perf",
+        desc: "`clone()` of an owned value which is sorted or deduplicated while the original is dropped",
+        deprecation: None,
+        module: "redundant_clone",
+    },
+    Lint {
+        name: "redundant_clone_into_owned_collection_from_iter",
+        group: "complexity",
+        desc: "collecting the clones of the elements of a collection into a collection of the same type",
+        deprecation: None,
+        module: "redundant_clone",
+    },
+    Lint {
+        name: "redundant_clone_of_literal",
+        group: "perf",
+        desc: "`clone()` of a freshly created temporary value",
+        deprecation: None,
+        module: "redundant_clone",
+    },
     Lint {
         name: "redundant_closure",
         group: "style",
@@ -1907,6 +2243,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "redundant_pub_crate",
     },
+    Lint {
+        name: "redundant_slicing",
+        group: "complexity",
+        desc: "slicing the whole range of a string or slice, or reborrowing a `&String` where a `&str` is expected",
+        deprecation: None,
+        module: "strings",
+    },
     Lint {
         name: "redundant_static_lifetimes",
         group: "style",
@@ -1935,6 +2278,20 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "matches",
     },
+    Lint {
+        name: "restarted_iter_in_loop",
+        group: "complexity",
+        desc: "taking the first element of a new iterator on every iteration of a loop",
+        deprecation: None,
+        module: "loops",
+    },
+    Lint {
+        name: "restarted_iter_in_loop_condition",
+        group: "correctness",
+        desc: "a loop condition taking the first element of a new iterator on every iteration",
+        deprecation: None,
+        module: "loops",
+    },
     Lint {
         name: "result_map_or_into_option",
         group: "style",
@@ -1949,6 +2306,20 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "map_unit_fn",
     },
+    Lint {
+        name: "result_opaque_err",
+        group: "restriction",
+        desc: "public library functions returning `Result<_, Box<dyn Error>>` or `Result<_, String>`",
+        deprecation: None,
+        module: "types",
+    },
+    Lint {
+        name: "result_unit_err",
+        group: "pedantic",
+        desc: "functions returning `Result<_, ()>`",
+        deprecation: None,
+        module: "types",
+    },
     Lint {
         name: "reversed_empty_ranges",
         group: "correctness",
@@ -2012,6 +2383,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "shadow",
     },
+    Lint {
+        name: "shift_overflow",
+        group: "correctness",
+        desc: "shifting an integer by at least its bit width",
+        deprecation: None,
+        module: "shift_overflow",
+    },
     Lint {
         name: "short_circuit_statement",
         group: "complexity",
@@ -2026,6 +2404,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "methods",
     },
+    Lint {
+        name: "signed_modulo_index",
+        group: "pedantic",
+        desc: "the remainder of a signed integer cast to `usize`",
+        deprecation: None,
+        module: "modulo_arithmetic",
+    },
     Lint {
         name: "similar_names",
         group: "pedantic",
@@ -2033,6 +2418,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "non_expressive_names",
     },
+    Lint {
+        name: "single_char_add_str",
+        group: "pedantic",
+        desc: "`+=` used to append a single-character string literal to a `String`",
+        deprecation: None,
+        module: "methods",
+    },
     Lint {
         name: "single_char_pattern",
         group: "perf",
@@ -2089,6 +2481,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "stable_sort_primitive",
     },
+    Lint {
+        name: "static_mut",
+        group: "restriction",
+        desc: "declarations and uses of `static mut` items",
+        deprecation: None,
+        module: "static_mut",
+    },
     Lint {
         name: "string_add",
         group: "restriction",
@@ -2131,6 +2530,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "floating_point_arithmetic",
     },
+    Lint {
+        name: "suspicious_address_comparisons",
+        group: "style",
+        desc: "comparison of references cast to raw pointers, of function pointers, or of slice addresses",
+        deprecation: None,
+        module: "unnamed_address",
+    },
     Lint {
         name: "suspicious_arithmetic_impl",
         group: "correctness",
@@ -2243,6 +2649,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "trait_bounds",
     },
+    Lint {
+        name: "transmute_bytes_to_num",
+        group: "complexity",
+        desc: "transmutes from a byte array to a number",
+        deprecation: None,
+        module: "transmute",
+    },
     Lint {
         name: "transmute_bytes_to_str",
         group: "complexity",
@@ -2341,6 +2754,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "trait_bounds",
     },
+    Lint {
+        name: "unchecked_shift",
+        group: "pedantic",
+        desc: "shifting an integer by a function parameter without checking it against the bit width",
+        deprecation: None,
+        module: "shift_overflow",
+    },
     Lint {
         name: "unicode_not_nfc",
         group: "pedantic",
@@ -2397,6 +2817,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "types",
     },
+    Lint {
+        name: "unnecessary_clone_for_pattern_binding",
+        group: "perf",
+        desc: "`clone()` of an owned value which is immediately destructured while the original is dropped",
+        deprecation: None,
+        module: "redundant_clone",
+    },
     Lint {
         name: "unnecessary_filter_map",
         group: "complexity",
@@ -2432,6 +2859,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "no_effect",
     },
+    Lint {
+        name: "unnecessary_safety_doc",
+        group: "restriction",
+        desc: "`pub fn` or `pub trait` with `# Safety` docs",
+        deprecation: None,
+        module: "doc",
+    },
     Lint {
         name: "unnecessary_sort_by",
         group: "complexity",
@@ -2439,6 +2873,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "unnecessary_sort_by",
     },
+    Lint {
+        name: "unnecessary_unsafe",
+        group: "complexity",
+        desc: "`unsafe` blocks that contain no `unsafe` operations",
+        deprecation: None,
+        module: "unnecessary_unsafe",
+    },
     Lint {
         name: "unnecessary_unwrap",
         group: "complexity",
@@ -2495,6 +2936,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "unsafe_removed_from_name",
     },
+    Lint {
+        name: "unsafe_sync_static",
+        group: "pedantic",
+        desc: "statics of types with `Cell` or `RefCell` fields which implement `Sync` through an `unsafe impl`",
+        deprecation: None,
+        module: "static_mut",
+    },
     Lint {
         name: "unseparated_literal_suffix",
         group: "pedantic",
@@ -2502,6 +2950,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "misc_early",
     },
+    Lint {
+        name: "unsigned_sub_underflow",
+        group: "pedantic",
+        desc: "unchecked subtraction of unsigned integers used for indexing or length computations",
+        deprecation: None,
+        module: "unsigned_sub_underflow",
+    },
     Lint {
         name: "unsound_collection_transmute",
         group: "correctness",
@@ -2509,6 +2964,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "transmute",
     },
+    Lint {
+        name: "unused_combinator_result",
+        group: "pedantic",
+        desc: "the result of an `Option` or `Result` combinator used as a statement is ignored",
+        deprecation: None,
+        module: "unused_combinator",
+    },
     Lint {
         name: "unused_io_amount",
         group: "correctness",
@@ -2516,6 +2978,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "unused_io_amount",
     },
+    Lint {
+        name: "unused_lazy_value",
+        group: "correctness",
+        desc: "a lazy iterator adapter or `format!` used as a statement, which does nothing",
+        deprecation: None,
+        module: "unused_combinator",
+    },
     Lint {
         name: "unused_self",
         group: "pedantic",
@@ -2616,7 +3085,7 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
     },
     Lint {
         name: "vec_box",
-        group: "complexity",
+        group: "pedantic",
         desc: "usage of `Vec<Box<T>>` where T: Sized, vector elements are already on the heap",
         deprecation: None,
         module: "types",
@@ -148,6 +148,45 @@ fn run_ui_toml(config: &mut compiletest::Config) {
     }
 }
 
+fn run_ui_profile(config: &mut compiletest::Config) {
+    const PROFILE_VAR: &str = "CLIPPY_PROFILE_REDUNDANT_CLONE";
+
+    // The profile is appended to, so start from an empty one
+    let profile_path = config.build_base.join("redundant_clone_profile.txt");
+    let _ = fs::remove_file(&profile_path);
+
+    config.mode = TestMode::Ui;
+    config.src_base = Path::new("tests").join("ui-profile");
+    set_var(PROFILE_VAR, &profile_path);
+    compiletest::run_tests(&config);
+    env::remove_var(PROFILE_VAR);
+
+    let profile = match fs::read_to_string(&profile_path) {
+        Ok(profile) => profile,
+        // The test was filtered out
+        Err(_) if config.filter.is_some() => return,
+        Err(e) => panic!("failed to read `{}`: {}", profile_path.display(), e),
+    };
+    // One summary for the test and one for its `.fixed` file, each followed by one line per body
+    let summaries: Vec<_> = profile.lines().filter(|line| !line.starts_with(' ')).collect();
+    assert_eq!(summaries.len(), 2, "unexpected profile:\n{}", profile);
+    assert!(
+        summaries[0].starts_with("redundant_clone profile of `redundant_clone_profile`: 1 bodies, 3 candidates, "),
+        "unexpected profile:\n{}",
+        profile
+    );
+    assert!(
+        summaries[1].starts_with("redundant_clone profile of `redundant_clone_profile`: 1 bodies, 0 candidates, "),
+        "unexpected profile:\n{}",
+        profile
+    );
+    let bodies = profile
+        .lines()
+        .filter(|line| line.ends_with(" candidates  main"))
+        .count();
+    assert_eq!(bodies, 2, "unexpected profile:\n{}", profile);
+}
+
 fn run_ui_cargo(config: &mut compiletest::Config) {
     fn run_tests(
         config: &compiletest::Config,
@@ -250,6 +289,7 @@ fn compile_test() {
     prepare_env();
     let mut config = default_config();
     run_mode(&mut config);
+    run_ui_profile(&mut config);
     run_ui_toml(&mut config);
     run_ui_cargo(&mut config);
 }
@@ -0,0 +1,54 @@
+//! Checks that lint diagnostics carry the name of their lint as diagnostic code, which tools
+//! consuming `--message-format=json` rely on to filter them.
+
+// Running `cargo-clippy` from the tests does not work on Windows, see `dogfood.rs`
+#![cfg(not(windows))]
+
+use cargo_metadata::Message;
+use lazy_static::lazy_static;
+use std::path::PathBuf;
+use std::process::Command;
+
+mod cargo;
+
+lazy_static! {
+    static ref CLIPPY_PATH: PathBuf = cargo::TARGET_LIB.join("cargo-clippy");
+}
+
+#[test]
+fn redundant_clone_code() {
+    if cargo::is_rustc_test_suite() {
+        return;
+    }
+    let root_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+
+    let output = Command::new(&*CLIPPY_PATH)
+        .current_dir(root_dir.join("tests/message_format/redundant_clone"))
+        .env("CARGO_INCREMENTAL", "0")
+        .env("CARGO_TARGET_DIR", cargo::CARGO_TARGET_DIR.join("message_format"))
+        .arg("clippy")
+        .arg("--message-format=json")
+        .arg("--")
+        .args(&["-W", "clippy::redundant_clone"])
+        .output()
+        .unwrap();
+    println!("status: {}", output.status);
+    println!("stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert!(output.status.success());
+
+    let codes: Vec<_> = Message::parse_stream(&output.stdout[..])
+        .filter_map(|message| match message.unwrap() {
+            Message::CompilerMessage(message) if message.message.message == "redundant clone" => {
+                Some(message.message.code.map(|code| code.code))
+            },
+            _ => None,
+        })
+        .collect();
+    println!("codes: {:?}", codes);
+
+    // One for each `clone` call in the fixture
+    assert_eq!(codes.len(), 3);
+    for code in codes {
+        assert_eq!(code.as_deref(), Some("clippy::redundant_clone"));
+    }
+}
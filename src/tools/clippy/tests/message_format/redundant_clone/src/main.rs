@@ -0,0 +1,16 @@
+fn take(_: String) {}
+
+fn main() {
+    // the receiver of a method call
+    let a = String::from("a");
+    take(a.clone());
+
+    // the argument of a function call
+    let b = String::from("b");
+    take(Clone::clone(&b));
+
+    // a clone which is neither consumed nor mutated
+    let c = String::from("c");
+    let _ = c.clone().len();
+    take(c);
+}
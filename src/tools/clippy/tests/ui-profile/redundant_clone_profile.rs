@@ -0,0 +1,15 @@
+// run-rustfix
+
+// The instrumentation of the analysis must not change its diagnostics.
+
+use std::path::Path;
+
+fn main() {
+    let s = String::from("foo");
+    let _s = s.clone();
+
+    let s = String::from("foo");
+    let _s = s.to_owned();
+
+    let _s = Path::new("/a/b/").join("c").to_path_buf();
+}
@@ -0,0 +1,46 @@
+// compile-flags: --test
+
+#![warn(clippy::blacklisted_name, clippy::just_underscores_and_digits, clippy::wildcard_imports)]
+#![allow(dead_code, unused_variables)]
+
+mod exports {
+    pub fn exported() {}
+}
+
+mod nested {
+    pub mod inner {
+        pub fn inner() {}
+    }
+}
+
+mod other {
+    pub fn other() {}
+}
+
+use crate::exports::*;
+use crate::nested::inner::*;
+use crate::other::*;
+
+fn helper() {}
+
+fn main() {
+    let foo = 1;
+    let tmp = 2;
+    let tmp_value = 3;
+    let _1 = 4;
+    let _2 = 5;
+    exported();
+    inner();
+    other();
+}
+
+// `use super::*` is allowed in `#[cfg(test)]` modules, whatever their name.
+#[cfg(test)]
+mod checks {
+    use super::*;
+
+    #[test]
+    fn check() {
+        helper();
+    }
+}
@@ -0,0 +1,11 @@
+#![warn(clippy::unreadable_literal, clippy::large_digit_groups)]
+
+fn main() {
+    let _good = (
+        0b1010_1010_1111_0000_u16,
+        0b10101010_11110000_u16,
+        0b10101010_u8,
+        0xAB_CD_EF_u32,
+    );
+    let _bad = (0b101010101_u16, 0b1_101010101_u16, 0xABCDEF_u32);
+}
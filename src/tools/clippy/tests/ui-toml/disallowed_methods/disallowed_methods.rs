@@ -0,0 +1,29 @@
+#![warn(clippy::disallowed_methods)]
+
+use std::io::{self, Write};
+
+struct Sink;
+
+impl Write for Sink {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn main() {
+    let _ = std::env::var("HOME");
+    let _ = Sink.write(b"data");
+    let _ = Vec::new().write(b"data");
+    let _ = Write::write(&mut Sink, b"data");
+    let _ = String::from_utf8_lossy(b"data");
+    let _: u32 = [1, 2, 3].iter().sum();
+
+    // OK, not disallowed
+    let _ = Sink.flush();
+    let _ = std::env::args();
+    let _: u32 = [1, 2, 3].iter().product();
+}
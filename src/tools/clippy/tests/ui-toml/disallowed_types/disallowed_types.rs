@@ -0,0 +1,21 @@
+#![warn(clippy::disallowed_types)]
+#![allow(dead_code)]
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+
+struct Cache {
+    entries: HashMap<String, u32>,
+}
+
+fn lock(value: &Mutex<u32>) -> u32 {
+    *value.lock().unwrap()
+}
+
+fn main() {
+    let _map: HashMap<u32, u32> = HashMap::new();
+    let _ = [(1, 2)].iter().copied().collect::<HashMap<u32, u32>>();
+
+    // OK, not disallowed
+    let _ = BTreeMap::<u32, u32>::new();
+}
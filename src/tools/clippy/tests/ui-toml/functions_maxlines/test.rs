@@ -41,5 +41,36 @@ fn comment_before_code() {
     the code but this line should still count. */ let _ = 5;
 }
 
+// This should fail, the `/*` is inside a string so the next line is code.
+fn comment_start_in_string() {
+    let _ = "/*";
+    let _ = 5;
+}
+
+// This should fail since the raw string spans two lines of code.
+#[rustfmt::skip]
+fn line_comment_in_raw_string() {
+    let _ = r#"first line
+// is not a comment"#;
+}
+
+// This should be considered one line.
+fn comment_markers_in_string_then_comment() {
+    let _ = "*/ // */"; /* a real
+    comment */
+}
+
+macro_rules! define_long_function {
+    () => {
+        // Functions defined by macros are not linted.
+        fn long_function_from_macro() {
+            println!("This is fine.");
+            println!("This is fine.");
+        }
+    };
+}
+
+define_long_function!();
+
 // This should be considered one line.
 fn main() {}
@@ -0,0 +1,56 @@
+#![warn(clippy::mutable_key_type)]
+
+use std::cell::Cell;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+
+/// Counts how often it is hashed, neither `Hash` nor `Eq` depend on the counter
+struct Counted {
+    id: u32,
+    hashed: Cell<usize>,
+}
+
+impl PartialEq for Counted {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Counted {}
+
+impl Hash for Counted {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.hashed.set(self.hashed.get() + 1);
+        self.id.hash(h);
+    }
+}
+
+struct Uncounted(Cell<u32>);
+
+impl PartialEq for Uncounted {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.get() == other.0.get()
+    }
+}
+
+impl Eq for Uncounted {}
+
+impl Hash for Uncounted {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.0.get().hash(h);
+    }
+}
+
+struct Sets {
+    counted: HashSet<Counted>,
+    nested: HashSet<(Counted, u32)>,
+    uncounted: HashSet<Uncounted>,
+}
+
+fn main() {
+    let _ = Sets {
+        counted: HashSet::new(),
+        nested: HashSet::new(),
+        uncounted: HashSet::new(),
+    };
+}
@@ -0,0 +1,30 @@
+#![warn(
+    clippy::checked_conversions,
+    clippy::map_clone,
+    clippy::option_as_ref_deref,
+    clippy::range_plus_one
+)]
+#![allow(clippy::iter_cloned_collect)]
+
+fn checked_conversion(value: i64) -> bool {
+    value <= (u32::MAX as i64) && value >= 0
+}
+
+fn map_clone(values: &[u8]) -> Vec<u8> {
+    values.iter().map(|x| *x).collect()
+}
+
+fn option_as_ref_deref(name: Option<String>) -> Option<usize> {
+    name.as_ref().map(String::as_str).map(str::len)
+}
+
+fn range_plus_one(end: usize) -> usize {
+    (0..end + 1).sum()
+}
+
+fn main() {
+    checked_conversion(0);
+    map_clone(&[]);
+    option_as_ref_deref(None);
+    range_plus_one(0);
+}
@@ -0,0 +1,10 @@
+#![warn(clippy::panic_in_result_fn)]
+
+fn sum(values: &[&str]) -> Result<u32, String> {
+    let parsed: Vec<u32> = values.iter().map(|v| v.parse().unwrap()).collect();
+    Ok(parsed.iter().sum())
+}
+
+fn main() {
+    let _ = sum(&["1", "2"]);
+}
@@ -0,0 +1,28 @@
+#![warn(clippy::redundant_clone)]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static REGISTERED: AtomicUsize = AtomicUsize::new(0);
+
+// Every clone registers itself, so removing one changes behavior
+struct Registration;
+
+impl Clone for Registration {
+    fn clone(&self) -> Self {
+        REGISTERED.fetch_add(1, Ordering::SeqCst);
+        Registration
+    }
+}
+
+#[derive(Clone)]
+struct Plain;
+
+fn take<T>(_: T) {}
+
+fn main() {
+    let r = Registration;
+    take(r.clone());
+
+    let p = Plain;
+    take(p.clone());
+}
@@ -0,0 +1,41 @@
+// compile-flags: --test
+
+#![warn(clippy::redundant_clone)]
+#![allow(dead_code)]
+
+fn take<T>(_: T) {}
+
+fn not_a_test() {
+    let s = String::new();
+    take(s.clone());
+}
+
+#[test]
+fn test_fn() {
+    let s = String::new();
+    take(s.clone());
+
+    let f = || {
+        let s = String::new();
+        take(s.clone());
+    };
+    f();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::take;
+
+    fn helper() {
+        let s = String::new();
+        take(s.clone());
+    }
+
+    #[test]
+    fn it_works() {
+        let s = String::new();
+        take(s.clone());
+    }
+}
+
+fn main() {}
@@ -1,3 +1,5 @@
+#![warn(clippy::vec_box)]
+
 struct S {
     x: u64,
 }
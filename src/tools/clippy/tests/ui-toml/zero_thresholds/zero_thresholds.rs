@@ -0,0 +1,3 @@
+// error-pattern: must be greater than zero
+
+fn main() {}
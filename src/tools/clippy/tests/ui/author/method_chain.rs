@@ -0,0 +1,5 @@
+fn main() {
+    let v = vec![1, 2, 3];
+    #[clippy::author]
+    let _: Vec<i32> = v.iter().map(|&x| x * 2).collect();
+}
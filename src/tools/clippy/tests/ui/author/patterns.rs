@@ -0,0 +1,11 @@
+#[clippy::author]
+fn classify(pair: (u8, Option<u8>)) -> u8 {
+    match pair {
+        (0..=9, Some(n)) | (n, None) => n,
+        _ => 0,
+    }
+}
+
+fn main() {
+    let _ = classify((1, Some(2)));
+}
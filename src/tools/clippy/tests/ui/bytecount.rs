@@ -1,3 +1,5 @@
+#![allow(clippy::redundant_slicing)]
+
 #[deny(clippy::naive_bytecount)]
 fn main() {
     let x = vec![0_u8; 16];
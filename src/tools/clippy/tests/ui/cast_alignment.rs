@@ -11,6 +11,8 @@ fn main() {
     // cast to more-strictly-aligned type
     (&1u8 as *const u8) as *const u16;
     (&mut 1u8 as *mut u8) as *mut u16;
+    // through a chain of casts
+    &1u32 as *const u32 as *const u8 as *const u64;
 
     /* These should be ok */
 
@@ -24,4 +26,13 @@ fn main() {
     (&1u32 as *const u32 as *const libc::c_void) as *const u32;
     // For ZST, we should trust the user. See #4256
     (&1u32 as *const u32 as *const ()) as *const u32;
+    // Byte-wise inspection, the alignment of `u8` is always fine
+    (&1u64 as *const u64) as *const u8;
+    // The alignment of unsized pointees is not known
+    (&[1u8][..] as *const [u8]) as *const [u16];
+    // The alignment of generic pointees is not known
+    fn generic<T>(p: *const u8) -> *const T {
+        p as *const T
+    }
+    generic::<u64>(&1u8);
 }
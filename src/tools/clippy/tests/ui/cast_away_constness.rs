@@ -0,0 +1,26 @@
+#![warn(clippy::cast_away_constness)]
+#![allow(clippy::cast_ref_to_mut)]
+
+use std::cell::UnsafeCell;
+
+fn main() {
+    let x = 42;
+    let _ = &x as *const i32 as *mut i32;
+    let _ = &x as *const i32 as *const u32 as *mut u32;
+    let _ = (&x as *const i32 as *mut i32) as *mut u32;
+
+    let p: *const i32 = &x;
+    let _ = p as *mut i32;
+
+    // OK, derived from a mutable reference
+    let mut y = 42;
+    let _ = &mut y as *mut i32;
+    let _ = &mut y as *mut i32 as *const i32 as *mut i32;
+
+    // OK, interior mutability
+    let cell = UnsafeCell::new(42);
+    let _ = &cell as *const UnsafeCell<i32> as *mut i32;
+
+    // OK, the result is not a mutable pointer
+    let _ = &x as *const i32 as *const u32;
+}
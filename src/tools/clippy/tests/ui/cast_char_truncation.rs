@@ -0,0 +1,58 @@
+#![warn(clippy::cast_char_truncation)]
+#![allow(dead_code, clippy::char_lit_as_u8)]
+
+const ASCII: char = 'a';
+const EURO: char = '€';
+
+trait Code {
+    const CODE: u32;
+}
+
+fn from_char(c: char) {
+    // Truncating
+    let _ = c as u8;
+    let _ = c as i8;
+    let _ = c as u16;
+    let _ = EURO as u8;
+    let _ = '\u{1F600}' as u16;
+
+    // OK, the value fits
+    let _ = ASCII as u8;
+    let _ = 'é' as u16;
+    let _ = '€' as u16;
+    let _ = c as u32;
+    let _ = c as i32;
+    let _ = c as u64;
+
+    // OK, checked to be ASCII
+    if c.is_ascii() {
+        let _ = c as u8;
+    }
+    if c != '0' && c.is_ascii_digit() {
+        let _ = c as i8;
+    }
+}
+
+fn to_char(n: u32, m: u16, k: i64) {
+    // Truncating
+    let _ = n as u8 as char;
+    let _ = m as u8 as char;
+    let _ = k as u8 as char;
+    let _ = 0x20ACu32 as u8 as char;
+    let _ = (n as u8) as char;
+
+    // OK, the value fits or nothing is truncated
+    let _ = 0x41_i64 as u8 as char;
+    let _ = 0xFFu32 as u8 as char;
+    let b = n as u8;
+    let _ = b as char;
+}
+
+// Generic values cannot be evaluated, this must not ICE
+fn generic<T: Code, U: Into<u32>>(u: U) {
+    let _ = T::CODE as u8 as char;
+    let n: u32 = u.into();
+    let _ = n as u8 as char;
+}
+
+fn main() {}
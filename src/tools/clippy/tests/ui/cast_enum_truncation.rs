@@ -0,0 +1,34 @@
+#![warn(clippy::cast_enum_truncation)]
+#![allow(dead_code)]
+
+enum Small {
+    A,
+    B = 255,
+}
+
+enum Large {
+    A,
+    B = 256,
+}
+
+#[repr(i16)]
+enum Negative {
+    A = -1,
+    B,
+}
+
+#[repr(u64)]
+enum Huge {
+    A = 1 << 40,
+}
+
+fn main() {
+    let _ = Small::B as u8;
+    let _ = Small::B as i8;
+    let _ = Large::A as u8;
+    let _ = Large::A as u16;
+    let _ = Negative::B as i8;
+    let _ = Negative::B as u32;
+    let _ = Huge::A as u64;
+    let _ = Huge::A as u32;
+}
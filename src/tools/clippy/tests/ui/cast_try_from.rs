@@ -0,0 +1,32 @@
+#![warn(clippy::cast_possible_truncation, clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+#![allow(dead_code)]
+
+const MAX: u64 = 1_000;
+// `try_from` cannot be called in constants
+const MAX_BYTE: u8 = MAX as u8;
+// the rounding methods cannot be called in constants either
+const HALF: i8 = 0.5f64 as i8;
+
+fn truncating(x: u32, y: u32) -> u8 {
+    (x + y) as u8
+}
+
+fn wrapping(x: u32) -> i32 {
+    x as i32
+}
+
+fn losing_sign(x: i64) -> u64 {
+    x as u64
+}
+
+fn float(x: f64, y: f64) -> i32 {
+    let a = x as i32;
+    let b = (x + y) as i32;
+    // the value is rounded already
+    let c = x.floor() as i32;
+    // `2.5.round()` does not compile
+    let d = 2.5 as i32;
+    a + b + c + d
+}
+
+fn main() {}
@@ -0,0 +1,48 @@
+#![warn(clippy::clone_before_drop_explicit)]
+
+#[derive(Clone)]
+struct Handle(Vec<u8>);
+
+fn consume<T>(_: T) {}
+
+fn main() {
+    // Dropping the clone before it is used
+    let x = String::from("foo");
+    let c = x.clone();
+    drop(c);
+    println!("{}", x);
+
+    let h = Handle(vec![1, 2, 3]);
+    let c = h.clone();
+    let _len = h.0.len();
+    drop(c);
+    consume(h);
+
+    // OK, the original is dropped, so the clone is needed
+    let x = String::from("foo");
+    let c = x.clone();
+    drop(x);
+    consume(c);
+
+    // OK, the clone is used before it is dropped
+    let x = String::from("foo");
+    let c = x.clone();
+    println!("{}", c);
+    drop(c);
+    consume(x);
+
+    // OK, the clone is used in a closure
+    let x = String::from("foo");
+    let c = x.clone();
+    let f = move || c.len();
+    drop(x);
+    f();
+
+    // OK, `Copy` types
+    let n = 42_u32;
+    #[allow(clippy::clone_on_copy)]
+    let m = n.clone();
+    #[allow(clippy::drop_copy)]
+    drop(m);
+    consume(n);
+}
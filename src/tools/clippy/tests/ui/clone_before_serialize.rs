@@ -0,0 +1,61 @@
+// run-rustfix
+#![warn(clippy::clone_before_serialize)]
+#![allow(dead_code, clippy::redundant_clone, clippy::clone_on_copy)]
+
+use std::fmt::Debug;
+
+mod serde_json {
+    pub fn to_string<T: ?Sized + std::fmt::Debug>(value: &T) -> String {
+        format!("{:?}", value)
+    }
+}
+
+struct Writer(Vec<String>);
+
+impl Writer {
+    fn write<T: Debug>(&mut self, value: &T) {
+        self.0.push(format!("{:?}", value));
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Config {
+    name: String,
+    values: Vec<u32>,
+}
+
+fn consume(config: Config) -> Config {
+    config
+}
+
+fn owned(config: Config) -> (String, Config) {
+    let json = serde_json::to_string(&config.clone());
+    (json, config)
+}
+
+fn borrowed(config: &Config) -> String {
+    serde_json::to_string(&config.clone())
+}
+
+fn field(config: &Config, writer: &mut Writer) {
+    writer.write(&config.values.clone());
+    writer.write(&config.name);
+}
+
+// OK, the clone is moved
+fn moved(config: &Config) -> Config {
+    consume(config.clone())
+}
+
+// OK, `Copy` types are linted by `clone_on_copy`
+fn copied(n: u32) -> String {
+    serde_json::to_string(&n.clone())
+}
+
+// OK, the clone is kept in a binding
+fn bound(config: &Config) -> String {
+    let copy = config.clone();
+    serde_json::to_string(&copy)
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+// run-rustfix
+#![warn(clippy::clone_in_debug_assert)]
+#![allow(clippy::redundant_clone)]
+
+fn main() {
+    let names = vec![String::from("a"), String::from("b")];
+    let expected = names.clone();
+    let name = String::from("a");
+
+    debug_assert_eq!(names.clone(), expected);
+    debug_assert_eq!(names, expected.clone());
+    debug_assert_ne!(name.clone(), "b");
+    debug_assert!(name.clone() == "a");
+
+    // OK, `assert_eq!` is not a debug assertion
+    assert_eq!(names.clone(), expected);
+
+    // OK, the clone changes the type
+    let name_ref = &name;
+    debug_assert_eq!(name_ref.clone(), name);
+
+    // OK, not a comparison
+    debug_assert!(!name.clone().is_empty());
+}
@@ -0,0 +1,65 @@
+// run-rustfix
+
+#![warn(clippy::clone_inside_unwrap_or_else_closure)]
+#![allow(dead_code, clippy::clone_on_copy)]
+
+fn option(opt: Option<String>) -> String {
+    let default = String::from("anonymous");
+    opt.unwrap_or_else(|| default.clone())
+}
+
+fn result(res: Result<Vec<u8>, ()>) -> Vec<u8> {
+    let fallback = vec![1, 2, 3];
+    println!("{:?}", fallback);
+    res.unwrap_or_else(|_| fallback.clone())
+}
+
+fn param(opt: Option<String>, default: String) -> usize {
+    let name = opt.unwrap_or_else(|| default.clone());
+    name.len()
+}
+
+// OK, `default` is used afterwards
+fn used_later(opt: Option<String>) -> (String, String) {
+    let default = String::from("anonymous");
+    let name = opt.unwrap_or_else(|| default.clone());
+    (name, default)
+}
+
+// OK, the closure does more than cloning, `unwrap_or` would evaluate it eagerly
+fn computed(opt: Option<String>) -> String {
+    let default = String::from("anonymous");
+    opt.unwrap_or_else(|| default.to_uppercase())
+}
+
+fn expensive() -> String {
+    String::from("anonymous")
+}
+
+// OK, the closure defers an expensive call
+fn deferred(opt: Option<String>) -> String {
+    opt.unwrap_or_else(expensive)
+}
+
+// OK, `default` is borrowed, a clone is needed
+fn borrowed(opt: Option<String>, default: &String) -> String {
+    opt.unwrap_or_else(|| default.clone())
+}
+
+// OK, `default` would be moved in each iteration
+fn in_loop(opts: Vec<Option<String>>) -> Vec<String> {
+    let default = String::from("anonymous");
+    let mut names = Vec::new();
+    for opt in opts {
+        names.push(opt.unwrap_or_else(|| default.clone()));
+    }
+    names
+}
+
+// OK, `Copy` values are not cloned
+fn copy(opt: Option<u32>) -> u32 {
+    let default = 0;
+    opt.unwrap_or_else(|| default.clone())
+}
+
+fn main() {}
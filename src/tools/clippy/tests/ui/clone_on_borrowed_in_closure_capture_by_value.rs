@@ -0,0 +1,33 @@
+#![warn(clippy::clone_on_borrowed_in_closure_capture_by_value)]
+#![allow(clippy::redundant_clone)]
+
+fn main() {
+    let v = vec![1, 2, 3];
+    let prefix = String::from("foo");
+
+    let p = prefix.clone();
+    let _ = v.iter().filter(move |i| p.len() < **i).count();
+
+    let p = prefix.clone();
+    let _: Vec<String> = v.iter().map(move |i| format!("{}{}", p, i)).collect();
+
+    let p = prefix.clone();
+    v.iter().for_each(move |i| println!("{}{}", p, i));
+
+    // OK, the clone is mutated
+    let mut p = prefix.clone();
+    v.iter().for_each(move |i| p.push_str(&i.to_string()));
+
+    // OK, the clone is used after the closure
+    let p = prefix.clone();
+    let _ = v.iter().filter(move |i| p.len() < **i).count();
+    let _ = p;
+
+    // OK, the closure is returned in the iterator
+    let p = prefix.clone();
+    let _ = v.iter().map(move |i| p.len() + i);
+
+    // OK, not a `move` closure
+    let p = prefix.clone();
+    let _ = v.iter().filter(|i| p.len() < **i).count();
+}
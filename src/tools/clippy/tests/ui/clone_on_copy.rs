@@ -3,6 +3,7 @@
 #![allow(
     unused,
     clippy::redundant_clone,
+    clippy::clone_then_drop_same_statement,
     clippy::deref_addrof,
     clippy::no_effect,
     clippy::unnecessary_operation
@@ -0,0 +1,28 @@
+#![warn(clippy::clone_on_iterator_adapter)]
+#![allow(clippy::redundant_clone)]
+
+use std::iter;
+
+fn main() {
+    let v = vec![1, 2, 3];
+
+    let doubled = v.iter().map(|x| x * 2);
+    let _: i32 = doubled.clone().sum();
+    let _ = doubled.max();
+
+    let evens = v.iter().filter(|x| *x % 2 == 0).rev();
+    for _ in evens.clone() {}
+
+    let chained = v.iter().chain(v.iter()).zip(v.iter()).skip(1);
+    let _ = chained.clone().count();
+
+    // OK, not an adapter
+    let it = v.iter();
+    let _ = it.clone().count();
+    let _ = iter::repeat(1).clone();
+    let _ = (0..10).clone();
+
+    // OK, the collected elements
+    let collected: Vec<i32> = v.iter().map(|x| x * 2).collect();
+    let _ = collected.clone();
+}
@@ -0,0 +1,49 @@
+#![warn(clippy::clone_on_static_ref)]
+#![allow(dead_code)]
+
+const GREETING: &str = "hello";
+static NAMES: &[&str] = &["a", "b"];
+
+fn takes_str(_: &str) {}
+
+fn takes_string(_: String) {}
+
+fn main() {
+    let s = "world".to_string();
+    takes_str(&s);
+    println!("{}", s);
+
+    let g = GREETING.to_owned();
+    if g == "hello" {
+        takes_str(g.trim());
+    }
+
+    let names = NAMES.to_owned();
+    let _len = names.len();
+    let _first = names.first();
+
+    let c = "in closure".to_string();
+    let f = || takes_str(&c);
+    f();
+
+    // OK, moved
+    let s = "world".to_string();
+    takes_string(s);
+
+    // OK, mutated
+    let mut s = "world".to_string();
+    s.push('!');
+
+    // OK, a method taking `self`
+    let s = "world".to_string();
+    let _bytes = s.into_bytes();
+
+    // OK, not used
+    let _s = "unused".to_string();
+
+    // OK, not `'static`
+    let local = String::from("local");
+    let r: &str = &local;
+    let s = r.to_string();
+    takes_str(&s);
+}
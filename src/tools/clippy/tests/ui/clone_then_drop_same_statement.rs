@@ -0,0 +1,44 @@
+// run-rustfix
+// rustfix-only-machine-applicable
+#![warn(clippy::clone_then_drop_same_statement)]
+#![allow(clippy::redundant_clone)]
+
+use std::rc::Rc;
+
+struct Config {
+    name: String,
+}
+
+fn make() -> Vec<u8> {
+    vec![1, 2, 3]
+}
+
+fn main() {
+    let v = vec![1, 2, 3];
+    v.clone();
+
+    let s = String::from("a");
+    s.to_owned();
+
+    let config = Config { name: s };
+    config.name.clone();
+
+    let r = &v;
+    r.clone();
+
+    // Removing this also removes the call to `make`
+    make().clone();
+
+    // OK, `Copy`
+    let x = 1;
+    x.clone();
+
+    // OK, the clone is used
+    let _w = v.clone();
+    drop(v.clone());
+
+    let rc = Rc::new(1);
+    let _ = Rc::clone(&rc);
+
+    let _ = (v, config.name, r.len());
+}
@@ -0,0 +1,41 @@
+#![warn(clippy::clone_to_satisfy_fnmut_reuse)]
+
+fn takes_str(_: &str) -> bool {
+    true
+}
+
+fn takes_owned(_: String) -> bool {
+    true
+}
+
+fn main() {
+    let v = vec![1, 2, 3];
+    let names = vec!["foo", "bar"];
+    let prefix = String::from("foo");
+
+    let _: Vec<usize> = v.iter().map(|i| prefix.clone().len() + i).collect();
+    let _ = names.iter().filter(|n| n.starts_with(&prefix.clone())).count();
+    let _: Vec<bool> = v.iter().map(|_| takes_str(&prefix.clone())).collect();
+
+    // OK, the clone is consumed
+    let _: Vec<bool> = v.iter().map(|_| takes_owned(prefix.clone())).collect();
+    let _: Vec<String> = v.iter().map(|_| prefix.clone()).collect();
+
+    // OK, the clone is mutated
+    let _: Vec<String> = names
+        .iter()
+        .map(|n| {
+            let mut p = prefix.clone();
+            p.push_str(n);
+            p
+        })
+        .collect();
+
+    // OK, the captured value is moved into the closure
+    let p = prefix.clone();
+    let _: Vec<usize> = v.iter().map(move |i| p.clone().len() + i).collect();
+
+    // OK, not an upvar
+    let strings = vec![prefix.clone()];
+    let _: Vec<usize> = strings.iter().map(|s| s.clone().len()).collect();
+}
@@ -0,0 +1,38 @@
+#![warn(clippy::clone_without_copy)]
+#![allow(dead_code)]
+
+#[derive(Clone)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Clone, Debug)]
+struct Id(u64);
+
+// the size of generic types is unknown
+#[derive(Clone)]
+struct Pair<T: Copy>(T, T);
+
+#[derive(Clone, Copy)]
+struct Meters(f64);
+
+// `String` is not `Copy`
+#[derive(Clone)]
+struct Name(String);
+
+// larger than `clone-without-copy-size-limit`
+#[derive(Clone)]
+struct Matrix([f64; 4]);
+
+// `Drop` rules out `Copy`
+#[derive(Clone)]
+struct Guard(u8);
+
+impl Drop for Guard {
+    fn drop(&mut self) {}
+}
+
+struct Plain(u8);
+
+fn main() {}
@@ -0,0 +1,130 @@
+#![warn(clippy::derivable_clone_impl)]
+#![allow(dead_code)]
+
+use std::rc::Rc;
+
+struct Tag {
+    name: String,
+    aliases: Vec<String>,
+}
+
+impl Clone for Tag {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            aliases: self.aliases.clone(),
+        }
+    }
+}
+
+struct Wrapper<T>(T, Vec<T>);
+
+impl<T: Clone> Clone for Wrapper<T> {
+    fn clone(&self) -> Self {
+        Wrapper(self.0.clone(), self.1.clone())
+    }
+}
+
+struct Pair<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> Clone for Pair<A, B>
+where
+    A: Clone,
+    B: Clone,
+{
+    fn clone(&self) -> Self {
+        Pair {
+            second: self.second.clone(),
+            first: self.first.clone(),
+        }
+    }
+}
+
+enum Shape {
+    Circle(String),
+    Rect { width: String, height: String },
+    Empty,
+}
+
+impl Clone for Shape {
+    fn clone(&self) -> Self {
+        match self {
+            Shape::Circle(radius) => Shape::Circle(radius.clone()),
+            Shape::Rect { width, height } => Shape::Rect {
+                width: width.clone(),
+                height: height.clone(),
+            },
+            Shape::Empty => Shape::Empty,
+        }
+    }
+}
+
+// the derived implementation would require `T: Clone`
+struct Shared<T>(Rc<T>);
+
+impl<T> Clone for Shared<T> {
+    fn clone(&self) -> Self {
+        Shared(self.0.clone())
+    }
+}
+
+// not every field is cloned
+struct Cache {
+    key: String,
+    entries: Vec<u32>,
+}
+
+impl Clone for Cache {
+    fn clone(&self) -> Self {
+        Self {
+            key: self.key.clone(),
+            entries: Vec::new(),
+        }
+    }
+}
+
+struct Swapped {
+    a: String,
+    b: String,
+}
+
+impl Clone for Swapped {
+    fn clone(&self) -> Self {
+        Self {
+            a: self.b.clone(),
+            b: self.a.clone(),
+        }
+    }
+}
+
+enum Toggle {
+    On(String),
+    Off(String),
+}
+
+impl Clone for Toggle {
+    fn clone(&self) -> Self {
+        match self {
+            Toggle::On(x) => Toggle::Off(x.clone()),
+            Toggle::Off(x) => Toggle::On(x.clone()),
+        }
+    }
+}
+
+// the derived implementation has no `clone_from`
+struct Buffer(Vec<u8>);
+
+impl Clone for Buffer {
+    fn clone(&self) -> Self {
+        Buffer(self.0.clone())
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.0.clone_from(&source.0);
+    }
+}
+
+fn main() {}
@@ -51,4 +51,23 @@ mod use_hash {
     }
 }
 
+mod borrowed {
+    use std::borrow::Borrow;
+
+    #[derive(PartialEq, Eq, Hash)]
+    struct Name(String);
+
+    impl Borrow<str> for Name {
+        fn borrow(&self) -> &str {
+            &self.0
+        }
+    }
+
+    impl PartialEq<str> for Name {
+        fn eq(&self, other: &str) -> bool {
+            self.0.eq_ignore_ascii_case(other)
+        }
+    }
+}
+
 fn main() {}
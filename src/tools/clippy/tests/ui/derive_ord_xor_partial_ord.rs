@@ -1,4 +1,5 @@
 #![warn(clippy::derive_ord_xor_partial_ord)]
+#![allow(clippy::non_canonical_partial_ord)]
 
 use std::cmp::Ordering;
 
@@ -65,4 +66,28 @@ mod use_ord {
     }
 }
 
+mod delegating {
+    use std::cmp::Ordering;
+
+    // OK, delegates to the derived `Ord`
+    #[derive(Ord, PartialEq, Eq)]
+    struct DeriveOrdCanonical;
+
+    impl PartialOrd for DeriveOrdCanonical {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    // OK, delegates to the derived `PartialOrd`
+    #[derive(PartialOrd, PartialEq, Eq)]
+    struct DerivePartialOrdDelegating;
+
+    impl Ord for DerivePartialOrdDelegating {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.partial_cmp(other).unwrap()
+        }
+    }
+}
+
 fn main() {}
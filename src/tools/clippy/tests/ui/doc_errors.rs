@@ -98,6 +98,36 @@ impl Trait1 for Struct1 {
     }
 }
 
+/// This function is not shown in the documentation.
+#[doc(hidden)]
+pub fn hidden_fn_missing_errors_header() -> Result<(), ()> {
+    unimplemented!();
+}
+
+#[doc = "This is not sufficiently documented."]
+pub fn doc_attr_missing_errors_header() -> Result<(), ()> {
+    unimplemented!();
+}
+
+#[doc = "# Errors"]
+#[doc = "A description of the errors goes here."]
+pub fn doc_attr_with_errors_header() -> Result<(), ()> {
+    unimplemented!();
+}
+
+/** This is not sufficiently documented. */
+pub fn block_doc_missing_errors_header() -> Result<(), ()> {
+    unimplemented!();
+}
+
+/**
+ * # Errors
+ * A description of the errors goes here.
+ */
+pub fn block_doc_with_errors_header() -> Result<(), ()> {
+    unimplemented!();
+}
+
 fn main() -> Result<(), ()> {
     Ok(())
 }
@@ -14,4 +14,27 @@ fn insert_if_absent0<K: Eq + Hash, V>(m: &mut HashMap<K, V>, k: K, v: V) {
     }
 }
 
+fn insert_if_absent_stmt<K: Eq + Hash, V>(m: &mut HashMap<K, V>, k: K, v: V) {
+    if !m.contains_key(&k) {
+        m.insert(k, v);
+    };
+}
+
+#[rustfmt::skip]
+fn insert_if_absent_same_line<K: Eq + Hash, V>(m: &mut HashMap<K, V>, k: K, v: V) {
+    foo(); if !m.contains_key(&k) { m.insert(k, v); } foo();
+}
+
+#[rustfmt::skip]
+mod tabs {
+	use std::collections::HashMap;
+	use std::hash::Hash;
+
+	fn insert_if_absent<K: Eq + Hash, V>(m: &mut HashMap<K, V>, k: K, v: V) {
+		if !m.contains_key(&k) {
+			m.insert(k, v);
+		}
+	}
+}
+
 fn main() {}
@@ -85,3 +85,19 @@ fn check_ignore_macro() {
     // checks if the lint ignores macros with `!` operator
     !bool_macro!(1) && !bool_macro!("");
 }
+
+#[warn(clippy::eq_op)]
+#[allow(unused, clippy::no_effect, clippy::unnecessary_operation)]
+fn check_rem_and_side_effects() {
+    let a = 5;
+    a % a;
+
+    // ok, overloaded operators on non-primitive types
+    let s: std::collections::HashSet<i32> = std::collections::HashSet::new();
+    &s - &s;
+    &s | &s;
+
+    // ok, dereferencing raw pointers
+    let p = &a as *const i32;
+    unsafe { *p == *p };
+}
@@ -6,7 +6,8 @@
     clippy::redundant_closure_call,
     clippy::many_single_char_names,
     clippy::needless_pass_by_value,
-    clippy::option_map_unit_fn
+    clippy::option_map_unit_fn,
+    clippy::unused_lazy_value
 )]
 #![warn(
     clippy::redundant_closure,
@@ -0,0 +1,19 @@
+// compile-flags: --test
+#![warn(clippy::exit)]
+
+struct Runner;
+
+impl Runner {
+    fn abort(&self) {
+        std::process::exit(1);
+    }
+}
+
+#[test]
+fn exits_with_success() {
+    std::process::exit(0);
+}
+
+fn main() {
+    Runner.abort();
+}
@@ -1,6 +1,7 @@
 // run-rustfix
 
 #![warn(clippy::expect_fun_call)]
+#![allow(clippy::format_literal)]
 
 /// Checks implementation of the `EXPECT_FUN_CALL` lint
 
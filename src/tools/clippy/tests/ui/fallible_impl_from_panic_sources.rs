@@ -0,0 +1,135 @@
+#![warn(clippy::fallible_impl_from)]
+
+use std::convert::TryInto;
+
+struct Expected(u32);
+
+impl From<Option<u32>> for Expected {
+    fn from(o: Option<u32>) -> Self {
+        Expected(o.expect("a value"))
+    }
+}
+
+struct First(u8);
+
+impl From<Vec<u8>> for First {
+    fn from(v: Vec<u8>) -> Self {
+        First(v[0])
+    }
+}
+
+struct Prefix(String);
+
+impl From<&str> for Prefix {
+    fn from(s: &str) -> Self {
+        Prefix(s[..3].to_string())
+    }
+}
+
+struct Byte(u8);
+
+impl From<u32> for Byte {
+    fn from(n: u32) -> Self {
+        assert!(n <= 255, "out of range");
+        Byte(n as u8)
+    }
+}
+
+struct Even(u32);
+
+impl From<u32> for Even {
+    fn from(n: u32) -> Self {
+        assert_eq!(n % 2, 0);
+        Even(n)
+    }
+}
+
+fn parse_port(s: &str) -> u16 {
+    s.parse().unwrap()
+}
+
+struct Port(u16);
+
+impl From<&str> for Port {
+    fn from(s: &str) -> Self {
+        Port(parse_port(s))
+    }
+}
+
+struct Validator;
+
+impl Validator {
+    fn check(&self, n: i32) -> i32 {
+        if n < 0 {
+            panic!("negative");
+        }
+        n
+    }
+}
+
+struct Positive(i32);
+
+impl From<i32> for Positive {
+    fn from(n: i32) -> Self {
+        Positive(Validator.check(n))
+    }
+}
+
+// OK, the failure is ruled out by a comparison of the same value
+struct Small(u8);
+
+impl From<u32> for Small {
+    fn from(n: u32) -> Self {
+        if n > 255 {
+            Small(u8::MAX)
+        } else {
+            Small(n.try_into().unwrap())
+        }
+    }
+}
+
+// OK, the function returns early for short slices
+struct Second(u8);
+
+impl From<&[u8]> for Second {
+    fn from(bytes: &[u8]) -> Self {
+        if bytes.len() < 2 {
+            return Second(0);
+        }
+        Second(bytes[1])
+    }
+}
+
+// OK, constant index in bounds of an array
+struct Third(u8);
+
+impl From<[u8; 4]> for Third {
+    fn from(a: [u8; 4]) -> Self {
+        Third(a[2])
+    }
+}
+
+// OK, debug assertions are not checked in release builds
+struct Checked(u32);
+
+impl From<u32> for Checked {
+    fn from(n: u32) -> Self {
+        debug_assert!(n > 0);
+        Checked(n)
+    }
+}
+
+// OK, only the functions called directly are checked
+fn outer_port(s: &str) -> u16 {
+    parse_port(s)
+}
+
+struct OuterPort(u16);
+
+impl From<&str> for OuterPort {
+    fn from(s: &str) -> Self {
+        OuterPort(outer_port(s))
+    }
+}
+
+fn main() {}
@@ -0,0 +1,71 @@
+#![warn(clippy::float_bits_hash_xor_eq)]
+#![allow(clippy::derive_hash_xor_eq)]
+
+use std::hash::{Hash, Hasher};
+
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+impl PartialEq for Point {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y.to_bits() == other.y.to_bits()
+    }
+}
+
+impl Hash for Point {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.x.to_bits().hash(state);
+        self.y.to_bits().hash(state);
+    }
+}
+
+struct Pos(f32, f32);
+
+struct Segment {
+    start: Pos,
+    len: u32,
+}
+
+impl PartialEq for Segment {
+    fn eq(&self, other: &Self) -> bool {
+        self.start.0 == other.start.0 && self.start.1 == other.start.1 && self.len == other.len
+    }
+}
+
+impl Hash for Segment {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.start.0.to_bits().hash(state);
+        self.start.1.to_bits().hash(state);
+        self.len.hash(state);
+    }
+}
+
+#[derive(PartialEq)]
+struct Weight(f64);
+
+impl Hash for Weight {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+// compares the bit patterns as well
+struct Exact {
+    value: f64,
+}
+
+impl PartialEq for Exact {
+    fn eq(&self, other: &Self) -> bool {
+        self.value.to_bits() == other.value.to_bits()
+    }
+}
+
+impl Hash for Exact {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.to_bits().hash(state);
+    }
+}
+
+fn main() {}
@@ -67,7 +67,7 @@ fn main() {
 
     let x: f64 = 1.0;
 
-    x == 1.0;
+    x == 1.0; // no error, comparison with one is ok
     x != 0f64; // no error, comparison with zero is ok
 
     twice(x) != twice(ONE as f64);
@@ -116,4 +116,19 @@ fn main() {
     1.23f64.signum() != x64.signum();
     1.23f64.signum() != -(x64.signum());
     1.23f64.signum() != 3.21f64.signum();
+
+    // not exactly representable, as strict as comparing with `0.1`
+    const TENTH: f64 = 0.1;
+    x == TENTH;
+    x == -1.0; // no error, comparison with minus one is ok
+
+    let t1 = (1.5f32, 2);
+    let t2 = (2.5f32, 2);
+    t1 == t2;
+
+    let ys = vec![0.5f32, 1.5];
+    ys.contains(&0.5);
+    ys.contains(&x32);
+    ys.contains(&0.0); // no error, comparison with zero is ok
+    ys.contains(&-1.0); // no error, comparison with minus one is ok
 }
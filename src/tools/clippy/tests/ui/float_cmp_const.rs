@@ -44,8 +44,8 @@ fn main() {
     let w = 1.1;
     v == w;
     v != w;
-    v == 1.0;
-    v != 1.0;
+    v == 1.5;
+    v != 1.5;
 
     const ZERO_ARRAY: [f32; 3] = [0.0, 0.0, 0.0];
     const ZERO_INF_ARRAY: [f32; 3] = [0.0, ::std::f32::INFINITY, ::std::f32::NEG_INFINITY];
@@ -57,6 +57,14 @@ fn main() {
     ZERO_ARRAY == NON_ZERO_ARRAY; // lhs is all zeros
     ZERO_INF_ARRAY == NON_ZERO_ARRAY; // lhs is all zeros or infinities
 
-    // has errors
+    // no errors, but will warn clippy::float_cmp, `0.1` and `0.2` are not exactly representable
     NON_ZERO_ARRAY == NON_ZERO_ARRAY2;
+
+    const HALF_ARRAY: [f32; 3] = [0.5, 1.5, 2.0];
+    const HALF_TUPLE: (f32, f32) = (0.5, 1.5);
+
+    // has errors
+    HALF_ARRAY == [0.5, 1.5, 2.5];
+    HALF_TUPLE != (v, w);
+    [v, w].contains(&ONE);
 }
@@ -0,0 +1,61 @@
+#![warn(clippy::float_partial_eq_hash_key)]
+#![allow(clippy::derive_hash_xor_eq, clippy::float_bits_hash_xor_eq)]
+
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+
+#[derive(PartialEq)]
+struct Weight(f64);
+
+impl Eq for Weight {}
+
+impl Hash for Weight {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+#[derive(PartialEq)]
+struct Pos {
+    x: f32,
+    y: f32,
+}
+
+#[derive(PartialEq)]
+struct Sample {
+    id: u32,
+    pos: Pos,
+}
+
+impl Eq for Sample {}
+
+impl Hash for Sample {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+// not used as a key
+#[derive(PartialEq)]
+struct Reading(f64);
+
+// no float field
+#[derive(PartialEq, Eq, Hash)]
+struct Id(u32);
+
+fn main() {
+    let mut seen = HashSet::new();
+    seen.insert(Weight(0.5));
+
+    let sample = Sample {
+        id: 1,
+        pos: Pos { x: 0.0, y: 1.0 },
+    };
+    let mut samples = HashMap::new();
+    samples.insert(sample, "first");
+
+    let _ = Reading(1.0) == Reading(2.0);
+
+    let mut ids = HashSet::new();
+    ids.insert(Id(1));
+}
@@ -6,6 +6,7 @@ use std::sync::Arc;
 fn a() {}
 
 #[warn(clippy::fn_address_comparisons)]
+#[allow(clippy::suspicious_address_comparisons)]
 fn main() {
     type F = fn();
     let f: F = a;
@@ -0,0 +1,50 @@
+#![warn(clippy::forget_self_in_drop)]
+#![allow(clippy::forget_ref)]
+
+use std::mem::{self, ManuallyDrop};
+
+struct Forget;
+
+impl Drop for Forget {
+    fn drop(&mut self) {
+        mem::forget(self);
+    }
+}
+
+struct Reborrow;
+
+impl Drop for Reborrow {
+    fn drop(&mut self) {
+        std::mem::forget(&mut *self);
+    }
+}
+
+struct Manual;
+
+impl Drop for Manual {
+    fn drop(&mut self) {
+        let _ = ManuallyDrop::new(self);
+    }
+}
+
+struct Field {
+    inner: Vec<u32>,
+}
+
+impl Drop for Field {
+    fn drop(&mut self) {
+        // OK, forgets the moved-out field, not `self`
+        mem::forget(mem::take(&mut self.inner));
+    }
+}
+
+struct NotDrop;
+
+impl NotDrop {
+    // OK, not `Drop::drop`
+    fn drop(self) {
+        mem::forget(self);
+    }
+}
+
+fn main() {}
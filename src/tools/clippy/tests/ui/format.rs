@@ -1,6 +1,7 @@
 // run-rustfix
 
-#![allow(clippy::print_literal, clippy::redundant_clone)]
+#![allow(clippy::print_literal, clippy::redundant_clone, clippy::manual_empty_new, clippy::format_literal)]
+#![allow(clippy::unused_lazy_value)]
 #![warn(clippy::useless_format)]
 
 struct Foo(pub String);
@@ -0,0 +1,28 @@
+// run-rustfix
+
+#![warn(clippy::format_literal)]
+#![allow(clippy::useless_format)]
+
+const NAME: &str = "World";
+
+fn main() {
+    let x = 1;
+    let name = "World";
+
+    // these should be fine
+    let _ = format!("{}", x);
+    let _ = format!("{:5}", "a");
+    let _ = format!("{:?}", "a");
+    let _ = format!("{:>8}", 42);
+    let _ = format!("{}", NAME);
+
+    // these should throw warnings
+    let _ = format!("{}{}", "a", x);
+    let _ = format!("{}, {}!", "Hello", name);
+    let _ = format!("{0}: {1} {0}", "x", x);
+    let _ = format!("{1} {0}", x, 'y');
+    let _ = format!("{name} = {value}", name = "x", value = x);
+    let _ = format!("{{{}}}", "braces");
+    let _ = format!("{} {}", "{}", x);
+    let _ = format!("{}", 42);
+}
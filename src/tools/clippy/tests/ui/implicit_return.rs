@@ -1,7 +1,7 @@
 // run-rustfix
 
 #![warn(clippy::implicit_return)]
-#![allow(clippy::needless_return, unused)]
+#![allow(clippy::needless_return, unused, clippy::format_literal)]
 
 fn test_end_of_fn() -> bool {
     if true {
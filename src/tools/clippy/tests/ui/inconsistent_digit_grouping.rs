@@ -37,6 +37,10 @@ fn main() {
     // Test UUID formatted literal
     let _: u128 = 0x12345678_1234_1234_1234_123456789012;
 
+    // Test that the exponent is neither checked nor regrouped
+    let _: f64 = 1_234e1_0;
+    let _: f64 = 1_23_456e1_0;
+
     // Ignore literals in macros
     let _ = mac1!();
     let _ = mac2!();
@@ -0,0 +1,63 @@
+#![allow(dead_code)]
+
+struct Pair;
+
+impl Iterator for Pair {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (2, Some(0))
+    }
+}
+
+struct Maybe(bool);
+
+impl Iterator for Maybe {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.0 {
+            return (5, Some(3));
+        }
+        (0, Some(1))
+    }
+}
+
+// OK, the bounds are consistent or unknown
+struct Unbounded;
+
+impl Iterator for Unbounded {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        Some(0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+struct Exact;
+
+impl Iterator for Exact {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(0))
+    }
+}
+
+fn main() {}
@@ -51,4 +51,20 @@ enum LargeEnumOk {
     LargeB([i32; 8001]),
 }
 
+enum GenericLarge<T> {
+    A(T),
+    B([u8; 1000], T),
+}
+
+#[repr(C)]
+enum ReprC {
+    A(u8),
+    B([u8; 1000]),
+}
+
+enum SeveralLargeFields {
+    A(u32),
+    B([u8; 1000], [u8; 300], u32),
+}
+
 fn main() {}
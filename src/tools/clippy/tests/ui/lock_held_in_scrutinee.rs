@@ -0,0 +1,78 @@
+#![warn(clippy::lock_held_in_scrutinee)]
+#![allow(clippy::if_let_mutex, clippy::single_match)]
+
+use std::cell::RefCell;
+use std::sync::{Mutex, RwLock};
+
+fn if_let_mutex(m: &Mutex<Vec<u8>>) {
+    if let Ok(guard) = m.lock() {
+        m.lock().unwrap().push(guard.len() as u8);
+    }
+}
+
+fn match_temporary(m: &Mutex<Vec<u8>>) {
+    match m.lock().unwrap().len() {
+        0 => m.lock().unwrap().push(0),
+        _ => {},
+    }
+}
+
+fn while_let_refcell(stack: &RefCell<Vec<u32>>) {
+    while let Some(x) = stack.borrow_mut().pop() {
+        if x > 0 {
+            stack.borrow_mut().push(x - 1);
+        }
+    }
+}
+
+fn if_let_rwlock(l: &RwLock<Option<u32>>) {
+    if let Some(x) = *l.read().unwrap() {
+        *l.write().unwrap() = Some(x + 1);
+    }
+}
+
+fn refcell_shared_then_mut(c: &RefCell<Option<u32>>) {
+    if let Some(x) = c.borrow().as_ref() {
+        c.borrow_mut().replace(*x);
+    }
+}
+
+// OK, shared borrows do not conflict
+fn refcell_shared(c: &RefCell<Vec<u32>>) {
+    if let Some(x) = c.borrow().first() {
+        println!("{} {}", x, c.borrow().len());
+    }
+}
+
+// OK, different cells
+fn different_cells(a: &RefCell<Vec<u32>>, b: &RefCell<Vec<u32>>) {
+    while let Some(x) = a.borrow_mut().pop() {
+        b.borrow_mut().push(x);
+    }
+}
+
+// OK, the guard is dropped before locking again
+fn dropped_guard(m: &Mutex<Vec<u8>>) {
+    if let Ok(guard) = m.lock() {
+        let len = guard.len();
+        drop(guard);
+        m.lock().unwrap().push(len as u8);
+    }
+}
+
+// OK, the guard is consumed in the scrutinee
+fn consumed_guard(m: &Mutex<u8>) {
+    if let Some(x) = m.lock().ok().map(|guard| *guard) {
+        *m.lock().unwrap() = x + 1;
+    }
+}
+
+// OK, bound before the `if let`
+fn bound_before(stack: &RefCell<Vec<u32>>) {
+    let top = stack.borrow_mut().pop();
+    if let Some(x) = top {
+        stack.borrow_mut().push(x);
+    }
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+// run-rustfix
+#![warn(clippy::manual_empty_new)]
+
+macro_rules! empty {
+    () => {
+        "".to_string()
+    };
+}
+
+fn main() {
+    let _ = "".to_string();
+    let _ = "".to_owned();
+    let _ = String::from("");
+    let _ = format!("");
+    let _: Vec<u8> = [].to_vec();
+
+    let mut v: Vec<u8> = vec![];
+    v.extend(&[1, 2, 3]);
+
+    // OK
+    let _ = "a".to_string();
+    let _ = String::from("a");
+    let _ = String::with_capacity(8);
+    let _ = std::path::PathBuf::from("");
+    let _: Vec<u8> = [1].to_vec();
+    let _ = empty!();
+
+    let mut w: Vec<u8> = vec![];
+    w.push(1);
+    let mut u = vec![];
+    u.extend(&[1u8]);
+}
@@ -0,0 +1,29 @@
+// run-rustfix
+
+#![warn(clippy::manual_ignore_case_cmp)]
+#![allow(dead_code)]
+
+fn literals(name: &str, owned: String) {
+    let _ = name.to_lowercase() == "foo";
+    let _ = name.to_ascii_uppercase() == "FOO";
+    let _ = "foo-1" == name.to_ascii_lowercase();
+    let _ = name.to_uppercase() != "FOO";
+    let _ = owned.to_lowercase() == "foo";
+    let _ = (name.to_string() + "bar").to_lowercase() == "foobar";
+}
+
+fn conversions(a: &str, b: String) {
+    let _ = a.to_ascii_lowercase() == b.to_ascii_lowercase();
+    let _ = b.to_ascii_uppercase() != a.to_ascii_uppercase();
+}
+
+fn ok(a: &str, b: &str) {
+    // different cases
+    let _ = a.to_ascii_lowercase() == b.to_ascii_uppercase();
+    // not a comparison of converted strings
+    let _ = a.to_lowercase().len() == 3;
+    let _ = a == "foo";
+    let _ = a.eq_ignore_ascii_case(b);
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+#![warn(clippy::manual_ignore_case_cmp)]
+#![allow(dead_code)]
+
+fn main() {
+    let (a, b) = ("Straße", String::from("STRASSE"));
+    // not necessarily ASCII
+    let _ = a.to_lowercase() == b.to_lowercase();
+    let _ = a.to_ascii_uppercase() == b.to_uppercase();
+    // non-ASCII literal
+    let _ = a.to_lowercase() == "straße";
+    let _ = "STRAẞE" != b.to_uppercase();
+}
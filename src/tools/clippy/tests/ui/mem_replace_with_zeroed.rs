@@ -0,0 +1,73 @@
+#![allow(deprecated, invalid_value, dead_code)]
+#![warn(clippy::mem_replace_with_zeroed)]
+
+use std::mem;
+use std::num::NonZeroU32;
+use std::ptr::NonNull;
+
+#[derive(Clone, Copy)]
+enum Level {
+    Low = 1,
+    High = 2,
+}
+
+#[derive(Clone, Copy)]
+enum Ordering {
+    Less = -1,
+    Equal = 0,
+    Greater = 1,
+}
+
+struct Bytes {
+    data: [u8; 4],
+    len: usize,
+}
+
+struct Wrapper<'a> {
+    inner: &'a i32,
+}
+
+fn generic<T>(t: &mut T) -> T {
+    // OK, the validity of `T` is not known
+    unsafe { mem::replace(t, mem::zeroed()) }
+}
+
+fn main() {
+    let value = 42;
+    let mut r: &i32 = &value;
+    let _ = unsafe { mem::replace(&mut r, mem::zeroed()) };
+
+    let mut b = Box::new(1);
+    let _ = unsafe { mem::replace(&mut b, mem::zeroed()) };
+
+    let mut p = NonNull::<u8>::dangling();
+    let _ = unsafe { mem::replace(&mut p, mem::zeroed()) };
+
+    let mut n = NonZeroU32::new(1).unwrap();
+    let _ = unsafe { mem::replace(&mut n, mem::zeroed()) };
+
+    let mut l = Level::Low;
+    let _ = unsafe { mem::replace(&mut l, mem::zeroed()) };
+
+    let mut w = Wrapper { inner: &value };
+    let _ = unsafe { mem::replace(&mut w, mem::zeroed()) };
+
+    let mut t = (0u8, &value);
+    let _ = unsafe { mem::replace(&mut t, mem::zeroed()) };
+
+    // OK, zero is a valid value
+    let mut i = 0u64;
+    let _ = unsafe { mem::replace(&mut i, mem::zeroed()) };
+
+    let mut bytes = Bytes { data: [1; 4], len: 4 };
+    let _ = unsafe { mem::replace(&mut bytes, mem::zeroed()) };
+
+    let mut o = Ordering::Less;
+    let _ = unsafe { mem::replace(&mut o, mem::zeroed()) };
+
+    let mut refs: [&i32; 0] = [];
+    let _ = unsafe { mem::replace(&mut refs, mem::zeroed()) };
+
+    let mut x = 1;
+    generic(&mut x);
+}
@@ -0,0 +1,18 @@
+#![allow(deprecated, invalid_value)]
+#![warn(clippy::mem_uninitialized)]
+
+use std::mem::{self, MaybeUninit};
+
+fn main() {
+    let _: u32 = unsafe { mem::uninitialized() };
+    let _: [u8; 16] = unsafe { std::mem::uninitialized() };
+    let _ = unsafe { mem::uninitialized::<String>() };
+
+    // OK, reported by `mem_replace_with_uninit`
+    let mut v = vec![1];
+    #[allow(clippy::mem_replace_with_uninit)]
+    let _ = unsafe { mem::replace(&mut v, mem::uninitialized()) };
+
+    // OK
+    let _: MaybeUninit<[u8; 16]> = MaybeUninit::uninit();
+}
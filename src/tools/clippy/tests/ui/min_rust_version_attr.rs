@@ -0,0 +1,38 @@
+#![feature(custom_inner_attributes)]
+#![clippy::msrv = "1.0"]
+#![warn(
+    clippy::cast_possible_truncation,
+    clippy::checked_conversions,
+    clippy::map_clone,
+    clippy::option_as_ref_deref,
+    clippy::range_plus_one
+)]
+#![allow(clippy::iter_cloned_collect)]
+
+fn cast_possible_truncation(value: u32) -> u8 {
+    value as u8
+}
+
+fn checked_conversion(value: i64) -> bool {
+    value <= (u32::MAX as i64) && value >= 0
+}
+
+fn map_clone(values: &[u8]) -> Vec<u8> {
+    values.iter().map(|x| *x).collect()
+}
+
+fn option_as_ref_deref(name: Option<String>) -> Option<usize> {
+    name.as_ref().map(String::as_str).map(str::len)
+}
+
+fn range_plus_one(end: usize) -> usize {
+    (0..end + 1).sum()
+}
+
+fn main() {
+    cast_possible_truncation(0);
+    checked_conversion(0);
+    map_clone(&[]);
+    option_as_ref_deref(None);
+    range_plus_one(0);
+}
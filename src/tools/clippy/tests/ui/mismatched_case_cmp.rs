@@ -0,0 +1,14 @@
+#![warn(clippy::mismatched_case_cmp)]
+#![allow(clippy::manual_ignore_case_cmp)]
+
+fn main() {
+    let name = "Foo";
+    let _ = name.to_lowercase() == "Foo";
+    let _ = name.to_ascii_uppercase() == "FOo";
+    let _ = "bar" != name.to_uppercase();
+    let _ = name.to_ascii_lowercase() != "fOO";
+
+    // OK
+    let _ = name.to_lowercase() == "foo";
+    let _ = name.to_uppercase() == "FOO-1";
+}
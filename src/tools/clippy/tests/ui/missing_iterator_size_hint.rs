@@ -0,0 +1,98 @@
+#![warn(clippy::missing_iterator_size_hint)]
+#![allow(dead_code)]
+
+struct Names<I>(I);
+
+impl<I: Iterator<Item = u32>> Iterator for Names<I> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        self.0.next().map(|id| format!("user{}", id))
+    }
+}
+
+struct Wrapper<I> {
+    inner: I,
+}
+
+impl<I: Iterator> Iterator for Wrapper<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        self.inner.next()
+    }
+}
+
+// `filter`-like adapters may skip items, only the upper bound is forwarded
+struct Evens<I> {
+    inner: I,
+}
+
+impl<I: Iterator<Item = u32>> Iterator for Evens<I> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        self.inner.find(|n| n % 2 == 0)
+    }
+}
+
+struct Parsed<I> {
+    lines: I,
+}
+
+impl<'a, I: Iterator<Item = &'a str>> Iterator for Parsed<I> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        self.lines.next().and_then(|line| line.parse().ok())
+    }
+}
+
+// OK, `size_hint` is forwarded
+struct Forwarded<I> {
+    inner: I,
+}
+
+impl<I: Iterator> Iterator for Forwarded<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+// OK, `next` does more than delegating
+struct Counted<I> {
+    inner: I,
+    count: usize,
+}
+
+impl<I: Iterator> Iterator for Counted<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let item = self.inner.next()?;
+        self.count += 1;
+        Some(item)
+    }
+}
+
+// OK, there is no inner iterator
+struct Countdown(u32);
+
+impl Iterator for Countdown {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        self.0.checked_sub(1).map(|n| {
+            self.0 = n;
+            n
+        })
+    }
+}
+
+fn main() {}
@@ -21,4 +21,8 @@ fn main() {
     let fail29 = 42279.911_32;
 
     let _ = 1.12345E1_32;
+
+    // valid groups of hexadecimal and octal digits
+    let ok_hex = 0x1F_32;
+    let ok_oct = 0o7_32;
 }
@@ -1,5 +1,7 @@
-use std::collections::{HashMap, HashSet};
+use std::cell::RefCell;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::hash::{Hash, Hasher};
+use std::rc::Rc;
 use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
 
 struct Key(AtomicUsize);
@@ -46,6 +48,43 @@ fn tuples<U>(_m: &mut HashMap<((), U), ()>) {}
 
 fn tuples_bad<U>(_m: &mut HashMap<(Key, U), bool>) {}
 
+struct Fields {
+    keys: HashSet<Key>,
+    nested: Option<HashMap<Key, usize>>,
+    shared: BTreeSet<Rc<RefCell<usize>>>,
+    ok: HashMap<usize, Key>,
+    rc_ok: HashSet<Rc<usize>>,
+}
+
+static S: Option<HashSet<Key>> = None;
+
+const C: Option<HashSet<Key>> = None;
+
+// A recursive key type without interior mutability
+#[derive(PartialEq, Eq, Hash)]
+struct List(Option<Box<List>>);
+
+static LIST: Option<HashSet<List>> = None;
+
+// The key type is only mutable for some instantiations, so the definition is not linted
+struct Cache<K> {
+    map: HashMap<K, u32>,
+}
+
+impl<K: Eq + Hash> Cache<K> {
+    fn insert(&mut self, key: K) {
+        self.map.insert(key, 0);
+    }
+}
+
+fn fields(f: &mut Fields, cache: &mut Cache<Key>, plain: &mut Cache<usize>) {
+    // already linted at the field
+    f.keys.insert(Key(AtomicUsize::new(0)));
+    cache.map.insert(Key(AtomicUsize::new(0)), 1);
+    cache.insert(Key(AtomicUsize::new(0)));
+    plain.map.insert(0, 1);
+}
+
 fn main() {
     let _ = should_not_take_this_arg(&mut HashMap::new(), 1);
     this_is_ok(&mut HashMap::new());
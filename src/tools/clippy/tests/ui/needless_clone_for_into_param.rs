@@ -0,0 +1,55 @@
+// run-rustfix
+
+#![warn(clippy::needless_clone_for_into_param)]
+#![allow(clippy::redundant_clone, dead_code)]
+
+fn greet(name: impl Into<String>) -> String {
+    name.into()
+}
+
+fn convert<T: Into<Vec<u8>>>(prefix: &str, data: T) -> usize {
+    prefix.len() + data.into().len()
+}
+
+fn bounded_in_where<T>(x: T) -> String
+where
+    T: Into<String>,
+{
+    x.into()
+}
+
+struct Builder {
+    name: String,
+}
+
+impl Builder {
+    fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+}
+
+fn take(_: String) {}
+
+fn main() {
+    let name = String::from("world");
+    greet(name.clone());
+
+    let data = vec![1, 2, 3];
+    convert("x", data.clone());
+
+    let s = String::from("a");
+    bounded_in_where(s.to_owned());
+
+    let n = String::from("b");
+    let _ = Builder { name: String::new() }.name(n.clone());
+
+    // OK, the original is used afterwards
+    let used = String::from("c");
+    greet(used.clone());
+    println!("{}", used);
+
+    // OK, not an `Into` parameter
+    let plain = String::from("d");
+    take(plain.clone());
+}
@@ -0,0 +1,98 @@
+#![warn(clippy::needless_clone_in_trait_default_method)]
+#![allow(clippy::clone_on_copy)]
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+trait Named {
+    fn name_ref(&self) -> &String;
+
+    fn names(&self) -> &[String];
+
+    fn name(&self) -> String {
+        self.name_ref().clone()
+    }
+
+    fn first_name(&self) -> String {
+        if self.names().is_empty() {
+            return self.name_ref().clone();
+        }
+        self.names()[0].clone()
+    }
+
+    fn take_name(&mut self) -> String {
+        self.name_ref().clone()
+    }
+
+    // OK, the clone is wrapped
+    fn maybe_name(&self) -> Option<String> {
+        Some(self.name_ref().clone())
+    }
+
+    // OK, the clone is not returned as is
+    fn name_len(&self) -> usize {
+        self.name_ref().clone().len()
+    }
+
+    // OK, not derived from `self`
+    fn default_name<T: Clone>(&self, other: &T) -> T {
+        other.clone()
+    }
+
+    // OK, `self` is taken by value
+    fn into_name(self) -> String
+    where
+        Self: Sized,
+    {
+        self.name_ref().clone()
+    }
+
+    // OK, `self` is not a reference
+    fn boxed_name(self: Box<Self>) -> String {
+        self.name_ref().clone()
+    }
+
+    // OK, not a default method
+    fn required_name(&self) -> String;
+}
+
+trait Shared {
+    fn id_ref(&self) -> &u32;
+
+    fn rc_ref(&self) -> &Rc<String>;
+
+    fn arc_ref(&self) -> &Arc<String>;
+
+    // OK, `Copy` values are cheap to clone
+    fn id(&self) -> u32 {
+        self.id_ref().clone()
+    }
+
+    // OK, cloning an `Rc` or `Arc` only bumps the reference count
+    fn rc(&self) -> Rc<String> {
+        self.rc_ref().clone()
+    }
+
+    fn arc(&self) -> Arc<String> {
+        self.arc_ref().clone()
+    }
+}
+
+struct Person(String, Vec<String>);
+
+// OK, not a default method
+impl Named for Person {
+    fn name_ref(&self) -> &String {
+        &self.0
+    }
+
+    fn names(&self) -> &[String] {
+        &self.1
+    }
+
+    fn required_name(&self) -> String {
+        self.0.clone()
+    }
+}
+
+fn main() {}
@@ -0,0 +1,107 @@
+#![warn(clippy::new_differs_from_default)]
+#![allow(dead_code)]
+
+pub struct Config {
+    retries: u32,
+    verbose: bool,
+    name: String,
+}
+
+impl Config {
+    pub fn new() -> Self {
+        Config {
+            retries: 3,
+            verbose: false,
+            name: String::new(),
+        }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            retries: 5,
+            verbose: true,
+            name: String::new(),
+        }
+    }
+}
+
+pub struct Scale(f32, i32);
+
+impl Scale {
+    pub fn new() -> Self {
+        Scale(1.0, -1)
+    }
+}
+
+impl Default for Scale {
+    fn default() -> Self {
+        Scale(1.0, 1)
+    }
+}
+
+// OK, `default` calls `new`
+pub struct Delegating {
+    level: u8,
+}
+
+impl Delegating {
+    pub fn new() -> Self {
+        Delegating { level: 1 }
+    }
+}
+
+impl Default for Delegating {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// OK, `new` calls `default`
+pub struct Reverse {
+    level: u8,
+}
+
+impl Reverse {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Default for Reverse {
+    fn default() -> Self {
+        Reverse { level: 2 }
+    }
+}
+
+// OK, the values are the same
+pub struct Same {
+    level: u8,
+}
+
+impl Same {
+    pub fn new() -> Self {
+        Same { level: 4 }
+    }
+}
+
+impl Default for Same {
+    fn default() -> Self {
+        Same { level: 4 }
+    }
+}
+
+// OK, derived implementations are not compared
+#[derive(Default)]
+pub struct Derived {
+    level: u8,
+}
+
+impl Derived {
+    pub fn new() -> Self {
+        Derived { level: 1 }
+    }
+}
+
+fn main() {}
@@ -80,7 +80,6 @@ impl<'c> LtKo<'c> {
     pub fn new() -> LtKo<'c> {
         unimplemented!()
     }
-    // FIXME: that suggestion is missing lifetimes
 }
 
 struct Private;
@@ -159,4 +158,17 @@ impl NewNotEqualToDerive {
     }
 }
 
+pub struct Wrapper<T> {
+    value: Option<T>,
+}
+
+impl<T> Wrapper<T>
+where
+    T: Clone,
+{
+    pub fn new() -> Self {
+        Wrapper { value: None }
+    }
+}
+
 fn main() {}
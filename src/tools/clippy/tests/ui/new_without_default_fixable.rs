@@ -0,0 +1,25 @@
+// run-rustfix
+
+#![allow(dead_code)]
+#![warn(clippy::new_without_default)]
+
+pub struct Foo;
+
+impl Foo {
+    pub fn new() -> Self {
+        Foo
+    }
+}
+
+#[rustfmt::skip]
+pub mod tabs {
+	pub struct Bar;
+
+	impl Bar {
+		pub fn new() -> Self {
+			Bar
+		}
+	}
+}
+
+fn main() {}
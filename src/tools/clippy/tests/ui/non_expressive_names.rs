@@ -1,5 +1,5 @@
 #![warn(clippy::all)]
-#![allow(unused, clippy::println_empty_string)]
+#![allow(unused, clippy::println_empty_string, clippy::unused_lazy_value)]
 
 #[derive(Clone, Debug)]
 enum MaybeInst {
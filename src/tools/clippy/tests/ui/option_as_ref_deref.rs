@@ -1,6 +1,6 @@
 // run-rustfix
 
-#![allow(unused_imports, clippy::redundant_clone)]
+#![allow(unused_imports, clippy::redundant_clone, clippy::redundant_slicing)]
 #![warn(clippy::option_as_ref_deref)]
 
 use std::ffi::{CString, OsString};
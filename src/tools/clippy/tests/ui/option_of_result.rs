@@ -0,0 +1,60 @@
+#![warn(clippy::option_of_result)]
+
+use std::num::ParseIntError;
+
+#[derive(Debug)]
+struct Error;
+
+type Result<T> = std::result::Result<T, Error>;
+
+fn input(_: Option<std::result::Result<u8, Error>>) {}
+
+fn output() -> Option<std::result::Result<u8, ParseIntError>> {
+    None
+}
+
+// Through a `Result` shorthand
+fn output_alias() -> Option<Result<u8>> {
+    None
+}
+
+struct Struct {
+    x: Vec<Option<Result<u8>>>,
+}
+
+type OptionResult<T> = Option<Result<T>>;
+
+// The lint allows this, the alias is linted where it is defined
+fn output_option_result_alias() -> OptionResult<u8> {
+    None
+}
+
+// The lint allows this
+fn result_option() -> Result<Option<u8>> {
+    Ok(None)
+}
+
+trait Trait {
+    fn trait_fn() -> Option<Result<u8>>;
+}
+
+// The lint allows this, the signature is given by the trait
+impl Trait for Struct {
+    fn trait_fn() -> Option<Result<u8>> {
+        None
+    }
+}
+
+// The lint allows this, the signature is given by the trait
+impl Iterator for Struct {
+    type Item = Result<u8>;
+
+    fn next(&mut self) -> Option<Result<u8>> {
+        None
+    }
+}
+
+fn main() {
+    // The lint allows this
+    let _: Option<Result<u8>> = result_option().transpose();
+}
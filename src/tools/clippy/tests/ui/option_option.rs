@@ -34,14 +34,41 @@ enum Enum {
     Struct { x: Option<Option<u8>> },
 }
 
-// The lint allows this
 type OptionOption = Option<Option<u32>>;
 
-// The lint allows this
+// The lint allows this, the alias is linted where it is defined
 fn output_type_alias() -> OptionOption {
     None
 }
 
+type Opt<T> = Option<T>;
+type MaybeU8 = Option<u8>;
+
+fn output_generic_alias() -> Opt<Opt<u8>> {
+    None
+}
+
+fn output_inner_alias() -> Option<MaybeU8> {
+    None
+}
+
+struct AliasField {
+    x: Opt<MaybeU8>,
+}
+
+type VecOptionOption = Vec<Option<MaybeU8>>;
+
+macro_rules! option_option {
+    () => {
+        Option<Option<u8>>
+    };
+}
+
+// The lint allows this, the type comes from a macro
+fn output_macro() -> option_option!() {
+    None
+}
+
 // The line allows this
 impl Trait for Struct {
     fn trait_fn() -> Option<Option<u8>> {
@@ -72,7 +99,6 @@ mod issue_4298 {
         #[serde(skip_serializing_if = "Option::is_none")]
         #[serde(default)]
         #[serde(borrow)]
-        // FIXME: should not lint here
         #[allow(clippy::option_option)]
         foo: Option<Option<Cow<'a, str>>>,
     }
@@ -1,7 +1,7 @@
 // run-rustfix
 
 #![warn(clippy::or_fun_call)]
-#![allow(dead_code)]
+#![allow(dead_code, clippy::manual_empty_new)]
 
 use std::collections::BTreeMap;
 use std::collections::HashMap;
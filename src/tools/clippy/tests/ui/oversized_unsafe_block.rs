@@ -0,0 +1,43 @@
+#![warn(clippy::oversized_unsafe_block)]
+
+unsafe fn dangerous() -> u32 {
+    42
+}
+
+fn main() {
+    let x = 42;
+    let p = &x as *const i32;
+    let m = &mut 0 as *mut i32;
+
+    unsafe {
+        let y = *p;
+        println!("{}", y);
+    }
+
+    unsafe {
+        println!("{}", x);
+        *m = 1;
+    }
+
+    let _ = unsafe {
+        let a = dangerous();
+        let b = a + 1;
+        a + b
+    };
+
+    let _ = unsafe {
+        let a = x + 1;
+        a + *p
+    };
+
+    // OK, only one statement
+    unsafe {
+        *m = 2;
+    }
+
+    // OK, every statement needs `unsafe`
+    unsafe {
+        *m = 3;
+        dangerous();
+    }
+}
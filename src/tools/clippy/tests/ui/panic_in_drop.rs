@@ -0,0 +1,111 @@
+#![warn(clippy::panic_in_drop)]
+#![allow(clippy::redundant_slicing, clippy::should_implement_trait)]
+
+use std::collections::HashMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::thread;
+
+struct Explicit;
+
+impl Drop for Explicit {
+    fn drop(&mut self) {
+        panic!("dropped");
+    }
+}
+
+struct Unwrap {
+    value: Option<u32>,
+    result: Result<u32, ()>,
+}
+
+impl Drop for Unwrap {
+    fn drop(&mut self) {
+        let _ = self.value.unwrap();
+        let _ = self.result.expect("no value");
+    }
+}
+
+struct Indexing {
+    vec: Vec<u32>,
+    map: HashMap<u32, u32>,
+    array: [u32; 4],
+}
+
+impl Drop for Indexing {
+    fn drop(&mut self) {
+        let _ = self.vec[0];
+        let _ = self.map[&0];
+        let _ = &self.vec[1..];
+        // OK, cannot panic
+        let _ = &self.vec[..];
+        let _ = self.array[3];
+    }
+}
+
+struct Guarded {
+    vec: Vec<u32>,
+}
+
+impl Drop for Guarded {
+    fn drop(&mut self) {
+        // OK, only runs when not already panicking
+        if !thread::panicking() {
+            assert_eq!(self.vec.len(), 1);
+        }
+        if !std::thread::panicking() && !self.vec.is_empty() {
+            let _ = self.vec[0];
+        }
+        if thread::panicking() {
+            eprintln!("dropped while panicking");
+        } else {
+            let _ = self.vec.first().unwrap();
+        }
+        // Not guarded
+        if self.vec.is_empty() {
+            unreachable!();
+        }
+    }
+}
+
+struct EarlyReturn {
+    vec: Vec<u32>,
+}
+
+impl Drop for EarlyReturn {
+    fn drop(&mut self) {
+        let _ = self.vec[0];
+        if thread::panicking() {
+            return;
+        }
+        // OK, only runs when not already panicking
+        let _ = self.vec[1];
+        todo!();
+    }
+}
+
+struct CatchUnwind {
+    vec: Vec<u32>,
+}
+
+impl Drop for CatchUnwind {
+    fn drop(&mut self) {
+        // `catch_unwind` does not help, a second panic aborts before it can be caught
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| self.vec[0]));
+        if !thread::panicking() {
+            let _ = panic::catch_unwind(AssertUnwindSafe(|| self.vec[0]));
+        }
+    }
+}
+
+struct NotDrop {
+    vec: Vec<u32>,
+}
+
+impl NotDrop {
+    // OK, not `Drop::drop`
+    fn drop(&mut self) {
+        let _ = self.vec[0];
+    }
+}
+
+fn main() {}
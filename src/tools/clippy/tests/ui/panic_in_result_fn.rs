@@ -0,0 +1,74 @@
+#![warn(clippy::panic_in_result_fn)]
+#![allow(dead_code)]
+
+struct Parser;
+
+impl Parser {
+    fn parse(&self, s: &str) -> Result<u32, String> {
+        if s.is_empty() {
+            panic!("empty input");
+        }
+        Ok(s.parse().unwrap())
+    }
+
+    fn unfinished(&self) -> Result<u32, String> {
+        todo!()
+    }
+
+    fn not_implemented(&self) -> Result<u32, String> {
+        unimplemented!("not yet")
+    }
+
+    // OK, does not return a `Result`
+    fn no_result(&self, s: &str) -> u32 {
+        s.parse().unwrap()
+    }
+}
+
+fn nested_blocks(x: Option<u32>) -> Result<u32, String> {
+    let y = {
+        let z = x.expect("x is set");
+        if z > 10 {
+            unreachable!();
+        }
+        z
+    };
+    assert!(y > 1);
+    assert_eq!(y, 2, "y must be two");
+    Ok(y)
+}
+
+// OK, debug assertions are not checked in release builds
+fn debug_assertions(x: u32) -> Result<u32, String> {
+    debug_assert!(x > 1);
+    debug_assert_eq!(x, 2);
+    debug_assert_ne!(x, 3, "x must not be three");
+    Ok(x)
+}
+
+// OK, closures are not checked by default
+fn closure(v: &[Option<u32>]) -> Result<Vec<u32>, String> {
+    Ok(v.iter().map(|x| x.unwrap()).collect())
+}
+
+// OK, errors are returned
+fn propagates(s: &str) -> Result<u32, std::num::ParseIntError> {
+    let n: u32 = s.parse()?;
+    Ok(n + 1)
+}
+
+macro_rules! panic {
+    ($msg:expr) => {
+        return Err($msg.to_string())
+    };
+}
+
+// OK, this `panic!` is not the one of `std`
+fn custom_panic(x: u32) -> Result<u32, String> {
+    if x == 0 {
+        panic!("zero");
+    }
+    Ok(x)
+}
+
+fn main() {}
@@ -39,3 +39,23 @@ fn main() {
     unimplemented();
     unreachable();
 }
+
+fn debug_assertions() {
+    let a = 2;
+    debug_assert!(a > 1);
+    debug_assert_eq!(a, 2);
+    debug_assert_ne!(a, 3);
+}
+
+mod custom {
+    macro_rules! unimplemented {
+        () => {
+            panic!("custom")
+        };
+    }
+
+    // Linted as `panic!`, this is not the `unimplemented!` of `core`
+    pub fn unimplemented() {
+        unimplemented!();
+    }
+}
@@ -0,0 +1,89 @@
+#![warn(clippy::partial_eq_ignores_hashed_fields)]
+#![allow(clippy::derive_hash_xor_eq)]
+
+use std::marker::PhantomData;
+
+#[derive(Hash)]
+struct User {
+    id: u32,
+    name: String,
+    last_seen: u64,
+}
+
+impl PartialEq for User {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+#[derive(Hash)]
+struct Point(i32, i32, i32);
+
+impl PartialEq for Point {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0 && self.1 == other.1
+    }
+}
+
+// OK, all fields are compared
+#[derive(Hash)]
+struct Pair {
+    a: u32,
+    b: Vec<u32>,
+}
+
+impl PartialEq for Pair {
+    fn eq(&self, other: &Self) -> bool {
+        self.a == other.a && self.b.iter().zip(&other.b).all(|(x, y)| x == y) && self.b.len() == other.b.len()
+    }
+}
+
+// OK, `PhantomData` does not contribute to the hash
+#[derive(Hash)]
+struct Tagged<T> {
+    id: u32,
+    tag: PhantomData<T>,
+}
+
+impl<T> PartialEq for Tagged<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+// OK, `self` is passed to a function, the compared fields are unknown
+#[derive(Hash)]
+struct Opaque {
+    key: u32,
+    value: u32,
+}
+
+fn key(o: &Opaque) -> u32 {
+    o.key
+}
+
+impl PartialEq for Opaque {
+    fn eq(&self, other: &Self) -> bool {
+        key(self) == key(other)
+    }
+}
+
+// OK, `Hash` is implemented manually
+struct Manual {
+    id: u32,
+    cache: u32,
+}
+
+impl std::hash::Hash for Manual {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl PartialEq for Manual {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+fn main() {}
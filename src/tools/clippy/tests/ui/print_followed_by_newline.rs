@@ -0,0 +1,34 @@
+// run-rustfix
+
+#![warn(clippy::print_followed_by_newline)]
+
+use std::fmt::Write;
+
+fn main() {
+    let name = "World";
+    let mut s = String::new();
+
+    print!("Hello {}!", name);
+    println!();
+    print!("Hello");
+    print!("\n");
+    eprint!("Hello {}!", name);
+    eprintln!("");
+    write!(s, "Hello {}!", name);
+    writeln!(s);
+    write!(s, "Hello");
+    write!(s, "\n");
+
+    // don't lint
+    print!("Hello");
+    print!(r"\n"); // raw string, prints a backslash and an `n`
+    print!("Hello");
+    print!("\n{}", name);
+    print!("Hello");
+    eprintln!(); // other stream
+    let mut t = String::new();
+    write!(s, "Hello");
+    writeln!(t); // other destination
+    println!("Hello");
+    println!();
+}
@@ -35,4 +35,19 @@ fn main() {
     // named args shouldn't change anything either
     println!("{foo} {bar}", foo = "hello", bar = "world");
     println!("{bar} {foo}", foo = "hello", bar = "world");
+
+    // literals are escaped in the suggestion
+    println!("{} {}", "{braces}", 'a');
+    println!("{} {}", "a\nb", 1_u8);
+    println!(r"{}", r"C:\path");
+    println!(r#"{}"#, "\"quoted\"");
+
+    // repeated placeholders are all replaced
+    println!("{0} and {0}", "twice");
+    println!("{0} and {0:5}", "twice");
+
+    // widths and precisions taken from the arguments block the rewrite
+    println!("{:.*} {}", 2, 1.5, "hello");
+    println!("{:1$} {}", "hello", 5);
+    println!("{:width$} {}", "hello", "world", width = 5);
 }
@@ -47,5 +47,11 @@ fn main() {
     print!("\r\n");
     print!("foo\r\n");
     print!("\\r\n"); //~ ERROR
-    print!("foo\rbar\n") // ~ ERROR
+    print!("foo\rbar\n"); // ~ ERROR
+
+    // `eprint!` is linted as well
+    eprint!("Hello\n");
+    eprint!("Hello {}\n", "world");
+    eprint!("{}\n{}", "Hello", "world");
+    eprint!(r"Hello\n");
 }
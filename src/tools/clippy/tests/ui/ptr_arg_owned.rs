@@ -0,0 +1,47 @@
+#![allow(unused)]
+#![warn(clippy::ptr_arg)]
+
+use std::borrow::Cow;
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+fn path(p: &PathBuf) -> PathBuf {
+    let path = p.as_path();
+    println!("{}", path.display());
+    p.clone()
+}
+
+fn os_string(s: &OsString) -> usize {
+    s.len()
+}
+
+fn cow_str(c: &Cow<'_, str>) -> usize {
+    c.len()
+}
+
+fn print_path(p: &PathBuf) {
+    println!("{}", p.display());
+}
+
+fn used_as_value(paths: &[PathBuf]) {
+    paths.iter().for_each(print_path);
+    print_path(&paths[0]);
+}
+
+// no error, `capacity` needs the owned type
+fn path_capacity(p: &PathBuf) -> usize {
+    p.capacity()
+}
+
+fn os_string_capacity(s: &OsString) -> usize {
+    s.capacity()
+}
+
+// no error, the signature is fixed by the callers
+#[allow(improper_ctypes_definitions)]
+extern "C" fn extern_fn(p: &PathBuf) {}
+
+#[no_mangle]
+pub fn no_mangle(s: &String, p: &PathBuf) {}
+
+fn main() {}
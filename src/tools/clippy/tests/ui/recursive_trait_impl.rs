@@ -0,0 +1,129 @@
+#![warn(clippy::recursive_trait_impl)]
+#![allow(clippy::to_string_in_display, dead_code)]
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+
+struct Name(String);
+
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl fmt::Debug for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Name({:?})", self)
+    }
+}
+
+struct Label(String);
+
+impl Label {
+    fn quoted(&self) -> String {
+        format!("\"{}\"", self.to_string())
+    }
+}
+
+impl fmt::Display for Label {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.quoted())
+    }
+}
+
+struct Id(u32);
+
+// formatting a field is fine
+impl fmt::Display for Id {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{}", self.0.to_string())
+    }
+}
+
+// `Debug` formatting `self` with `Display` is fine
+impl fmt::Debug for Id {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Id({})", self)
+    }
+}
+
+struct Meters(f64);
+struct Feet(f64);
+
+impl From<Feet> for Meters {
+    fn from(feet: Feet) -> Self {
+        feet.into()
+    }
+}
+
+// converting a field is fine
+impl From<Meters> for Feet {
+    fn from(meters: Meters) -> Self {
+        Feet(meters.0 * 3.28)
+    }
+}
+
+struct Key(String);
+
+impl Hash for Key {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(self, state);
+    }
+}
+
+struct OtherKey(String);
+
+// hashing a field is fine
+impl Hash for OtherKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+struct Wrapper(Vec<u8>);
+
+impl Deref for Wrapper {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &**self
+    }
+}
+
+struct OtherWrapper(Vec<u8>);
+
+// dereferencing a field is fine
+impl Deref for OtherWrapper {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &*self.0
+    }
+}
+
+struct Text(String);
+
+impl Deref for Text {
+    type Target = String;
+
+    fn deref(&self) -> &String {
+        &self.0
+    }
+}
+
+// formatting and hashing the target of `Deref` is fine
+impl fmt::Display for Text {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", **self)
+    }
+}
+
+impl Hash for Text {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
+
+fn main() {}
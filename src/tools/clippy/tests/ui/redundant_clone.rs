@@ -53,6 +53,8 @@ fn main() {
     not_consumed();
     issue_5405();
     manually_drop();
+    mut_borrow_at_clone();
+    assoc_type_copy();
 }
 
 #[derive(Clone)]
@@ -185,3 +187,53 @@ fn manually_drop() {
         Arc::from_raw(p);
     }
 }
+
+fn mut_borrow_at_clone() {
+    // OK, `v` is already mutably borrowed for `extend` when it is cloned
+    let mut v = vec![String::from("a")];
+    v.extend(v.clone());
+
+    let mut s = String::from("a");
+    s.push_str(&s.clone());
+
+    // OK, `p` still points to `s`, it must not be moved out
+    let mut s = String::from("a");
+    let p: *mut String = &mut s;
+    let t = s.clone();
+    unsafe { (*p).push('b') };
+    drop(t);
+}
+
+fn assoc_type_copy() {
+    trait Container {
+        type Item;
+        fn get(&self) -> &Self::Item;
+    }
+
+    // OK, `I::Item` is `Copy`
+    fn bound<I: Iterator>(mut iter: I) -> Option<I::Item>
+    where
+        I::Item: Copy,
+    {
+        let item = iter.next()?;
+        Some(Clone::clone(&item))
+    }
+
+    // OK, `C::Item` normalizes to `u32`
+    fn normalized<C: Container<Item = u32>>(c: &C) -> u32 {
+        let item = *c.get();
+        Clone::clone(&item)
+    }
+
+    struct Number(u32);
+
+    impl Container for Number {
+        type Item = u32;
+        fn get(&self) -> &u32 {
+            &self.0
+        }
+    }
+
+    bound(vec![1, 2].into_iter());
+    normalized(&Number(1));
+}
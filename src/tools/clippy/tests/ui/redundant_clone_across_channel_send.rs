@@ -0,0 +1,39 @@
+// run-rustfix
+#![warn(clippy::redundant_clone_across_channel_send)]
+#![allow(dead_code)]
+
+use std::sync::mpsc;
+
+fn channel(s: String) {
+    let (tx, rx) = mpsc::channel();
+    tx.send(s.clone()).unwrap();
+    rx.recv().unwrap();
+}
+
+fn sync_channel(v: Vec<u8>) {
+    let (tx, rx) = mpsc::sync_channel(1);
+    tx.send(v.clone()).unwrap();
+    rx.recv().unwrap();
+}
+
+// OK, the original is used afterwards
+fn used_later(s: String) -> String {
+    let (tx, rx) = mpsc::channel();
+    tx.send(s.clone()).unwrap();
+    rx.recv().unwrap();
+    s
+}
+
+struct Sender;
+
+impl Sender {
+    fn send<T>(&self, _: T) {}
+}
+
+// OK, not a standard library channel
+#[allow(clippy::redundant_clone)]
+fn custom_sender(s: String) {
+    Sender.send(s.clone());
+}
+
+fn main() {}
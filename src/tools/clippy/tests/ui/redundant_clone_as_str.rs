@@ -0,0 +1,43 @@
+// run-rustfix
+// rustfix-only-machine-applicable
+#![warn(clippy::redundant_clone)]
+#![allow(dead_code)]
+
+use std::path::{Path, PathBuf};
+
+struct Config {
+    name: String,
+    bytes: Vec<u8>,
+}
+
+fn takes_str(_: &str) {}
+
+fn takes_slice(_: &[u8]) {}
+
+fn takes_str_and_string(_: &str, _: String) {}
+
+fn fields(c: &Config) {
+    takes_str(c.name.clone().as_str());
+    takes_slice(c.bytes.to_owned().as_slice());
+}
+
+fn main() {
+    let s = String::from("foo");
+    let mut v = vec![1u8, 2, 3];
+    let p = PathBuf::from("/a/b");
+    let o = Some(String::from("bar"));
+
+    takes_str(s.clone().as_str());
+    let _len = s.clone().as_str().len();
+    takes_str(s.clone().as_ref());
+    takes_slice(v.clone().as_slice());
+    let _exists = Path::exists(p.clone().as_ref());
+    let _len = o.clone().as_ref().map(String::len);
+
+    // OK, the clone is mutated
+    takes_slice(v.clone().as_mut_slice());
+    v.push(4);
+
+    // Not machine applicable, `s` is moved while the borrow is alive
+    takes_str_and_string(s.clone().as_str(), s);
+}
@@ -0,0 +1,71 @@
+// run-rustfix
+
+#![warn(clippy::redundant_clone)]
+
+fn take(_: String) {}
+
+// OK, `x` is moved in one branch only
+fn moved_in_then(cond: bool) {
+    let x = String::from("a");
+    take(x.clone());
+    if cond {
+        take(x);
+    }
+}
+
+// OK, `x` is moved in one branch only
+fn moved_in_else(cond: bool) {
+    let x = String::from("a");
+    take(x.clone());
+    if cond {
+        take(String::new());
+    } else {
+        take(x);
+    }
+}
+
+// OK, `x` is borrowed in one arm only
+fn borrowed_in_one_arm(n: u8) {
+    let x = String::from("a");
+    take(x.clone());
+    match n {
+        0 => println!("{}", x),
+        1 => take(String::new()),
+        _ => {},
+    }
+}
+
+// OK, `x` is used after the branches join
+fn used_after_join(cond: bool) {
+    let x = String::from("a");
+    take(x.clone());
+    if cond {
+        take(String::new());
+    } else {
+        take(String::from("b"));
+    }
+    take(x);
+}
+
+fn unused_in_all_branches(cond: bool) {
+    let x = String::from("a");
+    take(x.clone());
+    if cond {
+        take(String::new());
+    } else {
+        take(String::from("b"));
+    }
+}
+
+// Only the clone in the branch which doesn't use `x` afterwards is redundant
+fn clone_in_branches(cond: bool) {
+    let x = String::from("a");
+    if cond {
+        take(x.clone());
+    } else {
+        take(x.clone());
+        println!("{}", x);
+    }
+}
+
+fn main() {}
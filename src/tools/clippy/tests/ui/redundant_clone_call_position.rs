@@ -0,0 +1,28 @@
+// run-rustfix
+
+#![warn(clippy::redundant_clone)]
+
+fn main() {
+    // the cloned value is the receiver
+    let s = String::from("a");
+    let _s = s.clone();
+
+    let tup = (String::from("b"), 1);
+    let _t = tup.0.clone();
+
+    let s = String::from("c");
+    let _s = s.to_owned();
+
+    // the cloned value is an argument
+    let s = String::from("d");
+    let _s = Clone::clone(&s);
+
+    let s = String::from("e");
+    let _s = String::clone(&s);
+
+    let s = String::from("f");
+    let _s = ToOwned::to_owned(&s);
+
+    let tup = (String::from("g"), 1);
+    let _t = Clone::clone(&tup.0);
+}
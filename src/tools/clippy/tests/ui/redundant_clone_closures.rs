@@ -0,0 +1,44 @@
+// run-rustfix
+
+#![warn(clippy::redundant_clone)]
+
+fn consume(_: String) {}
+
+fn main() {
+    // Upvars captured by value into a `FnOnce` closure can be moved out
+    let s = String::from("foo");
+    let u = String::from("bar");
+    let f = move || {
+        let t = s.clone();
+        consume(t);
+        consume(u);
+    };
+    f();
+
+    // OK, the upvar is used again after the clone
+    let s = String::from("foo");
+    let f = move || {
+        let t = s.clone();
+        consume(t);
+        consume(s);
+    };
+    f();
+
+    // OK, `Fn` closure: the environment is borrowed, the upvar can't be moved out
+    let s = String::from("foo");
+    let f = move || {
+        let t = s.clone();
+        consume(t);
+    };
+    f();
+    f();
+
+    // OK, captured by reference
+    let s = String::from("foo");
+    let f = || {
+        let t = s.clone();
+        consume(t);
+    };
+    f();
+    consume(s);
+}
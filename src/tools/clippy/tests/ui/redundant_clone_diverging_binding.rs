@@ -0,0 +1,43 @@
+// run-rustfix
+#![warn(clippy::redundant_clone)]
+#![allow(dead_code)]
+
+fn takes(_: String) {}
+
+fn early_return(opt: Option<String>) {
+    let name = match opt {
+        Some(name) => name,
+        None => return,
+    };
+    takes(name.clone());
+}
+
+fn early_continue(items: Vec<Option<String>>) {
+    for item in items {
+        let name = match item {
+            Some(name) => name,
+            None => continue,
+        };
+        takes(name.clone());
+    }
+}
+
+fn early_panic(res: Result<String, ()>) -> String {
+    let name = match res {
+        Ok(name) => name,
+        Err(()) => panic!("no name"),
+    };
+    name.clone()
+}
+
+// OK, the binding is used after the clone
+fn used_later(opt: Option<String>) {
+    let name = match opt {
+        Some(name) => name,
+        None => return,
+    };
+    takes(name.clone());
+    takes(name);
+}
+
+fn main() {}
@@ -0,0 +1,58 @@
+// run-rustfix
+#![warn(clippy::redundant_clone_in_enum_variant_payload)]
+#![allow(dead_code)]
+
+#[derive(Clone)]
+struct Payload(String);
+
+enum Message {
+    Text(String),
+    Pair(String, String),
+    Named { id: u32, payload: Payload },
+}
+
+fn text() -> Message {
+    let s = String::from("a");
+    Message::Text(s.clone())
+}
+
+// Only the second field is a dead clone
+fn pair_second() -> (Message, String) {
+    let a = String::from("a");
+    let b = String::from("b");
+    let m = Message::Pair(a.clone(), b.clone());
+    (m, a)
+}
+
+// Only the first field is a dead clone
+fn pair_first() -> (Message, String) {
+    let a = String::from("a");
+    let b = String::from("b");
+    let m = Message::Pair(a.clone(), b.clone());
+    (m, b)
+}
+
+// Fields written in a different order than declared
+fn named() -> Message {
+    let p = Payload(String::from("p"));
+    Message::Named {
+        payload: p.clone(),
+        id: 1,
+    }
+}
+
+// OK, both originals are used afterwards
+fn used_later() -> (Message, String, String) {
+    let a = String::from("a");
+    let b = String::from("b");
+    (Message::Pair(a.clone(), b.clone()), a, b)
+}
+
+// OK, `Option` is covered by `redundant_clone`
+#[allow(clippy::redundant_clone)]
+fn option() -> Option<String> {
+    let s = String::from("a");
+    Some(s.clone())
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+// run-rustfix
+#![warn(clippy::redundant_clone)]
+#![allow(dead_code)]
+
+use std::collections::VecDeque;
+
+fn take<T>(_: T) {}
+
+fn sized(v: Vec<u64>, d: VecDeque<(u8, u32)>, b: Box<[u16; 4]>) {
+    take(v.clone());
+    take(d.clone());
+    take(b.clone());
+}
+
+// No estimate, the size is not known statically or nothing is allocated
+fn unknown<T: Clone>(v: Vec<T>, s: String, b: Box<[u8]>, z: Vec<()>) {
+    take(v.clone());
+    take(s.clone());
+    take(b.clone());
+    take(z.clone());
+}
+
+fn main() {}
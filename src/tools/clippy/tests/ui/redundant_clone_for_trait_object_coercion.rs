@@ -0,0 +1,29 @@
+// run-rustfix
+#![warn(clippy::redundant_clone_for_trait_object_coercion)]
+#![allow(dead_code)]
+
+use std::fmt::{Debug, Display};
+use std::rc::Rc;
+
+fn boxed(name: String) -> Box<dyn Display> {
+    Box::new(name.clone()) as Box<dyn Display>
+}
+
+fn implicit(name: String) -> Box<dyn Display> {
+    Box::new(name.clone())
+}
+
+fn shared(name: String) -> Rc<dyn Debug> {
+    Rc::new(name.clone())
+}
+
+fn already_boxed(value: Box<String>) -> Box<dyn Display> {
+    value.clone()
+}
+
+// OK, the original is used afterwards
+fn used_later(name: String) -> (Box<dyn Display>, String) {
+    (Box::new(name.clone()), name)
+}
+
+fn main() {}
@@ -0,0 +1,44 @@
+// run-rustfix
+#![warn(clippy::redundant_clone)]
+#![allow(dead_code)]
+
+use std::fmt::{self, Write as _};
+use std::io::Write as _;
+
+struct Wrapper(String);
+
+impl fmt::Display for Wrapper {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.clone())?;
+        writeln!(f, "{:?}", self.0.clone())
+    }
+}
+
+fn main() {
+    let s = String::from("foo");
+    let v = vec![1, 2, 3];
+
+    let _ = format!("{} {:?}", s.clone(), v.clone());
+    print!("{}", s.clone());
+    println!("{}", s.to_owned());
+    eprint!("{:?}", v.clone());
+    eprintln!("{0} {0}", s.clone());
+    println!("{:>8}", s.clone());
+
+    let mut buf = String::new();
+    write!(buf, "{}", s.clone()).unwrap();
+    writeln!(buf, "{}", s.clone()).unwrap();
+    let mut out = Vec::new();
+    writeln!(out, "{:?}", v.clone()).unwrap();
+
+    // Closures are checked on their own
+    let f = || println!("{}", s.clone());
+    f();
+
+    // OK, nothing is cloned or the clone is not a direct argument
+    println!("{}", s);
+    println!("{}", s.len());
+    println!("{:?}", Some(s.clone()));
+
+    drop((s, v));
+}
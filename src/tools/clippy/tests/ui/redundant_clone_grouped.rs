@@ -0,0 +1,40 @@
+// run-rustfix
+
+#![warn(clippy::redundant_clone)]
+
+fn take(_: String) {}
+
+fn cloned_in_branches(cond: bool) {
+    let x = String::from("a");
+    if cond {
+        take(x.clone());
+    } else {
+        take(x.clone());
+    }
+}
+
+fn cloned_in_arms(n: u8) {
+    let x = String::from("a");
+    match n {
+        0 => take(x.clone()),
+        1 => take(x.to_owned()),
+        _ => take(x.clone()),
+    }
+}
+
+// Only one of the clones is redundant, it is reported on its own
+fn one_redundant(cond: bool) {
+    let x = String::from("a");
+    if cond {
+        take(x.clone());
+    } else {
+        take(x.clone());
+        println!("{}", x);
+    }
+}
+
+fn main() {
+    cloned_in_branches(true);
+    cloned_in_arms(0);
+    one_redundant(true);
+}
@@ -0,0 +1,65 @@
+// run-rustfix
+#![warn(clippy::redundant_clone_in_builder_with_method)]
+#![allow(dead_code, clippy::redundant_clone)]
+
+#[derive(Default)]
+struct RequestBuilder {
+    name: String,
+    title: String,
+    tags: Vec<String>,
+    entries: Vec<(String, String)>,
+}
+
+impl RequestBuilder {
+    fn with_name(mut self, name: String) -> Self {
+        self.name = name;
+        self
+    }
+
+    fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    fn with_entry(mut self, key: String, value: String) -> Self {
+        self.entries.push((key, value));
+        self
+    }
+
+    fn set_title(&mut self, title: String) {
+        self.title = title;
+    }
+
+    fn name(mut self, name: String) -> Self {
+        self.name = name;
+        self
+    }
+}
+
+fn with_name(name: String) -> RequestBuilder {
+    RequestBuilder::default().with_name(name.clone())
+}
+
+fn with_tags(tags: Vec<String>) -> RequestBuilder {
+    RequestBuilder::default().with_tags(tags.clone())
+}
+
+fn with_entry(value: String) -> RequestBuilder {
+    RequestBuilder::default().with_entry(String::from("key"), value.clone())
+}
+
+fn set_title(builder: &mut RequestBuilder, title: String) {
+    builder.set_title(title.clone());
+}
+
+// OK, the original is used afterwards
+fn used_later(name: String) -> (RequestBuilder, String) {
+    (RequestBuilder::default().with_name(name.clone()), name)
+}
+
+// OK, not a `with_*` or `set_*` method
+fn not_a_builder_method(name: String) -> RequestBuilder {
+    RequestBuilder::default().name(name.clone())
+}
+
+fn main() {}
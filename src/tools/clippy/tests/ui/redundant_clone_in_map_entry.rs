@@ -0,0 +1,21 @@
+// run-rustfix
+#![warn(clippy::redundant_clone_in_map_entry)]
+#![allow(dead_code)]
+
+use std::collections::{BTreeMap, HashMap};
+
+fn hash_map(map: &mut HashMap<u32, String>, s: String) {
+    map.entry(0).or_insert(s.clone());
+}
+
+fn btree_map(map: &mut BTreeMap<u32, Vec<u8>>, v: Vec<u8>) {
+    map.entry(0).or_insert(v.clone()).push(1);
+}
+
+// OK, the original is used afterwards
+fn used_later(map: &mut HashMap<u32, String>, s: String) -> String {
+    map.entry(0).or_insert(s.clone());
+    s
+}
+
+fn main() {}
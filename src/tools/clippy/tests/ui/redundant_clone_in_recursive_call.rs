@@ -0,0 +1,34 @@
+// run-rustfix
+#![warn(clippy::redundant_clone_in_recursive_call)]
+#![allow(dead_code)]
+
+fn walk(depth: u32, path: String) -> usize {
+    if depth == 0 {
+        return path.len();
+    }
+    walk(depth - 1, path.clone())
+}
+
+struct Tree {
+    children: Vec<Tree>,
+}
+
+impl Tree {
+    fn collect(&self, depth: u32, mut acc: Vec<u32>) -> Vec<u32> {
+        acc.push(depth);
+        match self.children.first() {
+            Some(child) => child.collect(depth + 1, acc.clone()),
+            None => acc,
+        }
+    }
+}
+
+// OK, the original is used after the recursive call
+fn used_after(depth: u32, path: String) -> usize {
+    if depth == 0 {
+        return path.len();
+    }
+    walk(depth - 1, path.clone()) + used_after(depth - 1, path.clone()) + path.len()
+}
+
+fn main() {}
@@ -0,0 +1,33 @@
+// run-rustfix
+#![warn(clippy::redundant_clone_in_sort_dedup)]
+#![allow(dead_code)]
+
+fn sort_dedup(orig: Vec<u32>) -> Vec<u32> {
+    let mut v = orig.clone();
+    v.sort();
+    v.dedup();
+    v
+}
+
+fn sort_by_key(orig: Vec<(u32, u32)>) -> Vec<(u32, u32)> {
+    let mut v = orig.clone();
+    v.sort_unstable_by_key(|&(k, _)| k);
+    v
+}
+
+fn dedup_only(orig: &[u32]) -> Vec<u32> {
+    let orig = orig.to_vec();
+    let mut v = orig.clone();
+    v.dedup();
+    v
+}
+
+// OK, the original is used afterwards
+fn used_later(orig: Vec<u32>) -> (Vec<u32>, Vec<u32>) {
+    let mut v = orig.clone();
+    v.sort();
+    v.dedup();
+    (v, orig)
+}
+
+fn main() {}
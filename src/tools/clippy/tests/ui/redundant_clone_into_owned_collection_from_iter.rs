@@ -0,0 +1,48 @@
+// run-rustfix
+
+#![warn(clippy::redundant_clone_into_owned_collection_from_iter)]
+#![allow(dead_code, clippy::map_clone, clippy::ptr_arg)]
+
+use std::collections::HashSet;
+
+fn moved(names: Vec<String>) -> Vec<String> {
+    names.iter().map(|name| name.clone()).collect()
+}
+
+fn borrowed(names: &Vec<String>) -> Vec<String> {
+    names.iter().map(|name| name.clone()).collect()
+}
+
+fn used_afterwards() {
+    let names = vec![String::from("a")];
+    let copy: Vec<String> = names.iter().map(Clone::clone).collect();
+    println!("{:?} {:?}", names, copy);
+}
+
+fn set(set: HashSet<String>) -> HashSet<String> {
+    set.iter().map(|s| s.clone()).collect::<HashSet<_>>()
+}
+
+fn in_loop() {
+    let names = vec![String::from("a")];
+    for _ in 0..2 {
+        let _copy: Vec<String> = names.iter().map(|name| name.clone()).collect();
+    }
+}
+
+// Not linted, the collection types differ
+fn other_collection(names: Vec<String>) -> HashSet<String> {
+    names.iter().map(|name| name.clone()).collect()
+}
+
+// Not linted, the elements are not cloned
+fn transformed(names: &Vec<String>) -> Vec<String> {
+    names.iter().map(|name| name.to_uppercase()).collect()
+}
+
+// Not linted, a slice is not a `Vec`
+fn slice(names: &[String]) -> Vec<String> {
+    names.iter().map(|name| name.clone()).collect()
+}
+
+fn main() {}
@@ -0,0 +1,39 @@
+// run-rustfix
+
+#![feature(stmt_expr_attributes)]
+#![warn(clippy::redundant_clone)]
+
+fn take(_: String) {}
+
+fn main() {
+    let a = String::from("a");
+    #[clippy::keep_clone]
+    let _a = a.clone();
+
+    let b = String::from("b");
+    #[clippy::keep_clone]
+    take(b.clone());
+
+    let c = String::from("c");
+    take(
+        #[clippy::keep_clone]
+        c.clone(),
+    );
+
+    // Only the annotated clone is kept
+    let e = String::from("e");
+    let f = String::from("f");
+    #[clippy::keep_clone]
+    let _e = e.clone();
+    let _f = f.clone();
+
+    let g = String::from("g");
+    let h = String::from("h");
+    let closure = move || {
+        #[clippy::keep_clone]
+        let g2 = g.clone();
+        take(g2);
+        take(h);
+    };
+    closure();
+}
@@ -0,0 +1,48 @@
+// run-rustfix
+
+#![warn(clippy::redundant_clone)]
+#![allow(clippy::never_loop)]
+
+fn break_with_clone(x: String) -> String {
+    let mut i = 0;
+    loop {
+        i += 1;
+        if i > 3 {
+            break x.clone();
+        }
+    }
+}
+
+fn break_with_clone_unconditionally(x: String) -> String {
+    loop {
+        break x.clone();
+    }
+}
+
+fn break_with_clone_of_loop_local() -> String {
+    let mut i = 0;
+    loop {
+        let s = i.to_string();
+        i += 1;
+        if i > 3 {
+            break s.clone();
+        }
+    }
+}
+
+// `x` is cloned again in the next iteration, the clone is needed
+fn clone_on_every_iteration(x: String) -> String {
+    loop {
+        let y = x.clone();
+        if y.len() > 3 {
+            break y;
+        }
+    }
+}
+
+fn main() {
+    let _ = break_with_clone(String::new());
+    let _ = break_with_clone_unconditionally(String::new());
+    let _ = break_with_clone_of_loop_local();
+    let _ = clone_on_every_iteration(String::from("abcd"));
+}
@@ -0,0 +1,33 @@
+// run-rustfix
+// rustfix-only-machine-applicable
+#![warn(clippy::redundant_clone)]
+#![allow(dead_code)]
+
+#[derive(Clone)]
+enum Shape {
+    Circle(f64),
+    Named(String),
+}
+
+fn by_ref(shape: &Shape, opt: &Option<String>) {
+    let _ = matches!(shape.clone(), Shape::Circle(_));
+    let _ = matches!(opt.clone(), None);
+}
+
+fn main() {
+    let shape = Shape::Named(String::from("square"));
+    let opt = Some(String::from("foo"));
+    let v = vec![1, 2, 3];
+
+    let _ = matches!(shape.clone(), Shape::Circle(_));
+    let _ = matches!(shape.clone(), Shape::Circle(r) if r > 1.0);
+    let _ = matches!(opt.clone(), Some(ref s) if s.is_empty());
+    let _ = matches!(opt.to_owned(), Some(_));
+
+    // OK, the pattern moves out of the clone
+    let _ = matches!(v.to_owned(), v if v.is_empty());
+    let _ = matches!(shape.clone(), Shape::Named(name) if name.is_empty());
+    let _ = matches!(opt.clone(), Some(s) if s.is_empty());
+
+    drop((shape, opt, v));
+}
@@ -0,0 +1,30 @@
+// run-rustfix
+#![warn(clippy::redundant_clone)]
+#![allow(dead_code)]
+
+use std::mem;
+
+struct Buffer {
+    text: String,
+}
+
+fn replace_name(name: &mut String, new: String) -> String {
+    mem::replace(name, new.clone())
+}
+
+fn swap_text(buf: &mut Buffer, text: String) -> String {
+    std::mem::replace(&mut buf.text, text.clone())
+}
+
+fn replace_local(new: String) -> usize {
+    let mut current = String::from("old");
+    let old = mem::replace(&mut current, new.clone());
+    old.len() + current.len()
+}
+
+// OK, the original is used afterwards
+fn used_later(name: &mut String, new: String) -> (String, String) {
+    (mem::replace(name, new.clone()), new)
+}
+
+fn main() {}
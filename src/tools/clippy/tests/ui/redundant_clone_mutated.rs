@@ -0,0 +1,50 @@
+// run-rustfix
+#![warn(clippy::redundant_clone)]
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+fn push(existing: Vec<u32>) -> Vec<u32> {
+    let mut v = existing.clone();
+    v.push(1);
+    v
+}
+
+fn extend_and_sort(existing: Vec<u32>) -> Vec<u32> {
+    let mut v = existing.clone();
+    v.extend(&[3, 2]);
+    v.sort_unstable();
+    v
+}
+
+fn insert(existing: HashMap<u32, u32>) -> HashMap<u32, u32> {
+    let mut m = existing.clone();
+    m.insert(1, 2);
+    m
+}
+
+// Overwriting the source does not need the cloned value
+fn overwritten(mut existing: Vec<u32>) -> (Vec<u32>, Vec<u32>) {
+    let mut v = existing.clone();
+    v.push(1);
+    existing = Vec::new();
+    (v, existing)
+}
+
+// OK, the source is mutated and used afterwards
+fn source_mutated(mut existing: Vec<u32>) -> (Vec<u32>, Vec<u32>) {
+    let mut v = existing.clone();
+    v.push(1);
+    existing.push(2);
+    (v, existing)
+}
+
+// OK, only a part of the source is overwritten
+fn partially_overwritten(mut existing: (Vec<u32>, u32)) -> ((Vec<u32>, u32), (Vec<u32>, u32)) {
+    let mut v = existing.clone();
+    v.1 = 1;
+    existing.1 = 2;
+    (v, existing)
+}
+
+fn main() {}
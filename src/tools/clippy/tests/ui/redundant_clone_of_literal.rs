@@ -0,0 +1,27 @@
+// run-rustfix
+#![warn(clippy::redundant_clone_of_literal)]
+#![allow(clippy::redundant_clone)]
+
+use std::path::PathBuf;
+
+fn main() {
+    let _s = "x".to_owned().clone();
+    let _s = "x".to_string().clone();
+    let _s = String::new().clone();
+    let _p = PathBuf::from("/a").clone();
+
+    // OK, clones of named bindings are left to `redundant_clone`
+    let s = "x".to_owned();
+    let _s = s.clone();
+
+    defaults();
+}
+
+fn defaults() {
+    let _v = Vec::<u8>::default().clone();
+    let _s = String::default().clone();
+    let _p = <PathBuf as Default>::default().clone();
+    for _ in 0..2 {
+        let _v = Vec::<u8>::default().clone();
+    }
+}
@@ -0,0 +1,40 @@
+// run-rustfix
+
+#![warn(clippy::redundant_clone)]
+#![allow(clippy::or_fun_call, clippy::clone_inside_unwrap_or_else_closure)]
+
+fn get_or_insert(mut opt: Option<String>) -> usize {
+    let x = String::from("foo");
+    opt.get_or_insert(x.clone()).len()
+}
+
+fn unwrap_or(opt: Option<String>) -> String {
+    let x = String::from("foo");
+    opt.unwrap_or(x.clone())
+}
+
+fn map_or(opt: Option<String>) -> String {
+    let x = String::from("foo");
+    opt.map_or(x.clone(), |s| s)
+}
+
+// OK, `x` is used after the call
+fn unwrap_or_used_later(opt: Option<String>) -> (String, String) {
+    let x = String::from("foo");
+    let s = opt.unwrap_or(x.clone());
+    (s, x)
+}
+
+// OK, `x` is borrowed by the closure
+fn unwrap_or_else(opt: Option<String>) -> String {
+    let x = String::from("foo");
+    opt.unwrap_or_else(|| x.clone())
+}
+
+fn main() {
+    get_or_insert(None);
+    unwrap_or(None);
+    map_or(None);
+    unwrap_or_used_later(None);
+    unwrap_or_else(None);
+}
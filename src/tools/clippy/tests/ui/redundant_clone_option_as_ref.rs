@@ -0,0 +1,38 @@
+// run-rustfix
+
+#![warn(clippy::redundant_clone)]
+#![allow(dead_code)]
+
+fn take(_: Option<String>) {}
+
+fn dead_after(opt: Option<String>) -> Option<String> {
+    opt.as_ref().cloned()
+}
+
+fn passed_on(opt: Option<Vec<u8>>) {
+    let len = opt.as_ref().map_or(0, Vec::len);
+    take(opt.as_ref().cloned().map(|v| format!("{:?}", v)));
+    let _ = len;
+}
+
+fn into_binding(opt: Option<String>) -> usize {
+    let copy = opt.as_ref().cloned();
+    copy.map_or(0, |s| s.len())
+}
+
+// OK, `opt` is used afterwards
+fn used_later(opt: Option<String>) -> (Option<String>, Option<String>) {
+    (opt.as_ref().cloned(), opt)
+}
+
+// OK, `opt` is borrowed
+fn borrowed(opt: &Option<String>) -> Option<String> {
+    opt.as_ref().cloned()
+}
+
+// OK, the elements are `Copy`
+fn copy_elements(opt: Option<u32>) -> Option<u32> {
+    opt.as_ref().cloned()
+}
+
+fn main() {}
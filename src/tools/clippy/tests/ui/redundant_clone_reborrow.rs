@@ -0,0 +1,44 @@
+// run-rustfix
+// rustfix-only-machine-applicable
+#![warn(clippy::redundant_clone)]
+#![allow(dead_code, clippy::ptr_arg)]
+
+use std::rc::Rc;
+
+fn takes_str(_: &str) {}
+
+fn takes_slice(_: &[u8]) {}
+
+fn takes_u32(_: &u32) {}
+
+fn takes_str_and_string(_: &str, _: String) {}
+
+fn by_ref(s: &String, v: &Vec<u8>, t: &str) {
+    takes_str(&*s.clone());
+    takes_slice(&*v.clone());
+    takes_str(&*t.to_owned());
+}
+
+fn main() {
+    let s = String::from("foo");
+    let v = vec![1u8, 2, 3];
+    let b = Box::new(1u32);
+    let r = Rc::new(2u32);
+
+    takes_str(&*s.clone());
+    takes_slice(&*v.to_owned());
+    takes_u32(&*b.clone());
+    takes_u32(&*r.clone());
+
+    // Not machine applicable, the borrow lives until the end of the block
+    let first = &*b.clone();
+    takes_u32(first);
+
+    // Not machine applicable, `s` is moved while the borrow is alive
+    takes_str_and_string(&*s.clone(), s);
+
+    // OK, the clone is mutated
+    let mut w = vec![3u8, 1];
+    (&mut *w.clone()).sort();
+    w.push(4);
+}
@@ -0,0 +1,20 @@
+// run-rustfix
+#![warn(clippy::redundant_clone)]
+#![allow(dead_code)]
+
+fn add_string(a: String, b: String) -> String {
+    a.clone() + &b
+}
+
+fn add_str(a: String) -> usize {
+    let s = a.clone() + "!";
+    s.len()
+}
+
+// OK, the original is used afterwards
+fn used_later(a: String, b: &str) -> (String, String) {
+    let s = a.clone() + b;
+    (s, a)
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+#![warn(clippy::redundant_clone)]
+
+// The casts are no-ops, which move the borrow through one temporary per cast before it reaches
+// the `clone` call, like `_4 = &_1; _3 = move _4; _2 = clone(move _3);`
+
+fn one_hop(s: String) -> String {
+    Clone::clone(&s as &String)
+}
+
+fn two_hops(s: String) -> String {
+    Clone::clone(&s as &String as &String)
+}
+
+fn three_hops(s: String) -> String {
+    ToOwned::to_owned(&s as &String as &String as &String)
+}
+
+// OK, the original is used afterwards
+fn used_later(s: String) -> (String, String) {
+    let t = Clone::clone(&s as &String as &String);
+    (t, s)
+}
+
+fn main() {}
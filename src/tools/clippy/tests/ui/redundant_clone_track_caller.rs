@@ -0,0 +1,47 @@
+// run-rustfix
+
+// The caller location of `#[track_caller]` functions is an implicit argument added during
+// codegen, it neither shows up in their MIR nor in the MIR of their callers.
+
+use std::panic::Location;
+
+struct Tracked(String);
+
+impl Clone for Tracked {
+    #[track_caller]
+    fn clone(&self) -> Self {
+        let _ = Location::caller();
+        Tracked(self.0.clone())
+    }
+}
+
+#[track_caller]
+fn consume(s: String) -> usize {
+    let t = s.clone();
+    t.len()
+}
+
+fn clone_tracked(tracked: Tracked) -> usize {
+    let copy = tracked.clone();
+    copy.0.len()
+}
+
+#[track_caller]
+fn clone_tracked_in_tracked(tracked: Tracked) -> usize {
+    let copy = tracked.clone();
+    copy.0.len()
+}
+
+// should not lint, `s` is used after the clone
+#[track_caller]
+fn used_after(s: String) -> usize {
+    let t = s.clone();
+    t.len() + s.len()
+}
+
+fn main() {
+    consume(String::new());
+    clone_tracked(Tracked(String::new()));
+    clone_tracked_in_tracked(Tracked(String::new()));
+    used_after(String::new());
+}
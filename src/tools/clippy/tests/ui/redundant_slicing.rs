@@ -0,0 +1,66 @@
+// run-rustfix
+
+#![warn(clippy::redundant_slicing)]
+#![allow(dead_code, clippy::boxed_local, clippy::ptr_arg)]
+
+fn takes_str(_: &str) {}
+
+fn takes_slice(_: &[u8]) {}
+
+fn takes_generic<T>(_: T) {}
+
+struct S;
+
+impl S {
+    fn takes_str(&self, _: &str) {}
+}
+
+fn arguments(s: String, r: &String, v: Vec<u8>, a: [u8; 4], b: Box<[u8]>, st: &str) {
+    takes_str(&s[..]);
+    takes_str(&r[..]);
+    takes_str(&String::from("foo")[..]);
+    takes_slice(&v[..]);
+    takes_slice(&a[..]);
+    takes_slice(&b[..]);
+    takes_str(&st[..]);
+    S.takes_str(&s[..]);
+    takes_str(&*r);
+    takes_str(&**r);
+    takes_generic(&**r);
+}
+
+fn let_bindings(s: String, r: &String, v: Vec<u8>) {
+    let _: &str = &s[..];
+    let _: &[u8] = &v[..];
+    let _ = &s[..];
+    let _ = &r[..];
+    let _ = &v[..];
+    let _ = &**r;
+}
+
+fn returns(r: &String) -> &str {
+    &r[..]
+}
+
+fn returns_early(r: &String, early: bool) -> &str {
+    if early {
+        return &r[..];
+    }
+    &**r
+}
+
+fn ok(s: String, a: [u8; 4], r: &String) {
+    // not the whole range
+    takes_str(&s[1..]);
+    // arrays have no `as_slice()`
+    let _ = &a[..];
+    // `String::from("foo").as_str()` would not live as long
+    let _ = &String::from("foo")[..];
+    // a `&String` is expected
+    takes_generic(&*r);
+    // mutable slicing
+    let mut s = s;
+    let _ = &mut s[..];
+}
+
+fn main() {}
@@ -73,7 +73,27 @@ fn trivial_regex() {
     let non_trivial_binary_builder = BRegexBuilder::new("foo|bar");
 }
 
+fn other_sources() {
+    static STATIC_PAREN: &str = "(";
+    static STATIC_FOOBAR: &str = "foobar";
+
+    // the error position is found in the source of the literal
+    let escaped_error = Regex::new("\\d\t\\q");
+    let unicode_escaped_error = Regex::new("\u{e9}[z-a]");
+    let raw_string_error = Regex::new(r"(?-u)\xFF+");
+    let non_utf8_binary = BRegex::new(r"(?-u)\xFF+");
+
+    let static_error = Regex::new(STATIC_PAREN);
+    let static_trivial = Regex::new(STATIC_FOOBAR);
+
+    let vec_set_error = RegexSet::new(vec![r"[a-z]+", OPENING_PAREN]);
+    let vec_bset_error = BRegexSet::new(vec!["a+", "(b"]);
+    let set_trivial = RegexSet::new(&["^foobar$", "a.b"]);
+    let binary_builder_trivial = BRegexBuilder::new("foobar");
+}
+
 fn main() {
     syntax_error();
     trivial_regex();
+    other_sources();
 }
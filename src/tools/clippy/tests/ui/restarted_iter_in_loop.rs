@@ -0,0 +1,89 @@
+#![warn(clippy::restarted_iter_in_loop_condition, clippy::restarted_iter_in_loop)]
+#![allow(clippy::iter_next_slice, unused)]
+
+fn condition(v: Vec<i32>, s: &str) {
+    while v.iter().next().is_some() {
+        println!("not empty");
+    }
+
+    while let Some(c) = s.chars().next() {
+        println!("{}", c);
+    }
+
+    let mut n = 0;
+    loop {
+        if n > 10 || v.iter().next() == Some(&0) {
+            break;
+        }
+        n += 1;
+    }
+}
+
+fn body(v: &[u8], s: String) {
+    for _ in 0..3 {
+        let x = v.iter().next();
+        println!("{:?}", x);
+    }
+
+    let mut n = 0;
+    while n < 3 {
+        let first = s.bytes().next();
+        println!("{:?}", first);
+        n += 1;
+    }
+}
+
+struct Queue {
+    items: Vec<u32>,
+}
+
+impl Queue {
+    fn drain_by_pop(&mut self) {
+        // the collection shrinks on every iteration, so the condition changes
+        while self.items.iter().next().is_some() {
+            self.items.pop();
+        }
+    }
+}
+
+fn drain_by_pop(mut v: Vec<i32>, mut s: String) {
+    while v.iter().next().is_some() {
+        v.pop();
+    }
+
+    while let Some(c) = s.chars().next() {
+        println!("{}", c);
+        s.remove(0);
+    }
+
+    while let Some(&x) = v.iter().next() {
+        v.retain(|&y| y != x);
+    }
+}
+
+fn no_lint(list: Vec<Vec<i32>>, words: &[&str]) {
+    // the receiver is different on every iteration
+    for l in &list {
+        let _ = l.iter().next();
+    }
+    for w in words {
+        println!("{:?}", w.chars().next());
+    }
+
+    // the iterator is created once
+    let mut iter = list.iter();
+    while let Some(l) = iter.next() {
+        println!("{:?}", l);
+    }
+
+    // not in a loop
+    let _ = list.iter().next();
+}
+
+fn main() {
+    condition(vec![], "");
+    body(&[], String::new());
+    Queue { items: vec![1] }.drain_by_pop();
+    drain_by_pop(vec![1], String::from("a"));
+    no_lint(vec![], &[]);
+}
@@ -0,0 +1,55 @@
+#![crate_type = "lib"]
+#![warn(clippy::result_opaque_err)]
+#![allow(dead_code)]
+
+use std::error::Error;
+
+pub type BoxResult<T> = Result<T, Box<dyn Error>>;
+
+pub fn boxed_error() -> Result<u32, Box<dyn Error>> {
+    Ok(0)
+}
+
+pub fn boxed_error_alias() -> BoxResult<u32> {
+    Ok(0)
+}
+
+pub fn boxed_send_sync_error() -> Result<u32, Box<dyn Error + Send + Sync>> {
+    Ok(0)
+}
+
+pub fn string_error() -> Result<u32, String> {
+    Ok(0)
+}
+
+pub fn io_error() -> Result<u32, std::io::Error> {
+    Ok(0)
+}
+
+// Not exported
+fn private_boxed_error() -> Result<u32, Box<dyn Error>> {
+    Ok(0)
+}
+
+mod private {
+    pub fn string_error() -> Result<u32, String> {
+        Ok(0)
+    }
+}
+
+pub struct Config;
+
+impl Config {
+    pub fn load() -> Result<Self, String> {
+        Ok(Config)
+    }
+}
+
+impl std::str::FromStr for Config {
+    type Err = String;
+
+    // The signature is dictated by the trait
+    fn from_str(_: &str) -> Result<Self, String> {
+        Ok(Config)
+    }
+}
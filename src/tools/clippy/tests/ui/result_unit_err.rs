@@ -0,0 +1,49 @@
+#![warn(clippy::result_unit_err)]
+#![allow(dead_code)]
+
+type Res<T> = Result<T, ()>;
+
+fn returns_unit_error() -> Result<u32, ()> {
+    Err(())
+}
+
+fn returns_unit_error_alias() -> Res<u32> {
+    Err(())
+}
+
+fn returns_option() -> Option<u32> {
+    None
+}
+
+fn returns_error() -> Result<u32, std::fmt::Error> {
+    Err(std::fmt::Error)
+}
+
+trait Parse {
+    fn parse(&self) -> Result<u32, ()>;
+
+    fn parse_checked(&self) -> Result<u32, std::fmt::Error>;
+}
+
+struct Digit(char);
+
+impl Digit {
+    fn value(&self) -> Result<u32, ()> {
+        self.0.to_digit(10).ok_or(())
+    }
+}
+
+// The signature is dictated by the trait
+impl Parse for Digit {
+    fn parse(&self) -> Result<u32, ()> {
+        self.value()
+    }
+
+    fn parse_checked(&self) -> Result<u32, std::fmt::Error> {
+        self.value().map_err(|_| std::fmt::Error)
+    }
+}
+
+fn main() -> Result<(), ()> {
+    Ok(())
+}
@@ -65,3 +65,35 @@ pub fn negatives_side_effects() {
 }
 
 fn main() {}
+
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub fn set_x(&mut self, x: i32) {
+        self.x = self.x;
+    }
+
+    pub fn new(x: i32, y: i32) -> Self {
+        let mut p = Point { x: 0, y };
+        p.x = p.x;
+        p
+    }
+}
+
+macro_rules! assign {
+    ($a:expr) => {
+        $a = $a;
+    };
+}
+
+pub fn negatives_macro_and_volatile(mut a: i32, p: *mut i32, mut d: std::collections::VecDeque<i32>) {
+    assign!(a);
+    unsafe {
+        *p = *p;
+    }
+    // `VecDeque` implements `IndexMut`, which is user code
+    d[0] = d[0];
+}
@@ -0,0 +1,55 @@
+#![warn(clippy::shift_overflow, clippy::unchecked_shift)]
+#![allow(arithmetic_overflow)]
+
+const SHIFTED: u8 = 1 << 7;
+
+const fn const_shift(x: u32, y: u32) -> u32 {
+    x << y
+}
+
+fn constant_amount(x: u8, y: i64) {
+    let _ = 1u8 << 8;
+    let _ = x >> 8;
+    let _ = y << 64;
+    let _ = y >> -1;
+    let mut z = 1u16;
+    z <<= 16;
+    // OK
+    let _ = 1u8 << 7;
+    let _ = y >> 63;
+}
+
+fn param_amount(x: u32, y: u32, z: i8) {
+    let _ = x << y;
+    let _ = 1u64 >> y;
+    let _ = z << y;
+    if y > 8 {
+        let _ = x << y;
+    }
+}
+
+fn checked_amount(x: u32, y: u32) {
+    if y < 32 {
+        let _ = x << y;
+    }
+    if y <= 31 && x != 0 {
+        let _ = x >> y;
+    }
+    let _ = x << (y & 31);
+    let _ = x << (y % 32);
+    let amount = 3;
+    let _ = x << amount;
+}
+
+fn generic<T: std::ops::Shl<u32, Output = T>>(x: T, y: u32) -> T {
+    x << y
+}
+
+// `unchecked_shift` leaves constant amounts to `shift_overflow`
+#[allow(clippy::shift_overflow)]
+fn constant_amount_unchecked(x: u8) {
+    let _ = 1u8 << 8;
+    let _ = x << 8;
+}
+
+fn main() {}
@@ -0,0 +1,60 @@
+// run-rustfix
+// rustfix-only-machine-applicable
+#![warn(clippy::signed_modulo_index)]
+#![allow(dead_code)]
+
+const BUCKETS: i32 = 16;
+
+fn index_i32(v: &[u8], x: i32) -> u8 {
+    v[(x % 4) as usize]
+}
+
+fn get_i64(v: &[u8], x: i64, n: i64) -> Option<&u8> {
+    v.get((x % n) as usize)
+}
+
+fn bucket(x: i32, y: i32) -> usize {
+    ((x + y) % BUCKETS) as usize
+}
+
+fn negative_constant() -> usize {
+    (-7i32 % 4) as usize
+}
+
+// OK, the dividends are non-negative constants
+fn non_negative_constant() -> usize {
+    (7i32 % 4) as usize + (BUCKETS % 4) as usize
+}
+
+// OK, the dividends are checked to be non-negative
+fn guarded(v: &[u8], x: i32, y: i64) -> u8 {
+    if x >= 0 && y > 0 {
+        v[(x % 4) as usize] + v[(y % 4) as usize]
+    } else if 0 <= x {
+        v[(x % 4) as usize]
+    } else {
+        0
+    }
+}
+
+// OK, unsigned
+fn unsigned(v: &[u8], x: u32) -> u8 {
+    v[(x % 4) as usize]
+}
+
+// OK, not cast to `usize`
+fn other_cast(x: i32) -> u32 {
+    (x % 4) as u32
+}
+
+// OK, never negative
+fn euclid(v: &[u8], x: i32) -> u8 {
+    v[x.rem_euclid(4) as usize]
+}
+
+// `rem_euclid` is not const-stable
+const fn const_bucket(x: i32) -> usize {
+    (x % BUCKETS) as usize
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+// run-rustfix
+#![warn(clippy::single_char_add_str)]
+
+fn main() {
+    let mut string = String::new();
+    string += "R";
+    string += "'";
+    string += "é";
+    string += r"\";
+
+    let s = &mut string;
+    *s += "x";
+
+    // OK
+    string += "st";
+    string += "";
+    string += "❤️";
+}
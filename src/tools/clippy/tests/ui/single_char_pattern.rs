@@ -12,12 +12,7 @@ fn main() {
 
     let y = "x";
     x.split(y);
-    // Not yet testing for multi-byte characters
-    // Changing `r.len() == 1` to `r.chars().count() == 1` in `lint_clippy::single_char_pattern`
-    // should have done this but produced an ICE
-    //
-    // We may not want to suggest changing these anyway
-    // See: https://github.com/rust-lang/rust-clippy/issues/650#issuecomment-184328984
+    // Multi-byte characters
     x.split("ß");
     x.split("ℝ");
     x.split("💣");
@@ -39,6 +34,10 @@ fn main() {
     x.rmatch_indices("x");
     x.trim_start_matches("x");
     x.trim_end_matches("x");
+    x.trim_matches("x");
+    x.strip_prefix("x");
+    x.strip_suffix("x");
+    x.replacen("x", "y", 1);
     // Make sure we escape characters correctly.
     x.split("\n");
     x.split("'");
@@ -50,9 +49,10 @@ fn main() {
     x.replace(";", ",").split(","); // issue #2978
     x.starts_with("\x03"); // issue #2996
 
-    // Issue #3204
+    // Constants are resolved
     const S: &str = "#";
     x.find(S);
+    x.split(&S);
 
     // Raw string
     x.split(r"a");
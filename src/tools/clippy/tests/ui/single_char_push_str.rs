@@ -12,4 +12,16 @@ fn main() {
     string.push_str("\x52");
     string.push_str("\u{0052}");
     string.push_str(r##"a"##);
+    string.push_str("é");
+    string.push_str(r"\");
+    string.push_str(r#"'"#);
+    string.push_str(r#"""#);
+    string.push_str("\"");
+    string.push_str(&'x'.to_string());
+    let c = 'y';
+    string.push_str(&c.to_string());
+
+    // Not a single `char`
+    string.push_str("❤️");
+    string.push_str(&"z".to_string());
 }
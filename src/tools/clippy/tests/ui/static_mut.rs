@@ -0,0 +1,30 @@
+#![warn(clippy::static_mut)]
+#![allow(dead_code)]
+
+use std::ptr;
+
+static mut COUNTER: u32 = 0;
+static mut READY: bool = false;
+static mut OFFSET: isize = -1;
+static mut HEAD: *mut u8 = ptr::null_mut();
+static mut TOTAL: u128 = 0;
+
+#[derive(Clone, Copy)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+static mut ORIGIN: Point = Point { x: 0, y: 0 };
+static mut NAMES: Vec<String> = Vec::new();
+
+// not mutable
+static LIMIT: u32 = 10;
+
+fn main() {
+    unsafe {
+        COUNTER += 1;
+        NAMES.push(String::from("main"));
+    }
+    let _ = LIMIT;
+}
@@ -4,7 +4,7 @@
 extern crate macro_rules;
 
 #[warn(clippy::string_add)]
-#[allow(clippy::string_add_assign, unused)]
+#[allow(clippy::string_add_assign, clippy::manual_empty_new, unused)]
 fn main() {
     // ignores assignment distinction
     let mut x = "".to_owned();
@@ -1,6 +1,6 @@
 // run-rustfix
 
-#[allow(clippy::string_add, unused)]
+#[allow(clippy::string_add, clippy::manual_empty_new, unused)]
 #[warn(clippy::string_add_assign)]
 fn main() {
     // ignores assignment distinction
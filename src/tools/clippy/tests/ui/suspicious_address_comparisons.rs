@@ -0,0 +1,50 @@
+#![warn(clippy::suspicious_address_comparisons)]
+#![allow(clippy::fn_address_comparisons)]
+
+use std::ptr;
+use std::rc::Rc;
+
+fn a() {}
+fn b() {}
+
+struct Node {
+    value: u32,
+}
+
+impl Node {
+    fn same_node(&self, other: &Node) -> bool {
+        ptr::eq(self, other)
+    }
+}
+
+fn main() {
+    let x = 1;
+    let y = 1;
+    let _ = &x as *const _ == &y as *const _;
+    let _ = &x as *const i32 != &y as *const i32;
+
+    let f: fn() = a;
+    let g: fn() = b;
+    let _ = f == g;
+    let _ = f != g;
+
+    let s = [1, 2, 3];
+    let _ = ptr::eq(&s[..], &s[..2]);
+    let _ = ptr::eq("abc", &"abcd"[..3]);
+
+    // These should be fine:
+    let p = &x as *const i32;
+    let q = &y as *const i32;
+    let _ = p == q;
+    let _ = &x as *const i32 as *const u8 == &y as *const i32 as *const u8;
+    let _ = ptr::eq(&x, &y);
+    let _ = ptr::eq(s.as_ptr(), s[1..].as_ptr());
+
+    let r = Rc::new(vec![1]);
+    let r2 = Rc::clone(&r);
+    let _ = Rc::ptr_eq(&r, &r2);
+
+    let n = Node { value: 1 };
+    let _ = n.same_node(&n);
+    let _ = n.value;
+}
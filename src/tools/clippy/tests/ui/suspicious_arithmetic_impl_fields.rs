@@ -0,0 +1,105 @@
+#![warn(clippy::suspicious_arithmetic_impl, clippy::suspicious_op_assign_impl)]
+
+use std::ops::{Add, Mul, Neg, Sub, SubAssign};
+
+#[derive(Clone, Copy)]
+struct Vec2 {
+    x: i32,
+    y: i32,
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, rhs: Vec2) -> Vec2 {
+        Vec2 {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, rhs: Vec2) -> Vec2 {
+        Vec2 {
+            x: self.x - rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl SubAssign for Vec2 {
+    fn sub_assign(&mut self, rhs: Vec2) {
+        self.x -= rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        Vec2 { x: -self.x, y: !self.y }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Counter(u8);
+
+impl Add for Counter {
+    type Output = Counter;
+
+    fn add(self, rhs: Counter) -> Counter {
+        Counter(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl Sub for Counter {
+    type Output = Counter;
+
+    fn sub(self, rhs: Counter) -> Counter {
+        Counter(self.0.wrapping_add(rhs.0))
+    }
+}
+
+// OK, complex multiplication mixes `*`, `-` and `+`
+#[derive(Clone, Copy)]
+struct Complex {
+    re: f64,
+    im: f64,
+}
+
+impl Mul for Complex {
+    type Output = Complex;
+
+    fn mul(self, rhs: Complex) -> Complex {
+        Complex {
+            re: self.re * rhs.re - self.im * rhs.im,
+            im: self.re * rhs.im + self.im * rhs.re,
+        }
+    }
+}
+
+// OK, delegates to `Add`
+#[derive(Clone, Copy)]
+struct Meters(i32);
+
+impl Add for Meters {
+    type Output = Meters;
+
+    fn add(self, rhs: Meters) -> Meters {
+        Meters(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Meters {
+    type Output = Meters;
+
+    fn sub(self, rhs: Meters) -> Meters {
+        self + Meters(-rhs.0)
+    }
+}
+
+fn main() {}
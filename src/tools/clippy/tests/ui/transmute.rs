@@ -109,4 +109,14 @@ fn bytes_to_str(b: &[u8], mb: &mut [u8]) {
     let _: &mut str = unsafe { std::mem::transmute(mb) };
 }
 
+const fn bytes_to_str_const(b: &[u8]) -> &str {
+    unsafe { std::mem::transmute(b) }
+}
+
+#[allow(clippy::needless_lifetimes)]
+#[warn(clippy::useless_transmute)]
+unsafe fn extend_lifetime<'a>(s: &'a [u8]) -> &'static [u8] {
+    std::mem::transmute(s)
+}
+
 fn main() {}
@@ -0,0 +1,25 @@
+#![feature(const_fn_transmute)]
+#![warn(clippy::transmute_bytes_to_num)]
+
+fn bytes_to_num(bytes: [u8; 4], long: [u8; 8]) {
+    let _: u32 = unsafe { std::mem::transmute(bytes) };
+    let _: i32 = unsafe { std::mem::transmute(bytes) };
+    let _: f32 = unsafe { std::mem::transmute(bytes) };
+    let _: u64 = unsafe { std::mem::transmute(long) };
+    let _: f64 = unsafe { std::mem::transmute([0_u8; 8]) };
+
+    // OK, the elements are not bytes
+    let _: u64 = unsafe { std::mem::transmute([0_u16; 4]) };
+    let _: u32 = unsafe { std::mem::transmute([0_i8; 4]) };
+}
+
+mod const_context {
+    const VALUE32: u32 = unsafe { std::mem::transmute([1_u8, 0, 0, 0]) };
+    const FLOAT32: f32 = unsafe { std::mem::transmute([0_u8; 4]) };
+
+    const fn from_bytes_64(bytes: [u8; 8]) -> f64 {
+        unsafe { std::mem::transmute(bytes) }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,43 @@
+// run-rustfix
+#![warn(clippy::unnecessary_clone_for_pattern_binding)]
+#![allow(dead_code)]
+
+#[derive(Clone)]
+struct Pair(String, String);
+
+#[derive(Clone)]
+struct Person {
+    name: String,
+    age: u32,
+    city: String,
+}
+
+fn tuple_struct(p: Pair) -> String {
+    let Pair(a, b) = p.clone();
+    a + &b
+}
+
+fn named_struct(p: Person) -> (String, u32) {
+    let Person { name, age, .. } = p.clone();
+    (name, age)
+}
+
+fn tuple(t: (String, String)) -> String {
+    let (a, _) = t.clone();
+    a
+}
+
+// OK, the original is used afterwards
+fn used_later(p: Pair) -> (String, Pair) {
+    let Pair(a, _) = p.clone();
+    (a, p)
+}
+
+// OK, not destructured
+#[allow(clippy::redundant_clone)]
+fn not_destructured(p: Pair) -> Pair {
+    let q = p.clone();
+    q
+}
+
+fn main() {}
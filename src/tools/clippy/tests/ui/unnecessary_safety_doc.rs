@@ -0,0 +1,70 @@
+#![warn(clippy::missing_safety_doc, clippy::unnecessary_safety_doc)]
+#![allow(dead_code)]
+
+/// # Safety
+///
+/// This function used to be unsafe.
+pub fn safe_fn_with_safety_header() {}
+
+#[doc = "# Safety"]
+#[doc = ""]
+#[doc = "Nothing to uphold here."]
+pub fn safe_fn_with_safety_doc_attr() {}
+
+/**
+ * # Safety
+ *
+ * Nothing to uphold here either.
+ */
+pub fn safe_fn_with_safety_block_doc() {}
+
+#[doc = "Deletes the universe."]
+pub unsafe fn unsafe_fn_without_safety_doc_attr() {}
+
+/** Deletes the universe. */
+pub unsafe fn unsafe_fn_without_safety_block_doc() {}
+
+/// # Safety
+///
+/// Only to be called once.
+#[doc(hidden)]
+pub fn hidden_safe_fn_with_safety_header() {}
+
+#[doc(hidden)]
+pub unsafe fn hidden_unsafe_fn() {}
+
+/// Implementors must be `Send` in disguise.
+pub unsafe trait UnsafeTrait {}
+
+/// # Safety
+///
+/// Implementors must be `Send` in disguise.
+pub unsafe trait DocumentedUnsafeTrait {}
+
+/// # Safety
+///
+/// There is nothing to uphold.
+pub trait SafeTrait {
+    /// # Safety
+    ///
+    /// Can be called at any time.
+    fn safe_method(&self);
+}
+
+pub struct Struct;
+
+impl SafeTrait for Struct {
+    /// # Safety
+    ///
+    /// Methods of trait implementations are not checked.
+    fn safe_method(&self) {}
+}
+
+unsafe impl UnsafeTrait for Struct {}
+
+/// # Safety
+///
+/// Private functions are not checked.
+fn private_safe_fn_with_safety_header() {}
+
+fn main() {}
@@ -0,0 +1,58 @@
+#![warn(clippy::unnecessary_unsafe)]
+#![allow(dead_code, unused_unsafe, clippy::oversized_unsafe_block)]
+
+static mut COUNTER: u32 = 0;
+
+union U {
+    a: u32,
+    b: f32,
+}
+
+unsafe fn dangerous() -> u32 {
+    42
+}
+
+struct S;
+
+impl S {
+    unsafe fn method(&self) -> u32 {
+        42
+    }
+}
+
+macro_rules! safe_in_unsafe {
+    () => {
+        unsafe { 1 + 1 }
+    };
+}
+
+fn main() {
+    let x = 42;
+    let _ = unsafe { x + 1 };
+
+    unsafe {
+        let y = x * 2;
+        println!("{}", y);
+    }
+
+    let u = U { a: 1 };
+    let p = &x as *const i32;
+
+    // OK, these all contain an `unsafe` operation
+    let _ = unsafe { *p };
+    let _ = unsafe { dangerous() };
+    let _ = unsafe { S.method() };
+    let _ = unsafe { COUNTER };
+    let _ = unsafe { u.b };
+    let f = dangerous as unsafe fn() -> u32;
+    let _ = unsafe { f() };
+    let _ = unsafe { [1, 2].iter().map(|i| i + *p).sum::<i32>() };
+
+    // OK, from a macro
+    let _ = safe_in_unsafe!();
+}
+
+// OK, inside an `unsafe fn`
+unsafe fn in_unsafe_fn() -> i32 {
+    unsafe { 1 + 1 }
+}
@@ -30,6 +30,10 @@ fn main() {
     let _fail10: u32 = 0xBAFEBAFE;
     let _fail11 = 0xabcdeff;
     let _fail12: i128 = 0xabcabcabcabcabcabc;
+    let _fail13 = 0o1234567;
+    let _fail14 = 0xFFFFFFu32;
+    let _fail15 = 1234567e1_0;
+    let _good_hex = (0x1F32, 0xFF_FFu32);
 
     let _ = foo!();
 }
@@ -0,0 +1,43 @@
+#![warn(clippy::unsafe_sync_static)]
+#![allow(dead_code)]
+
+use std::cell::{Cell, RefCell};
+use std::sync::atomic::AtomicU64;
+
+struct Stats {
+    hits: Cell<u64>,
+}
+
+unsafe impl Sync for Stats {}
+
+static STATS: Stats = Stats { hits: Cell::new(0) };
+
+struct Registry {
+    names: RefCell<Vec<&'static str>>,
+    len: usize,
+}
+
+unsafe impl Sync for Registry {}
+
+static REGISTRY: Registry = Registry {
+    names: RefCell::new(Vec::new()),
+    len: 0,
+};
+
+// synchronized with an atomic
+struct Counter {
+    value: AtomicU64,
+}
+
+static COUNTER: Counter = Counter {
+    value: AtomicU64::new(0),
+};
+
+// `Sync` without interior mutability
+struct Handle(*const u8);
+
+unsafe impl Sync for Handle {}
+
+static HANDLE: Handle = Handle(std::ptr::null());
+
+fn main() {}
@@ -0,0 +1,58 @@
+#![warn(clippy::unsigned_sub_underflow)]
+#![allow(clippy::identity_op, clippy::len_zero)]
+
+const N: usize = 4;
+
+fn unguarded(v: &[u8], i: usize, j: usize) {
+    let _ = v[i - 1];
+    let _ = v[v.len() - 1];
+    let _ = &v[j..i - j];
+    for _ in 0..v.len() - 1 {}
+    if i < v.len() - 1 {}
+    if v.len() - i > 2 {}
+    if i >= 1 {
+        // guards `i - 1`, not `i - 2`
+        let _ = v[i - 2];
+    }
+    if i > 0 {
+    } else {
+        let _ = v[i - 1];
+    }
+}
+
+fn guarded(v: &[u8], i: usize, j: usize) {
+    if i > 0 {
+        let _ = v[i - 1];
+    }
+    if i != 0 {
+        let _ = v[i - 1];
+    }
+    if i >= 2 && j < v.len() {
+        let _ = v[i - 2];
+    }
+    if i >= j {
+        let _ = &v[..i - j];
+    }
+    if j <= i {
+        let _ = &v[i - j..];
+    }
+    if !v.is_empty() {
+        let _ = v[v.len() - 1];
+    }
+    if v.len() > 0 {
+        let _ = v[v.len() - 1];
+    }
+}
+
+fn not_linted(v: &[u8], i: usize, k: isize) {
+    // provably no underflow
+    let _ = v[N - 1];
+    let _ = v[i - 0];
+    // signed
+    let _ = [0; 4][(k - 1) as usize];
+    // not used for indexing or length math
+    let _ = i - 1;
+    if i - 1 > 2 {}
+}
+
+fn main() {}
@@ -0,0 +1,49 @@
+#![warn(clippy::unused_lazy_value, clippy::unused_combinator_result)]
+#![allow(unused_must_use, clippy::option_map_unit_fn, clippy::result_map_unit_fn)]
+
+fn send(x: i32) -> Result<i32, ()> {
+    Ok(x)
+}
+
+macro_rules! iterate {
+    ($v:expr) => {
+        $v.iter().map(|x| x + 1);
+    };
+}
+
+fn main() {
+    let mut v = vec![1, 2, 3];
+    let x = 42;
+
+    // Lazy values
+    v.iter().map(|x| println!("{}", x));
+    v.iter().cloned();
+    v.iter_mut();
+    (0..10).filter(|x| x % 2 == 0);
+    v.iter().enumerate().skip(1);
+    format!("{}", x);
+
+    // Ignored combinator results
+    let opt = Some(1);
+    opt.map(send);
+    opt.and_then(|x| send(x).ok());
+    let res = send(1);
+    res.ok();
+    res.map_err(|_| "error");
+
+    // OK, consumed or bound
+    v.iter().for_each(|x| println!("{}", x));
+    let _ = v.iter().map(|x| x + 1);
+    let _s = format!("{}", x);
+    let _ = send(1).ok();
+    let mut iter = v.iter();
+    iter.next();
+    iter.by_ref();
+    v.drain(..);
+
+    // OK, `map` returning `()` is linted by `option_map_unit_fn`
+    opt.map(|x| println!("{}", x));
+
+    // OK, in a macro
+    iterate!(v);
+}
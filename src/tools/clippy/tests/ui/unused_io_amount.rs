@@ -22,4 +22,83 @@ fn vectored<T: io::Read + io::Write>(s: &mut T) -> io::Result<()> {
     Ok(())
 }
 
+fn unwrap_or<T: io::Read + io::Write>(s: &mut T) {
+    s.write(b"test").unwrap_or(0);
+    let mut buf = [0u8; 4];
+    s.read(&mut buf).unwrap_or(0);
+}
+
+fn read_in_loop<T: io::Read>(s: &mut T) -> io::Result<Vec<u8>> {
+    let mut out = Vec::new();
+    let mut buf = [0u8; 16];
+    while out.len() < 64 {
+        let n = s.read(&mut buf)?;
+        out.extend_from_slice(&buf[..n]);
+    }
+    for _ in 0..4 {
+        let n = s.read(&mut buf).unwrap();
+        out.extend_from_slice(&buf[..n]);
+    }
+    Ok(out)
+}
+
+// OK, the end of the stream is handled
+fn read_in_loop_eof<T: io::Read>(s: &mut T) -> io::Result<Vec<u8>> {
+    let mut out = Vec::new();
+    let mut buf = [0u8; 16];
+    loop {
+        let n = s.read(&mut buf)?;
+        if n == 0 {
+            return Ok(out);
+        }
+        out.extend_from_slice(&buf[..n]);
+    }
+}
+
+// OK, the end of the stream is handled
+fn read_in_loop_match<T: io::Read>(s: &mut T) -> io::Result<usize> {
+    let mut buf = [0u8; 16];
+    let mut total = 0;
+    loop {
+        let n = s.read(&mut buf)?;
+        match n {
+            0 => return Ok(total),
+            n => total += n,
+        }
+    }
+}
+
+// OK, not in a loop
+fn read_once<T: io::Read>(s: &mut T) -> io::Result<Vec<u8>> {
+    let mut buf = [0u8; 16];
+    let n = s.read(&mut buf)?;
+    Ok(buf[..n].to_vec())
+}
+
+mod futures {
+    use std::io;
+
+    pub trait AsyncRead {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize>;
+    }
+
+    pub trait AsyncWrite {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize>;
+        fn write_vectored(&mut self, bufs: &[io::IoSlice<'_>]) -> io::Result<usize>;
+    }
+}
+
+// OK, these are not the methods of `io::Read` and `io::Write`
+fn not_std_io<T: futures::AsyncRead + futures::AsyncWrite>(s: &mut T) -> io::Result<()> {
+    s.write(b"test")?;
+    s.write_vectored(&[io::IoSlice::new(&[])])?;
+    let mut buf = [0u8; 4];
+    s.read(&mut buf)?;
+    s.read(&mut buf).unwrap();
+    for _ in 0..4 {
+        let _n = s.read(&mut buf)?;
+    }
+    Ok(())
+}
+
 fn main() {}
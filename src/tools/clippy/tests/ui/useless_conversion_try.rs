@@ -1,4 +1,5 @@
 #![deny(clippy::useless_conversion)]
+#![allow(clippy::manual_empty_new, clippy::format_literal)]
 
 use std::convert::{TryFrom, TryInto};
 
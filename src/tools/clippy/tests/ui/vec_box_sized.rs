@@ -1,5 +1,6 @@
 // run-rustfix
 
+#![warn(clippy::vec_box)]
 #![allow(dead_code)]
 
 struct SizedStruct(i32);
@@ -16,6 +17,9 @@ mod should_trigger {
 
     struct A(Vec<Box<SizedStruct>>);
     struct B(Vec<Vec<Box<(u32)>>>);
+
+    type Boxes = Vec<Box<SizedStruct>>;
+    struct E(Boxes);
 }
 
 /// The following should not trigger the lint
@@ -33,6 +37,22 @@ mod should_not_trigger {
         // Regression test for #3720. This was causing an ICE.
         inner: Vec<Box<T>>,
     }
+
+    // The box is needed for the type to be representable
+    struct Node {
+        children: Vec<Box<Node>>,
+    }
+
+    // The same goes for mutually recursive types
+    struct A {
+        v: Vec<Box<B>>,
+    }
+
+    struct B {
+        a: A,
+    }
+
+    struct TraitObjects(Vec<Box<dyn std::fmt::Debug>>);
 }
 
 fn main() {}
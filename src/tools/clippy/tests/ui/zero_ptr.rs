@@ -8,6 +8,7 @@ fn main() {
 
     foo(0 as _, 0 as _);
     foo(0 as *const _, 0 as *mut _);
+    let _ = 0 as usize as *const u32;
 
     let z = 0;
     let _ = z as *const usize; // this is currently not caught